@@ -3,11 +3,21 @@
     feature = "vulkan",
     feature = "metal",
     feature = "dx11",
-    feature = "dx12"
+    feature = "dx12",
+    feature = "software"
 )))]
 compile_error!(
     "You need to enable a graphics backend feature. \
-     Available options: opengl, vulkan, metal, dx11, dx12."
+     Available options: opengl, vulkan, metal, dx11, dx12, software."
+);
+
+#[cfg(all(
+    feature = "recording",
+    not(any(feature = "opengl", feature = "software"))
+))]
+compile_error!(
+    "The recording feature is only supported by the opengl and software \
+     backends."
 );
 
 fn main() {}
@@ -65,6 +65,7 @@ impl Game for Colors {
                 },
                 position: Point::new(0.0, 0.0),
                 size: (500.0, 500.0),
+                ..Quad::default()
             },
             target,
         );
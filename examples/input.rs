@@ -155,6 +155,7 @@ impl Game for InputExample {
                 },
                 position: self.cursor_position - Vector::new(3.0, 3.0),
                 scale: (6.0, 6.0),
+                ..Sprite::default()
             },
             &mut frame.as_target(),
         );
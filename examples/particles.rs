@@ -150,6 +150,7 @@ impl Game for Particles {
                 },
                 position: particle.position + velocity * delta_factor,
                 scale: (1.0, 1.0),
+                ..Sprite::default()
             }
         });
 
@@ -0,0 +1,79 @@
+//! Play sound effects and music.
+//!
+//! Audio in Coffee revolves around two kinds of assets and a [`Mixer`]:
+//!
+//!   * A [`Sound`] is fully decoded when loaded. Use it for short sound
+//!     effects that need to play instantly and often.
+//!   * A [`Music`] keeps its encoded data in memory and is decoded while it
+//!     plays. Use it for long tracks.
+//!
+//! Both [`Sound`] and [`Music`] can be decoded from WAV and OGG Vorbis files,
+//! and they have `load` functions that return a [`Task`], just like
+//! [`Image::load`].
+//!
+//! The [`Mixer`] of your game is available through [`Window::mixer`]. It
+//! plays audio on a fixed amount of channels. Each channel has its own volume,
+//! panning, and looping settings.
+//!
+//! ```no_run
+//! # use coffee::audio::{Mixer, Sound};
+//! # use coffee::graphics::Window;
+//! #
+//! # fn update(window: &Window, jump: &Sound) {
+//! const EFFECTS: usize = 1;
+//!
+//! let mixer = window.mixer();
+//!
+//! mixer.set_volume(EFFECTS, 0.5);
+//! mixer.set_panning(EFFECTS, -0.3);
+//! mixer.play(EFFECTS, jump);
+//! # }
+//! ```
+//!
+//! When no audio device is available, like in continuous integration
+//! machines, or when running a [`Simulation`], the [`Mixer`] uses a null
+//! output device. Everything keeps working, but nothing is heard.
+//!
+//! This module is only available with the `audio` feature.
+//!
+//! [`Mixer`]: struct.Mixer.html
+//! [`Sound`]: struct.Sound.html
+//! [`Music`]: struct.Music.html
+//! [`Task`]: ../load/struct.Task.html
+//! [`Image::load`]: ../graphics/struct.Image.html#method.load
+//! [`Window::mixer`]: ../graphics/struct.Window.html#method.mixer
+//! [`Simulation`]: ../struct.Simulation.html
+mod decoder;
+mod mixer;
+mod music;
+mod output;
+mod sound;
+
+pub use mixer::Mixer;
+pub use music::Music;
+pub use sound::Sound;
+
+use std::fmt;
+
+/// An audio decoding error.
+#[derive(Debug)]
+pub enum Error {
+    /// The data is neither a WAV nor an OGG Vorbis file.
+    UnsupportedFormat,
+
+    /// A WAV file could not be decoded.
+    Wav(hound::Error),
+
+    /// An OGG Vorbis file could not be decoded.
+    Ogg(lewton::VorbisError),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::UnsupportedFormat => write!(f, "Unsupported audio format"),
+            Error::Wav(error) => write!(f, "WAV error: {}", error),
+            Error::Ogg(error) => write!(f, "OGG error: {}", error),
+        }
+    }
+}
@@ -0,0 +1,141 @@
+use std::io::Cursor;
+use std::sync::Arc;
+
+use lewton::inside_ogg::OggStreamReader;
+
+use crate::audio::Error;
+
+/// A stereo audio frame.
+pub type Frame = [f32; 2];
+
+/// Decodes WAV or OGG Vorbis data into stereo frames, one at a time.
+pub struct Decoder {
+    format: Format,
+    sample_rate: u32,
+}
+
+enum Format {
+    Wav {
+        reader: hound::WavReader<Cursor<Arc<[u8]>>>,
+        channels: usize,
+        scale: Option<f32>,
+    },
+    Ogg {
+        reader: OggStreamReader<Cursor<Arc<[u8]>>>,
+        channels: usize,
+        packet: Vec<i16>,
+        position: usize,
+    },
+}
+
+impl Decoder {
+    pub fn new(bytes: Arc<[u8]>) -> Result<Decoder, Error> {
+        if bytes.starts_with(b"RIFF") {
+            let reader = hound::WavReader::new(Cursor::new(bytes))
+                .map_err(Error::Wav)?;
+
+            let spec = reader.spec();
+
+            // Integer samples are normalized, float samples are already in
+            // [-1.0, 1.0]
+            let scale = match spec.sample_format {
+                hound::SampleFormat::Int => {
+                    Some((1u64 << (spec.bits_per_sample - 1)) as f32)
+                }
+                hound::SampleFormat::Float => None,
+            };
+
+            Ok(Decoder {
+                sample_rate: spec.sample_rate,
+                format: Format::Wav {
+                    reader,
+                    channels: spec.channels as usize,
+                    scale,
+                },
+            })
+        } else if bytes.starts_with(b"OggS") {
+            let reader =
+                OggStreamReader::new(Cursor::new(bytes)).map_err(Error::Ogg)?;
+
+            Ok(Decoder {
+                sample_rate: reader.ident_hdr.audio_sample_rate,
+                format: Format::Ogg {
+                    channels: reader.ident_hdr.audio_channels as usize,
+                    reader,
+                    packet: Vec::new(),
+                    position: 0,
+                },
+            })
+        } else {
+            Err(Error::UnsupportedFormat)
+        }
+    }
+
+    pub fn sample_rate(&self) -> u32 {
+        self.sample_rate
+    }
+
+    /// Decodes the next frame.
+    ///
+    /// Mono audio is played on both sides and any channel after the second
+    /// one is dropped. A decoding error ends the stream.
+    pub fn next_frame(&mut self) -> Option<Frame> {
+        match &mut self.format {
+            Format::Wav {
+                reader,
+                channels,
+                scale,
+            } => {
+                let mut samples = [0.0; 2];
+
+                for i in 0..*channels {
+                    let sample = match scale {
+                        Some(scale) => {
+                            reader.samples::<i32>().next()?.ok()? as f32
+                                / *scale
+                        }
+                        None => reader.samples::<f32>().next()?.ok()?,
+                    };
+
+                    if i < 2 {
+                        samples[i] = sample;
+                    }
+                }
+
+                Some(into_stereo(samples, *channels))
+            }
+            Format::Ogg {
+                reader,
+                channels,
+                packet,
+                position,
+            } => {
+                while *position + *channels > packet.len() {
+                    *packet = reader.read_dec_packet_itl().ok()??;
+                    *position = 0;
+                }
+
+                let mut samples = [0.0; 2];
+
+                for (i, sample) in samples.iter_mut().enumerate() {
+                    if i < *channels {
+                        *sample = f32::from(packet[*position + i])
+                            / f32::from(std::i16::MAX);
+                    }
+                }
+
+                *position += *channels;
+
+                Some(into_stereo(samples, *channels))
+            }
+        }
+    }
+}
+
+fn into_stereo(samples: [f32; 2], channels: usize) -> Frame {
+    if channels == 1 {
+        [samples[0], samples[0]]
+    } else {
+        samples
+    }
+}
@@ -0,0 +1,414 @@
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use crate::audio::decoder::Frame;
+use crate::audio::music::{Refill, Stream};
+use crate::audio::output::Output;
+use crate::audio::sound::Track;
+use crate::audio::{Music, Sound};
+
+/// A mixer that plays audio on multiple channels.
+///
+/// A [`Mixer`] has [`Mixer::CHANNELS`] channels, identified by their index.
+/// Every channel plays at most one [`Sound`] or [`Music`] at a time, and has
+/// its own volume, panning, and looping settings. These settings persist
+/// between plays.
+///
+/// The [`Mixer`] of your game is available through [`Window::mixer`].
+///
+/// All the methods of a [`Mixer`] panic if given a channel index that is
+/// not less than [`Mixer::CHANNELS`].
+///
+/// [`Mixer`]: struct.Mixer.html
+/// [`Mixer::CHANNELS`]: struct.Mixer.html#associatedconstant.CHANNELS
+/// [`Sound`]: struct.Sound.html
+/// [`Music`]: struct.Music.html
+/// [`Window::mixer`]: ../graphics/struct.Window.html#method.mixer
+pub struct Mixer {
+    state: Arc<Mutex<State>>,
+    output: Output,
+}
+
+impl Mixer {
+    /// The amount of channels of a [`Mixer`].
+    ///
+    /// [`Mixer`]: struct.Mixer.html
+    pub const CHANNELS: usize = 16;
+
+    /// Creates a [`Mixer`] connected to the default audio device.
+    ///
+    /// It falls back to a null output device if no audio device is
+    /// available.
+    ///
+    /// [`Mixer`]: struct.Mixer.html
+    pub(crate) fn new() -> Mixer {
+        let state = Arc::new(Mutex::new(State::new()));
+        let output = Output::new(state.clone());
+
+        Mixer { state, output }
+    }
+
+    /// Creates a [`Mixer`] connected to a null output device.
+    ///
+    /// Nothing is heard, but the mixed audio can be read with
+    /// [`Mixer::read`]. It plays at 44100 Hz.
+    ///
+    /// [`Mixer`]: struct.Mixer.html
+    /// [`Mixer::read`]: struct.Mixer.html#method.read
+    #[cfg(feature = "software")]
+    pub fn null() -> Mixer {
+        Mixer {
+            state: Arc::new(Mutex::new(State::new())),
+            output: Output::Null,
+        }
+    }
+
+    /// Mixes the next frames of all the channels into the given interleaved
+    /// buffer, like an audio device would.
+    ///
+    /// This is useful to test the audio of a [`Simulation`]. Reading from a
+    /// [`Mixer`] connected to an audio device steals audio from it.
+    ///
+    /// [`Simulation`]: ../struct.Simulation.html
+    /// [`Mixer`]: struct.Mixer.html
+    #[cfg(feature = "software")]
+    pub fn read(&self, buffer: &mut [f32], channels: usize) {
+        mix(&self.state, buffer, channels);
+    }
+
+    /// Plays a [`Sound`] on the given channel, replacing whatever it was
+    /// playing.
+    ///
+    /// [`Sound`]: struct.Sound.html
+    pub fn play(&self, channel: usize, sound: &Sound) {
+        self.channel(channel).voice = Some(Voice::new(
+            Source::Sound {
+                track: sound.track.clone(),
+                position: 0,
+            },
+            sound.track.sample_rate,
+        ));
+    }
+
+    /// Plays a [`Music`] track on the given channel, replacing whatever it
+    /// was playing.
+    ///
+    /// [`Music`]: struct.Music.html
+    pub fn play_music(&self, channel: usize, music: &Music) {
+        // Decoding starts before locking, like the rest of the decoding
+        let stream = Stream::new(music);
+        let sample_rate = stream.sample_rate();
+
+        self.channel(channel).voice =
+            Some(Voice::new(Source::Music(stream), sample_rate));
+    }
+
+    /// Stops the audio playing on the given channel, if any.
+    pub fn stop(&self, channel: usize) {
+        self.channel(channel).voice = None;
+    }
+
+    /// Returns true if the given channel is playing some audio.
+    pub fn is_playing(&self, channel: usize) -> bool {
+        self.channel(channel).voice.is_some()
+    }
+
+    /// Sets the volume of the given channel.
+    ///
+    /// A volume of `0.0` is silent and a volume of `1.0` plays audio at its
+    /// original volume. By default, channels have a volume of `1.0`.
+    pub fn set_volume(&self, channel: usize, volume: f32) {
+        self.channel(channel).volume = volume.max(0.0);
+    }
+
+    /// Sets the panning of the given channel.
+    ///
+    /// A panning of `-1.0` plays audio only on the left side, while `1.0`
+    /// plays it only on the right side. By default, channels have a panning
+    /// of `0.0`, which is centered.
+    pub fn set_panning(&self, channel: usize, panning: f32) {
+        self.channel(channel).panning = panning.max(-1.0).min(1.0);
+    }
+
+    /// Sets whether the given channel restarts its audio when it ends.
+    ///
+    /// By default, channels do not loop.
+    pub fn set_looping(&self, channel: usize, is_looping: bool) {
+        self.channel(channel).is_looping = is_looping;
+    }
+
+    /// Sets the volume applied to all the channels.
+    ///
+    /// By default, it is `1.0`.
+    pub fn set_master_volume(&self, volume: f32) {
+        self.state().master_volume = volume.max(0.0);
+    }
+
+    fn state(&self) -> MutexGuard<'_, State> {
+        // The audio thread never panics while holding the lock
+        self.state.lock().expect("Lock mixer state")
+    }
+
+    fn channel(&self, index: usize) -> ChannelGuard<'_> {
+        assert!(index < Mixer::CHANNELS, "Invalid channel: {}", index);
+
+        ChannelGuard {
+            state: self.state(),
+            index,
+        }
+    }
+}
+
+impl std::fmt::Debug for Mixer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Mixer {{ output: {:?} }}", self.output)
+    }
+}
+
+struct ChannelGuard<'a> {
+    state: MutexGuard<'a, State>,
+    index: usize,
+}
+
+impl<'a> std::ops::Deref for ChannelGuard<'a> {
+    type Target = Channel;
+
+    fn deref(&self) -> &Channel {
+        &self.state.channels[self.index]
+    }
+}
+
+impl<'a> std::ops::DerefMut for ChannelGuard<'a> {
+    fn deref_mut(&mut self) -> &mut Channel {
+        &mut self.state.channels[self.index]
+    }
+}
+
+pub(super) struct State {
+    channels: Vec<Channel>,
+    master_volume: f32,
+    sample_rate: u32,
+}
+
+impl State {
+    fn new() -> State {
+        State {
+            channels: (0..Mixer::CHANNELS).map(|_| Channel::new()).collect(),
+            master_volume: 1.0,
+            sample_rate: 44_100,
+        }
+    }
+
+    pub fn set_sample_rate(&mut self, sample_rate: u32) {
+        self.sample_rate = sample_rate;
+    }
+
+    /// Takes the decoding needed by the music playing on every channel to
+    /// mix the given amount of frames.
+    fn refills(&mut self, frames: usize) -> Vec<(usize, Refill)> {
+        let output_sample_rate = u64::from(self.sample_rate.max(1));
+        let mut refills = Vec::new();
+
+        for (index, channel) in self.channels.iter_mut().enumerate() {
+            let is_looping = channel.is_looping;
+
+            if let Some(Voice {
+                source: Source::Music(stream),
+                sample_rate,
+                ..
+            }) = &mut channel.voice
+            {
+                // Resampling consumes frames at a different pace
+                let needed = frames as u64 * u64::from(*sample_rate)
+                    / output_sample_rate;
+
+                if let Some(refill) =
+                    stream.refill(needed as usize + 2, is_looping)
+                {
+                    refills.push((index, refill));
+                }
+            }
+        }
+
+        refills
+    }
+
+    fn finish_refills(&mut self, refills: Vec<(usize, Refill)>) {
+        for (index, refill) in refills {
+            if let Some(Voice {
+                source: Source::Music(stream),
+                ..
+            }) = &mut self.channels[index].voice
+            {
+                stream.finish_refill(refill);
+            }
+        }
+    }
+
+    /// Mixes all the channels into the given interleaved buffer.
+    fn mix(&mut self, buffer: &mut [f32], output_channels: usize) {
+        let sample_rate = self.sample_rate;
+        let master_volume = self.master_volume;
+
+        for samples in buffer.chunks_mut(output_channels) {
+            let mut frame = [0.0, 0.0];
+
+            for channel in self.channels.iter_mut() {
+                if let Some(mixed) = channel.next_frame(sample_rate) {
+                    frame[0] += mixed[0];
+                    frame[1] += mixed[1];
+                }
+            }
+
+            for (i, sample) in samples.iter_mut().enumerate() {
+                *sample = match (i, output_channels) {
+                    (0, 1) => (frame[0] + frame[1]) * 0.5,
+                    (0, _) | (1, _) => frame[i],
+                    _ => 0.0,
+                };
+
+                *sample = (*sample * master_volume).max(-1.0).min(1.0);
+            }
+        }
+    }
+}
+
+struct Channel {
+    voice: Option<Voice>,
+    volume: f32,
+    panning: f32,
+    is_looping: bool,
+}
+
+impl Channel {
+    fn new() -> Channel {
+        Channel {
+            voice: None,
+            volume: 1.0,
+            panning: 0.0,
+            is_looping: false,
+        }
+    }
+
+    fn next_frame(&mut self, sample_rate: u32) -> Option<Frame> {
+        let frame = self
+            .voice
+            .as_mut()?
+            .next_frame(sample_rate, self.is_looping);
+
+        match frame {
+            Some([left, right]) => {
+                let left_gain = (1.0 - self.panning).min(1.0);
+                let right_gain = (1.0 + self.panning).min(1.0);
+
+                Some([
+                    left * left_gain * self.volume,
+                    right * right_gain * self.volume,
+                ])
+            }
+            None => {
+                self.voice = None;
+
+                None
+            }
+        }
+    }
+}
+
+/// Audio that is being played, resampled to the output sample rate using
+/// linear interpolation.
+struct Voice {
+    source: Source,
+    sample_rate: u32,
+    current: Option<Frame>,
+    next: Option<Frame>,
+    offset: f64,
+}
+
+impl Voice {
+    fn new(mut source: Source, sample_rate: u32) -> Voice {
+        let current = source.next_frame(false);
+        let next = source.next_frame(false);
+
+        Voice {
+            source,
+            sample_rate,
+            current,
+            next,
+            offset: 0.0,
+        }
+    }
+
+    fn next_frame(
+        &mut self,
+        output_sample_rate: u32,
+        is_looping: bool,
+    ) -> Option<Frame> {
+        let current = self.current?;
+        let next = self.next.unwrap_or(current);
+        let t = self.offset as f32;
+
+        let frame = [
+            current[0] + (next[0] - current[0]) * t,
+            current[1] + (next[1] - current[1]) * t,
+        ];
+
+        self.offset +=
+            f64::from(self.sample_rate) / f64::from(output_sample_rate);
+
+        while self.offset >= 1.0 && self.current.is_some() {
+            self.offset -= 1.0;
+            self.current = self.next;
+            self.next = self.source.next_frame(is_looping);
+        }
+
+        Some(frame)
+    }
+}
+
+enum Source {
+    Sound { track: Arc<Track>, position: usize },
+    Music(Stream),
+}
+
+impl Source {
+    fn next_frame(&mut self, is_looping: bool) -> Option<Frame> {
+        match self {
+            Source::Sound { track, position } => {
+                if *position >= track.frames.len() && is_looping {
+                    *position = 0;
+                }
+
+                let frame = track.frames.get(*position).cloned()?;
+                *position += 1;
+
+                Some(frame)
+            }
+            // Music loops while decoding
+            Source::Music(stream) => stream.next_frame(),
+        }
+    }
+}
+
+/// Mixes the given state into an interleaved buffer.
+///
+/// Music is decoded beforehand without holding the lock, so decoding never
+/// blocks the methods of a [`Mixer`].
+///
+/// [`Mixer`]: struct.Mixer.html
+pub(super) fn mix(state: &Mutex<State>, buffer: &mut [f32], channels: usize) {
+    let frames = buffer.len() / channels.max(1);
+
+    let mut refills = match state.lock() {
+        Ok(mut state) => state.refills(frames),
+        Err(_) => return,
+    };
+
+    for (_, refill) in refills.iter_mut() {
+        refill.run();
+    }
+
+    if let Ok(mut state) = state.lock() {
+        state.finish_refills(refills);
+        state.mix(buffer, channels);
+    }
+}
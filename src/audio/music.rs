@@ -0,0 +1,186 @@
+use std::collections::VecDeque;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use crate::audio::decoder::{Decoder, Frame};
+use crate::load::Task;
+use crate::Result;
+
+/// A music track.
+///
+/// A [`Music`] keeps the encoded data of a WAV or OGG Vorbis file in memory
+/// and decodes it while it plays. This uses considerably less memory than a
+/// [`Sound`] for long tracks.
+///
+/// Cloning a [`Music`] is cheap, it only clones a handle. It does not create
+/// a new copy of the encoded data.
+///
+/// [`Music`]: struct.Music.html
+/// [`Sound`]: struct.Sound.html
+#[derive(Clone)]
+pub struct Music {
+    bytes: Arc<[u8]>,
+}
+
+impl Music {
+    /// Loads a [`Music`] track from the given WAV or OGG Vorbis file.
+    ///
+    /// [`Music`]: struct.Music.html
+    pub fn new<P: AsRef<Path>>(path: P) -> Result<Music> {
+        let bytes = std::fs::read(path)?;
+
+        Music::from_bytes(&bytes)
+    }
+
+    /// Creates a [`Task`] that loads a [`Music`] track from the given WAV or
+    /// OGG Vorbis file.
+    ///
+    /// [`Task`]: ../load/struct.Task.html
+    /// [`Music`]: struct.Music.html
+    pub fn load<P: Into<PathBuf>>(path: P) -> Task<Music> {
+        let p = path.into();
+
+        Task::new(move || Music::new(&p))
+    }
+
+    /// Creates a [`Music`] track from the raw data of a WAV or OGG Vorbis
+    /// file.
+    ///
+    /// The headers of the data are validated, but the audio itself is only
+    /// decoded when played.
+    ///
+    /// [`Music`]: struct.Music.html
+    pub fn from_bytes(bytes: &[u8]) -> Result<Music> {
+        let bytes: Arc<[u8]> = Arc::from(bytes);
+        let _ = Decoder::new(bytes.clone())?;
+
+        Ok(Music { bytes })
+    }
+
+    pub(super) fn decoder(&self) -> Decoder {
+        Decoder::new(self.bytes.clone())
+            .expect("Music data is validated on creation")
+    }
+}
+
+impl std::fmt::Debug for Music {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Music {{ size: {} }}", self.bytes.len())
+    }
+}
+
+/// A [`Music`] track being played.
+///
+/// Its frames are decoded ahead of time by a `Refill`, which runs while the
+/// mixer is unlocked.
+///
+/// [`Music`]: struct.Music.html
+pub(super) struct Stream {
+    music: Music,
+    decoder: Option<Decoder>,
+    frames: VecDeque<Frame>,
+    sample_rate: u32,
+    is_finished: bool,
+}
+
+impl Stream {
+    pub fn new(music: &Music) -> Stream {
+        let mut decoder = music.decoder();
+        let sample_rate = decoder.sample_rate();
+
+        // A voice needs two frames to start playing
+        let frames = (0..2).filter_map(|_| decoder.next_frame()).collect();
+
+        Stream {
+            music: music.clone(),
+            decoder: Some(decoder),
+            frames,
+            sample_rate,
+            is_finished: false,
+        }
+    }
+
+    pub fn sample_rate(&self) -> u32 {
+        self.sample_rate
+    }
+
+    pub fn next_frame(&mut self) -> Option<Frame> {
+        match self.frames.pop_front() {
+            Some(frame) => Some(frame),
+            None if self.is_finished => None,
+            // Decoding fell behind, keep playing silence until it catches up
+            None => Some([0.0, 0.0]),
+        }
+    }
+
+    /// Takes the decoder of the stream to have at least the given amount of
+    /// frames ready.
+    ///
+    /// It returns `None` if enough frames are ready already.
+    pub fn refill(
+        &mut self,
+        frames: usize,
+        is_looping: bool,
+    ) -> Option<Refill> {
+        if self.is_finished || self.frames.len() >= frames {
+            return None;
+        }
+
+        let decoder = self.decoder.take()?;
+
+        Some(Refill {
+            music: self.music.clone(),
+            decoder,
+            frames: Vec::new(),
+            amount: frames - self.frames.len(),
+            is_looping,
+            is_finished: false,
+        })
+    }
+
+    /// Gives back the decoder of a `Refill`, along with its frames.
+    pub fn finish_refill(&mut self, refill: Refill) {
+        // Only a refilled stream lacks its decoder. Otherwise, another stream
+        // replaced it while decoding.
+        if self.decoder.is_none() {
+            self.decoder = Some(refill.decoder);
+            self.frames.extend(refill.frames);
+            self.is_finished = refill.is_finished;
+        }
+    }
+}
+
+/// The decoding of the next frames of a `Stream`.
+pub(super) struct Refill {
+    music: Music,
+    decoder: Decoder,
+    frames: Vec<Frame>,
+    amount: usize,
+    is_looping: bool,
+    is_finished: bool,
+}
+
+impl Refill {
+    /// Decodes the missing frames, restarting the track if looping.
+    pub fn run(&mut self) {
+        let mut is_restarted = false;
+
+        while self.frames.len() < self.amount {
+            match self.decoder.next_frame() {
+                Some(frame) => {
+                    self.frames.push(frame);
+                    is_restarted = false;
+                }
+                // A track without frames would restart forever
+                None if self.is_looping && !is_restarted => {
+                    self.decoder = self.music.decoder();
+                    is_restarted = true;
+                }
+                None => {
+                    self.is_finished = true;
+                    break;
+                }
+            }
+        }
+    }
+}
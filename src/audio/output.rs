@@ -0,0 +1,102 @@
+use std::sync::{Arc, Mutex};
+
+use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
+
+use crate::audio::mixer::{self, State};
+
+/// The device where the mixed audio ends up.
+pub enum Output {
+    /// A stream playing on the default audio device.
+    ///
+    /// The stream runs its own audio thread, which stops when the stream is
+    /// dropped.
+    Device(cpal::Stream),
+    Null,
+}
+
+impl Output {
+    /// Starts streaming the given mixer state to the default audio device.
+    ///
+    /// It returns a null output if no audio device is available.
+    pub fn new(state: Arc<Mutex<State>>) -> Output {
+        let device = match cpal::default_host().default_output_device() {
+            Some(device) => device,
+            None => return Output::Null,
+        };
+
+        let format = match device.default_output_format() {
+            Ok(format) => format,
+            Err(_) => return Output::Null,
+        };
+
+        if let Ok(mut state) = state.lock() {
+            state.set_sample_rate(format.sample_rate.0);
+        }
+
+        let channels = format.channels as usize;
+        let mut buffer = Vec::new();
+
+        let stream = device.build_output_stream(
+            &format,
+            move |data| {
+                if let cpal::StreamData::Output { buffer: output } = data {
+                    write(&state, &mut buffer, channels, output);
+                }
+            },
+            // The device may disappear while playing, in which case we just
+            // stop hearing anything
+            |_| {},
+        );
+
+        match stream {
+            Ok(stream) => match stream.play() {
+                Ok(()) => Output::Device(stream),
+                Err(_) => Output::Null,
+            },
+            Err(_) => Output::Null,
+        }
+    }
+}
+
+impl std::fmt::Debug for Output {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Output::Device(_) => write!(f, "Device"),
+            Output::Null => write!(f, "Null"),
+        }
+    }
+}
+
+fn write(
+    state: &Mutex<State>,
+    buffer: &mut Vec<f32>,
+    channels: usize,
+    output: cpal::UnknownTypeOutputBuffer<'_>,
+) {
+    let len = match &output {
+        cpal::UnknownTypeOutputBuffer::U16(output) => output.len(),
+        cpal::UnknownTypeOutputBuffer::I16(output) => output.len(),
+        cpal::UnknownTypeOutputBuffer::F32(output) => output.len(),
+    };
+
+    buffer.clear();
+    buffer.resize(len, 0.0);
+
+    mixer::mix(state, buffer, channels);
+
+    match output {
+        cpal::UnknownTypeOutputBuffer::U16(mut output) => {
+            for (out, sample) in output.iter_mut().zip(buffer.iter()) {
+                *out = ((sample + 1.0) * 0.5 * f32::from(std::u16::MAX)) as u16;
+            }
+        }
+        cpal::UnknownTypeOutputBuffer::I16(mut output) => {
+            for (out, sample) in output.iter_mut().zip(buffer.iter()) {
+                *out = (sample * f32::from(std::i16::MAX)) as i16;
+            }
+        }
+        cpal::UnknownTypeOutputBuffer::F32(mut output) => {
+            output.copy_from_slice(buffer);
+        }
+    }
+}
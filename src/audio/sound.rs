@@ -0,0 +1,84 @@
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::audio::decoder::{Decoder, Frame};
+use crate::load::Task;
+use crate::Result;
+
+/// A decoded sound effect.
+///
+/// A [`Sound`] is fully decoded into memory when loaded, which makes it ready
+/// to be played instantly. Use a [`Music`] for long tracks instead.
+///
+/// Cloning a [`Sound`] is cheap, it only clones a handle. It does not create
+/// a new copy of the decoded audio.
+///
+/// [`Sound`]: struct.Sound.html
+/// [`Music`]: struct.Music.html
+#[derive(Clone)]
+pub struct Sound {
+    pub(super) track: Arc<Track>,
+}
+
+pub(super) struct Track {
+    pub sample_rate: u32,
+    pub frames: Vec<Frame>,
+}
+
+impl Sound {
+    /// Loads a [`Sound`] from the given WAV or OGG Vorbis file.
+    ///
+    /// [`Sound`]: struct.Sound.html
+    pub fn new<P: AsRef<Path>>(path: P) -> Result<Sound> {
+        let bytes = std::fs::read(path)?;
+
+        Sound::from_bytes(&bytes)
+    }
+
+    /// Creates a [`Task`] that loads a [`Sound`] from the given WAV or OGG
+    /// Vorbis file.
+    ///
+    /// [`Task`]: ../load/struct.Task.html
+    /// [`Sound`]: struct.Sound.html
+    pub fn load<P: Into<PathBuf>>(path: P) -> Task<Sound> {
+        let p = path.into();
+
+        Task::new(move || Sound::new(&p))
+    }
+
+    /// Decodes a [`Sound`] from the raw data of a WAV or OGG Vorbis file.
+    ///
+    /// [`Sound`]: struct.Sound.html
+    pub fn from_bytes(bytes: &[u8]) -> Result<Sound> {
+        let mut decoder = Decoder::new(Arc::from(bytes))?;
+        let mut frames = Vec::new();
+
+        while let Some(frame) = decoder.next_frame() {
+            frames.push(frame);
+        }
+
+        Ok(Sound {
+            track: Arc::new(Track {
+                sample_rate: decoder.sample_rate(),
+                frames,
+            }),
+        })
+    }
+
+    /// Returns the duration of the [`Sound`].
+    ///
+    /// [`Sound`]: struct.Sound.html
+    pub fn duration(&self) -> Duration {
+        let frames = self.track.frames.len() as u64;
+        let sample_rate = u64::from(self.track.sample_rate.max(1));
+
+        Duration::from_micros(frames * 1_000_000 / sample_rate)
+    }
+}
+
+impl std::fmt::Debug for Sound {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Sound {{ duration: {:?} }}", self.duration())
+    }
+}
@@ -1,6 +1,7 @@
 use std::time;
 
-use crate::graphics;
+use crate::graphics::{self, capture};
+use crate::{Error, Result};
 
 /// A bunch of performance information about your game. It can be drawn!
 ///
@@ -36,6 +37,9 @@ pub struct Debug {
     text: Vec<(String, String)>,
     draw_rate: u16,
     frames_until_refresh: u16,
+    capture: Option<capture::Recorder>,
+    capture_toggle: Option<capture::Settings>,
+    capture_error: Option<Error>,
 }
 
 impl Debug {
@@ -63,6 +67,9 @@ impl Debug {
             text: Vec::new(),
             draw_rate: 10,
             frames_until_refresh: 0,
+            capture: None,
+            capture_toggle: None,
+            capture_error: None,
         }
     }
 
@@ -167,6 +174,45 @@ impl Debug {
         self.frames_until_refresh = 0;
     }
 
+    pub(crate) fn toggle_capture(&mut self, settings: capture::Settings) {
+        self.capture_toggle = Some(settings);
+    }
+
+    /// Captures the given frame, if a capture is in progress.
+    ///
+    /// A failing capture is stopped and its error is shown in the debug
+    /// view, but the game keeps running.
+    pub(crate) fn capture(&mut self, frame: &mut graphics::Frame<'_>) {
+        if let Err(error) = self.try_capture(frame) {
+            self.capture = None;
+            self.capture_error = Some(error);
+            self.frames_until_refresh = 0;
+        }
+    }
+
+    /// Returns the error that stopped the last frame capture, if any.
+    pub fn capture_error(&self) -> Option<&Error> {
+        self.capture_error.as_ref()
+    }
+
+    fn try_capture(&mut self, frame: &mut graphics::Frame<'_>) -> Result<()> {
+        if let Some(settings) = self.capture_toggle.take() {
+            match self.capture.take() {
+                Some(mut recorder) => recorder.finish()?,
+                None => {
+                    self.capture_error = None;
+                    self.capture = Some(capture::Recorder::new(settings)?);
+                }
+            }
+        }
+
+        if let Some(recorder) = &mut self.capture {
+            recorder.capture(frame)?;
+        }
+
+        Ok(())
+    }
+
     pub(crate) fn debug_started(&mut self) {
         self.debug_start = time::Instant::now();
     }
@@ -231,6 +277,13 @@ impl Debug {
 
             self.text.push((String::from(*title), formatted_duration));
         }
+
+        if let Some(error) = &self.capture_error {
+            self.text.push((
+                String::from("Capture:"),
+                format!("Stopped, {}", error),
+            ));
+        }
     }
 
     fn draw_text(&mut self, frame: &mut graphics::Frame<'_>) {
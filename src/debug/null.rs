@@ -1,4 +1,4 @@
-use crate::graphics;
+use crate::graphics::{self, capture};
 
 // Null debug implementation
 #[cfg(not(debug_assertions))]
@@ -30,6 +30,11 @@ impl Debug {
     #[allow(dead_code)]
     pub(crate) fn toggle(&mut self) {}
 
+    #[allow(dead_code)]
+    pub(crate) fn toggle_capture(&mut self, _settings: capture::Settings) {}
+
+    pub(crate) fn capture(&mut self, _frame: &mut graphics::Frame<'_>) {}
+
     pub(crate) fn is_enabled(&self) -> bool {
         false
     }
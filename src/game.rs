@@ -1,6 +1,6 @@
 use crate::graphics::window;
-use crate::graphics::{Frame, Window, WindowSettings};
-use crate::input::{self, gamepad, keyboard, mouse, Input};
+use crate::graphics::{capture, Frame, Window, WindowSettings};
+use crate::input::{self, gamepad, keyboard, mouse, Input, Recording};
 use crate::load::{LoadingScreen, Task};
 use crate::{Debug, Result, Timer};
 
@@ -56,6 +56,23 @@ pub trait Game {
     /// [`debug`]: #method.debug
     const DEBUG_KEY: Option<keyboard::KeyCode> = Some(keyboard::KeyCode::F12);
 
+    /// Defines the key that will be used to start and stop capturing frames.
+    /// Set it to `None` if you want to disable it.
+    ///
+    /// Frames are captured before the [`debug`] view is drawn, so it never
+    /// shows up in captures. Use [`capture_settings`] to choose how and where
+    /// frames are saved. If saving a frame fails, the capture stops and the
+    /// error is printed, but the game keeps running.
+    ///
+    /// Like the [`debug`] view, capturing is only available when compiling
+    /// with `debug_assertions` _or_ the `debug` feature enabled.
+    ///
+    /// By default, it is set to `F11`.
+    ///
+    /// [`debug`]: #method.debug
+    /// [`capture_settings`]: #method.capture_settings
+    const CAPTURE_KEY: Option<keyboard::KeyCode> = Some(keyboard::KeyCode::F11);
+
     /// Loads the [`Game`].
     ///
     /// Use the [`load`] module to load your assets here.
@@ -144,6 +161,18 @@ pub trait Game {
         debug.draw(frame);
     }
 
+    /// Returns the [`capture::Settings`] used when the [`CAPTURE_KEY`] starts a
+    /// new capture.
+    ///
+    /// By default, it returns the default [`capture::Settings`], which save
+    /// every other frame as a PNG sequence in a `captures` directory.
+    ///
+    /// [`capture::Settings`]: graphics/capture/struct.Settings.html
+    /// [`CAPTURE_KEY`]: #associatedconstant.CAPTURE_KEY
+    fn capture_settings(&self) -> capture::Settings {
+        capture::Settings::default()
+    }
+
     /// Handles a close request from the operating system to the game window.
     ///
     /// This function should return true to allow the game loop to end,
@@ -173,73 +202,129 @@ pub trait Game {
     where
         Self: Sized + 'static,
     {
-        // Set up window
-        let event_loop = &mut window::EventLoop::new();
-        let window = &mut Window::new(window_settings, &event_loop)?;
-        let mut debug = Debug::new(window.gpu());
-
-        // Load game
-        debug.loading_started();
-        let mut loading_screen = Self::LoadingScreen::new(window.gpu())?;
-        let game = &mut loading_screen.run(Self::load(window), window)?;
-        let input = &mut Self::Input::new();
-        let mut gamepads = gamepad::Tracker::new();
-        debug.loading_finished();
-
-        // Game loop
-        let mut timer = Timer::new(Self::TICKS_PER_SECOND);
-        let mut alive = true;
-
-        while alive && !game.is_finished() {
-            debug.frame_started();
-            timer.update();
-
-            while timer.tick() {
-                interact(
-                    game,
-                    input,
-                    &mut debug,
-                    window,
-                    event_loop,
-                    gamepads.as_mut(),
-                    &mut alive,
-                );
-
-                debug.update_started();
-                game.update(window);
-                debug.update_finished();
-            }
+        run::<Self>(window_settings, &mut input::Tape::live())
+    }
 
-            if !timer.has_ticked() {
-                interact(
-                    game,
-                    input,
-                    &mut debug,
-                    window,
-                    event_loop,
-                    gamepads.as_mut(),
-                    &mut alive,
-                );
-            }
+    /// Runs the [`Game`] with the given [`WindowSettings`] while recording its
+    /// input.
+    ///
+    /// Every input event is logged together with the tick it was processed
+    /// on. The resulting [`Recording`] is returned once the game loop ends, and
+    /// you can play it back using [`replay`].
+    ///
+    /// [`Game`]: trait.Game.html
+    /// [`WindowSettings`]: graphics/struct.WindowSettings.html
+    /// [`Recording`]: input/struct.Recording.html
+    /// [`replay`]: #method.replay
+    fn record(window_settings: WindowSettings) -> Result<Recording>
+    where
+        Self: Sized + 'static,
+    {
+        let mut tape = input::Tape::record();
 
-            debug.draw_started();
-            game.draw(&mut window.frame(), &timer);
-            debug.draw_finished();
+        run::<Self>(window_settings, &mut tape)?;
 
-            if debug.is_enabled() {
-                debug.debug_started();
-                game.debug(input, &mut window.frame(), &mut debug);
-                debug.debug_finished();
-            }
+        Ok(tape.into_recording().unwrap_or_default())
+    }
 
-            window.swap_buffers();
-            debug.frame_finished();
+    /// Runs the [`Game`] with the given [`WindowSettings`], feeding it the
+    /// input events of a [`Recording`] instead of the live ones.
+    ///
+    /// The recorded events are processed on the same ticks they were recorded
+    /// on. Once the [`Recording`] is exhausted, the [`Game`] keeps running
+    /// without any input until the window is closed.
+    ///
+    /// [`Game`]: trait.Game.html
+    /// [`WindowSettings`]: graphics/struct.WindowSettings.html
+    /// [`Recording`]: input/struct.Recording.html
+    fn replay(
+        window_settings: WindowSettings,
+        recording: Recording,
+    ) -> Result<()>
+    where
+        Self: Sized + 'static,
+    {
+        run::<Self>(window_settings, &mut input::Tape::replay(recording))
+    }
+}
+
+fn run<G: Game + 'static>(
+    window_settings: WindowSettings,
+    tape: &mut input::Tape,
+) -> Result<()> {
+    // Set up window
+    let event_loop = &mut window::EventLoop::new();
+    let window = &mut Window::new(window_settings, &event_loop)?;
+    let mut debug = Debug::new(window.gpu());
+
+    // Load game
+    debug.loading_started();
+    let mut loading_screen = G::LoadingScreen::new(window.gpu())?;
+    let game = &mut loading_screen.run(G::load(window), window)?;
+    let input = &mut G::Input::new();
+    let mut gamepads = gamepad::Tracker::new();
+    debug.loading_finished();
+
+    // Game loop
+    let mut timer = Timer::new(G::TICKS_PER_SECOND);
+    let mut alive = true;
+
+    while alive && !game.is_finished() {
+        debug.frame_started();
+        timer.update();
+
+        while timer.tick() {
+            interact(
+                game,
+                input,
+                &mut debug,
+                window,
+                event_loop,
+                gamepads.as_mut(),
+                tape,
+                &mut alive,
+            );
+
+            debug.update_started();
+            game.update(window);
+            debug.update_finished();
+
+            tape.tick();
         }
 
-        Ok(())
+        if !timer.has_ticked() {
+            interact(
+                game,
+                input,
+                &mut debug,
+                window,
+                event_loop,
+                gamepads.as_mut(),
+                tape,
+                &mut alive,
+            );
+        }
+
+        debug.draw_started();
+        game.draw(&mut window.frame(), &timer);
+        debug.draw_finished();
+
+        debug.capture(&mut window.frame());
+
+        if debug.is_enabled() {
+            debug.debug_started();
+            game.debug(input, &mut window.frame(), &mut debug);
+            debug.debug_finished();
+        }
+
+        window.swap_buffers();
+        debug.frame_finished();
     }
+
+    Ok(())
 }
 
+#[allow(clippy::too_many_arguments)]
 fn interact<G: Game>(
     game: &mut G,
     input: &mut G::Input,
@@ -247,15 +332,17 @@ fn interact<G: Game>(
     window: &mut Window,
     event_loop: &mut window::EventLoop,
     gamepads: Option<&mut gamepad::Tracker>,
+    tape: &mut input::Tape,
     alive: &mut bool,
 ) {
     debug.interact_started();
 
     event_loop.poll(|event| {
-        process_window_event(game, input, debug, window, alive, event)
+        process_window_event(game, input, debug, window, tape, alive, event)
     });
 
-    process_gamepad_events(gamepads, input);
+    process_gamepad_events(gamepads, input, tape);
+    tape.play(input);
 
     game.interact(input, window);
     input.clear();
@@ -268,12 +355,13 @@ pub(crate) fn process_window_event<G: Game, I: Input>(
     input: &mut I,
     debug: &mut Debug,
     window: &mut Window,
+    tape: &mut input::Tape,
     alive: &mut bool,
     event: window::Event,
 ) {
     match event {
         window::Event::Input(input_event) => {
-            input.update(input_event);
+            tape.feed(input, input_event);
 
             #[cfg(any(debug_assertions, feature = "debug"))]
             match input_event {
@@ -283,24 +371,36 @@ pub(crate) fn process_window_event<G: Game, I: Input>(
                 }) if Some(key_code) == G::DEBUG_KEY => {
                     debug.toggle();
                 }
+                input::Event::Keyboard(keyboard::Event::Input {
+                    state: input::ButtonState::Released,
+                    key_code,
+                }) if Some(key_code) == G::CAPTURE_KEY => {
+                    debug.toggle_capture(game.capture_settings());
+                }
                 _ => {}
             }
         }
         window::Event::CursorMoved(logical_position) => {
             let position = logical_position.to_physical(window.dpi());
 
-            input.update(input::Event::Mouse(mouse::Event::CursorMoved {
-                x: position.x as f32,
-                y: position.y as f32,
-            }));
+            tape.feed(
+                input,
+                input::Event::Mouse(mouse::Event::CursorMoved {
+                    x: position.x as f32,
+                    y: position.y as f32,
+                }),
+            );
         }
         window::Event::Moved(logical_position) => {
             let position = logical_position.to_physical(window.dpi());
 
-            input.update(input::Event::Window(input::window::Event::Moved {
-                x: position.x as f32,
-                y: position.y as f32,
-            }))
+            tape.feed(
+                input,
+                input::Event::Window(input::window::Event::Moved {
+                    x: position.x as f32,
+                    y: position.y as f32,
+                }),
+            )
         }
         window::Event::CloseRequested => {
             if game.on_close_request() {
@@ -316,10 +416,11 @@ pub(crate) fn process_window_event<G: Game, I: Input>(
 pub(crate) fn process_gamepad_events<I: Input>(
     gamepads: Option<&mut gamepad::Tracker>,
     input: &mut I,
+    tape: &mut input::Tape,
 ) {
     if let Some(tracker) = gamepads {
         while let Some((id, event, time)) = tracker.next_event() {
-            input.update(input::Event::Gamepad { id, event, time });
+            tape.feed(input, input::Event::Gamepad { id, event, time });
         }
     }
 }
@@ -105,7 +105,15 @@ mod backend_wgpu;
 ))]
 use backend_wgpu as gpu;
 
+#[cfg(feature = "software")]
+mod backend_software;
+#[cfg(feature = "software")]
+use backend_software as gpu;
+
 mod batch;
+mod bitmap_font;
+mod blend_mode;
+mod camera;
 mod canvas;
 mod color;
 mod font;
@@ -114,6 +122,7 @@ mod mesh;
 mod point;
 mod quad;
 mod rectangle;
+mod shader;
 mod shape;
 mod sprite;
 mod target;
@@ -121,11 +130,18 @@ mod text;
 mod transformation;
 mod vector;
 
+pub mod animation;
+pub mod atlas;
+pub mod capture;
 pub mod texture_array;
+pub mod tilemap;
 pub(crate) mod window;
 
 pub use self::image::Image;
 pub use batch::Batch;
+pub use bitmap_font::BitmapFont;
+pub use blend_mode::BlendMode;
+pub use camera::Camera;
 pub use canvas::Canvas;
 pub use color::Color;
 pub use font::Font;
@@ -134,10 +150,14 @@ pub use mesh::Mesh;
 pub use point::Point;
 pub use quad::{IntoQuad, Quad};
 pub use rectangle::Rectangle;
+pub use shader::Shader;
 pub use shape::Shape;
 pub use sprite::Sprite;
 pub use target::Target;
-pub use text::{HorizontalAlignment, Text, VerticalAlignment};
+pub use text::{
+    FontStyle, GlyphInfo, HorizontalAlignment, RichText, Span, Text,
+    VerticalAlignment,
+};
 pub use texture_array::TextureArray;
 pub use transformation::Transformation;
 pub use vector::Vector;
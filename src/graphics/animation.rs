@@ -0,0 +1,258 @@
+//! Animate sprites using frames of a sprite sheet.
+mod json;
+mod sprite_sheet;
+
+pub use sprite_sheet::SpriteSheet;
+
+use std::time::Duration;
+
+use crate::graphics::texture_array::Index;
+use crate::graphics::{Rectangle, Sprite};
+
+/// A frame of an [`Animation`].
+///
+/// Its `source` is usually a [`Rectangle`] of a sprite sheet or an [`Index`]
+/// of a [`TextureArray`].
+///
+/// [`Animation`]: struct.Animation.html
+/// [`Rectangle`]: ../struct.Rectangle.html
+/// [`Index`]: ../texture_array/struct.Index.html
+/// [`TextureArray`]: ../texture_array/struct.TextureArray.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Frame<T> {
+    /// The source of the frame.
+    pub source: T,
+
+    /// How long the frame is shown.
+    pub duration: Duration,
+}
+
+/// The way an [`Animation`] plays its frames.
+///
+/// [`Animation`]: struct.Animation.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    /// Plays the frames once and stops at the last one.
+    Once,
+
+    /// Plays the frames from the first to the last one, over and over.
+    Loop,
+
+    /// Plays the frames forwards and then backwards, over and over.
+    PingPong,
+}
+
+/// A sequence of frames that changes over time.
+///
+/// An [`Animation`] does not draw anything by itself. Advance it in your
+/// [`Game::update`] and draw its current frame using [`sprite`]:
+///
+/// ```
+/// use coffee::graphics::animation::{Animation, Frame, Mode};
+/// use coffee::graphics::{Batch, Point, Rectangle, Sprite};
+/// use std::time::Duration;
+///
+/// fn walk() -> Animation {
+///     let frame = |x| Frame {
+///         source: Rectangle {
+///             x,
+///             y: 0,
+///             width: 16,
+///             height: 16,
+///         },
+///         duration: Duration::from_millis(100),
+///     };
+///
+///     Animation::new(vec![frame(0), frame(16), frame(32)], Mode::Loop)
+/// }
+///
+/// fn update(animation: &mut Animation) {
+///     // Call this once per tick, in `Game::update`
+///     animation.tick(60);
+/// }
+///
+/// fn draw(animation: &Animation, batch: &mut Batch) {
+///     batch.add(Sprite {
+///         position: Point::new(100.0, 100.0),
+///         ..animation.sprite()
+///     });
+/// }
+/// ```
+///
+/// [`Animation`]: struct.Animation.html
+/// [`Game::update`]: ../../trait.Game.html#method.update
+/// [`sprite`]: #method.sprite
+#[derive(Debug, Clone, PartialEq)]
+pub struct Animation<T = Rectangle<u16>> {
+    frames: Vec<Frame<T>>,
+    mode: Mode,
+    current: usize,
+    elapsed: Duration,
+    is_reversed: bool,
+    is_finished: bool,
+}
+
+impl<T> Animation<T> {
+    /// Creates a new [`Animation`] with the given frames and [`Mode`].
+    ///
+    /// # Panics
+    /// It panics if there are no frames.
+    ///
+    /// [`Animation`]: struct.Animation.html
+    /// [`Mode`]: enum.Mode.html
+    pub fn new(frames: Vec<Frame<T>>, mode: Mode) -> Animation<T> {
+        assert!(!frames.is_empty(), "An animation needs at least one frame");
+
+        Animation {
+            frames,
+            mode,
+            current: 0,
+            elapsed: Duration::from_secs(0),
+            is_reversed: false,
+            is_finished: false,
+        }
+    }
+
+    /// Returns the frames of the [`Animation`].
+    ///
+    /// [`Animation`]: struct.Animation.html
+    pub fn frames(&self) -> &[Frame<T>] {
+        &self.frames
+    }
+
+    /// Returns the [`Mode`] of the [`Animation`].
+    ///
+    /// [`Mode`]: enum.Mode.html
+    /// [`Animation`]: struct.Animation.html
+    pub fn mode(&self) -> Mode {
+        self.mode
+    }
+
+    /// Returns the source of the frame that is currently shown.
+    pub fn current(&self) -> &T {
+        &self.frames[self.current].source
+    }
+
+    /// Returns the index of the frame that is currently shown.
+    pub fn current_index(&self) -> usize {
+        self.current
+    }
+
+    /// Returns `true` if the [`Animation`] has stopped at its last frame.
+    ///
+    /// Only an [`Animation`] with [`Mode::Once`] can finish.
+    ///
+    /// [`Animation`]: struct.Animation.html
+    /// [`Mode::Once`]: enum.Mode.html#variant.Once
+    pub fn is_finished(&self) -> bool {
+        self.is_finished
+    }
+
+    /// Restarts the [`Animation`] from its first frame.
+    ///
+    /// [`Animation`]: struct.Animation.html
+    pub fn reset(&mut self) {
+        self.current = 0;
+        self.elapsed = Duration::from_secs(0);
+        self.is_reversed = false;
+        self.is_finished = false;
+    }
+
+    /// Advances the [`Animation`] by one tick of a game running at the given
+    /// amount of ticks per second.
+    ///
+    /// Call it in [`Game::update`] with [`Game::TICKS_PER_SECOND`].
+    ///
+    /// [`Animation`]: struct.Animation.html
+    /// [`Game::update`]: ../../trait.Game.html#method.update
+    /// [`Game::TICKS_PER_SECOND`]: ../../trait.Game.html#associatedconstant.TICKS_PER_SECOND
+    pub fn tick(&mut self, ticks_per_second: u16) {
+        if ticks_per_second > 0 {
+            self.advance(Duration::from_secs(1) / u32::from(ticks_per_second));
+        }
+    }
+
+    /// Advances the [`Animation`] by the given amount of time.
+    ///
+    /// [`Animation`]: struct.Animation.html
+    pub fn advance(&mut self, delta: Duration) {
+        self.elapsed += delta;
+
+        while !self.is_finished {
+            let duration = self.frames[self.current].duration;
+
+            // Frames without duration would loop forever
+            if self.elapsed < duration || duration == Duration::from_secs(0) {
+                break;
+            }
+
+            self.elapsed -= duration;
+            self.next_frame();
+        }
+    }
+
+    fn next_frame(&mut self) {
+        let last = self.frames.len() - 1;
+
+        match self.mode {
+            Mode::Once => {
+                if self.current == last {
+                    self.is_finished = true;
+                    self.elapsed = Duration::from_secs(0);
+                } else {
+                    self.current += 1;
+                }
+            }
+            Mode::Loop => {
+                self.current = if self.current == last {
+                    0
+                } else {
+                    self.current + 1
+                };
+            }
+            Mode::PingPong => {
+                if last == 0 {
+                    return;
+                }
+
+                if self.is_reversed && self.current == 0 {
+                    self.is_reversed = false;
+                } else if !self.is_reversed && self.current == last {
+                    self.is_reversed = true;
+                }
+
+                if self.is_reversed {
+                    self.current -= 1;
+                } else {
+                    self.current += 1;
+                }
+            }
+        }
+    }
+}
+
+impl Animation<Rectangle<u16>> {
+    /// Returns a [`Sprite`] showing the current frame.
+    ///
+    /// The rest of the fields of the [`Sprite`] have their default values.
+    ///
+    /// [`Sprite`]: ../struct.Sprite.html
+    pub fn sprite(&self) -> Sprite {
+        Sprite {
+            source: *self.current(),
+            ..Sprite::default()
+        }
+    }
+}
+
+impl Animation<Index> {
+    /// Returns the [`Index`] of the current frame.
+    ///
+    /// Use it to add the frame to a [`texture_array::Batch`].
+    ///
+    /// [`Index`]: ../texture_array/struct.Index.html
+    /// [`texture_array::Batch`]: ../texture_array/struct.Batch.html
+    pub fn index(&self) -> &Index {
+        self.current()
+    }
+}
@@ -0,0 +1,235 @@
+//! Parse sprite sheet metadata exported by Aseprite and TexturePacker.
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use serde::de::{self, Deserializer, MapAccess, SeqAccess, Visitor};
+use serde::Deserialize;
+
+use super::{Frame, Mode};
+use crate::graphics::Rectangle;
+use crate::Result;
+
+/// The duration of frames that do not specify one.
+const DEFAULT_DURATION: Duration = Duration::from_millis(100);
+
+/// A sprite sheet, before loading its image.
+pub struct Sheet {
+    pub image: PathBuf,
+    pub frames: Vec<(String, Frame<Rectangle<u16>>)>,
+    pub animations: Vec<(String, Vec<usize>, Mode)>,
+}
+
+#[derive(Deserialize)]
+struct RawSheet {
+    frames: RawFrames,
+    meta: RawMeta,
+    #[serde(default)]
+    animations: HashMap<String, Vec<String>>,
+}
+
+#[derive(Deserialize)]
+struct RawMeta {
+    image: String,
+    #[serde(rename = "frameTags", default)]
+    frame_tags: Vec<RawTag>,
+}
+
+#[derive(Deserialize)]
+struct RawFrame {
+    #[serde(default)]
+    filename: String,
+    frame: RawRectangle,
+    #[serde(default)]
+    rotated: bool,
+    duration: Option<u64>,
+}
+
+#[derive(Deserialize)]
+struct RawRectangle {
+    x: u16,
+    y: u16,
+    w: u16,
+    h: u16,
+}
+
+#[derive(Deserialize)]
+struct RawTag {
+    name: String,
+    from: usize,
+    to: usize,
+    #[serde(default)]
+    direction: String,
+}
+
+/// The frames of a sheet, in order.
+///
+/// They can be exported either as an array or as an object keyed by name.
+/// The order of the keys matters, so we cannot use a `HashMap`.
+struct RawFrames(Vec<RawFrame>);
+
+impl<'de> Deserialize<'de> for RawFrames {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct FramesVisitor;
+
+        impl<'de> Visitor<'de> for FramesVisitor {
+            type Value = RawFrames;
+
+            fn expecting(
+                &self,
+                formatter: &mut fmt::Formatter<'_>,
+            ) -> fmt::Result {
+                formatter.write_str("an array or a map of frames")
+            }
+
+            fn visit_seq<A>(
+                self,
+                mut seq: A,
+            ) -> std::result::Result<RawFrames, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let mut frames = Vec::new();
+
+                while let Some(frame) = seq.next_element()? {
+                    frames.push(frame);
+                }
+
+                Ok(RawFrames(frames))
+            }
+
+            fn visit_map<A>(
+                self,
+                mut map: A,
+            ) -> std::result::Result<RawFrames, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut frames = Vec::new();
+
+                while let Some((filename, frame)) =
+                    map.next_entry::<String, RawFrame>()?
+                {
+                    frames.push(RawFrame { filename, ..frame });
+                }
+
+                Ok(RawFrames(frames))
+            }
+        }
+
+        deserializer.deserialize_any(FramesVisitor)
+    }
+}
+
+pub fn aseprite(path: &Path) -> Result<Sheet> {
+    let raw = parse(path)?;
+    let frame_count = raw.frames.0.len();
+
+    let mut animations = Vec::new();
+
+    for tag in raw.meta.frame_tags {
+        if tag.from > tag.to || tag.to >= frame_count {
+            return Err(invalid(format!("invalid frame tag: {}", tag.name)));
+        }
+
+        let frames: Vec<usize> = (tag.from..=tag.to).collect();
+
+        let (frames, mode) = match tag.direction.as_str() {
+            "" | "forward" => (frames, Mode::Loop),
+            "reverse" => (frames.into_iter().rev().collect(), Mode::Loop),
+            "pingpong" => (frames, Mode::PingPong),
+            "pingpong_reverse" => {
+                (frames.into_iter().rev().collect(), Mode::PingPong)
+            }
+            direction => {
+                return Err(invalid(format!(
+                    "unknown tag direction: {}",
+                    direction
+                )));
+            }
+        };
+
+        animations.push((tag.name, frames, mode));
+    }
+
+    Ok(Sheet {
+        image: image(path, &raw.meta),
+        frames: frames(raw.frames)?,
+        animations,
+    })
+}
+
+pub fn texture_packer(path: &Path) -> Result<Sheet> {
+    let raw = parse(path)?;
+    let image = image(path, &raw.meta);
+    let frames = frames(raw.frames)?;
+
+    let mut animations = Vec::new();
+
+    for (name, names) in raw.animations {
+        let indices = names
+            .iter()
+            .map(|frame| {
+                frames
+                    .iter()
+                    .position(|(name, _)| name == frame)
+                    .ok_or_else(|| invalid(format!("unknown frame: {}", frame)))
+            })
+            .collect::<Result<_>>()?;
+
+        animations.push((name, indices, Mode::Loop));
+    }
+
+    Ok(Sheet {
+        image,
+        frames,
+        animations,
+    })
+}
+
+fn parse(path: &Path) -> Result<RawSheet> {
+    Ok(serde_json::from_slice(&fs::read(path)?)?)
+}
+
+fn image(path: &Path, meta: &RawMeta) -> PathBuf {
+    path.parent()
+        .unwrap_or_else(|| Path::new(""))
+        .join(&meta.image)
+}
+
+fn frames(raw: RawFrames) -> Result<Vec<(String, Frame<Rectangle<u16>>)>> {
+    raw.0
+        .into_iter()
+        .map(|frame| {
+            if frame.rotated {
+                return Err(invalid(format!(
+                    "rotated frames are not supported: {}",
+                    frame.filename
+                )));
+            }
+
+            let source = Rectangle {
+                x: frame.frame.x,
+                y: frame.frame.y,
+                width: frame.frame.w,
+                height: frame.frame.h,
+            };
+
+            let duration = frame
+                .duration
+                .map(Duration::from_millis)
+                .unwrap_or(DEFAULT_DURATION);
+
+            Ok((frame.filename, Frame { source, duration }))
+        })
+        .collect()
+}
+
+fn invalid(reason: String) -> crate::Error {
+    crate::Error::Serialization(de::Error::custom(reason))
+}
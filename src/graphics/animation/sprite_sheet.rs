@@ -0,0 +1,167 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use super::json::{self, Sheet};
+use super::{Animation, Frame, Mode};
+use crate::graphics::{Gpu, Image, Rectangle};
+use crate::load::Task;
+use crate::Result;
+
+/// An [`Image`] containing frames and the [`Animation`]s that use them.
+///
+/// You can load the sprite sheets exported by [Aseprite] and [TexturePacker]
+/// as JSON.
+///
+/// [`Image`]: ../struct.Image.html
+/// [`Animation`]: struct.Animation.html
+/// [Aseprite]: https://www.aseprite.org
+/// [TexturePacker]: https://www.codeandweb.com/texturepacker
+#[derive(Clone)]
+pub struct SpriteSheet {
+    image: Image,
+    frames: Vec<Frame<Rectangle<u16>>>,
+    names: HashMap<String, usize>,
+    animations: HashMap<String, (Vec<usize>, Mode)>,
+}
+
+impl SpriteSheet {
+    /// Loads a [`SpriteSheet`] from the JSON file exported by [Aseprite].
+    ///
+    /// Every frame tag becomes an [`Animation`] with the same name. Frames
+    /// keep their duration and tags keep their direction.
+    ///
+    /// [`SpriteSheet`]: struct.SpriteSheet.html
+    /// [`Animation`]: struct.Animation.html
+    /// [Aseprite]: https://www.aseprite.org
+    pub fn from_aseprite<P: AsRef<Path>>(
+        gpu: &mut Gpu,
+        path: P,
+    ) -> Result<SpriteSheet> {
+        SpriteSheet::build(gpu, json::aseprite(path.as_ref())?)
+    }
+
+    /// Creates a [`Task`] that loads a [`SpriteSheet`] from the JSON file
+    /// exported by [Aseprite].
+    ///
+    /// [`Task`]: ../../load/struct.Task.html
+    /// [`SpriteSheet`]: struct.SpriteSheet.html
+    /// [Aseprite]: https://www.aseprite.org
+    pub fn load_aseprite<P: Into<PathBuf>>(path: P) -> Task<SpriteSheet> {
+        let path = path.into();
+
+        Task::using_gpu(move |gpu| SpriteSheet::from_aseprite(gpu, &path))
+    }
+
+    /// Loads a [`SpriteSheet`] from a JSON file exported by [TexturePacker],
+    /// either as a hash or as an array.
+    ///
+    /// Every entry of the `animations` object, if present, becomes a looping
+    /// [`Animation`]. Frames last 100 milliseconds.
+    ///
+    /// Rotated frames are not supported.
+    ///
+    /// [`SpriteSheet`]: struct.SpriteSheet.html
+    /// [`Animation`]: struct.Animation.html
+    /// [TexturePacker]: https://www.codeandweb.com/texturepacker
+    pub fn from_texture_packer<P: AsRef<Path>>(
+        gpu: &mut Gpu,
+        path: P,
+    ) -> Result<SpriteSheet> {
+        SpriteSheet::build(gpu, json::texture_packer(path.as_ref())?)
+    }
+
+    /// Creates a [`Task`] that loads a [`SpriteSheet`] from a JSON file
+    /// exported by [TexturePacker].
+    ///
+    /// [`Task`]: ../../load/struct.Task.html
+    /// [`SpriteSheet`]: struct.SpriteSheet.html
+    /// [TexturePacker]: https://www.codeandweb.com/texturepacker
+    pub fn load_texture_packer<P: Into<PathBuf>>(path: P) -> Task<SpriteSheet> {
+        let path = path.into();
+
+        Task::using_gpu(move |gpu| SpriteSheet::from_texture_packer(gpu, &path))
+    }
+
+    fn build(gpu: &mut Gpu, sheet: Sheet) -> Result<SpriteSheet> {
+        let image = Image::new(gpu, &sheet.image)?;
+
+        let mut frames = Vec::with_capacity(sheet.frames.len());
+        let mut names = HashMap::new();
+
+        for (i, (name, frame)) in sheet.frames.into_iter().enumerate() {
+            let _ = names.insert(name, i);
+            frames.push(frame);
+        }
+
+        let animations = sheet
+            .animations
+            .into_iter()
+            .filter(|(_, frames, _)| !frames.is_empty())
+            .map(|(name, frames, mode)| (name, (frames, mode)))
+            .collect();
+
+        Ok(SpriteSheet {
+            image,
+            frames,
+            names,
+            animations,
+        })
+    }
+
+    /// Returns the [`Image`] of the [`SpriteSheet`].
+    ///
+    /// Use it to create the [`Batch`] that draws its frames.
+    ///
+    /// [`Image`]: ../struct.Image.html
+    /// [`SpriteSheet`]: struct.SpriteSheet.html
+    /// [`Batch`]: ../struct.Batch.html
+    pub fn image(&self) -> &Image {
+        &self.image
+    }
+
+    /// Returns all the frames of the [`SpriteSheet`], in order.
+    ///
+    /// [`SpriteSheet`]: struct.SpriteSheet.html
+    pub fn frames(&self) -> &[Frame<Rectangle<u16>>] {
+        &self.frames
+    }
+
+    /// Returns the frame with the given name, if any.
+    pub fn frame(&self, name: &str) -> Option<&Frame<Rectangle<u16>>> {
+        self.names.get(name).map(|i| &self.frames[*i])
+    }
+
+    /// Creates the [`Animation`] with the given name, if any.
+    ///
+    /// The returned [`Animation`] starts at its first frame.
+    ///
+    /// [`Animation`]: struct.Animation.html
+    pub fn animation(&self, name: &str) -> Option<Animation> {
+        self.animations.get(name).map(|(indices, mode)| {
+            Animation::new(
+                indices.iter().map(|i| self.frames[*i]).collect(),
+                *mode,
+            )
+        })
+    }
+
+    /// Returns the names of the animations of the [`SpriteSheet`].
+    ///
+    /// [`SpriteSheet`]: struct.SpriteSheet.html
+    pub fn animation_names(&self) -> impl Iterator<Item = &str> {
+        self.animations.keys().map(String::as_str)
+    }
+}
+
+impl fmt::Debug for SpriteSheet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "SpriteSheet {{ image: {:?}, frames: {}, animations: {:?} }}",
+            self.image,
+            self.frames.len(),
+            self.animations.keys().collect::<Vec<_>>()
+        )
+    }
+}
@@ -0,0 +1,489 @@
+//! Pack many images into a single one.
+mod packer;
+
+use std::collections::HashMap;
+use std::fmt;
+use std::path::Path;
+
+use packer::Packer;
+
+use crate::graphics::{Gpu, Image, Point, Rectangle, Sprite, Vector};
+use crate::load::Task;
+use crate::Result;
+
+/// The size an [`Atlas`] starts with before growing.
+///
+/// [`Atlas`]: struct.Atlas.html
+const INITIAL_SIZE: u16 = 256;
+
+/// An [`Image`] containing many named images, packed together.
+///
+/// Drawing from a single [`Image`] allows you to draw all of them with the
+/// same [`Batch`].
+///
+/// You can build an [`Atlas`] from many files at once with a [`Builder`], or
+/// insert images on the fly with [`Atlas::insert`]. The [`Atlas`] grows when
+/// it runs out of space, up to the maximum size of its [`Settings`].
+///
+/// [`Image`]: ../struct.Image.html
+/// [`Batch`]: ../struct.Batch.html
+/// [`Atlas`]: struct.Atlas.html
+/// [`Builder`]: struct.Builder.html
+/// [`Atlas::insert`]: struct.Atlas.html#method.insert
+/// [`Settings`]: struct.Settings.html
+#[derive(Clone)]
+pub struct Atlas {
+    image: Image,
+    packing: Packing,
+}
+
+impl Atlas {
+    /// Creates a new, empty [`Atlas`] with the given [`Settings`].
+    ///
+    /// [`Atlas`]: struct.Atlas.html
+    /// [`Settings`]: struct.Settings.html
+    pub fn new(gpu: &mut Gpu, settings: Settings) -> Result<Atlas> {
+        Packing::new(settings).upload(gpu)
+    }
+
+    /// Inserts an image in the [`Atlas`] with the given name and returns its
+    /// [`Region`].
+    ///
+    /// Only the pixels of the new image are uploaded. However, if the
+    /// [`Atlas`] does not have enough space left, it grows. Growing replaces
+    /// its [`Image`], so make sure to use the new one to draw!
+    ///
+    /// An image with an existing name replaces the old one, but the space of
+    /// the old one is not reused.
+    ///
+    /// [`Atlas`]: struct.Atlas.html
+    /// [`Region`]: struct.Region.html
+    /// [`Image`]: #method.image
+    pub fn insert(
+        &mut self,
+        gpu: &mut Gpu,
+        name: &str,
+        image: &image::DynamicImage,
+    ) -> Result<Region> {
+        let region = self.packing.pack(name, image.to_rgba())?;
+
+        let (width, height) = self.packing.pixels.dimensions();
+
+        if width != u32::from(self.image.width())
+            || height != u32::from(self.image.height())
+        {
+            self.image = Image::from_image(gpu, &self.packing.to_image())?;
+        } else if region.source.width > 0 && region.source.height > 0 {
+            let extrusion = self.packing.settings.extrusion;
+
+            let area = Rectangle {
+                x: region.source.x - extrusion,
+                y: region.source.y - extrusion,
+                width: region.source.width + 2 * extrusion,
+                height: region.source.height + 2 * extrusion,
+            };
+
+            self.image
+                .update(gpu, area.x, area.y, &self.packing.crop(area));
+        }
+
+        Ok(region)
+    }
+
+    /// Returns the [`Image`] containing all the packed images.
+    ///
+    /// [`Image`]: ../struct.Image.html
+    pub fn image(&self) -> &Image {
+        &self.image
+    }
+
+    /// Returns the [`Region`] of the image with the given name, if any.
+    ///
+    /// [`Region`]: struct.Region.html
+    pub fn region(&self, name: &str) -> Option<&Region> {
+        self.packing.regions.get(name)
+    }
+
+    /// Returns the names and [`Region`]s of all the packed images.
+    ///
+    /// [`Region`]: struct.Region.html
+    pub fn regions(&self) -> impl Iterator<Item = (&str, &Region)> {
+        self.packing
+            .regions
+            .iter()
+            .map(|(name, region)| (name.as_str(), region))
+    }
+}
+
+impl fmt::Debug for Atlas {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Atlas {{ image: {:?}, regions: {:?} }}",
+            self.image, self.packing.regions
+        )
+    }
+}
+
+/// The pixels and the placements of an [`Atlas`], before uploading them.
+///
+/// [`Atlas`]: struct.Atlas.html
+#[derive(Debug, Clone)]
+struct Packing {
+    pixels: image::RgbaImage,
+    packer: Packer,
+    regions: HashMap<String, Region>,
+    settings: Settings,
+}
+
+impl Packing {
+    fn new(settings: Settings) -> Packing {
+        let width = u32::from(INITIAL_SIZE.min(settings.max_width));
+        let height = u32::from(INITIAL_SIZE.min(settings.max_height));
+
+        Packing {
+            pixels: image::RgbaImage::new(width, height),
+            packer: Packer::new(width, height),
+            regions: HashMap::new(),
+            settings,
+        }
+    }
+
+    fn pack(&mut self, name: &str, image: image::RgbaImage) -> Result<Region> {
+        let (image, offset) = if self.settings.trim {
+            trim(image)
+        } else {
+            (image, (0, 0))
+        };
+
+        let offset = Vector::new(f32::from(offset.0), f32::from(offset.1));
+
+        // Fully transparent images do not need any space
+        if image.width() == 0 || image.height() == 0 {
+            let region = Region {
+                source: Rectangle {
+                    x: 0,
+                    y: 0,
+                    width: 0,
+                    height: 0,
+                },
+                offset,
+            };
+
+            let _ = self.regions.insert(String::from(name), region.clone());
+
+            return Ok(region);
+        }
+
+        let extrusion = u32::from(self.settings.extrusion);
+        let padding = u32::from(self.settings.padding);
+
+        let width = image.width() + 2 * extrusion + padding;
+        let height = image.height() + 2 * extrusion + padding;
+
+        let placement = loop {
+            if let Some(placement) = self.packer.pack(width, height) {
+                break placement;
+            }
+
+            if !self.grow() {
+                return Err(crate::Error::Atlas(Error::ImageIsTooBig(
+                    String::from(name),
+                )));
+            }
+        };
+
+        let x = placement.x + extrusion;
+        let y = placement.y + extrusion;
+
+        blit(&mut self.pixels, &image, x, y, extrusion);
+
+        let region = Region {
+            source: Rectangle {
+                x: x as u16,
+                y: y as u16,
+                width: image.width() as u16,
+                height: image.height() as u16,
+            },
+            offset,
+        };
+
+        let _ = self.regions.insert(String::from(name), region.clone());
+
+        Ok(region)
+    }
+
+    /// Doubles the shortest side, if possible.
+    fn grow(&mut self) -> bool {
+        let max_width = u32::from(self.settings.max_width);
+        let max_height = u32::from(self.settings.max_height);
+
+        let width = self.packer.width();
+        let height = self.packer.height();
+
+        let (new_width, new_height) =
+            if (width <= height || height == max_height) && width < max_width {
+                ((width * 2).min(max_width), height)
+            } else if height < max_height {
+                (width, (height * 2).min(max_height))
+            } else {
+                return false;
+            };
+
+        let mut pixels = image::RgbaImage::new(new_width, new_height);
+        blit(&mut pixels, &self.pixels, 0, 0, 0);
+
+        self.pixels = pixels;
+        self.packer.grow(new_width, new_height);
+
+        true
+    }
+
+    fn crop(&self, area: Rectangle<u16>) -> image::RgbaImage {
+        image::RgbaImage::from_fn(
+            u32::from(area.width),
+            u32::from(area.height),
+            |x, y| {
+                *self
+                    .pixels
+                    .get_pixel(u32::from(area.x) + x, u32::from(area.y) + y)
+            },
+        )
+    }
+
+    fn to_image(&self) -> image::DynamicImage {
+        image::DynamicImage::ImageRgba8(self.pixels.clone())
+    }
+
+    fn upload(self, gpu: &mut Gpu) -> Result<Atlas> {
+        Ok(Atlas {
+            image: Image::from_image(gpu, &self.to_image())?,
+            packing: self,
+        })
+    }
+}
+
+/// The location of an image inside an [`Atlas`].
+///
+/// [`Atlas`]: struct.Atlas.html
+#[derive(Debug, Clone, PartialEq)]
+pub struct Region {
+    /// The portion of the [`Atlas`] image that contains the image.
+    ///
+    /// [`Atlas`]: struct.Atlas.html
+    pub source: Rectangle<u16>,
+
+    /// The amount of transparent pixels trimmed from the left and top
+    /// borders of the image.
+    pub offset: Vector,
+}
+
+impl Region {
+    /// Returns a [`Sprite`] drawing the image with its top-left corner at the
+    /// given position, as if it had not been trimmed.
+    ///
+    /// [`Sprite`]: ../struct.Sprite.html
+    pub fn sprite(&self, position: Point) -> Sprite {
+        Sprite {
+            source: self.source,
+            position: position + self.offset,
+            ..Sprite::default()
+        }
+    }
+}
+
+/// The settings of an [`Atlas`].
+///
+/// [`Atlas`]: struct.Atlas.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Settings {
+    /// The maximum width of the [`Atlas`].
+    ///
+    /// [`Atlas`]: struct.Atlas.html
+    pub max_width: u16,
+
+    /// The maximum height of the [`Atlas`].
+    ///
+    /// [`Atlas`]: struct.Atlas.html
+    pub max_height: u16,
+
+    /// The amount of transparent pixels between images.
+    pub padding: u16,
+
+    /// The amount of pixels the borders of every image are repeated.
+    ///
+    /// It avoids bleeding of neighboring pixels when sampling images with
+    /// scaling or at fractional positions.
+    pub extrusion: u16,
+
+    /// Whether the transparent borders of images should be trimmed.
+    pub trim: bool,
+}
+
+impl Default for Settings {
+    fn default() -> Settings {
+        Settings {
+            max_width: 2048,
+            max_height: 2048,
+            padding: 1,
+            extrusion: 0,
+            trim: true,
+        }
+    }
+}
+
+/// An [`Atlas`] builder.
+///
+/// It sorts all the images by size before packing them, which usually packs
+/// them tighter than inserting them one by one.
+///
+/// [`Atlas`]: struct.Atlas.html
+#[derive(Debug)]
+pub struct Builder {
+    settings: Settings,
+    images: Vec<(String, image::RgbaImage)>,
+}
+
+impl Builder {
+    /// Creates a new [`Builder`] of an [`Atlas`] with the given [`Settings`].
+    ///
+    /// [`Builder`]: struct.Builder.html
+    /// [`Atlas`]: struct.Atlas.html
+    /// [`Settings`]: struct.Settings.html
+    pub fn new(settings: Settings) -> Builder {
+        Builder {
+            settings,
+            images: Vec::new(),
+        }
+    }
+
+    /// Loads an image from the given path and adds it with the given name.
+    pub fn add<P: AsRef<Path>>(&mut self, name: &str, path: P) -> Result<()> {
+        let image = image::open(path)?;
+
+        self.add_image(name, &image);
+
+        Ok(())
+    }
+
+    /// Adds an image with the given name.
+    pub fn add_image(&mut self, name: &str, image: &image::DynamicImage) {
+        self.images.push((String::from(name), image.to_rgba()));
+    }
+
+    /// Builds the [`Atlas`].
+    ///
+    /// [`Atlas`]: struct.Atlas.html
+    pub fn build(mut self, gpu: &mut Gpu) -> Result<Atlas> {
+        let mut packing = Packing::new(self.settings);
+
+        self.images.sort_by_key(|(_, image)| {
+            std::cmp::Reverse((
+                image.width().max(image.height()),
+                image.width().min(image.height()),
+            ))
+        });
+
+        for (name, image) in self.images {
+            let _ = packing.pack(&name, image)?;
+        }
+
+        packing.upload(gpu)
+    }
+
+    /// Creates a [`Task`] that builds the [`Atlas`].
+    ///
+    /// [`Task`]: ../../load/struct.Task.html
+    /// [`Atlas`]: struct.Atlas.html
+    pub fn load(self) -> Task<Atlas> {
+        Task::using_gpu(move |gpu| self.build(gpu))
+    }
+}
+
+/// An atlas error.
+#[derive(Debug, Clone)]
+pub enum Error {
+    /// The image with the given name did not fit in the atlas.
+    ImageIsTooBig(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::ImageIsTooBig(name) => {
+                write!(f, "Image does not fit: {}", name)
+            }
+        }
+    }
+}
+
+/// Removes the transparent borders of an image.
+///
+/// It returns the trimmed image and the amount of pixels removed from the
+/// left and top borders.
+fn trim(image: image::RgbaImage) -> (image::RgbaImage, (u16, u16)) {
+    let is_opaque = |x, y| image.get_pixel(x, y).data[3] > 0;
+
+    let (width, height) = image.dimensions();
+
+    let columns: Vec<u32> = (0..width)
+        .filter(|x| (0..height).any(|y| is_opaque(*x, y)))
+        .collect();
+
+    let rows: Vec<u32> = (0..height)
+        .filter(|y| (0..width).any(|x| is_opaque(x, *y)))
+        .collect();
+
+    match (columns.first(), columns.last(), rows.first(), rows.last()) {
+        (Some(&left), Some(&right), Some(&top), Some(&bottom)) => {
+            if left == 0
+                && top == 0
+                && right == width - 1
+                && bottom == height - 1
+            {
+                return (image, (0, 0));
+            }
+
+            let trimmed = image::imageops::crop(
+                &mut image.clone(),
+                left,
+                top,
+                right - left + 1,
+                bottom - top + 1,
+            )
+            .to_image();
+
+            (trimmed, (left as u16, top as u16))
+        }
+        _ => (image::RgbaImage::new(0, 0), (0, 0)),
+    }
+}
+
+/// Copies an image into another at the given position, repeating its borders
+/// the given amount of pixels.
+fn blit(
+    target: &mut image::RgbaImage,
+    image: &image::RgbaImage,
+    x: u32,
+    y: u32,
+    extrusion: u32,
+) {
+    let (width, height) = image.dimensions();
+
+    if width == 0 || height == 0 {
+        return;
+    }
+
+    for target_y in y - extrusion..y + height + extrusion {
+        for target_x in x - extrusion..x + width + extrusion {
+            let source_x = (target_x.max(x) - x).min(width - 1);
+            let source_y = (target_y.max(y) - y).min(height - 1);
+
+            target.put_pixel(
+                target_x,
+                target_y,
+                *image.get_pixel(source_x, source_y),
+            );
+        }
+    }
+}
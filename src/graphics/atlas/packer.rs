@@ -0,0 +1,174 @@
+use crate::graphics::Rectangle;
+
+/// A rectangle packer using the MaxRects algorithm.
+///
+/// It keeps track of the maximal free rectangles of the area and places every
+/// new rectangle where it leaves the shortest side free.
+#[derive(Debug, Clone)]
+pub struct Packer {
+    width: u32,
+    height: u32,
+    free: Vec<Rectangle<u32>>,
+}
+
+impl Packer {
+    pub fn new(width: u32, height: u32) -> Packer {
+        Packer {
+            width,
+            height,
+            free: vec![Rectangle {
+                x: 0,
+                y: 0,
+                width,
+                height,
+            }],
+        }
+    }
+
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    pub fn pack(&mut self, width: u32, height: u32) -> Option<Rectangle<u32>> {
+        let placement = self
+            .free
+            .iter()
+            .filter(|free| free.width >= width && free.height >= height)
+            .min_by_key(|free| {
+                let leftover_x = free.width - width;
+                let leftover_y = free.height - height;
+
+                (leftover_x.min(leftover_y), leftover_x.max(leftover_y))
+            })
+            .map(|free| Rectangle {
+                x: free.x,
+                y: free.y,
+                width,
+                height,
+            })?;
+
+        self.split(&placement);
+        self.prune();
+
+        Some(placement)
+    }
+
+    /// Grows the packing area, keeping the current placements.
+    pub fn grow(&mut self, width: u32, height: u32) {
+        for free in self.free.iter_mut() {
+            if free.x + free.width == self.width {
+                free.width = width - free.x;
+            }
+
+            if free.y + free.height == self.height {
+                free.height = height - free.y;
+            }
+        }
+
+        if width > self.width {
+            self.free.push(Rectangle {
+                x: self.width,
+                y: 0,
+                width: width - self.width,
+                height,
+            });
+        }
+
+        if height > self.height {
+            self.free.push(Rectangle {
+                x: 0,
+                y: self.height,
+                width,
+                height: height - self.height,
+            });
+        }
+
+        self.width = width;
+        self.height = height;
+
+        self.prune();
+    }
+
+    fn split(&mut self, used: &Rectangle<u32>) {
+        let mut free = Vec::with_capacity(self.free.len() + 4);
+
+        for rectangle in self.free.drain(..) {
+            if !intersects(&rectangle, used) {
+                free.push(rectangle);
+                continue;
+            }
+
+            if used.x > rectangle.x {
+                free.push(Rectangle {
+                    width: used.x - rectangle.x,
+                    ..rectangle
+                });
+            }
+
+            if used.x + used.width < rectangle.x + rectangle.width {
+                free.push(Rectangle {
+                    x: used.x + used.width,
+                    width: rectangle.x + rectangle.width - used.x - used.width,
+                    ..rectangle
+                });
+            }
+
+            if used.y > rectangle.y {
+                free.push(Rectangle {
+                    height: used.y - rectangle.y,
+                    ..rectangle
+                });
+            }
+
+            if used.y + used.height < rectangle.y + rectangle.height {
+                free.push(Rectangle {
+                    y: used.y + used.height,
+                    height: rectangle.y + rectangle.height
+                        - used.y
+                        - used.height,
+                    ..rectangle
+                });
+            }
+        }
+
+        self.free = free;
+    }
+
+    /// Removes the free rectangles contained in other free rectangles.
+    fn prune(&mut self) {
+        let mut i = 0;
+
+        while i < self.free.len() {
+            let is_contained =
+                self.free.iter().enumerate().any(|(j, other)| {
+                    i != j
+                        && contains(other, &self.free[i])
+                        && (self.free[i] != *other || j < i)
+                });
+
+            if is_contained {
+                let _ = self.free.swap_remove(i);
+            } else {
+                i += 1;
+            }
+        }
+    }
+}
+
+fn intersects(a: &Rectangle<u32>, b: &Rectangle<u32>) -> bool {
+    a.x < b.x + b.width
+        && b.x < a.x + a.width
+        && a.y < b.y + b.height
+        && b.y < a.y + a.height
+}
+
+fn contains(a: &Rectangle<u32>, b: &Rectangle<u32>) -> bool {
+    a.x <= b.x
+        && a.y <= b.y
+        && b.x + b.width <= a.x + a.width
+        && b.y + b.height <= a.y + a.height
+}
@@ -0,0 +1,41 @@
+use gfx::state::{Blend, BlendChannel, BlendValue, Equation, Factor};
+
+use crate::graphics::BlendMode;
+
+pub fn state(blend_mode: BlendMode) -> Blend {
+    let (color, alpha) = match blend_mode {
+        BlendMode::Alpha => (
+            channel(
+                Factor::ZeroPlus(BlendValue::SourceAlpha),
+                Factor::OneMinus(BlendValue::SourceAlpha),
+            ),
+            channel(Factor::One, Factor::OneMinus(BlendValue::SourceAlpha)),
+        ),
+        BlendMode::Additive => (
+            channel(Factor::ZeroPlus(BlendValue::SourceAlpha), Factor::One),
+            channel(Factor::Zero, Factor::One),
+        ),
+        BlendMode::Multiply => (
+            channel(Factor::ZeroPlus(BlendValue::DestColor), Factor::Zero),
+            channel(Factor::Zero, Factor::One),
+        ),
+        BlendMode::Premultiplied => (
+            channel(Factor::One, Factor::OneMinus(BlendValue::SourceAlpha)),
+            channel(Factor::One, Factor::OneMinus(BlendValue::SourceAlpha)),
+        ),
+        BlendMode::Replace => (
+            channel(Factor::One, Factor::Zero),
+            channel(Factor::One, Factor::Zero),
+        ),
+    };
+
+    Blend { color, alpha }
+}
+
+fn channel(source: Factor, destination: Factor) -> BlendChannel {
+    BlendChannel {
+        equation: Equation::Add,
+        source,
+        destination,
+    }
+}
@@ -1,30 +1,58 @@
+use std::borrow::Cow;
+
 use gfx_device_gl as gl;
-use gfx_glyph::GlyphCruncher;
+use gfx_glyph::{GlyphCruncher, GlyphPositioner};
 
+use crate::graphics::font::{Glyph, Section};
 use crate::graphics::gpu::{TargetView, Transformation};
-use crate::graphics::{HorizontalAlignment, Text, VerticalAlignment};
+use crate::graphics::{HorizontalAlignment, Rectangle, VerticalAlignment};
+use crate::{Error, Result};
 
 pub struct Font {
+    sections: Vec<gfx_glyph::OwnedVariedSection>,
     glyphs: gfx_glyph::GlyphBrush<'static, gl::Resources, gl::Factory>,
+    typefaces: Vec<gfx_glyph::Font<'static>>,
 }
 
 impl Font {
-    pub fn from_bytes(factory: &mut gl::Factory, bytes: &'static [u8]) -> Font {
-        Font {
-            glyphs: gfx_glyph::GlyphBrushBuilder::using_font_bytes(bytes)
+    pub fn from_bytes(
+        factory: &mut gl::Factory,
+        bytes: Cow<'static, [u8]>,
+    ) -> Result<Font> {
+        let typeface = parse(bytes)?;
+
+        Ok(Font {
+            sections: Vec::new(),
+            glyphs: gfx_glyph::GlyphBrushBuilder::using_font(typeface.clone())
                 .depth_test(gfx::preset::depth::PASS_TEST)
                 .texture_filter_method(gfx::texture::FilterMethod::Scale)
                 .build(factory.clone()),
-        }
+            typefaces: vec![typeface],
+        })
+    }
+
+    pub fn add_typeface(&mut self, bytes: Cow<'static, [u8]>) -> Result<usize> {
+        let typeface = parse(bytes)?;
+        let font_id = self.glyphs.add_font(typeface.clone());
+
+        self.typefaces.push(typeface);
+
+        Ok(font_id.0)
     }
 
-    pub fn add(&mut self, text: Text<'_>) {
-        let section: gfx_glyph::Section<'_> = text.into();
-        self.glyphs.queue(section);
+    pub fn has_glyph(&self, typeface: usize, character: char) -> bool {
+        self.typefaces[typeface].glyph(character).id().0 != 0
+    }
+
+    pub fn add(&mut self, text: Section<'_>) {
+        let section = section(&text);
+
+        // Text is queued when drawn, once its clip rectangle is known
+        self.sections.push(section.to_owned());
     }
 
-    pub fn measure(&mut self, text: Text<'_>) -> (f32, f32) {
-        let section: gfx_glyph::Section<'_> = text.into();
+    pub fn measure(&mut self, text: Section<'_>) -> (f32, f32) {
+        let section = section(&text);
         let bounds = self.glyphs.glyph_bounds(section);
 
         match bounds {
@@ -33,16 +61,59 @@ impl Font {
         }
     }
 
+    pub fn layout(&mut self, text: Section<'_>) -> Vec<Glyph> {
+        let section = section(&text);
+        let geometry = gfx_glyph::SectionGeometry::from(&section);
+        let glyphs = section.layout.calculate_glyphs(
+            &self.typefaces,
+            &geometry,
+            &section.text,
+        );
+
+        glyphs
+            .into_iter()
+            .map(|(glyph, _color, font_id)| {
+                let v_metrics =
+                    self.typefaces[font_id.0].v_metrics(glyph.scale());
+                let position = glyph.position();
+
+                Glyph {
+                    bounds: Rectangle {
+                        x: position.x,
+                        y: position.y - v_metrics.ascent,
+                        width: glyph.unpositioned().h_metrics().advance_width,
+                        height: v_metrics.ascent - v_metrics.descent,
+                    },
+                    baseline: position.y,
+                }
+            })
+            .collect()
+    }
+
     pub fn draw(
         &mut self,
         encoder: &mut gfx::Encoder<gl::Resources, gl::CommandBuffer>,
         target: &TargetView,
         transformation: Transformation,
+        clip: Option<Rectangle<f32>>,
     ) {
+        for section in self.sections.drain(..) {
+            let section = section.to_borrowed();
+
+            match clip {
+                Some(clip) => {
+                    let layout = Clipped::new(section.layout, clip);
+
+                    self.glyphs.queue_custom_layout(&section, &layout);
+                }
+                None => self.glyphs.queue(&section),
+            }
+        }
+
         let typed_target: gfx::handle::RenderTargetView<
             gl::Resources,
             gfx::format::Srgba8,
-        > = gfx::memory::Typed::new(target.clone());
+        > = gfx::memory::Typed::new(target.color.clone());
 
         self.glyphs
             .use_queue()
@@ -52,35 +123,116 @@ impl Font {
     }
 }
 
-impl<'a> From<Text<'a>> for gfx_glyph::Section<'a> {
-    fn from(text: Text<'a>) -> gfx_glyph::Section<'a> {
-        let x = match text.horizontal_alignment {
-            HorizontalAlignment::Left => text.position.x,
-            HorizontalAlignment::Center => {
-                text.position.x + text.bounds.0 / 2.0
-            }
-            HorizontalAlignment::Right => text.position.x + text.bounds.0,
-        };
+fn parse(bytes: Cow<'static, [u8]>) -> Result<gfx_glyph::Font<'static>> {
+    let typeface = match bytes {
+        Cow::Borrowed(bytes) => gfx_glyph::Font::from_bytes(bytes),
+        Cow::Owned(bytes) => gfx_glyph::Font::from_bytes(bytes),
+    };
+
+    typeface.map_err(|error| Error::Font(error.to_string()))
+}
+
+fn section<'a>(text: &Section<'a>) -> gfx_glyph::VariedSection<'a> {
+    let x = match text.horizontal_alignment {
+        HorizontalAlignment::Left => text.position.x,
+        HorizontalAlignment::Center => text.position.x + text.bounds.0 / 2.0,
+        HorizontalAlignment::Right => text.position.x + text.bounds.0,
+    };
+
+    let y = match text.vertical_alignment {
+        VerticalAlignment::Top => text.position.y,
+        VerticalAlignment::Center => text.position.y + text.bounds.1 / 2.0,
+        VerticalAlignment::Bottom => text.position.y + text.bounds.1,
+    };
+
+    gfx_glyph::VariedSection {
+        text: text
+            .runs
+            .iter()
+            .map(|run| gfx_glyph::SectionText {
+                text: run.content,
+                scale: gfx_glyph::Scale {
+                    x: run.size,
+                    y: run.size,
+                },
+                color: run.color.into_linear(),
+                font_id: gfx_glyph::FontId(run.typeface),
+            })
+            .collect(),
+        screen_position: (x, y),
+        bounds: text.bounds,
+        layout: gfx_glyph::Layout::default()
+            .h_align(text.horizontal_alignment.into())
+            .v_align(text.vertical_alignment.into()),
+        ..Default::default()
+    }
+}
+
+/// A layout that clips its glyphs to a rectangle.
+///
+/// The glyph brush clips the glyphs of a section to its bounds, so we shrink
+/// them to fit inside the clip rectangle.
+#[derive(Hash)]
+struct Clipped {
+    layout: gfx_glyph::Layout<gfx_glyph::BuiltInLineBreaker>,
+    // `f32` is not `Hash`, so we keep the raw bits of the rectangle
+    clip: [u32; 4],
+}
+
+impl Clipped {
+    fn new(
+        layout: gfx_glyph::Layout<gfx_glyph::BuiltInLineBreaker>,
+        clip: Rectangle<f32>,
+    ) -> Clipped {
+        Clipped {
+            layout,
+            clip: [
+                clip.x.to_bits(),
+                clip.y.to_bits(),
+                clip.width.to_bits(),
+                clip.height.to_bits(),
+            ],
+        }
+    }
+}
+
+impl GlyphPositioner for Clipped {
+    fn calculate_glyphs<'font, F: gfx_glyph::FontMap<'font>>(
+        &self,
+        fonts: &F,
+        geometry: &gfx_glyph::SectionGeometry,
+        sections: &[gfx_glyph::SectionText<'_>],
+    ) -> Vec<(
+        gfx_glyph::PositionedGlyph<'font>,
+        [f32; 4],
+        gfx_glyph::FontId,
+    )> {
+        self.layout.calculate_glyphs(fonts, geometry, sections)
+    }
+
+    fn bounds_rect(
+        &self,
+        geometry: &gfx_glyph::SectionGeometry,
+    ) -> gfx_glyph::Rect<f32> {
+        let bounds = self.layout.bounds_rect(geometry);
+        let [x, y, width, height] = self.clip;
+
+        let left = f32::from_bits(x);
+        let top = f32::from_bits(y);
+        let right = left + f32::from_bits(width);
+        let bottom = top + f32::from_bits(height);
 
-        let y = match text.vertical_alignment {
-            VerticalAlignment::Top => text.position.y,
-            VerticalAlignment::Center => text.position.y + text.bounds.1 / 2.0,
-            VerticalAlignment::Bottom => text.position.y + text.bounds.1,
+        let min = gfx_glyph::Point {
+            x: bounds.min.x.max(left),
+            y: bounds.min.y.max(top),
         };
 
-        gfx_glyph::Section {
-            text: &text.content,
-            screen_position: (x, y),
-            scale: gfx_glyph::Scale {
-                x: text.size,
-                y: text.size,
+        gfx_glyph::Rect {
+            min,
+            max: gfx_glyph::Point {
+                x: bounds.max.x.min(right).max(min.x),
+                y: bounds.max.y.min(bottom).max(min.y),
             },
-            color: text.color.into_linear(),
-            bounds: text.bounds,
-            layout: gfx_glyph::Layout::default()
-                .h_align(text.horizontal_alignment.into())
-                .v_align(text.vertical_alignment.into()),
-            ..Default::default()
         }
     }
 }
@@ -1,6 +1,10 @@
+mod blend;
 mod font;
 mod format;
 mod quad;
+mod resolve;
+mod shader;
+mod stencil;
 mod surface;
 pub mod texture;
 mod triangle;
@@ -8,17 +12,30 @@ mod types;
 
 pub use font::Font;
 pub use quad::Quad;
+pub use shader::Shader;
 pub use surface::{winit, Surface};
 pub use texture::Texture;
 pub use triangle::Vertex;
 pub use types::TargetView;
 
+use std::borrow::Cow;
+
 use gfx::{self, Device};
 use gfx_device_gl as gl;
 
-use crate::graphics::{Color, Transformation};
+use crate::graphics::shader::Kind;
+use crate::graphics::target::DrawState;
+use crate::graphics::{Color, Rectangle, Transformation};
 use crate::Result;
 
+// OpenGL constants that `gfx` does not expose
+const GL_MAX_SAMPLES: u32 = 0x8D57;
+const GL_READ_FRAMEBUFFER: u32 = 0x8CA8;
+const GL_BACK: u32 = 0x0405;
+const GL_PACK_ALIGNMENT: u32 = 0x0D05;
+const GL_RGBA: u32 = 0x1908;
+const GL_UNSIGNED_BYTE: u32 = 0x1401;
+
 /// A link between your game and a graphics processor.
 ///
 /// It is necessary to perform any kind of graphical operation, like loading
@@ -36,6 +53,8 @@ pub struct Gpu {
     encoder: gfx::Encoder<gl::Resources, gl::CommandBuffer>,
     triangle_pipeline: triangle::Pipeline,
     quad_pipeline: quad::Pipeline,
+    resolve_pipeline: resolve::Pipeline,
+    max_samples: u16,
 }
 
 impl Gpu {
@@ -43,9 +62,17 @@ impl Gpu {
         builder: winit::WindowBuilder,
         events_loop: &winit::EventsLoop,
     ) -> Result<(Gpu, Surface)> {
-        let (surface, device, mut factory) =
+        let (surface, mut device, mut factory) =
             Surface::new(builder, events_loop)?;
 
+        // `gfx` does not expose the multisampling limits of the device
+        let mut max_samples: i32 = 0;
+
+        unsafe {
+            device
+                .with_gl(|gl| gl.GetIntegerv(GL_MAX_SAMPLES, &mut max_samples));
+        }
+
         let mut encoder: gfx::Encoder<gl::Resources, gl::CommandBuffer> =
             factory.create_command_buffer().into();
 
@@ -58,6 +85,8 @@ impl Gpu {
         let quad_pipeline =
             quad::Pipeline::new(&mut factory, &mut encoder, surface.target());
 
+        let resolve_pipeline = resolve::Pipeline::new(&mut factory);
+
         Ok((
             Gpu {
                 device,
@@ -65,6 +94,8 @@ impl Gpu {
                 encoder,
                 triangle_pipeline,
                 quad_pipeline,
+                resolve_pipeline,
+                max_samples: max_samples.max(1).min(64) as u16,
             },
             surface,
         ))
@@ -74,7 +105,7 @@ impl Gpu {
         let typed_render_target: gfx::handle::RenderTargetView<
             gl::Resources,
             gfx::format::Srgba8,
-        > = gfx::memory::Typed::new(view.clone());
+        > = gfx::memory::Typed::new(view.color.clone());
 
         self.encoder
             .clear(&typed_render_target, color.into_linear())
@@ -95,6 +126,16 @@ impl Gpu {
         Texture::new(&mut self.factory, image)
     }
 
+    pub(super) fn update_texture(
+        &mut self,
+        texture: &Texture,
+        x: u16,
+        y: u16,
+        image: &image::RgbaImage,
+    ) {
+        texture.update(&mut self.encoder, x, y, image);
+    }
+
     pub(super) fn upload_texture_array(
         &mut self,
         layers: &[image::DynamicImage],
@@ -102,12 +143,23 @@ impl Gpu {
         Texture::new_array(&mut self.factory, layers)
     }
 
+    pub(super) fn max_samples(&self) -> u16 {
+        self.max_samples
+    }
+
     pub(super) fn create_drawable_texture(
         &mut self,
         width: u16,
         height: u16,
+        samples: u16,
     ) -> texture::Drawable {
-        texture::Drawable::new(&mut self.factory, width, height)
+        texture::Drawable::new(&mut self.factory, width, height, samples)
+    }
+
+    pub(super) fn resolve_drawable(&mut self, drawable: &texture::Drawable) {
+        if let Some(multisample) = drawable.multisample() {
+            self.resolve_pipeline.draw(&mut self.encoder, multisample);
+        }
     }
 
     pub(super) fn read_drawable_texture_pixels(
@@ -119,16 +171,72 @@ impl Gpu {
         drawable.read_pixels(&mut self.device, &mut self.factory)
     }
 
-    pub(super) fn upload_font(&mut self, bytes: &'static [u8]) -> Font {
+    /// Reads the pixels of the back buffer of the window, before it is
+    /// swapped.
+    pub(super) fn read_window_pixels(
+        &mut self,
+        width: u16,
+        height: u16,
+    ) -> image::DynamicImage {
+        self.flush();
+
+        let row = width as usize * 4;
+        let mut rgba = vec![0u8; row * height as usize];
+
+        unsafe {
+            self.device.with_gl(|gl| {
+                gl.BindFramebuffer(GL_READ_FRAMEBUFFER, 0);
+                gl.ReadBuffer(GL_BACK);
+                gl.PixelStorei(GL_PACK_ALIGNMENT, 1);
+                gl.ReadPixels(
+                    0,
+                    0,
+                    i32::from(width),
+                    i32::from(height),
+                    GL_RGBA,
+                    GL_UNSIGNED_BYTE,
+                    rgba.as_mut_ptr() as *mut _,
+                );
+            });
+        }
+
+        // OpenGL reads rows from the bottom up
+        let flipped: Vec<u8> =
+            rgba.chunks(row.max(1)).rev().flatten().cloned().collect();
+
+        image::DynamicImage::ImageRgba8(
+            image::ImageBuffer::from_raw(
+                u32::from(width),
+                u32::from(height),
+                flipped,
+            )
+            .expect("Create RGBA8 image"),
+        )
+    }
+
+    pub(super) fn upload_font(
+        &mut self,
+        bytes: Cow<'static, [u8]>,
+    ) -> Result<Font> {
         Font::from_bytes(&mut self.factory, bytes)
     }
 
+    pub(super) fn create_shader(
+        &mut self,
+        kind: Kind,
+        source: &[u8],
+    ) -> Result<Shader> {
+        Shader::new(&mut self.factory, kind, source)
+    }
+
     pub(super) fn draw_triangles(
         &mut self,
         vertices: &[Vertex],
         indices: &[u32],
         view: &TargetView,
         transformation: &Transformation,
+        state: DrawState,
+        shader: Option<&Shader>,
     ) {
         self.triangle_pipeline.draw(
             &mut self.factory,
@@ -136,7 +244,30 @@ impl Gpu {
             vertices,
             indices,
             transformation,
+            state,
+            shader.map(Shader::triangles),
             view,
+            scissor(view, state.clip),
+        );
+    }
+
+    pub(super) fn draw_mask(
+        &mut self,
+        vertices: &[Vertex],
+        indices: &[u32],
+        view: &TargetView,
+        transformation: &Transformation,
+        state: DrawState,
+    ) {
+        self.triangle_pipeline.draw_mask(
+            &mut self.factory,
+            &mut self.encoder,
+            vertices,
+            indices,
+            transformation,
+            state.mask,
+            view,
+            scissor(view, state.clip),
         );
     }
 
@@ -146,6 +277,8 @@ impl Gpu {
         instances: &[Quad],
         view: &TargetView,
         transformation: &Transformation,
+        state: DrawState,
+        shader: Option<&Shader>,
     ) {
         self.quad_pipeline.bind_texture(texture);
 
@@ -153,7 +286,10 @@ impl Gpu {
             &mut self.encoder,
             instances,
             transformation,
+            state,
+            shader.map(Shader::quads),
             view,
+            scissor(view, state.clip),
         );
     }
 
@@ -162,7 +298,37 @@ impl Gpu {
         font: &mut Font,
         target: &TargetView,
         transformation: Transformation,
+        clip: Option<Rectangle<f32>>,
     ) {
-        font.draw(&mut self.encoder, target, transformation);
+        font.draw(&mut self.encoder, target, transformation, clip);
+    }
+}
+
+/// Converts a clip rectangle into a scissor rectangle for the given view.
+///
+/// The origin of OpenGL framebuffers is at their bottom-left corner.
+fn scissor(view: &TargetView, clip: Option<Rectangle<u32>>) -> gfx::Rect {
+    let (width, height, _, _) = view.color.get_dimensions();
+
+    match clip {
+        Some(clip) => {
+            let x = clip.x.min(u32::from(width));
+            let y = clip.y.min(u32::from(height));
+            let w = clip.width.min(u32::from(width) - x);
+            let h = clip.height.min(u32::from(height) - y);
+
+            gfx::Rect {
+                x: x as u16,
+                y: (u32::from(height) - y - h) as u16,
+                w: w as u16,
+                h: h as u16,
+            }
+        }
+        None => gfx::Rect {
+            x: 0,
+            y: 0,
+            w: width,
+            h: height,
+        },
     }
 }
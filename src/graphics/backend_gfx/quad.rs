@@ -1,10 +1,15 @@
+use std::collections::HashMap;
+
 use gfx::traits::FactoryExt;
 use gfx::{self, *};
 use gfx_device_gl as gl;
 
-use super::format;
+use super::shader::Uniforms;
 use super::texture::Texture;
-use crate::graphics::{self, Transformation};
+use super::types::TargetView;
+use super::{blend, format, stencil};
+use crate::graphics::target::DrawState;
+use crate::graphics::{self, BlendMode, Transformation};
 
 const MAX_INSTANCES: u32 = 100_000;
 const QUAD_INDICES: [u16; 6] = [0, 1, 2, 0, 2, 3];
@@ -33,6 +38,8 @@ gfx_defines! {
         src: [f32; 4] = "a_Src",
         translation: [f32; 2] = "a_Translation",
         scale: [f32; 2] = "a_Scale",
+        rotation: f32 = "a_Rotation",
+        tint: [f32; 4] = "a_Tint",
         layer: u32 = "t_Layer",
     }
 
@@ -44,7 +51,11 @@ gfx_defines! {
         vertices: gfx::VertexBuffer<Vertex> = (),
         texture: gfx::TextureSampler<[f32; 4]> = "t_Texture",
         globals: gfx::ConstantBuffer<Globals> = "Globals",
+        uniforms: gfx::pso::buffer::RawConstantBuffer = "Uniforms",
+        scissor: gfx::Scissor = (),
         instances: gfx::InstanceBuffer<Quad> = (),
+        stencil: gfx::StencilTarget<gfx::format::DepthStencil> =
+            stencil::unmasked(),
         out: gfx::RawRenderTarget =
           (
               "Target0",
@@ -58,15 +69,16 @@ gfx_defines! {
 pub struct Pipeline {
     slice: gfx::Slice<gl::Resources>,
     data: pipe::Data<gl::Resources>,
-    shader: Shader,
+    shaders: Shaders,
     globals: Globals,
+    uniforms: gfx::handle::Buffer<gl::Resources, [f32; 4]>,
 }
 
 impl Pipeline {
     pub fn new(
         factory: &mut gl::Factory,
         encoder: &mut gfx::Encoder<gl::Resources, gl::CommandBuffer>,
-        target: &gfx::handle::RawRenderTargetView<gl::Resources>,
+        target: &TargetView,
     ) -> Pipeline {
         // Create point buffer
         let instances = factory
@@ -95,25 +107,34 @@ impl Pipeline {
             )),
         );
 
+        let uniforms = factory
+            .create_buffer(
+                graphics::Shader::MAX_UNIFORMS,
+                gfx::buffer::Role::Constant,
+                gfx::memory::Usage::Dynamic,
+                gfx::memory::Bind::empty(),
+            )
+            .expect("Uniform buffer creation");
+
         let data = pipe::Data {
             vertices: quads.clone(),
             texture: (texture.view().clone(), sampler),
             globals: factory.create_constant_buffer(1),
+            uniforms: uniforms.raw().clone(),
+            scissor: gfx::Rect {
+                x: 0,
+                y: 0,
+                w: 1,
+                h: 1,
+            },
             instances,
-            out: target.clone(),
+            stencil: (target.depth_stencil.clone(), (0, 0)),
+            out: target.color.clone(),
         };
 
-        let init = pipe::Init {
-            out: (
-                "Target0",
-                format::COLOR,
-                gfx::state::ColorMask::all(),
-                Some(gfx::preset::blend::ALPHA),
-            ),
-            ..pipe::new()
-        };
-
-        let shader = Shader::new(factory, init);
+        let shaders =
+            create_shaders(factory, include_bytes!("shader/quad.frag"))
+                .expect("Shader creation");
 
         let globals = Globals {
             mvp: Transformation::identity().into(),
@@ -126,8 +147,9 @@ impl Pipeline {
         Pipeline {
             slice,
             data,
-            shader,
+            shaders,
             globals,
+            uniforms,
         }
     }
 
@@ -140,7 +162,10 @@ impl Pipeline {
         encoder: &mut gfx::Encoder<gl::Resources, gl::CommandBuffer>,
         instances: &[Quad],
         transformation: &Transformation,
-        view: &gfx::handle::RawRenderTargetView<gl::Resources>,
+        state: DrawState,
+        shader: Option<(&Shaders, &Uniforms)>,
+        view: &TargetView,
+        scissor: gfx::Rect,
     ) {
         let transformation_matrix: [[f32; 4]; 4] =
             transformation.clone().into();
@@ -153,7 +178,21 @@ impl Pipeline {
                 .expect("Globals upload");
         }
 
-        self.data.out = view.clone();
+        self.data.out = view.color.clone();
+        self.data.stencil =
+            (view.depth_stencil.clone(), (state.mask, state.mask));
+        self.data.scissor = scissor;
+
+        let shaders = match shader {
+            Some((shaders, uniforms)) => {
+                encoder
+                    .update_buffer(&self.uniforms, &uniforms[..], 0)
+                    .expect("Uniforms upload");
+
+                shaders
+            }
+            None => &self.shaders,
+        };
 
         let mut i = 0;
         let total = instances.len();
@@ -167,57 +206,107 @@ impl Pipeline {
 
             self.slice.instances = Some((end as u32 - i as u32, 0));
 
-            encoder.draw(&self.slice, &self.shader.state, &self.data);
+            encoder.draw(
+                &self.slice,
+                shaders[&state.blend_mode].state(state.mask),
+                &self.data,
+            );
 
             i += MAX_INSTANCES as usize;
         }
     }
 }
 
+pub type Shaders = HashMap<BlendMode, Shader>;
+
+pub fn create_shaders(
+    factory: &mut gl::Factory,
+    fragment: &[u8],
+) -> Result<Shaders, String> {
+    BlendMode::ALL
+        .iter()
+        .map(|blend_mode| {
+            let init = |stencil| pipe::Init {
+                stencil,
+                out: (
+                    "Target0",
+                    format::COLOR,
+                    gfx::state::ColorMask::all(),
+                    Some(blend::state(*blend_mode)),
+                ),
+                ..pipe::new()
+            };
+
+            let shader = Shader {
+                state: create_state(
+                    factory,
+                    init(stencil::unmasked()),
+                    fragment,
+                )?,
+                masked: create_state(
+                    factory,
+                    init(stencil::masked()),
+                    fragment,
+                )?,
+            };
+
+            Ok((*blend_mode, shader))
+        })
+        .collect()
+}
+
 pub struct Shader {
     state: gfx::pso::PipelineState<gl::Resources, pipe::Meta>,
+    masked: gfx::pso::PipelineState<gl::Resources, pipe::Meta>,
 }
 
 impl Shader {
-    pub fn new(factory: &mut gl::Factory, init: pipe::Init<'_>) -> Shader {
-        let set = factory
-            .create_shader_set(
-                include_bytes!("shader/quad.vert"),
-                include_bytes!("shader/quad.frag"),
-            )
-            .expect("Shader set creation");
-
-        let rasterizer = gfx::state::Rasterizer {
-            front_face: gfx::state::FrontFace::CounterClockwise,
-            cull_face: gfx::state::CullFace::Nothing,
-            method: gfx::state::RasterMethod::Fill,
-            offset: None,
-            samples: None,
-        };
-
-        let state = factory
-            .create_pipeline_state(
-                &set,
-                Primitive::TriangleList,
-                rasterizer,
-                init,
-            )
-            .expect("Pipeline state creation");
-
-        Shader { state }
+    fn state(
+        &self,
+        mask: u8,
+    ) -> &gfx::pso::PipelineState<gl::Resources, pipe::Meta> {
+        if mask > 0 {
+            &self.masked
+        } else {
+            &self.state
+        }
     }
 }
 
+fn create_state(
+    factory: &mut gl::Factory,
+    init: pipe::Init<'_>,
+    fragment: &[u8],
+) -> Result<gfx::pso::PipelineState<gl::Resources, pipe::Meta>, String> {
+    let set = factory
+        .create_shader_set(include_bytes!("shader/quad.vert"), fragment)
+        .map_err(|error| format!("{:?}", error))?;
+
+    let rasterizer = gfx::state::Rasterizer {
+        front_face: gfx::state::FrontFace::CounterClockwise,
+        cull_face: gfx::state::CullFace::Nothing,
+        method: gfx::state::RasterMethod::Fill,
+        offset: None,
+        samples: Some(gfx::state::MultiSample),
+    };
+
+    let state = factory
+        .create_pipeline_state(&set, Primitive::TriangleList, rasterizer, init)
+        .map_err(|error| format!("{:?}", error))?;
+
+    Ok(state)
+}
+
 impl From<graphics::Quad> for Quad {
     fn from(quad: graphics::Quad) -> Quad {
-        let source = quad.source;
-        let position = quad.position;
         let (width, height) = quad.size;
 
         Quad {
-            src: [source.x, source.y, source.width, source.height],
-            translation: [position.x, position.y],
+            src: quad.flipped_source(),
+            translation: quad.top_left(),
             scale: [width, height],
+            rotation: quad.rotation,
+            tint: quad.tint.into_linear(),
             layer: 0,
         }
     }
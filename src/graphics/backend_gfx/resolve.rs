@@ -0,0 +1,92 @@
+use gfx::traits::FactoryExt;
+use gfx::{self, *};
+use gfx_device_gl as gl;
+
+use super::format;
+use super::texture::Multisample;
+
+gfx_defines! {
+    pipeline pipe {
+        texture: gfx::RawShaderResource = "t_Texture",
+        sampler: gfx::Sampler = "t_Texture",
+        samples: gfx::Global<i32> = "u_Samples",
+        out: gfx::RawRenderTarget =
+          (
+              "Target0",
+               format::COLOR,
+               gfx::state::ColorMask::all(),
+               None
+          ),
+    }
+}
+
+/// Averages the samples of a multisampled texture into a regular one.
+pub struct Pipeline {
+    state: gfx::pso::PipelineState<gl::Resources, pipe::Meta>,
+    sampler: gfx::handle::Sampler<gl::Resources>,
+    slice: gfx::Slice<gl::Resources>,
+}
+
+impl Pipeline {
+    pub fn new(factory: &mut gl::Factory) -> Pipeline {
+        let set = factory
+            .create_shader_set(
+                include_bytes!("shader/resolve.vert"),
+                include_bytes!("shader/resolve.frag"),
+            )
+            .expect("Resolve shader creation");
+
+        let rasterizer = gfx::state::Rasterizer {
+            front_face: gfx::state::FrontFace::CounterClockwise,
+            cull_face: gfx::state::CullFace::Nothing,
+            method: gfx::state::RasterMethod::Fill,
+            offset: None,
+            samples: None,
+        };
+
+        let state = factory
+            .create_pipeline_state(
+                &set,
+                Primitive::TriangleList,
+                rasterizer,
+                pipe::new(),
+            )
+            .expect("Resolve pipeline creation");
+
+        let sampler = factory.create_sampler(gfx::texture::SamplerInfo::new(
+            gfx::texture::FilterMethod::Scale,
+            gfx::texture::WrapMode::Clamp,
+        ));
+
+        // A single triangle covering the whole target, generated in the
+        // vertex shader
+        let slice = gfx::Slice {
+            start: 0,
+            end: 3,
+            base_vertex: 0,
+            instances: None,
+            buffer: gfx::IndexBuffer::Auto,
+        };
+
+        Pipeline {
+            state,
+            sampler,
+            slice,
+        }
+    }
+
+    pub fn draw(
+        &mut self,
+        encoder: &mut gfx::Encoder<gl::Resources, gl::CommandBuffer>,
+        multisample: &Multisample,
+    ) {
+        let data = pipe::Data {
+            texture: multisample.view.clone(),
+            sampler: self.sampler.clone(),
+            samples: i32::from(multisample.samples),
+            out: multisample.resolve.clone(),
+        };
+
+        encoder.draw(&self.slice, &self.state, &data);
+    }
+}
@@ -0,0 +1,57 @@
+use gfx_device_gl as gl;
+
+use super::{quad, triangle};
+use crate::graphics::{self, shader::Kind};
+use crate::{Error, Result};
+
+pub type Uniforms = [[f32; 4]; graphics::Shader::MAX_UNIFORMS];
+
+pub struct Shader {
+    pipelines: Pipelines,
+    uniforms: Uniforms,
+}
+
+enum Pipelines {
+    Quads(quad::Shaders),
+    Triangles(triangle::Shaders),
+}
+
+impl Shader {
+    pub fn new(
+        factory: &mut gl::Factory,
+        kind: Kind,
+        source: &[u8],
+    ) -> Result<Shader> {
+        let pipelines = match kind {
+            Kind::Quads => {
+                quad::create_shaders(factory, source).map(Pipelines::Quads)
+            }
+            Kind::Meshes => triangle::create_shaders(factory, source)
+                .map(Pipelines::Triangles),
+        }
+        .map_err(Error::Shader)?;
+
+        Ok(Shader {
+            pipelines,
+            uniforms: [[0.0; 4]; graphics::Shader::MAX_UNIFORMS],
+        })
+    }
+
+    pub fn set_uniform(&mut self, index: usize, value: [f32; 4]) {
+        self.uniforms[index] = value;
+    }
+
+    pub fn quads(&self) -> (&quad::Shaders, &Uniforms) {
+        match &self.pipelines {
+            Pipelines::Quads(shaders) => (shaders, &self.uniforms),
+            Pipelines::Triangles(_) => panic!("The shader cannot draw quads"),
+        }
+    }
+
+    pub fn triangles(&self) -> (&triangle::Shaders, &Uniforms) {
+        match &self.pipelines {
+            Pipelines::Triangles(shaders) => (shaders, &self.uniforms),
+            Pipelines::Quads(_) => panic!("The shader cannot draw triangles"),
+        }
+    }
+}
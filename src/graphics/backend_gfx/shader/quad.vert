@@ -5,6 +5,8 @@ in vec2 a_Pos;
 in vec4 a_Src;
 in vec2 a_Scale;
 in vec2 a_Translation;
+in float a_Rotation;
+in vec4 a_Tint;
 in uint t_Layer;
 
 layout (std140) uniform Globals {
@@ -13,6 +15,7 @@ layout (std140) uniform Globals {
 
 out vec2 v_Uv;
 flat out uint v_Layer;
+out vec4 v_Tint;
 
 const mat4 INVERT_Y_AXIS = mat4(
     vec4(1.0, 0.0, 0.0, 0.0),
@@ -24,10 +27,14 @@ const mat4 INVERT_Y_AXIS = mat4(
 void main() {
     v_Uv = a_Pos * a_Src.zw + a_Src.xy;
     v_Layer = t_Layer;
+    v_Tint = a_Tint;
+
+    float s = sin(a_Rotation);
+    float c = cos(a_Rotation);
 
     mat4 instance_transform = mat4(
-        vec4(a_Scale.x, 0.0, 0.0, 0.0),
-        vec4(0.0, a_Scale.y, 0.0, 0.0),
+        vec4(a_Scale.x * c, a_Scale.x * s, 0.0, 0.0),
+        vec4(-a_Scale.y * s, a_Scale.y * c, 0.0, 0.0),
         vec4(0.0, 0.0, 1.0, 0.0),
         vec4(a_Translation, 0.0, 1.0)
     );
@@ -0,0 +1,37 @@
+use gfx::state::{Comparison, Stencil, StencilOp};
+
+/// Draws every pixel, ignoring the stencil buffer.
+pub fn unmasked() -> Stencil {
+    Stencil::new(
+        Comparison::Always,
+        0xFF,
+        (StencilOp::Keep, StencilOp::Keep, StencilOp::Keep),
+    )
+}
+
+/// Draws the pixels with a stencil value equal to the reference.
+pub fn masked() -> Stencil {
+    Stencil::new(
+        Comparison::Equal,
+        0xFF,
+        (StencilOp::Keep, StencilOp::Keep, StencilOp::Keep),
+    )
+}
+
+/// Increments the stencil value of the pixels equal to the reference.
+pub fn write_mask() -> Stencil {
+    Stencil::new(
+        Comparison::Equal,
+        0xFF,
+        (StencilOp::Keep, StencilOp::Keep, StencilOp::IncrementClamp),
+    )
+}
+
+/// Replaces the stencil values greater than the reference with it.
+pub fn reset_mask() -> Stencil {
+    Stencil::new(
+        Comparison::Less,
+        0xFF,
+        (StencilOp::Keep, StencilOp::Keep, StencilOp::Replace),
+    )
+}
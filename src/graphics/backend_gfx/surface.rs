@@ -22,7 +22,7 @@ impl Surface {
             .with_pixel_format(24, 8)
             .with_vsync(true);
 
-        let (context, device, factory, target, _depth) =
+        let (context, device, factory, color, depth_stencil) =
             gfx_window_glutin::init_raw(
                 builder,
                 gl_builder,
@@ -32,6 +32,11 @@ impl Surface {
             )
             .map_err(|error| Error::WindowCreation(error.to_string()))?;
 
+        let target = TargetView {
+            color,
+            depth_stencil: gfx::memory::Typed::new(depth_stencil),
+        };
+
         Ok((Self { context, target }, device, factory))
     }
 
@@ -43,18 +48,29 @@ impl Surface {
         &self.target
     }
 
+    pub fn read_pixels(&self, gpu: &mut Gpu) -> image::DynamicImage {
+        let (width, height, _, _) = self.target.color.get_dimensions();
+
+        gpu.read_window_pixels(width, height)
+    }
+
     pub fn resize(&mut self, _gpu: &mut Gpu, size: winit::dpi::PhysicalSize) {
         self.context.resize(size);
 
-        let dimensions = self.target.get_dimensions();
+        let dimensions = self.target.color.get_dimensions();
 
-        if let Some((target, _depth)) = gfx_window_glutin::update_views_raw(
-            &self.context,
-            dimensions,
-            format::COLOR,
-            format::DEPTH,
-        ) {
-            self.target = target;
+        if let Some((color, depth_stencil)) =
+            gfx_window_glutin::update_views_raw(
+                &self.context,
+                dimensions,
+                format::COLOR,
+                format::DEPTH,
+            )
+        {
+            self.target = TargetView {
+                color,
+                depth_stencil: gfx::memory::Typed::new(depth_stencil),
+            };
         }
     }
 
@@ -7,7 +7,10 @@ use gfx_core::factory::Factory;
 use gfx_device_gl as gl;
 
 use super::format::{Channel, Surface};
-use super::types::{RawTexture, ShaderResource, TargetView};
+use super::types::{
+    DepthStencilView, RawShaderResource, RawTargetView, RawTexture,
+    ShaderResource, TargetView,
+};
 use crate::graphics::vector::Vector;
 use crate::graphics::Transformation;
 
@@ -78,6 +81,38 @@ impl Texture {
         }
     }
 
+    pub(super) fn update(
+        &self,
+        encoder: &mut gfx::Encoder<gl::Resources, gl::CommandBuffer>,
+        x: u16,
+        y: u16,
+        image: &image::RgbaImage,
+    ) {
+        let texture: gfx::handle::Texture<gl::Resources, Surface> =
+            Typed::new(self.raw.clone());
+
+        let pixels: Vec<[u8; 4]> =
+            image.pixels().map(|pixel| pixel.data).collect();
+
+        encoder
+            .update_texture::<Surface, gfx::format::Srgba8>(
+                &texture,
+                None,
+                gfx::texture::NewImageInfo {
+                    xoffset: x,
+                    yoffset: y,
+                    zoffset: 0,
+                    width: image.width() as u16,
+                    height: image.height() as u16,
+                    depth: 1,
+                    format: (),
+                    mipmap: 0,
+                },
+                &pixels,
+            )
+            .expect("Update texture");
+    }
+
     pub(super) fn handle(&self) -> &RawTexture {
         &self.raw
     }
@@ -99,10 +134,23 @@ impl Texture {
 pub struct Drawable {
     texture: Texture,
     target: TargetView,
+    multisample: Option<Multisample>,
+}
+
+#[derive(Clone)]
+pub struct Multisample {
+    pub view: RawShaderResource,
+    pub resolve: RawTargetView,
+    pub samples: u8,
 }
 
 impl Drawable {
-    pub fn new(factory: &mut gl::Factory, width: u16, height: u16) -> Drawable {
+    pub fn new(
+        factory: &mut gl::Factory,
+        width: u16,
+        height: u16,
+        samples: u16,
+    ) -> Drawable {
         let (raw, view) = create_texture_array(
             factory,
             width,
@@ -127,11 +175,70 @@ impl Drawable {
             layer: Some(0),
         };
 
-        let target = factory
+        let color = factory
             .view_texture_as_render_target_raw(texture.handle(), render_desc)
             .expect("View texture as render target");
 
-        Drawable { texture, target }
+        if samples > 1 {
+            let aa_mode = gfx::texture::AaMode::Multi(samples as u8);
+            let multisampled =
+                create_multisampled_texture(factory, width, height, aa_mode);
+
+            let target = TargetView {
+                color: factory
+                    .view_texture_as_render_target_raw(
+                        &multisampled,
+                        gfx::texture::RenderDesc {
+                            channel: Channel::get_channel_type(),
+                            level: 0,
+                            layer: None,
+                        },
+                    )
+                    .expect("View multisampled texture as render target"),
+                depth_stencil: create_depth_stencil(
+                    factory, width, height, aa_mode,
+                ),
+            };
+
+            let view = factory
+                .view_texture_as_shader_resource_raw(
+                    &multisampled,
+                    gfx::texture::ResourceDesc {
+                        channel: Channel::get_channel_type(),
+                        layer: None,
+                        min: 0,
+                        max: 0,
+                        swizzle: gfx::format::Swizzle::new(),
+                    },
+                )
+                .expect("View multisampled texture as a shader resource");
+
+            Drawable {
+                texture,
+                target,
+                multisample: Some(Multisample {
+                    view,
+                    resolve: color,
+                    samples: samples as u8,
+                }),
+            }
+        } else {
+            let target = TargetView {
+                color,
+                depth_stencil: create_depth_stencil(
+                    factory,
+                    width,
+                    height,
+                    gfx::texture::AaMode::Single,
+                ),
+            };
+
+            Drawable {
+                texture,
+                target,
+                multisample: None,
+            }
+        }
     }
 
     pub fn texture(&self) -> &Texture {
@@ -142,6 +249,17 @@ impl Drawable {
         &self.target
     }
 
+    pub fn multisample(&self) -> Option<&Multisample> {
+        self.multisample.as_ref()
+    }
+
+    pub fn samples(&self) -> u16 {
+        self.multisample
+            .as_ref()
+            .map(|multisample| u16::from(multisample.samples))
+            .unwrap_or(1)
+    }
+
     pub fn read_pixels(
         &self,
         device: &mut gl::Device,
@@ -246,3 +364,55 @@ fn create_texture_array(
 
     (texture, typed_view)
 }
+
+fn create_multisampled_texture(
+    factory: &mut gl::Factory,
+    width: u16,
+    height: u16,
+    aa_mode: gfx::texture::AaMode,
+) -> RawTexture {
+    let info = gfx::texture::Info {
+        kind: gfx::texture::Kind::D2(width, height, aa_mode),
+        levels: 1,
+        format: Surface::get_surface_type(),
+        bind: gfx::memory::Bind::SHADER_RESOURCE
+            | gfx::memory::Bind::RENDER_TARGET,
+        usage: gfx::memory::Usage::Data,
+    };
+
+    factory
+        .create_texture_raw(info, Some(Channel::get_channel_type()), None)
+        .expect("Multisampled texture creation")
+}
+
+fn create_depth_stencil(
+    factory: &mut gl::Factory,
+    width: u16,
+    height: u16,
+    aa_mode: gfx::texture::AaMode,
+) -> DepthStencilView {
+    let info = gfx::texture::Info {
+        kind: gfx::texture::Kind::D2(width, height, aa_mode),
+        levels: 1,
+        format: gfx::format::SurfaceType::D24_S8,
+        bind: gfx::memory::Bind::DEPTH_STENCIL,
+        usage: gfx::memory::Usage::Data,
+    };
+
+    let texture = factory
+        .create_texture_raw(info, Some(gfx::format::ChannelType::Unorm), None)
+        .expect("Depth stencil texture creation");
+
+    let view = factory
+        .view_texture_as_depth_stencil_raw(
+            &texture,
+            gfx::texture::DepthStencilDesc {
+                level: 0,
+                layer: None,
+                flags: gfx::texture::DepthStencilFlags::empty(),
+            },
+        )
+        .expect("View texture as depth stencil");
+
+    gfx::memory::Typed::new(view)
+}
@@ -1,9 +1,36 @@
+use std::collections::HashMap;
+
 use gfx::traits::FactoryExt;
 use gfx::{self, *};
 use gfx_device_gl as gl;
 
-use super::format;
-use crate::graphics::Transformation;
+use super::shader::Uniforms;
+use super::types::TargetView;
+use super::{blend, format, stencil};
+use crate::graphics::target::DrawState;
+use crate::graphics::{self, BlendMode, Transformation};
+
+/// Two triangles covering the whole target in normalized device coordinates.
+const FULLSCREEN_VERTICES: [Vertex; 4] = [
+    Vertex {
+        position: [-1.0, -1.0],
+        color: [1.0, 1.0, 1.0, 1.0],
+    },
+    Vertex {
+        position: [1.0, -1.0],
+        color: [1.0, 1.0, 1.0, 1.0],
+    },
+    Vertex {
+        position: [1.0, 1.0],
+        color: [1.0, 1.0, 1.0, 1.0],
+    },
+    Vertex {
+        position: [-1.0, 1.0],
+        color: [1.0, 1.0, 1.0, 1.0],
+    },
+];
+
+const FULLSCREEN_INDICES: [u32; 6] = [0, 1, 2, 0, 2, 3];
 
 gfx_defines! {
     vertex Vertex {
@@ -18,6 +45,10 @@ gfx_defines! {
     pipeline pipe {
         vertices: gfx::VertexBuffer<Vertex> = (),
         globals: gfx::ConstantBuffer<Globals> = "Globals",
+        uniforms: gfx::pso::buffer::RawConstantBuffer = "Uniforms",
+        scissor: gfx::Scissor = (),
+        stencil: gfx::StencilTarget<gfx::format::DepthStencil> =
+            stencil::unmasked(),
         out: gfx::RawRenderTarget =
           (
               "Target0",
@@ -31,8 +62,16 @@ gfx_defines! {
 pub struct Pipeline {
     data: pipe::Data<gl::Resources>,
     indices: gfx::handle::Buffer<gl::Resources, u32>,
-    shader: Shader,
+    shaders: Shaders,
+    masks: Masks,
     globals: Globals,
+    uniforms: gfx::handle::Buffer<gl::Resources, [f32; 4]>,
+}
+
+/// The pipeline states used to draw masks on the stencil buffer.
+struct Masks {
+    write: gfx::pso::PipelineState<gl::Resources, pipe::Meta>,
+    reset: gfx::pso::PipelineState<gl::Resources, pipe::Meta>,
 }
 
 impl Pipeline {
@@ -41,7 +80,7 @@ impl Pipeline {
     pub fn new(
         factory: &mut gl::Factory,
         encoder: &mut gfx::Encoder<gl::Resources, gl::CommandBuffer>,
-        target: &gfx::handle::RawRenderTargetView<gl::Resources>,
+        target: &TargetView,
     ) -> Pipeline {
         let vertices = factory
             .create_buffer(
@@ -61,23 +100,39 @@ impl Pipeline {
             )
             .expect("Index buffer creation");
 
+        let uniforms = factory
+            .create_buffer(
+                graphics::Shader::MAX_UNIFORMS,
+                gfx::buffer::Role::Constant,
+                gfx::memory::Usage::Dynamic,
+                gfx::memory::Bind::empty(),
+            )
+            .expect("Uniform buffer creation");
+
         let data = pipe::Data {
             vertices,
             globals: factory.create_constant_buffer(1),
-            out: target.clone(),
+            uniforms: uniforms.raw().clone(),
+            scissor: gfx::Rect {
+                x: 0,
+                y: 0,
+                w: 1,
+                h: 1,
+            },
+            stencil: (target.depth_stencil.clone(), (0, 0)),
+            out: target.color.clone(),
         };
 
-        let init = pipe::Init {
-            out: (
-                "Target0",
-                format::COLOR,
-                gfx::state::ColorMask::all(),
-                Some(gfx::preset::blend::ALPHA),
-            ),
-            ..pipe::new()
-        };
+        let shaders =
+            create_shaders(factory, include_bytes!("shader/triangle.frag"))
+                .expect("Shader creation");
 
-        let shader = Shader::new(factory, init);
+        let masks = Masks {
+            write: create_mask_state(factory, stencil::write_mask())
+                .expect("Mask pipeline creation"),
+            reset: create_mask_state(factory, stencil::reset_mask())
+                .expect("Mask pipeline creation"),
+        };
 
         let globals = Globals {
             mvp: Transformation::identity().into(),
@@ -90,8 +145,10 @@ impl Pipeline {
         Pipeline {
             data,
             indices,
-            shader,
+            shaders,
+            masks,
             globals,
+            uniforms,
         }
     }
 
@@ -102,8 +159,107 @@ impl Pipeline {
         vertices: &[Vertex],
         indices: &[u32],
         transformation: &Transformation,
-        view: &gfx::handle::RawRenderTargetView<gl::Resources>,
+        state: DrawState,
+        shader: Option<(&Shaders, &Uniforms)>,
+        view: &TargetView,
+        scissor: gfx::Rect,
     ) {
+        let slice = self.upload(
+            factory,
+            encoder,
+            vertices,
+            indices,
+            transformation,
+            view,
+            scissor,
+        );
+
+        self.data.stencil.1 = (state.mask, state.mask);
+
+        let shaders = match shader {
+            Some((shaders, uniforms)) => {
+                encoder
+                    .update_buffer(&self.uniforms, &uniforms[..], 0)
+                    .expect("Uniforms upload");
+
+                shaders
+            }
+            None => &self.shaders,
+        };
+
+        encoder.draw(
+            &slice,
+            shaders[&state.blend_mode].state(state.mask),
+            &self.data,
+        );
+    }
+
+    /// Draws the given triangles on the stencil buffer, incrementing the
+    /// stencil value of the covered pixels that have the given `level`.
+    ///
+    /// Any stencil value greater than `level`, left by a previous mask of
+    /// the same level, is reset first.
+    pub fn draw_mask(
+        &mut self,
+        factory: &mut gl::Factory,
+        encoder: &mut gfx::Encoder<gl::Resources, gl::CommandBuffer>,
+        vertices: &[Vertex],
+        indices: &[u32],
+        transformation: &Transformation,
+        level: u8,
+        view: &TargetView,
+        scissor: gfx::Rect,
+    ) {
+        if level == 0 {
+            encoder.clear_stencil(&view.depth_stencil, 0);
+        } else {
+            let (width, height, _, _) = view.color.get_dimensions();
+
+            let slice = self.upload(
+                factory,
+                encoder,
+                &FULLSCREEN_VERTICES,
+                &FULLSCREEN_INDICES,
+                &Transformation::identity(),
+                view,
+                gfx::Rect {
+                    x: 0,
+                    y: 0,
+                    w: width,
+                    h: height,
+                },
+            );
+
+            self.data.stencil.1 = (level, level);
+
+            encoder.draw(&slice, &self.masks.reset, &self.data);
+        }
+
+        let slice = self.upload(
+            factory,
+            encoder,
+            vertices,
+            indices,
+            transformation,
+            view,
+            scissor,
+        );
+
+        self.data.stencil.1 = (level, level);
+
+        encoder.draw(&slice, &self.masks.write, &self.data);
+    }
+
+    fn upload(
+        &mut self,
+        factory: &mut gl::Factory,
+        encoder: &mut gfx::Encoder<gl::Resources, gl::CommandBuffer>,
+        vertices: &[Vertex],
+        indices: &[u32],
+        transformation: &Transformation,
+        view: &TargetView,
+        scissor: gfx::Rect,
+    ) -> gfx::Slice<gl::Resources> {
         let transformation_matrix: [[f32; 4]; 4] =
             transformation.clone().into();
 
@@ -115,7 +271,9 @@ impl Pipeline {
                 .expect("Globals upload");
         }
 
-        self.data.out = view.clone();
+        self.data.out = view.color.clone();
+        self.data.stencil.0 = view.depth_stencil.clone();
+        self.data.scissor = scissor;
 
         if self.data.vertices.len() < vertices.len()
             || self.indices.len() < indices.len()
@@ -150,50 +308,112 @@ impl Pipeline {
             .update_buffer(&self.indices, &indices, 0)
             .expect("Index upload");
 
-        let slice = gfx::Slice {
+        gfx::Slice {
             start: 0,
             end: indices.len() as u32,
             base_vertex: 0,
             instances: None,
             buffer: gfx::IndexBuffer::Index32(self.indices.clone()),
-        };
-
-        encoder.draw(&slice, &self.shader.state, &self.data);
+        }
     }
 }
 
+pub type Shaders = HashMap<BlendMode, Shader>;
+
+pub fn create_shaders(
+    factory: &mut gl::Factory,
+    fragment: &[u8],
+) -> Result<Shaders, String> {
+    BlendMode::ALL
+        .iter()
+        .map(|blend_mode| {
+            let init = |stencil| pipe::Init {
+                stencil,
+                out: (
+                    "Target0",
+                    format::COLOR,
+                    gfx::state::ColorMask::all(),
+                    Some(blend::state(*blend_mode)),
+                ),
+                ..pipe::new()
+            };
+
+            let shader = Shader {
+                state: create_state(
+                    factory,
+                    init(stencil::unmasked()),
+                    fragment,
+                )?,
+                masked: create_state(
+                    factory,
+                    init(stencil::masked()),
+                    fragment,
+                )?,
+            };
+
+            Ok((*blend_mode, shader))
+        })
+        .collect()
+}
+
 pub struct Shader {
     state: gfx::pso::PipelineState<gl::Resources, pipe::Meta>,
+    masked: gfx::pso::PipelineState<gl::Resources, pipe::Meta>,
 }
 
 impl Shader {
-    pub fn new(factory: &mut gl::Factory, init: pipe::Init<'_>) -> Shader {
-        let set = factory
-            .create_shader_set(
-                include_bytes!("shader/triangle.vert"),
-                include_bytes!("shader/triangle.frag"),
-            )
-            .expect("Shader set creation");
-
-        let rasterizer = gfx::state::Rasterizer {
-            front_face: gfx::state::FrontFace::CounterClockwise,
-            cull_face: gfx::state::CullFace::Nothing,
-            method: gfx::state::RasterMethod::Fill,
-            offset: None,
-            samples: None,
-        };
+    fn state(
+        &self,
+        mask: u8,
+    ) -> &gfx::pso::PipelineState<gl::Resources, pipe::Meta> {
+        if mask > 0 {
+            &self.masked
+        } else {
+            &self.state
+        }
+    }
+}
 
-        let state = factory
-            .create_pipeline_state(
-                &set,
-                Primitive::TriangleList,
-                rasterizer,
-                init,
-            )
-            .expect("Pipeline state creation");
+fn create_mask_state(
+    factory: &mut gl::Factory,
+    stencil: gfx::state::Stencil,
+) -> Result<gfx::pso::PipelineState<gl::Resources, pipe::Meta>, String> {
+    let init = pipe::Init {
+        stencil,
+        out: (
+            "Target0",
+            format::COLOR,
+            gfx::state::ColorMask::empty(),
+            None,
+        ),
+        ..pipe::new()
+    };
 
-        Shader { state }
-    }
+    create_state(factory, init, include_bytes!("shader/triangle.frag"))
+}
+
+fn create_state(
+    factory: &mut gl::Factory,
+    init: pipe::Init<'_>,
+    fragment: &[u8],
+) -> Result<gfx::pso::PipelineState<gl::Resources, pipe::Meta>, String> {
+    let set = factory
+        .create_shader_set(include_bytes!("shader/triangle.vert"), fragment)
+        .map_err(|error| format!("{:?}", error))?;
+
+    let rasterizer = gfx::state::Rasterizer {
+        front_face: gfx::state::FrontFace::CounterClockwise,
+        cull_face: gfx::state::CullFace::Nothing,
+        method: gfx::state::RasterMethod::Fill,
+        offset: None,
+        samples: Some(gfx::state::MultiSample),
+    };
+
+    let state = factory
+        .create_pipeline_state(&set, Primitive::TriangleList, rasterizer, init)
+        .map_err(|error| format!("{:?}", error))?;
+
+    Ok(state)
 }
 
 impl Vertex {
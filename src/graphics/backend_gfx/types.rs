@@ -2,9 +2,20 @@ use gfx_device_gl as gl;
 
 use super::format;
 
-pub type TargetView = gfx::handle::RawRenderTargetView<gl::Resources>;
+#[derive(Clone)]
+pub struct TargetView {
+    pub color: RawTargetView,
+    pub depth_stencil: DepthStencilView,
+}
+
+pub type RawTargetView = gfx::handle::RawRenderTargetView<gl::Resources>;
+
+pub type DepthStencilView =
+    gfx::handle::DepthStencilView<gl::Resources, gfx::format::DepthStencil>;
 
 pub type RawTexture = gfx::handle::RawTexture<gl::Resources>;
 
 pub type ShaderResource =
     gfx::handle::ShaderResourceView<gl::Resources, format::View>;
+
+pub type RawShaderResource = gfx::handle::RawShaderResourceView<gl::Resources>;
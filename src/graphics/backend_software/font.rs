@@ -0,0 +1,280 @@
+use std::borrow::Cow;
+
+use glyph_brush_layout::{self as layout, GlyphPositioner};
+
+use super::raster::{self, Pixels, Projection};
+use crate::graphics::font;
+use crate::graphics::target::DrawState;
+use crate::graphics::{
+    BlendMode, HorizontalAlignment, Rectangle, Transformation,
+    VerticalAlignment,
+};
+use crate::{Error, Result};
+
+pub struct Font {
+    fonts: Vec<rusttype::Font<'static>>,
+    queue: Vec<(rusttype::PositionedGlyph<'static>, [f32; 4])>,
+}
+
+impl Font {
+    pub fn from_bytes(bytes: Cow<'static, [u8]>) -> Result<Font> {
+        Ok(Font {
+            fonts: vec![parse(bytes)?],
+            queue: Vec::new(),
+        })
+    }
+
+    pub fn add_typeface(&mut self, bytes: Cow<'static, [u8]>) -> Result<usize> {
+        self.fonts.push(parse(bytes)?);
+
+        Ok(self.fonts.len() - 1)
+    }
+
+    pub fn has_glyph(&self, typeface: usize, character: char) -> bool {
+        self.fonts[typeface].glyph(character).id().0 != 0
+    }
+
+    pub fn add(&mut self, text: font::Section<'_>) {
+        let section = Section::new(&text);
+        let glyphs = section.layout.calculate_glyphs(
+            &self.fonts,
+            &section.geometry,
+            &section.text,
+        );
+
+        self.queue.extend(
+            glyphs
+                .into_iter()
+                .map(|(glyph, color, _font_id)| (glyph, color)),
+        );
+    }
+
+    pub fn measure(&mut self, text: font::Section<'_>) -> (f32, f32) {
+        let section = Section::new(&text);
+        let glyphs = section.layout.calculate_glyphs(
+            &self.fonts,
+            &section.geometry,
+            &section.text,
+        );
+
+        let mut bounds: Option<[f32; 4]> = None;
+
+        for (glyph, _color, font_id) in glyphs {
+            let v_metrics = self.fonts[font_id.0].v_metrics(glyph.scale());
+            let position = glyph.position();
+            let advance = glyph.unpositioned().h_metrics().advance_width;
+
+            let glyph_bounds = [
+                position.x,
+                position.y - v_metrics.ascent,
+                position.x + advance,
+                position.y - v_metrics.descent,
+            ];
+
+            bounds = Some(match bounds {
+                Some(bounds) => [
+                    bounds[0].min(glyph_bounds[0]),
+                    bounds[1].min(glyph_bounds[1]),
+                    bounds[2].max(glyph_bounds[2]),
+                    bounds[3].max(glyph_bounds[3]),
+                ],
+                None => glyph_bounds,
+            });
+        }
+
+        match bounds {
+            Some(bounds) => (bounds[2] - bounds[0], bounds[3] - bounds[1]),
+            None => (0.0, 0.0),
+        }
+    }
+
+    pub fn layout(&mut self, text: font::Section<'_>) -> Vec<font::Glyph> {
+        let section = Section::new(&text);
+        let glyphs = section.layout.calculate_glyphs(
+            &self.fonts,
+            &section.geometry,
+            &section.text,
+        );
+
+        glyphs
+            .into_iter()
+            .map(|(glyph, _color, font_id)| {
+                let v_metrics = self.fonts[font_id.0].v_metrics(glyph.scale());
+                let position = glyph.position();
+
+                font::Glyph {
+                    bounds: Rectangle {
+                        x: position.x,
+                        y: position.y - v_metrics.ascent,
+                        width: glyph.unpositioned().h_metrics().advance_width,
+                        height: v_metrics.ascent - v_metrics.descent,
+                    },
+                    baseline: position.y,
+                }
+            })
+            .collect()
+    }
+
+    pub fn draw(
+        &mut self,
+        target: &mut Pixels,
+        transformation: Transformation,
+        clip: Option<Rectangle<f32>>,
+    ) {
+        let projection =
+            Projection::new(&transformation, target.width(), target.height());
+
+        for (glyph, color) in self.queue.drain(..) {
+            let bounds = match glyph.pixel_bounding_box() {
+                Some(bounds) => bounds,
+                None => continue,
+            };
+
+            let width = bounds.width() as usize;
+            let height = bounds.height() as usize;
+            let mut coverage = vec![0.0; width * height];
+
+            glyph.draw(|x, y, value| {
+                coverage[y as usize * width + x as usize] = value;
+            });
+
+            let corners = [
+                [0.0, 0.0],
+                [width as f32, 0.0],
+                [width as f32, height as f32],
+                [0.0, height as f32],
+            ];
+
+            let positions: Vec<[f32; 2]> = corners
+                .iter()
+                .map(|corner| {
+                    projection.project(
+                        bounds.min.x as f32 + corner[0],
+                        bounds.min.y as f32 + corner[1],
+                    )
+                })
+                .collect();
+
+            for &[a, b, c] in [[0, 1, 2], [0, 2, 3]].iter() {
+                raster::fill_triangle(
+                    target,
+                    [positions[a], positions[b], positions[c]],
+                    DrawState {
+                        blend_mode: BlendMode::Alpha,
+                        clip: None,
+                        mask: 0,
+                    },
+                    |weights| {
+                        let x = weights[0] * corners[a][0]
+                            + weights[1] * corners[b][0]
+                            + weights[2] * corners[c][0];
+
+                        let y = weights[0] * corners[a][1]
+                            + weights[1] * corners[b][1]
+                            + weights[2] * corners[c][1];
+
+                        let is_clipped = clip.map_or(false, |clip| {
+                            let text_x = bounds.min.x as f32 + x;
+                            let text_y = bounds.min.y as f32 + y;
+
+                            text_x < clip.x
+                                || text_y < clip.y
+                                || text_x > clip.x + clip.width
+                                || text_y > clip.y + clip.height
+                        });
+
+                        if is_clipped {
+                            return [0.0, 0.0, 0.0, 0.0];
+                        }
+
+                        let x = (x.max(0.0) as usize).min(width - 1);
+                        let y = (y.max(0.0) as usize).min(height - 1);
+
+                        [
+                            color[0],
+                            color[1],
+                            color[2],
+                            color[3] * coverage[y * width + x],
+                        ]
+                    },
+                );
+            }
+        }
+    }
+}
+
+fn parse(bytes: Cow<'static, [u8]>) -> Result<rusttype::Font<'static>> {
+    let font = match bytes {
+        Cow::Borrowed(bytes) => rusttype::Font::from_bytes(bytes),
+        Cow::Owned(bytes) => rusttype::Font::from_bytes(bytes),
+    };
+
+    font.map_err(|error| Error::Font(error.to_string()))
+}
+
+struct Section<'a> {
+    layout: layout::Layout<layout::BuiltInLineBreaker>,
+    geometry: layout::SectionGeometry,
+    text: Vec<layout::SectionText<'a>>,
+}
+
+impl<'a> Section<'a> {
+    fn new(text: &font::Section<'a>) -> Section<'a> {
+        let x = match text.horizontal_alignment {
+            HorizontalAlignment::Left => text.position.x,
+            HorizontalAlignment::Center => {
+                text.position.x + text.bounds.0 / 2.0
+            }
+            HorizontalAlignment::Right => text.position.x + text.bounds.0,
+        };
+
+        let y = match text.vertical_alignment {
+            VerticalAlignment::Top => text.position.y,
+            VerticalAlignment::Center => text.position.y + text.bounds.1 / 2.0,
+            VerticalAlignment::Bottom => text.position.y + text.bounds.1,
+        };
+
+        Section {
+            layout: layout::Layout::default()
+                .h_align(text.horizontal_alignment.into())
+                .v_align(text.vertical_alignment.into()),
+            geometry: layout::SectionGeometry {
+                screen_position: (x, y),
+                bounds: text.bounds,
+            },
+            text: text
+                .runs
+                .iter()
+                .map(|run| layout::SectionText {
+                    text: run.content,
+                    scale: rusttype::Scale {
+                        x: run.size,
+                        y: run.size,
+                    },
+                    color: run.color.into_linear(),
+                    font_id: layout::FontId(run.typeface),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<HorizontalAlignment> for layout::HorizontalAlign {
+    fn from(alignment: HorizontalAlignment) -> layout::HorizontalAlign {
+        match alignment {
+            HorizontalAlignment::Left => layout::HorizontalAlign::Left,
+            HorizontalAlignment::Center => layout::HorizontalAlign::Center,
+            HorizontalAlignment::Right => layout::HorizontalAlign::Right,
+        }
+    }
+}
+
+impl From<VerticalAlignment> for layout::VerticalAlign {
+    fn from(alignment: VerticalAlignment) -> layout::VerticalAlign {
+        match alignment {
+            VerticalAlignment::Top => layout::VerticalAlign::Top,
+            VerticalAlignment::Center => layout::VerticalAlign::Center,
+            VerticalAlignment::Bottom => layout::VerticalAlign::Bottom,
+        }
+    }
+}
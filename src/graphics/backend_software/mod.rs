@@ -0,0 +1,197 @@
+mod font;
+mod quad;
+mod raster;
+mod shader;
+mod surface;
+pub mod texture;
+mod triangle;
+mod types;
+
+pub use font::Font;
+pub use quad::Quad;
+pub use shader::Shader;
+pub use surface::{winit, Surface};
+pub use texture::Texture;
+pub use triangle::Vertex;
+pub use types::TargetView;
+
+use std::borrow::Cow;
+
+use crate::graphics::shader::Kind;
+use crate::graphics::target::DrawState;
+use crate::graphics::{Color, Rectangle, Transformation};
+use crate::{Error, Result};
+
+/// A link between your game and a graphics processor.
+///
+/// It is necessary to perform any kind of graphical operation, like loading
+/// resources and drawing.
+///
+/// The software backend rasterizes everything on the CPU. It does not need a
+/// window, so you can create a [`Gpu`] directly with [`Gpu::headless`]. This
+/// is useful to render [`Canvas`] contents in environments without a graphics
+/// processor, like continuous integration machines or servers.
+///
+/// [`Gpu`]: struct.Gpu.html
+/// [`Gpu::headless`]: struct.Gpu.html#method.headless
+/// [`Canvas`]: struct.Canvas.html
+#[allow(missing_debug_implementations)]
+pub struct Gpu {
+    _private: (),
+}
+
+impl Gpu {
+    /// Creates a new [`Gpu`] that is not linked to any window.
+    ///
+    /// [`Gpu`]: struct.Gpu.html
+    pub fn headless() -> Gpu {
+        Gpu { _private: () }
+    }
+
+    pub(super) fn for_headless_window(
+        width: u16,
+        height: u16,
+    ) -> (Gpu, Surface) {
+        (Gpu::headless(), Surface::new(width, height))
+    }
+
+    pub(super) fn for_window(
+        _builder: winit::WindowBuilder,
+        _events_loop: &winit::EventsLoop,
+    ) -> Result<(Gpu, Surface)> {
+        Err(Error::WindowCreation(String::from(
+            "the software backend cannot open windows",
+        )))
+    }
+
+    pub(super) fn clear(&mut self, view: &TargetView, color: Color) {
+        view.borrow_mut().fill(color.into_linear());
+    }
+
+    pub(super) fn upload_texture(
+        &mut self,
+        image: &image::DynamicImage,
+    ) -> Texture {
+        Texture::new(image)
+    }
+
+    pub(super) fn update_texture(
+        &mut self,
+        texture: &Texture,
+        x: u16,
+        y: u16,
+        image: &image::RgbaImage,
+    ) {
+        texture.update(x, y, image);
+    }
+
+    pub(super) fn upload_texture_array(
+        &mut self,
+        layers: &[image::DynamicImage],
+    ) -> Texture {
+        Texture::new_array(layers)
+    }
+
+    pub(super) fn max_samples(&self) -> u16 {
+        // Multisampling is emulated, so this only bounds memory usage
+        16
+    }
+
+    pub(super) fn create_drawable_texture(
+        &mut self,
+        width: u16,
+        height: u16,
+        samples: u16,
+    ) -> texture::Drawable {
+        texture::Drawable::new(width, height, samples)
+    }
+
+    pub(super) fn resolve_drawable(&mut self, drawable: &texture::Drawable) {
+        drawable.resolve();
+    }
+
+    pub(super) fn read_drawable_texture_pixels(
+        &mut self,
+        drawable: &texture::Drawable,
+    ) -> image::DynamicImage {
+        drawable.read_pixels()
+    }
+
+    pub(super) fn upload_font(
+        &mut self,
+        bytes: Cow<'static, [u8]>,
+    ) -> Result<Font> {
+        Font::from_bytes(bytes)
+    }
+
+    pub(super) fn create_shader(
+        &mut self,
+        _kind: Kind,
+        _source: &[u8],
+    ) -> Result<Shader> {
+        Ok(Shader)
+    }
+
+    pub(super) fn draw_triangles(
+        &mut self,
+        vertices: &[Vertex],
+        indices: &[u32],
+        view: &TargetView,
+        transformation: &Transformation,
+        state: DrawState,
+        _shader: Option<&Shader>,
+    ) {
+        triangle::draw(
+            vertices,
+            indices,
+            transformation,
+            state,
+            &mut view.borrow_mut(),
+        );
+    }
+
+    pub(super) fn draw_mask(
+        &mut self,
+        vertices: &[Vertex],
+        indices: &[u32],
+        view: &TargetView,
+        transformation: &Transformation,
+        state: DrawState,
+    ) {
+        triangle::draw_mask(
+            vertices,
+            indices,
+            transformation,
+            state,
+            &mut view.borrow_mut(),
+        );
+    }
+
+    pub(super) fn draw_texture_quads(
+        &mut self,
+        texture: &Texture,
+        instances: &[Quad],
+        view: &TargetView,
+        transformation: &Transformation,
+        state: DrawState,
+        _shader: Option<&Shader>,
+    ) {
+        quad::draw_textured(
+            texture,
+            instances,
+            transformation,
+            state,
+            &mut view.borrow_mut(),
+        );
+    }
+
+    pub(super) fn draw_font(
+        &mut self,
+        font: &mut Font,
+        target: &TargetView,
+        transformation: Transformation,
+        clip: Option<Rectangle<f32>>,
+    ) {
+        font.draw(&mut target.borrow_mut(), transformation, clip);
+    }
+}
@@ -0,0 +1,92 @@
+use super::raster::{self, Pixels, Projection};
+use super::texture::Texture;
+use crate::graphics::target::DrawState;
+use crate::graphics::{self, Transformation};
+
+const QUAD_INDICES: [[usize; 3]; 2] = [[0, 1, 2], [0, 2, 3]];
+const QUAD_VERTS: [[f32; 2]; 4] =
+    [[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0]];
+
+#[derive(Debug, Clone, Copy)]
+pub struct Quad {
+    source: [f32; 4],
+    translation: [f32; 2],
+    scale: [f32; 2],
+    rotation: f32,
+    tint: [f32; 4],
+    pub layer: u32,
+}
+
+pub fn draw_textured(
+    texture: &Texture,
+    instances: &[Quad],
+    transformation: &Transformation,
+    state: DrawState,
+    target: &mut Pixels,
+) {
+    let projection =
+        Projection::new(transformation, target.width(), target.height());
+
+    for instance in instances {
+        let layer = match texture.layer(instance.layer) {
+            Some(layer) => layer.borrow(),
+            None => continue,
+        };
+
+        let [x, y, width, height] = instance.source;
+        let [red, green, blue, alpha] = instance.tint;
+        let (sin, cos) = instance.rotation.sin_cos();
+
+        let positions: Vec<[f32; 2]> = QUAD_VERTS
+            .iter()
+            .map(|vertex| {
+                let x = vertex[0] * instance.scale[0];
+                let y = vertex[1] * instance.scale[1];
+
+                projection.project(
+                    instance.translation[0] + x * cos - y * sin,
+                    instance.translation[1] + x * sin + y * cos,
+                )
+            })
+            .collect();
+
+        for indices in QUAD_INDICES.iter() {
+            let [a, b, c] = *indices;
+
+            raster::fill_triangle(
+                target,
+                [positions[a], positions[b], positions[c]],
+                state,
+                |weights| {
+                    let u = weights[0] * QUAD_VERTS[a][0]
+                        + weights[1] * QUAD_VERTS[b][0]
+                        + weights[2] * QUAD_VERTS[c][0];
+
+                    let v = weights[0] * QUAD_VERTS[a][1]
+                        + weights[1] * QUAD_VERTS[b][1]
+                        + weights[2] * QUAD_VERTS[c][1];
+
+                    let [r, g, b, a] =
+                        layer.sample(x + u * width, y + v * height);
+
+                    [r * red, g * green, b * blue, a * alpha]
+                },
+            );
+        }
+    }
+}
+
+impl From<graphics::Quad> for Quad {
+    fn from(quad: graphics::Quad) -> Quad {
+        let (width, height) = quad.size;
+
+        Quad {
+            source: quad.flipped_source(),
+            translation: quad.top_left(),
+            scale: [width, height],
+            rotation: quad.rotation,
+            tint: quad.tint.into_linear(),
+            layer: 0,
+        }
+    }
+}
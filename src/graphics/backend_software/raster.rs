@@ -0,0 +1,419 @@
+use nalgebra::Matrix3;
+
+use crate::graphics::target::DrawState;
+use crate::graphics::{BlendMode, Rectangle, Transformation};
+
+/// A buffer of linear RGBA pixels.
+///
+/// Both textures and render targets are stored this way. Blending happens in
+/// linear space and pixels are only converted back to sRGB when read.
+///
+/// Render targets can be supersampled: every target pixel is then stored as
+/// `scale * scale` pixels, which are averaged when resolved.
+pub struct Pixels {
+    width: u32,
+    height: u32,
+    scale: u32,
+    data: Vec<[f32; 4]>,
+    stencil: Vec<u8>,
+}
+
+impl Pixels {
+    pub fn new(width: u32, height: u32) -> Pixels {
+        Self::supersampled(width, height, 1)
+    }
+
+    pub fn supersampled(width: u32, height: u32, scale: u32) -> Pixels {
+        let width = width * scale;
+        let height = height * scale;
+
+        Pixels {
+            width,
+            height,
+            scale,
+            data: vec![[0.0, 0.0, 0.0, 0.0]; width as usize * height as usize],
+            stencil: Vec::new(),
+        }
+    }
+
+    pub fn from_image(image: &image::RgbaImage) -> Pixels {
+        Pixels {
+            width: image.width(),
+            height: image.height(),
+            scale: 1,
+            data: image.pixels().map(to_linear).collect(),
+            stencil: Vec::new(),
+        }
+    }
+
+    /// Replaces the pixels of the given image, placing its top-left corner
+    /// at the given position.
+    pub fn write(&mut self, x: u32, y: u32, image: &image::RgbaImage) {
+        for (i, j, pixel) in image.enumerate_pixels() {
+            let index =
+                (y + j) as usize * self.width as usize + (x + i) as usize;
+
+            self.data[index] = to_linear(pixel);
+        }
+    }
+
+    pub fn to_image(&self) -> image::RgbaImage {
+        let mut rgba = Vec::with_capacity(self.data.len() * 4);
+
+        for pixel in &self.data {
+            rgba.push(linear_to_srgb(pixel[0]));
+            rgba.push(linear_to_srgb(pixel[1]));
+            rgba.push(linear_to_srgb(pixel[2]));
+            rgba.push((pixel[3].max(0.0).min(1.0) * 255.0).round() as u8);
+        }
+
+        image::ImageBuffer::from_raw(self.width, self.height, rgba)
+            .expect("Create RGBA8 image")
+    }
+
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    pub fn fill(&mut self, color: [f32; 4]) {
+        for pixel in self.data.iter_mut() {
+            *pixel = color;
+        }
+    }
+
+    /// Averages the samples of a supersampled buffer into the given one,
+    /// which must have the size of the target.
+    pub fn resolve(&self, target: &mut Pixels) {
+        let samples = (self.scale * self.scale) as f32;
+
+        for y in 0..target.height {
+            for x in 0..target.width {
+                let mut color = [0.0; 4];
+
+                for sample_y in 0..self.scale {
+                    for sample_x in 0..self.scale {
+                        let index = (y * self.scale + sample_y) * self.width
+                            + x * self.scale
+                            + sample_x;
+
+                        let sample = self.data[index as usize];
+
+                        for (component, value) in color.iter_mut().zip(&sample)
+                        {
+                            *component += value;
+                        }
+                    }
+                }
+
+                for component in color.iter_mut() {
+                    *component /= samples;
+                }
+
+                target.data[(y * target.width + x) as usize] = color;
+            }
+        }
+    }
+
+    /// Prepares the stencil buffer to draw a mask of the given `level`.
+    ///
+    /// At level `0`, the whole buffer is cleared. Otherwise, the stencil
+    /// values greater than `level`, left by a previous mask of the same
+    /// level, are reset.
+    pub fn reset_stencil(&mut self, level: u8) {
+        if level == 0 || self.stencil.is_empty() {
+            self.stencil = vec![0; self.data.len()];
+        } else {
+            for value in self.stencil.iter_mut() {
+                *value = (*value).min(level);
+            }
+        }
+    }
+
+    /// Samples the pixel nearest to the given normalized coordinates, clamping
+    /// to the edges.
+    pub fn sample(&self, u: f32, v: f32) -> [f32; 4] {
+        if self.width == 0 || self.height == 0 {
+            return [0.0, 0.0, 0.0, 0.0];
+        }
+
+        let x = ((u * self.width as f32).floor().max(0.0) as u32)
+            .min(self.width - 1);
+        let y = ((v * self.height as f32).floor().max(0.0) as u32)
+            .min(self.height - 1);
+
+        self.data[(y * self.width + x) as usize]
+    }
+
+    /// Blends the given color into the pixel at `(x, y)`.
+    ///
+    /// It uses the same blend equations as the hardware backends.
+    pub fn blend(
+        &mut self,
+        x: u32,
+        y: u32,
+        color: [f32; 4],
+        blend_mode: BlendMode,
+    ) {
+        let pixel = &mut self.data[(y * self.width + x) as usize];
+        let alpha = color[3];
+
+        let channels = pixel.iter_mut().zip(color.iter());
+
+        match blend_mode {
+            BlendMode::Alpha => {
+                for (channel, source) in channels.take(3) {
+                    *channel = source * alpha + *channel * (1.0 - alpha);
+                }
+
+                pixel[3] = alpha + pixel[3] * (1.0 - alpha);
+            }
+            BlendMode::Additive => {
+                for (channel, source) in channels.take(3) {
+                    *channel = (source * alpha + *channel).min(1.0);
+                }
+            }
+            BlendMode::Multiply => {
+                for (channel, source) in channels.take(3) {
+                    *channel *= source;
+                }
+            }
+            BlendMode::Premultiplied => {
+                for (channel, source) in channels {
+                    *channel = (source + *channel * (1.0 - alpha)).min(1.0);
+                }
+            }
+            BlendMode::Replace => {
+                *pixel = color;
+            }
+        }
+    }
+}
+
+impl std::fmt::Debug for Pixels {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Pixels {{ width: {}, height: {} }}",
+            self.width, self.height
+        )
+    }
+}
+
+/// Projects points from the coordinate system of a [`Transformation`] to the
+/// pixels of a target.
+///
+/// The resulting coordinate system has its origin at the top-left corner of
+/// the target, matching the hardware backends.
+///
+/// [`Transformation`]: ../../struct.Transformation.html
+pub struct Projection {
+    matrix: Matrix3<f32>,
+    width: f32,
+    height: f32,
+}
+
+impl Projection {
+    pub fn new(
+        transformation: &Transformation,
+        width: u32,
+        height: u32,
+    ) -> Projection {
+        Projection {
+            matrix: (*transformation).into(),
+            width: width as f32,
+            height: height as f32,
+        }
+    }
+
+    pub fn project(&self, x: f32, y: f32) -> [f32; 2] {
+        let m = &self.matrix;
+
+        let ndc_x = m[(0, 0)] * x + m[(0, 1)] * y + m[(0, 2)];
+        let ndc_y = m[(1, 0)] * x + m[(1, 1)] * y + m[(1, 2)];
+
+        [
+            (ndc_x + 1.0) * 0.5 * self.width,
+            (ndc_y + 1.0) * 0.5 * self.height,
+        ]
+    }
+}
+
+/// Rasterizes a triangle given in pixel coordinates.
+///
+/// The `shade` closure receives the barycentric weights of every covered
+/// pixel center and returns the color to blend.
+///
+/// A top-left fill rule is used, so triangles sharing an edge never blend the
+/// same pixel twice. Pixels outside of the clip rectangle of the `state` are
+/// skipped, as well as the pixels outside of its mask.
+pub fn fill_triangle<F>(
+    pixels: &mut Pixels,
+    vertices: [[f32; 2]; 3],
+    state: DrawState,
+    mut shade: F,
+) where
+    F: FnMut([f32; 3]) -> [f32; 4],
+{
+    let width = pixels.width;
+    let mask = state.mask;
+
+    for_each_pixel(pixels, vertices, state.clip, |pixels, x, y, weights| {
+        let index = (y * width + x) as usize;
+
+        if mask == 0 || pixels.stencil.get(index) == Some(&mask) {
+            pixels.blend(x, y, shade(weights), state.blend_mode);
+        }
+    });
+}
+
+/// Rasterizes a triangle given in pixel coordinates on the stencil buffer,
+/// incrementing the stencil value of the covered pixels that have the given
+/// `level`.
+pub fn mark_triangle(
+    pixels: &mut Pixels,
+    vertices: [[f32; 2]; 3],
+    clip: Option<Rectangle<u32>>,
+    level: u8,
+) {
+    let width = pixels.width;
+
+    for_each_pixel(pixels, vertices, clip, |pixels, x, y, _| {
+        let value = &mut pixels.stencil[(y * width + x) as usize];
+
+        if *value == level {
+            *value += 1;
+        }
+    });
+}
+
+fn for_each_pixel<F>(
+    pixels: &mut Pixels,
+    vertices: [[f32; 2]; 3],
+    clip: Option<Rectangle<u32>>,
+    mut f: F,
+) where
+    F: FnMut(&mut Pixels, u32, u32, [f32; 3]),
+{
+    let [a, b, c] = vertices;
+    let area = edge(a, b, c);
+
+    if area == 0.0 || !area.is_finite() {
+        return;
+    }
+
+    // Keep a consistent winding, remembering to swap the weights back
+    let (b, c, swapped) = if area < 0.0 {
+        (c, b, true)
+    } else {
+        (b, c, false)
+    };
+
+    let area = area.abs();
+
+    let min_x = a[0].min(b[0]).min(c[0]).floor().max(0.0) as u32;
+    let min_y = a[1].min(b[1]).min(c[1]).floor().max(0.0) as u32;
+    let max_x = a[0]
+        .max(b[0])
+        .max(c[0])
+        .ceil()
+        .min(pixels.width as f32)
+        .max(0.0) as u32;
+    let max_y = a[1]
+        .max(b[1])
+        .max(c[1])
+        .ceil()
+        .min(pixels.height as f32)
+        .max(0.0) as u32;
+
+    // The clip rectangle is given in target pixels
+    let scale = pixels.scale;
+    let clip = clip.map(|clip| Rectangle {
+        x: clip.x.saturating_mul(scale),
+        y: clip.y.saturating_mul(scale),
+        width: clip.width.saturating_mul(scale),
+        height: clip.height.saturating_mul(scale),
+    });
+
+    let (min_x, min_y, max_x, max_y) = match clip {
+        Some(clip) => (
+            min_x.max(clip.x),
+            min_y.max(clip.y),
+            max_x.min(clip.x.saturating_add(clip.width)),
+            max_y.min(clip.y.saturating_add(clip.height)),
+        ),
+        None => (min_x, min_y, max_x, max_y),
+    };
+
+    for y in min_y..max_y {
+        for x in min_x..max_x {
+            let p = [x as f32 + 0.5, y as f32 + 0.5];
+
+            let w0 = edge(b, c, p);
+            let w1 = edge(c, a, p);
+            let w2 = edge(a, b, p);
+
+            if covers(w0, b, c) && covers(w1, c, a) && covers(w2, a, b) {
+                let weights = if swapped {
+                    [w0 / area, w2 / area, w1 / area]
+                } else {
+                    [w0 / area, w1 / area, w2 / area]
+                };
+
+                f(pixels, x, y, weights);
+            }
+        }
+    }
+}
+
+fn edge(a: [f32; 2], b: [f32; 2], p: [f32; 2]) -> f32 {
+    (b[0] - a[0]) * (p[1] - a[1]) - (b[1] - a[1]) * (p[0] - a[0])
+}
+
+fn covers(weight: f32, from: [f32; 2], to: [f32; 2]) -> bool {
+    if weight != 0.0 {
+        return weight > 0.0;
+    }
+
+    let dx = to[0] - from[0];
+    let dy = to[1] - from[1];
+
+    // Top or left edge
+    (dy == 0.0 && dx > 0.0) || dy < 0.0
+}
+
+fn to_linear(pixel: &image::Rgba<u8>) -> [f32; 4] {
+    [
+        srgb_to_linear(pixel[0]),
+        srgb_to_linear(pixel[1]),
+        srgb_to_linear(pixel[2]),
+        pixel[3] as f32 / 255.0,
+    ]
+}
+
+// As described in:
+// https://en.wikipedia.org/wiki/SRGB
+fn srgb_to_linear(component: u8) -> f32 {
+    let u = component as f32 / 255.0;
+
+    if u < 0.04045 {
+        u / 12.92
+    } else {
+        ((u + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+fn linear_to_srgb(component: f32) -> u8 {
+    let u = component.max(0.0).min(1.0);
+
+    let srgb = if u <= 0.003_130_8 {
+        u * 12.92
+    } else {
+        1.055 * u.powf(1.0 / 2.4) - 0.055
+    };
+
+    (srgb * 255.0).round() as u8
+}
@@ -0,0 +1,9 @@
+/// A custom shader.
+///
+/// Shaders cannot run on the CPU, so the software backend ignores them and
+/// draws as usual.
+pub struct Shader;
+
+impl Shader {
+    pub fn set_uniform(&mut self, _index: usize, _value: [f32; 4]) {}
+}
@@ -0,0 +1,82 @@
+pub use gfx_winit as winit;
+
+use super::texture::Drawable;
+use super::{Gpu, TargetView};
+
+/// An off-screen surface.
+///
+/// The software backend never opens a window. Instead, frames are rendered
+/// into a drawable texture that can be inspected afterwards.
+pub struct Surface {
+    window: Headless,
+    drawable: Drawable,
+}
+
+impl Surface {
+    pub(super) fn new(width: u16, height: u16) -> Surface {
+        Surface {
+            window: Headless {
+                size: winit::dpi::LogicalSize::new(width as f64, height as f64),
+            },
+            drawable: Drawable::new(width, height, 1),
+        }
+    }
+
+    pub fn window(&self) -> &Headless {
+        &self.window
+    }
+
+    pub fn target(&self) -> &TargetView {
+        self.drawable.target()
+    }
+
+    pub fn drawable(&self) -> &Drawable {
+        &self.drawable
+    }
+
+    pub fn read_pixels(&self, gpu: &mut Gpu) -> image::DynamicImage {
+        gpu.read_drawable_texture_pixels(&self.drawable)
+    }
+
+    pub fn resize(&mut self, _gpu: &mut Gpu, size: winit::dpi::PhysicalSize) {
+        self.window.size = size.to_logical(1.0);
+        self.drawable = Drawable::new(
+            size.width.round() as u16,
+            size.height.round() as u16,
+            1,
+        );
+    }
+
+    pub fn swap_buffers(&mut self, _gpu: &mut Gpu) {}
+}
+
+/// A stand-in for a `winit` window, exposing the little part of its API that
+/// a [`Window`] needs.
+///
+/// [`Window`]: ../../struct.Window.html
+pub struct Headless {
+    size: winit::dpi::LogicalSize,
+}
+
+impl Headless {
+    pub fn get_inner_size(&self) -> Option<winit::dpi::LogicalSize> {
+        Some(self.size)
+    }
+
+    pub fn get_hidpi_factor(&self) -> f64 {
+        1.0
+    }
+
+    pub fn get_primary_monitor(&self) -> Monitor {
+        Monitor
+    }
+
+    pub fn set_fullscreen(&self, _monitor: Option<Monitor>) {}
+
+    pub fn set_cursor(&self, _cursor: winit::MouseCursor) {}
+}
+
+/// The only monitor a [`Headless`] window knows about.
+///
+/// [`Headless`]: struct.Headless.html
+pub struct Monitor;
@@ -0,0 +1,135 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use super::raster::Pixels;
+use super::types::TargetView;
+use crate::graphics::Transformation;
+
+#[derive(Clone, Debug)]
+pub struct Texture {
+    layers: Vec<TargetView>,
+    width: u16,
+    height: u16,
+}
+
+impl Texture {
+    pub(super) fn new(image: &image::DynamicImage) -> Texture {
+        let rgba = image.to_rgba();
+        let width = rgba.width() as u16;
+        let height = rgba.height() as u16;
+
+        Texture {
+            layers: vec![Rc::new(RefCell::new(Pixels::from_image(&rgba)))],
+            width,
+            height,
+        }
+    }
+
+    pub(super) fn new_array(layers: &[image::DynamicImage]) -> Texture {
+        let first_layer = &layers[0].to_rgba();
+        let width = first_layer.width() as u16;
+        let height = first_layer.height() as u16;
+
+        Texture {
+            layers: layers
+                .iter()
+                .map(|layer| {
+                    Rc::new(RefCell::new(Pixels::from_image(&layer.to_rgba())))
+                })
+                .collect(),
+            width,
+            height,
+        }
+    }
+
+    pub(super) fn update(&self, x: u16, y: u16, image: &image::RgbaImage) {
+        self.layers[0]
+            .borrow_mut()
+            .write(u32::from(x), u32::from(y), image);
+    }
+
+    pub(super) fn layer(&self, layer: u32) -> Option<&TargetView> {
+        self.layers.get(layer as usize)
+    }
+
+    pub fn width(&self) -> u16 {
+        self.width
+    }
+
+    pub fn height(&self) -> u16 {
+        self.height
+    }
+}
+
+#[derive(Clone)]
+pub struct Drawable {
+    texture: Texture,
+    target: TargetView,
+    samples: u16,
+}
+
+impl Drawable {
+    /// Multisampling is emulated with supersampling: the target is rendered
+    /// at a higher resolution and averaged into the texture when resolved.
+    pub fn new(width: u16, height: u16, samples: u16) -> Drawable {
+        let texture = Texture {
+            layers: vec![Rc::new(RefCell::new(Pixels::new(
+                width as u32,
+                height as u32,
+            )))],
+            width,
+            height,
+        };
+
+        // The smallest grid with at least the requested amount of samples
+        let scale = (f32::from(samples).sqrt().ceil() as u32).max(1);
+
+        let target = if scale > 1 {
+            Rc::new(RefCell::new(Pixels::supersampled(
+                width as u32,
+                height as u32,
+                scale,
+            )))
+        } else {
+            texture.layers[0].clone()
+        };
+
+        Drawable {
+            texture,
+            target,
+            samples: samples.max(1),
+        }
+    }
+
+    pub fn texture(&self) -> &Texture {
+        &self.texture
+    }
+
+    pub fn target(&self) -> &TargetView {
+        &self.target
+    }
+
+    pub fn samples(&self) -> u16 {
+        self.samples
+    }
+
+    pub fn resolve(&self) {
+        if !Rc::ptr_eq(&self.target, &self.texture.layers[0]) {
+            self.target
+                .borrow()
+                .resolve(&mut self.texture.layers[0].borrow_mut());
+        }
+    }
+
+    pub fn read_pixels(&self) -> image::DynamicImage {
+        self.resolve();
+
+        image::DynamicImage::ImageRgba8(
+            self.texture.layers[0].borrow().to_image(),
+        )
+    }
+
+    pub fn render_transformation() -> Transformation {
+        Transformation::identity()
+    }
+}
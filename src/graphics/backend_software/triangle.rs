@@ -0,0 +1,91 @@
+use super::raster::{self, Pixels, Projection};
+use crate::graphics::target::DrawState;
+use crate::graphics::Transformation;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Vertex {
+    position: [f32; 2],
+    color: [f32; 4],
+}
+
+impl Vertex {
+    pub fn new(position: [f32; 2], color: [f32; 4]) -> Vertex {
+        Vertex { position, color }
+    }
+}
+
+pub fn draw(
+    vertices: &[Vertex],
+    indices: &[u32],
+    transformation: &Transformation,
+    state: DrawState,
+    target: &mut Pixels,
+) {
+    let positions = project(vertices, transformation, target);
+
+    for triangle in indices.chunks_exact(3) {
+        let a = triangle[0] as usize;
+        let b = triangle[1] as usize;
+        let c = triangle[2] as usize;
+
+        let colors = [vertices[a].color, vertices[b].color, vertices[c].color];
+
+        raster::fill_triangle(
+            target,
+            [positions[a], positions[b], positions[c]],
+            state,
+            |weights| {
+                let mut color = [0.0; 4];
+
+                for (i, component) in color.iter_mut().enumerate() {
+                    *component = weights[0] * colors[0][i]
+                        + weights[1] * colors[1][i]
+                        + weights[2] * colors[2][i];
+                }
+
+                color
+            },
+        );
+    }
+}
+
+pub fn draw_mask(
+    vertices: &[Vertex],
+    indices: &[u32],
+    transformation: &Transformation,
+    state: DrawState,
+    target: &mut Pixels,
+) {
+    target.reset_stencil(state.mask);
+
+    let positions = project(vertices, transformation, target);
+
+    for triangle in indices.chunks_exact(3) {
+        raster::mark_triangle(
+            target,
+            [
+                positions[triangle[0] as usize],
+                positions[triangle[1] as usize],
+                positions[triangle[2] as usize],
+            ],
+            state.clip,
+            state.mask,
+        );
+    }
+}
+
+fn project(
+    vertices: &[Vertex],
+    transformation: &Transformation,
+    target: &Pixels,
+) -> Vec<[f32; 2]> {
+    let projection =
+        Projection::new(transformation, target.width(), target.height());
+
+    vertices
+        .iter()
+        .map(|vertex| {
+            projection.project(vertex.position[0], vertex.position[1])
+        })
+        .collect()
+}
@@ -0,0 +1,6 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use super::raster::Pixels;
+
+pub type TargetView = Rc<RefCell<Pixels>>;
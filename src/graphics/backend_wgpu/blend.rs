@@ -0,0 +1,41 @@
+use crate::graphics::BlendMode;
+
+pub fn color_state(blend_mode: BlendMode) -> wgpu::ColorStateDescriptor {
+    use wgpu::BlendFactor::*;
+
+    let (color, alpha) = match blend_mode {
+        BlendMode::Alpha => (
+            descriptor(SrcAlpha, OneMinusSrcAlpha),
+            descriptor(One, OneMinusSrcAlpha),
+        ),
+        BlendMode::Additive => {
+            (descriptor(SrcAlpha, One), descriptor(Zero, One))
+        }
+        BlendMode::Multiply => {
+            (descriptor(DstColor, Zero), descriptor(Zero, One))
+        }
+        BlendMode::Premultiplied => (
+            descriptor(One, OneMinusSrcAlpha),
+            descriptor(One, OneMinusSrcAlpha),
+        ),
+        BlendMode::Replace => (descriptor(One, Zero), descriptor(One, Zero)),
+    };
+
+    wgpu::ColorStateDescriptor {
+        format: wgpu::TextureFormat::Bgra8UnormSrgb,
+        color_blend: color,
+        alpha_blend: alpha,
+        write_mask: wgpu::ColorWrite::ALL,
+    }
+}
+
+fn descriptor(
+    src_factor: wgpu::BlendFactor,
+    dst_factor: wgpu::BlendFactor,
+) -> wgpu::BlendDescriptor {
+    wgpu::BlendDescriptor {
+        src_factor,
+        dst_factor,
+        operation: wgpu::BlendOperation::Add,
+    }
+}
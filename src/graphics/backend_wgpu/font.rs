@@ -1,30 +1,58 @@
+use std::borrow::Cow;
+
+use crate::graphics::font::{Glyph, Section};
 use crate::graphics::gpu::TargetView;
 use crate::graphics::{
-    HorizontalAlignment, Text, Transformation, VerticalAlignment,
+    HorizontalAlignment, Rectangle, Transformation, VerticalAlignment,
 };
+use crate::{Error, Result};
 
-use wgpu_glyph::GlyphCruncher;
+use wgpu_glyph::{GlyphCruncher, GlyphPositioner};
 
 pub struct Font {
+    sections: Vec<wgpu_glyph::OwnedVariedSection>,
     glyphs: wgpu_glyph::GlyphBrush<'static, ()>,
+    typefaces: Vec<wgpu_glyph::Font<'static>>,
 }
 
 impl Font {
-    pub fn from_bytes(device: &mut wgpu::Device, bytes: &'static [u8]) -> Font {
-        Font {
-            glyphs: wgpu_glyph::GlyphBrushBuilder::using_font_bytes(bytes)
+    pub fn from_bytes(
+        device: &mut wgpu::Device,
+        bytes: Cow<'static, [u8]>,
+    ) -> Result<Font> {
+        let typeface = parse(bytes)?;
+
+        Ok(Font {
+            sections: Vec::new(),
+            glyphs: wgpu_glyph::GlyphBrushBuilder::using_font(typeface.clone())
                 .texture_filter_method(wgpu::FilterMode::Nearest)
                 .build(device, wgpu::TextureFormat::Bgra8UnormSrgb),
-        }
+            typefaces: vec![typeface],
+        })
+    }
+
+    pub fn add_typeface(&mut self, bytes: Cow<'static, [u8]>) -> Result<usize> {
+        let typeface = parse(bytes)?;
+        let font_id = self.glyphs.add_font(typeface.clone());
+
+        self.typefaces.push(typeface);
+
+        Ok(font_id.0)
+    }
+
+    pub fn has_glyph(&self, typeface: usize, character: char) -> bool {
+        self.typefaces[typeface].glyph(character).id().0 != 0
     }
 
-    pub fn add(&mut self, text: Text<'_>) {
-        let section: wgpu_glyph::Section<'_> = text.into();
-        self.glyphs.queue(section);
+    pub fn add(&mut self, text: Section<'_>) {
+        let section = section(&text);
+
+        // Text is queued when drawn, once its clip rectangle is known
+        self.sections.push(section.to_owned());
     }
 
-    pub fn measure(&mut self, text: Text<'_>) -> (f32, f32) {
-        let section: wgpu_glyph::Section<'_> = text.into();
+    pub fn measure(&mut self, text: Section<'_>) -> (f32, f32) {
+        let section = section(&text);
         let bounds = self.glyphs.glyph_bounds(section);
 
         match bounds {
@@ -33,13 +61,56 @@ impl Font {
         }
     }
 
+    pub fn layout(&mut self, text: Section<'_>) -> Vec<Glyph> {
+        let section = section(&text);
+        let geometry = wgpu_glyph::SectionGeometry::from(&section);
+        let glyphs = section.layout.calculate_glyphs(
+            &self.typefaces,
+            &geometry,
+            &section.text,
+        );
+
+        glyphs
+            .into_iter()
+            .map(|(glyph, _color, font_id)| {
+                let v_metrics =
+                    self.typefaces[font_id.0].v_metrics(glyph.scale());
+                let position = glyph.position();
+
+                Glyph {
+                    bounds: Rectangle {
+                        x: position.x,
+                        y: position.y - v_metrics.ascent,
+                        width: glyph.unpositioned().h_metrics().advance_width,
+                        height: v_metrics.ascent - v_metrics.descent,
+                    },
+                    baseline: position.y,
+                }
+            })
+            .collect()
+    }
+
     pub fn draw(
         &mut self,
         device: &mut wgpu::Device,
         encoder: &mut wgpu::CommandEncoder,
         target: &TargetView,
         transformation: Transformation,
+        clip: Option<Rectangle<f32>>,
     ) {
+        for section in self.sections.drain(..) {
+            let section = section.to_borrowed();
+
+            match clip {
+                Some(clip) => {
+                    let layout = Clipped::new(section.layout, clip);
+
+                    self.glyphs.queue_custom_layout(&section, &layout);
+                }
+                None => self.glyphs.queue(&section),
+            }
+        }
+
         self.glyphs
             .draw_queued_with_transform(
                 device,
@@ -51,35 +122,116 @@ impl Font {
     }
 }
 
-impl<'a> From<Text<'a>> for wgpu_glyph::Section<'a> {
-    fn from(text: Text<'a>) -> wgpu_glyph::Section<'a> {
-        let x = match text.horizontal_alignment {
-            HorizontalAlignment::Left => text.position.x,
-            HorizontalAlignment::Center => {
-                text.position.x + text.bounds.0 / 2.0
-            }
-            HorizontalAlignment::Right => text.position.x + text.bounds.0,
-        };
+fn parse(bytes: Cow<'static, [u8]>) -> Result<wgpu_glyph::Font<'static>> {
+    let typeface = match bytes {
+        Cow::Borrowed(bytes) => wgpu_glyph::Font::from_bytes(bytes),
+        Cow::Owned(bytes) => wgpu_glyph::Font::from_bytes(bytes),
+    };
+
+    typeface.map_err(|error| Error::Font(error.to_string()))
+}
+
+fn section<'a>(text: &Section<'a>) -> wgpu_glyph::VariedSection<'a> {
+    let x = match text.horizontal_alignment {
+        HorizontalAlignment::Left => text.position.x,
+        HorizontalAlignment::Center => text.position.x + text.bounds.0 / 2.0,
+        HorizontalAlignment::Right => text.position.x + text.bounds.0,
+    };
+
+    let y = match text.vertical_alignment {
+        VerticalAlignment::Top => text.position.y,
+        VerticalAlignment::Center => text.position.y + text.bounds.1 / 2.0,
+        VerticalAlignment::Bottom => text.position.y + text.bounds.1,
+    };
+
+    wgpu_glyph::VariedSection {
+        text: text
+            .runs
+            .iter()
+            .map(|run| wgpu_glyph::SectionText {
+                text: run.content,
+                scale: wgpu_glyph::Scale {
+                    x: run.size,
+                    y: run.size,
+                },
+                color: run.color.into_linear(),
+                font_id: wgpu_glyph::FontId(run.typeface),
+            })
+            .collect(),
+        screen_position: (x, y),
+        bounds: text.bounds,
+        layout: wgpu_glyph::Layout::default()
+            .h_align(text.horizontal_alignment.into())
+            .v_align(text.vertical_alignment.into()),
+        ..Default::default()
+    }
+}
+
+/// A layout that clips its glyphs to a rectangle.
+///
+/// The glyph brush clips the glyphs of a section to its bounds, so we shrink
+/// them to fit inside the clip rectangle.
+#[derive(Hash)]
+struct Clipped {
+    layout: wgpu_glyph::Layout<wgpu_glyph::BuiltInLineBreaker>,
+    // `f32` is not `Hash`, so we keep the raw bits of the rectangle
+    clip: [u32; 4],
+}
+
+impl Clipped {
+    fn new(
+        layout: wgpu_glyph::Layout<wgpu_glyph::BuiltInLineBreaker>,
+        clip: Rectangle<f32>,
+    ) -> Clipped {
+        Clipped {
+            layout,
+            clip: [
+                clip.x.to_bits(),
+                clip.y.to_bits(),
+                clip.width.to_bits(),
+                clip.height.to_bits(),
+            ],
+        }
+    }
+}
+
+impl GlyphPositioner for Clipped {
+    fn calculate_glyphs<'font, F: wgpu_glyph::FontMap<'font>>(
+        &self,
+        fonts: &F,
+        geometry: &wgpu_glyph::SectionGeometry,
+        sections: &[wgpu_glyph::SectionText<'_>],
+    ) -> Vec<(
+        wgpu_glyph::PositionedGlyph<'font>,
+        [f32; 4],
+        wgpu_glyph::FontId,
+    )> {
+        self.layout.calculate_glyphs(fonts, geometry, sections)
+    }
+
+    fn bounds_rect(
+        &self,
+        geometry: &wgpu_glyph::SectionGeometry,
+    ) -> wgpu_glyph::Rect<f32> {
+        let bounds = self.layout.bounds_rect(geometry);
+        let [x, y, width, height] = self.clip;
+
+        let left = f32::from_bits(x);
+        let top = f32::from_bits(y);
+        let right = left + f32::from_bits(width);
+        let bottom = top + f32::from_bits(height);
 
-        let y = match text.vertical_alignment {
-            VerticalAlignment::Top => text.position.y,
-            VerticalAlignment::Center => text.position.y + text.bounds.1 / 2.0,
-            VerticalAlignment::Bottom => text.position.y + text.bounds.1,
+        let min = wgpu_glyph::Point {
+            x: bounds.min.x.max(left),
+            y: bounds.min.y.max(top),
         };
 
-        wgpu_glyph::Section {
-            text: &text.content,
-            screen_position: (x, y),
-            scale: wgpu_glyph::Scale {
-                x: text.size,
-                y: text.size,
+        wgpu_glyph::Rect {
+            min,
+            max: wgpu_glyph::Point {
+                x: bounds.max.x.min(right).max(min.x),
+                y: bounds.max.y.min(bottom).max(min.y),
             },
-            color: text.color.into_linear(),
-            bounds: text.bounds,
-            layout: wgpu_glyph::Layout::default()
-                .h_align(text.horizontal_alignment.into())
-                .v_align(text.vertical_alignment.into()),
-            ..Default::default()
         }
     }
 }
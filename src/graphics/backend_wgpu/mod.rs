@@ -1,5 +1,9 @@
+mod blend;
 mod font;
+mod pipeline;
 mod quad;
+mod shader;
+mod stencil;
 mod surface;
 pub mod texture;
 mod triangle;
@@ -7,14 +11,25 @@ mod types;
 
 pub use font::Font;
 pub use quad::Quad;
+pub use shader::Shader;
 pub use surface::{winit, Surface};
 pub use texture::Texture;
 pub use triangle::Vertex;
 pub use types::TargetView;
 
-use crate::graphics::{Color, Transformation};
+use std::borrow::Cow;
+
+use crate::graphics::shader::Kind;
+use crate::graphics::target::DrawState;
+use crate::graphics::{self, BlendMode, Color, Rectangle, Transformation};
 use crate::{Error, Result};
 
+/// The maximum number of samples of a multisampled texture.
+///
+/// `wgpu` does not expose the multisampling limits of the adapter yet, so we
+/// only allow the sample counts that every adapter supports.
+const MAX_SAMPLES: u16 = 4;
+
 #[allow(missing_debug_implementations)]
 #[allow(missing_docs)]
 pub struct Gpu {
@@ -71,7 +86,7 @@ impl Gpu {
 
         let _ = self.encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
             color_attachments: &[wgpu::RenderPassColorAttachmentDescriptor {
-                attachment: &view,
+                attachment: view.attachment(),
                 resolve_target: None,
                 load_op: wgpu::LoadOp::Clear,
                 store_op: wgpu::StoreOp::Store,
@@ -88,6 +103,16 @@ impl Gpu {
         Texture::new(&mut self.device, &self.quad_pipeline, image)
     }
 
+    pub(super) fn update_texture(
+        &mut self,
+        texture: &Texture,
+        x: u16,
+        y: u16,
+        image: &image::RgbaImage,
+    ) {
+        texture.update(&mut self.device, &mut self.encoder, x, y, image);
+    }
+
     pub(super) fn upload_texture_array(
         &mut self,
         layers: &[image::DynamicImage],
@@ -95,19 +120,52 @@ impl Gpu {
         Texture::new_array(&mut self.device, &self.quad_pipeline, layers)
     }
 
+    pub(super) fn max_samples(&self) -> u16 {
+        MAX_SAMPLES
+    }
+
     pub(super) fn create_drawable_texture(
         &mut self,
         width: u16,
         height: u16,
+        samples: u16,
     ) -> texture::Drawable {
         texture::Drawable::new(
             &mut self.device,
             &self.quad_pipeline,
             width,
             height,
+            samples,
         )
     }
 
+    pub(super) fn resolve_drawable(&mut self, drawable: &texture::Drawable) {
+        self.resolve(drawable.target());
+    }
+
+    fn resolve(&mut self, view: &TargetView) {
+        if let Some(multisample) = &view.multisample {
+            let _ =
+                self.encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                    color_attachments: &[
+                        wgpu::RenderPassColorAttachmentDescriptor {
+                            attachment: multisample.attachment.view(),
+                            resolve_target: Some(&view.color),
+                            load_op: wgpu::LoadOp::Load,
+                            store_op: wgpu::StoreOp::Store,
+                            clear_color: wgpu::Color {
+                                r: 0.0,
+                                g: 0.0,
+                                b: 0.0,
+                                a: 0.0,
+                            },
+                        },
+                    ],
+                    depth_stencil_attachment: None,
+                });
+        }
+    }
+
     pub(super) fn read_drawable_texture_pixels(
         &mut self,
         drawable: &texture::Drawable,
@@ -121,16 +179,29 @@ impl Gpu {
         drawable.read_pixels(&mut self.device, encoder)
     }
 
-    pub(super) fn upload_font(&mut self, bytes: &'static [u8]) -> Font {
+    pub(super) fn upload_font(
+        &mut self,
+        bytes: Cow<'static, [u8]>,
+    ) -> Result<Font> {
         Font::from_bytes(&mut self.device, bytes)
     }
 
+    pub(super) fn create_shader(
+        &mut self,
+        kind: Kind,
+        source: &[u8],
+    ) -> Result<Shader> {
+        Shader::new(&mut self.device, kind, source)
+    }
+
     pub(super) fn draw_triangles(
         &mut self,
         vertices: &[Vertex],
         indices: &[u32],
         view: &TargetView,
         transformation: &Transformation,
+        state: DrawState,
+        shader: Option<&Shader>,
     ) {
         self.triangle_pipeline.draw(
             &mut self.device,
@@ -138,16 +209,40 @@ impl Gpu {
             vertices,
             indices,
             transformation,
+            state,
+            shader.map(Shader::triangles),
             view,
         );
     }
 
+    pub(super) fn draw_mask(
+        &mut self,
+        vertices: &[Vertex],
+        indices: &[u32],
+        view: &TargetView,
+        transformation: &Transformation,
+        state: DrawState,
+    ) {
+        self.triangle_pipeline.draw_mask(
+            &mut self.device,
+            &mut self.encoder,
+            vertices,
+            indices,
+            transformation,
+            state.mask,
+            view,
+            state.clip,
+        );
+    }
+
     pub(super) fn draw_texture_quads(
         &mut self,
         texture: &Texture,
         instances: &[Quad],
         view: &TargetView,
         transformation: &Transformation,
+        state: DrawState,
+        shader: Option<&Shader>,
     ) {
         self.quad_pipeline.draw_textured(
             &mut self.device,
@@ -155,6 +250,8 @@ impl Gpu {
             texture.binding(),
             instances,
             transformation,
+            state,
+            shader.map(Shader::quads),
             view,
         );
     }
@@ -164,7 +261,41 @@ impl Gpu {
         font: &mut Font,
         target: &TargetView,
         transformation: Transformation,
+        clip: Option<Rectangle<f32>>,
     ) {
-        font.draw(&mut self.device, &mut self.encoder, target, transformation);
+        // The glyph brush cannot draw on multisampled targets, so we draw the
+        // text on the resolved texture and copy it back
+        self.resolve(target);
+
+        font.draw(
+            &mut self.device,
+            &mut self.encoder,
+            &target.color,
+            transformation,
+            clip,
+        );
+
+        if let Some(multisample) = &target.multisample {
+            let width = f32::from(multisample.resolve.width());
+            let height = f32::from(multisample.resolve.height());
+
+            self.quad_pipeline.draw_textured(
+                &mut self.device,
+                &mut self.encoder,
+                multisample.resolve.binding(),
+                &[Quad::from(graphics::Quad {
+                    size: (width, height),
+                    ..graphics::Quad::default()
+                })],
+                &Transformation::orthographic(width, height),
+                DrawState {
+                    blend_mode: BlendMode::Replace,
+                    clip: None,
+                    mask: 0,
+                },
+                None,
+                target,
+            );
+        }
     }
 }
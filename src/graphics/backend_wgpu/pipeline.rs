@@ -0,0 +1,59 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use super::stencil;
+use crate::graphics::BlendMode;
+
+pub type Key = (BlendMode, stencil::Mode, u32);
+
+/// The render pipelines of a fragment shader.
+///
+/// A render pipeline depends on the blend mode, the stencil mode, and the
+/// sample count of the target of a draw operation. Most combinations are
+/// never used, so the pipelines are created lazily and cached.
+pub struct Cache {
+    fragment_shader: wgpu::ShaderModule,
+    has_uniforms: bool,
+    pipelines: RefCell<HashMap<Key, Rc<wgpu::RenderPipeline>>>,
+}
+
+impl Cache {
+    pub fn new(fragment_shader: wgpu::ShaderModule) -> Cache {
+        Cache {
+            fragment_shader,
+            has_uniforms: false,
+            pipelines: RefCell::new(HashMap::new()),
+        }
+    }
+
+    pub fn with_uniforms(fragment_shader: wgpu::ShaderModule) -> Cache {
+        Cache {
+            has_uniforms: true,
+            ..Cache::new(fragment_shader)
+        }
+    }
+
+    pub fn has_uniforms(&self) -> bool {
+        self.has_uniforms
+    }
+
+    pub fn get_or_create<F>(
+        &self,
+        key: Key,
+        create: F,
+    ) -> Rc<wgpu::RenderPipeline>
+    where
+        F: FnOnce(&wgpu::ShaderModule) -> wgpu::RenderPipeline,
+    {
+        if let Some(pipeline) = self.pipelines.borrow().get(&key) {
+            return pipeline.clone();
+        }
+
+        let pipeline = Rc::new(create(&self.fragment_shader));
+
+        let _ = self.pipelines.borrow_mut().insert(key, pipeline.clone());
+
+        pipeline
+    }
+}
@@ -1,9 +1,20 @@
 use std::mem;
+use std::rc::Rc;
 
-use crate::graphics::{self, Transformation};
+use super::pipeline;
+use super::shader::Uniforms;
+use super::types::TargetView;
+use super::{blend, stencil};
+use crate::graphics::target::DrawState;
+use crate::graphics::{self, BlendMode, Transformation};
 
 pub struct Pipeline {
-    pipeline: wgpu::RenderPipeline,
+    pipelines: Pipelines,
+    layout: wgpu::PipelineLayout,
+    shader_layout: wgpu::PipelineLayout,
+    vertex_shader: wgpu::ShaderModule,
+    uniforms: wgpu::Buffer,
+    uniforms_bind_group: wgpu::BindGroup,
     transform: wgpu::Buffer,
     vertices: wgpu::Buffer,
     indices: wgpu::Buffer,
@@ -83,86 +94,46 @@ impl Pipeline {
                 bind_group_layouts: &[&constant_layout, &texture_layout],
             });
 
-        let vs_module =
-            device.create_shader_module(include_bytes!("shader/quad.vert.spv"));
-        let fs_module =
-            device.create_shader_module(include_bytes!("shader/quad.frag.spv"));
+        let uniforms_layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                bindings: &[wgpu::BindGroupLayoutBinding {
+                    binding: 0,
+                    visibility: wgpu::ShaderStage::FRAGMENT,
+                    ty: wgpu::BindingType::UniformBuffer,
+                }],
+            });
 
-        let pipeline =
-            device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
-                layout: &layout,
-                vertex_stage: wgpu::PipelineStageDescriptor {
-                    module: &vs_module,
-                    entry_point: "main",
-                },
-                fragment_stage: Some(wgpu::PipelineStageDescriptor {
-                    module: &fs_module,
-                    entry_point: "main",
-                }),
-                rasterization_state: wgpu::RasterizationStateDescriptor {
-                    front_face: wgpu::FrontFace::Cw,
-                    cull_mode: wgpu::CullMode::None,
-                    depth_bias: 0,
-                    depth_bias_slope_scale: 0.0,
-                    depth_bias_clamp: 0.0,
-                },
-                primitive_topology: wgpu::PrimitiveTopology::TriangleList,
-                color_states: &[wgpu::ColorStateDescriptor {
-                    format: wgpu::TextureFormat::Bgra8UnormSrgb,
-                    color_blend: wgpu::BlendDescriptor {
-                        src_factor: wgpu::BlendFactor::SrcAlpha,
-                        dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
-                        operation: wgpu::BlendOperation::Add,
-                    },
-                    alpha_blend: wgpu::BlendDescriptor {
-                        src_factor: wgpu::BlendFactor::One,
-                        dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
-                        operation: wgpu::BlendOperation::Add,
+        let uniforms = device.create_buffer(&wgpu::BufferDescriptor {
+            size: mem::size_of::<Uniforms>() as u64,
+            usage: wgpu::BufferUsage::UNIFORM | wgpu::BufferUsage::TRANSFER_DST,
+        });
+
+        let uniforms_bind_group =
+            device.create_bind_group(&wgpu::BindGroupDescriptor {
+                layout: &uniforms_layout,
+                bindings: &[wgpu::Binding {
+                    binding: 0,
+                    resource: wgpu::BindingResource::Buffer {
+                        buffer: &uniforms,
+                        range: 0..mem::size_of::<Uniforms>() as u64,
                     },
-                    write_mask: wgpu::ColorWrite::ALL,
                 }],
-                depth_stencil_state: None,
-                index_format: wgpu::IndexFormat::Uint16,
-                vertex_buffers: &[
-                    wgpu::VertexBufferDescriptor {
-                        stride: mem::size_of::<Vertex>() as u64,
-                        step_mode: wgpu::InputStepMode::Vertex,
-                        attributes: &[wgpu::VertexAttributeDescriptor {
-                            shader_location: 0,
-                            format: wgpu::VertexFormat::Float2,
-                            offset: 0,
-                        }],
-                    },
-                    wgpu::VertexBufferDescriptor {
-                        stride: mem::size_of::<Quad>() as u64,
-                        step_mode: wgpu::InputStepMode::Instance,
-                        attributes: &[
-                            wgpu::VertexAttributeDescriptor {
-                                shader_location: 1,
-                                format: wgpu::VertexFormat::Float4,
-                                offset: 0,
-                            },
-                            wgpu::VertexAttributeDescriptor {
-                                shader_location: 2,
-                                format: wgpu::VertexFormat::Float2,
-                                offset: 4 * 4,
-                            },
-                            wgpu::VertexAttributeDescriptor {
-                                shader_location: 3,
-                                format: wgpu::VertexFormat::Float2,
-                                offset: 4 * (4 + 2),
-                            },
-                            wgpu::VertexAttributeDescriptor {
-                                shader_location: 4,
-                                format: wgpu::VertexFormat::Uint,
-                                offset: 4 * (4 + 2 + 2),
-                            },
-                        ],
-                    },
+            });
+
+        let shader_layout =
+            device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+                bind_group_layouts: &[
+                    &constant_layout,
+                    &texture_layout,
+                    &uniforms_layout,
                 ],
-                sample_count: 1,
             });
 
+        let vs_module =
+            device.create_shader_module(include_bytes!("shader/quad.vert.spv"));
+        let fs_module =
+            device.create_shader_module(include_bytes!("shader/quad.frag.spv"));
+
         let vertices = device
             .create_buffer_mapped(QUAD_VERTS.len(), wgpu::BufferUsage::VERTEX)
             .fill_from_slice(&QUAD_VERTS);
@@ -177,7 +148,12 @@ impl Pipeline {
         });
 
         Pipeline {
-            pipeline,
+            pipelines: Pipelines::new(fs_module),
+            layout,
+            shader_layout,
+            vertex_shader: vs_module,
+            uniforms,
+            uniforms_bind_group,
             transform: transform_buffer,
             vertices,
             indices,
@@ -210,7 +186,9 @@ impl Pipeline {
         texture: &TextureBinding,
         instances: &[Quad],
         transformation: &Transformation,
-        target: &wgpu::TextureView,
+        state: DrawState,
+        shader: Option<(&Pipelines, &Uniforms)>,
+        target: &TargetView,
     ) {
         let matrix: [f32; 16] = transformation.clone().into();
 
@@ -226,6 +204,36 @@ impl Pipeline {
             16 * 4,
         );
 
+        let pipelines = match shader {
+            Some((pipelines, uniforms)) => {
+                let uniforms_buffer = device
+                    .create_buffer_mapped(
+                        uniforms.len(),
+                        wgpu::BufferUsage::TRANSFER_SRC,
+                    )
+                    .fill_from_slice(&uniforms[..]);
+
+                encoder.copy_buffer_to_buffer(
+                    &uniforms_buffer,
+                    0,
+                    &self.uniforms,
+                    0,
+                    mem::size_of::<Uniforms>() as u64,
+                );
+
+                pipelines
+            }
+            None => &self.pipelines,
+        };
+
+        let pipeline = self.render_pipeline(
+            device,
+            pipelines,
+            state.blend_mode,
+            stencil::Mode::for_mask(state.mask),
+            target.samples(),
+        );
+
         let mut i = 0;
         let total = instances.len();
 
@@ -249,7 +257,7 @@ impl Pipeline {
                     encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
                         color_attachments: &[
                             wgpu::RenderPassColorAttachmentDescriptor {
-                                attachment: target,
+                                attachment: target.attachment(),
                                 resolve_target: None,
                                 load_op: wgpu::LoadOp::Load,
                                 store_op: wgpu::StoreOp::Store,
@@ -261,12 +269,33 @@ impl Pipeline {
                                 },
                             },
                         ],
-                        depth_stencil_attachment: None,
+                        depth_stencil_attachment: Some(stencil::attachment(
+                            target, false,
+                        )),
                     });
 
-                render_pass.set_pipeline(&self.pipeline);
+                render_pass.set_pipeline(&pipeline);
+                render_pass.set_stencil_reference(u32::from(state.mask));
                 render_pass.set_bind_group(0, &self.constants, &[]);
                 render_pass.set_bind_group(1, &texture.0, &[]);
+
+                if let Some(clip) = state.clip {
+                    render_pass.set_scissor_rect(
+                        clip.x,
+                        clip.y,
+                        clip.width,
+                        clip.height,
+                    );
+                }
+
+                if shader.is_some() {
+                    render_pass.set_bind_group(
+                        2,
+                        &self.uniforms_bind_group,
+                        &[],
+                    );
+                }
+
                 render_pass.set_index_buffer(&self.indices, 0);
                 render_pass.set_vertex_buffers(&[
                     (&self.vertices, 0),
@@ -283,6 +312,119 @@ impl Pipeline {
             i += Quad::MAX;
         }
     }
+
+    fn render_pipeline(
+        &self,
+        device: &mut wgpu::Device,
+        pipelines: &Pipelines,
+        blend_mode: BlendMode,
+        stencil_mode: stencil::Mode,
+        samples: u32,
+    ) -> Rc<wgpu::RenderPipeline> {
+        let layout = if pipelines.has_uniforms() {
+            &self.shader_layout
+        } else {
+            &self.layout
+        };
+
+        pipelines.get_or_create(
+            (blend_mode, stencil_mode, samples),
+            |fs_module| {
+                create_pipeline(
+                    device,
+                    layout,
+                    &self.vertex_shader,
+                    fs_module,
+                    blend_mode,
+                    stencil_mode,
+                    samples,
+                )
+            },
+        )
+    }
+}
+
+pub type Pipelines = pipeline::Cache;
+
+fn create_pipeline(
+    device: &mut wgpu::Device,
+    layout: &wgpu::PipelineLayout,
+    vs_module: &wgpu::ShaderModule,
+    fs_module: &wgpu::ShaderModule,
+    blend_mode: BlendMode,
+    stencil_mode: stencil::Mode,
+    samples: u32,
+) -> wgpu::RenderPipeline {
+    device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+        layout,
+        vertex_stage: wgpu::PipelineStageDescriptor {
+            module: vs_module,
+            entry_point: "main",
+        },
+        fragment_stage: Some(wgpu::PipelineStageDescriptor {
+            module: fs_module,
+            entry_point: "main",
+        }),
+        rasterization_state: wgpu::RasterizationStateDescriptor {
+            front_face: wgpu::FrontFace::Cw,
+            cull_mode: wgpu::CullMode::None,
+            depth_bias: 0,
+            depth_bias_slope_scale: 0.0,
+            depth_bias_clamp: 0.0,
+        },
+        primitive_topology: wgpu::PrimitiveTopology::TriangleList,
+        color_states: &[blend::color_state(blend_mode)],
+        depth_stencil_state: Some(stencil::state(stencil_mode)),
+        index_format: wgpu::IndexFormat::Uint16,
+        vertex_buffers: &[
+            wgpu::VertexBufferDescriptor {
+                stride: mem::size_of::<Vertex>() as u64,
+                step_mode: wgpu::InputStepMode::Vertex,
+                attributes: &[wgpu::VertexAttributeDescriptor {
+                    shader_location: 0,
+                    format: wgpu::VertexFormat::Float2,
+                    offset: 0,
+                }],
+            },
+            wgpu::VertexBufferDescriptor {
+                stride: mem::size_of::<Quad>() as u64,
+                step_mode: wgpu::InputStepMode::Instance,
+                attributes: &[
+                    wgpu::VertexAttributeDescriptor {
+                        shader_location: 1,
+                        format: wgpu::VertexFormat::Float4,
+                        offset: 0,
+                    },
+                    wgpu::VertexAttributeDescriptor {
+                        shader_location: 2,
+                        format: wgpu::VertexFormat::Float2,
+                        offset: 4 * 4,
+                    },
+                    wgpu::VertexAttributeDescriptor {
+                        shader_location: 3,
+                        format: wgpu::VertexFormat::Float2,
+                        offset: 4 * (4 + 2),
+                    },
+                    wgpu::VertexAttributeDescriptor {
+                        shader_location: 5,
+                        format: wgpu::VertexFormat::Float,
+                        offset: 4 * (4 + 2 + 2),
+                    },
+                    wgpu::VertexAttributeDescriptor {
+                        shader_location: 6,
+                        format: wgpu::VertexFormat::Float4,
+                        offset: 4 * (4 + 2 + 2 + 1),
+                    },
+                    wgpu::VertexAttributeDescriptor {
+                        shader_location: 4,
+                        format: wgpu::VertexFormat::Uint,
+                        offset: 4 * (4 + 2 + 2 + 1 + 4),
+                    },
+                ],
+            },
+        ],
+        sample_count: samples,
+    })
 }
 
 #[derive(Clone, Copy)]
@@ -312,6 +454,8 @@ pub struct Quad {
     source: [f32; 4],
     scale: [f32; 2],
     translation: [f32; 2],
+    rotation: f32,
+    tint: [f32; 4],
     pub layer: u32,
 }
 
@@ -321,14 +465,14 @@ impl Quad {
 
 impl From<graphics::Quad> for Quad {
     fn from(quad: graphics::Quad) -> Quad {
-        let source = quad.source;
-        let position = quad.position;
         let (width, height) = quad.size;
 
         Quad {
-            source: [source.x, source.y, source.width, source.height],
-            translation: [position.x, position.y],
+            source: quad.flipped_source(),
+            translation: quad.top_left(),
             scale: [width, height],
+            rotation: quad.rotation,
+            tint: quad.tint.into_linear(),
             layer: 0,
         }
     }
@@ -0,0 +1,78 @@
+use super::{quad, triangle};
+use crate::graphics::{self, shader::Kind};
+use crate::{Error, Result};
+
+pub type Uniforms = [[f32; 4]; graphics::Shader::MAX_UNIFORMS];
+
+const SPIRV_MAGIC_NUMBER: u32 = 0x0723_0203;
+
+pub struct Shader {
+    pipelines: Pipelines,
+    uniforms: Uniforms,
+}
+
+enum Pipelines {
+    Quads(quad::Pipelines),
+    Triangles(triangle::Pipelines),
+}
+
+impl Shader {
+    pub fn new(
+        device: &mut wgpu::Device,
+        kind: Kind,
+        source: &[u8],
+    ) -> Result<Shader> {
+        validate(source)?;
+
+        let module = device.create_shader_module(source);
+
+        let pipelines = match kind {
+            Kind::Quads => {
+                Pipelines::Quads(quad::Pipelines::with_uniforms(module))
+            }
+            Kind::Meshes => {
+                Pipelines::Triangles(triangle::Pipelines::with_uniforms(module))
+            }
+        };
+
+        Ok(Shader {
+            pipelines,
+            uniforms: [[0.0; 4]; graphics::Shader::MAX_UNIFORMS],
+        })
+    }
+
+    pub fn set_uniform(&mut self, index: usize, value: [f32; 4]) {
+        self.uniforms[index] = value;
+    }
+
+    pub fn quads(&self) -> (&quad::Pipelines, &Uniforms) {
+        match &self.pipelines {
+            Pipelines::Quads(pipelines) => (pipelines, &self.uniforms),
+            Pipelines::Triangles(_) => panic!("The shader cannot draw quads"),
+        }
+    }
+
+    pub fn triangles(&self) -> (&triangle::Pipelines, &Uniforms) {
+        match &self.pipelines {
+            Pipelines::Triangles(pipelines) => (pipelines, &self.uniforms),
+            Pipelines::Quads(_) => panic!("The shader cannot draw triangles"),
+        }
+    }
+}
+
+/// Checks that the source looks like SPIR-V, as `wgpu` does not validate
+/// shader modules.
+fn validate(source: &[u8]) -> Result<()> {
+    if source.len() < 4 || source.len() % 4 != 0 {
+        return Err(Error::Shader(String::from("Invalid SPIR-V length")));
+    }
+
+    let magic_number =
+        u32::from_le_bytes([source[0], source[1], source[2], source[3]]);
+
+    if magic_number != SPIRV_MAGIC_NUMBER {
+        return Err(Error::Shader(String::from("Invalid SPIR-V magic number")));
+    }
+
+    Ok(())
+}
@@ -5,6 +5,8 @@ layout(location = 1) in vec4 a_Src;
 layout(location = 2) in vec2 a_Scale;
 layout(location = 3) in vec2 a_Translation;
 layout(location = 4) in uint t_Layer;
+layout(location = 5) in float a_Rotation;
+layout(location = 6) in vec4 a_Tint;
 
 layout (set = 0, binding = 0) uniform Globals {
     mat4 u_Transform;
@@ -12,14 +14,19 @@ layout (set = 0, binding = 0) uniform Globals {
 
 layout(location = 0) out vec2 v_Uv;
 layout(location = 1) flat out uint v_Layer;
+layout(location = 2) out vec4 v_Tint;
 
 void main() {
     v_Uv = a_Pos * a_Src.zw + a_Src.xy;
     v_Layer = t_Layer;
+    v_Tint = a_Tint;
+
+    float s = sin(a_Rotation);
+    float c = cos(a_Rotation);
 
     mat4 a_Transform = mat4(
-        vec4(a_Scale.x, 0.0, 0.0, 0.0),
-        vec4(0.0, a_Scale.y, 0.0, 0.0),
+        vec4(a_Scale.x * c, a_Scale.x * s, 0.0, 0.0),
+        vec4(-a_Scale.y * s, a_Scale.y * c, 0.0, 0.0),
         vec4(0.0, 0.0, 1.0, 0.0),
         vec4(a_Translation, 0.0, 1.0)
     );
@@ -0,0 +1,99 @@
+use crate::graphics::gpu::TargetView;
+
+pub const FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::D24UnormS8Uint;
+
+/// The way a pipeline uses the stencil buffer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Mode {
+    /// Draws every pixel, ignoring the stencil buffer.
+    Unmasked,
+
+    /// Draws the pixels with a stencil value equal to the reference.
+    Masked,
+
+    /// Increments the stencil value of the pixels equal to the reference,
+    /// without drawing any color.
+    WriteMask,
+
+    /// Replaces the stencil values greater than the reference with it,
+    /// without drawing any color.
+    ResetMask,
+}
+
+impl Mode {
+    pub fn for_mask(mask: u8) -> Mode {
+        if mask > 0 {
+            Mode::Masked
+        } else {
+            Mode::Unmasked
+        }
+    }
+
+    pub fn writes_color(self) -> bool {
+        match self {
+            Mode::Unmasked | Mode::Masked => true,
+            Mode::WriteMask | Mode::ResetMask => false,
+        }
+    }
+}
+
+pub fn state(mode: Mode) -> wgpu::DepthStencilStateDescriptor {
+    let (compare, pass_op) = match mode {
+        Mode::Unmasked => {
+            (wgpu::CompareFunction::Always, wgpu::StencilOperation::Keep)
+        }
+        Mode::Masked => {
+            (wgpu::CompareFunction::Equal, wgpu::StencilOperation::Keep)
+        }
+        Mode::WriteMask => (
+            wgpu::CompareFunction::Equal,
+            wgpu::StencilOperation::IncrementClamp,
+        ),
+        Mode::ResetMask => {
+            (wgpu::CompareFunction::Less, wgpu::StencilOperation::Replace)
+        }
+    };
+
+    wgpu::DepthStencilStateDescriptor {
+        format: FORMAT,
+        depth_write_enabled: false,
+        depth_compare: wgpu::CompareFunction::Always,
+        stencil_front: face(compare, pass_op),
+        stencil_back: face(compare, pass_op),
+        stencil_read_mask: 0xFF,
+        stencil_write_mask: 0xFF,
+    }
+}
+
+/// Returns the depth stencil attachment of the given target, clearing its
+/// stencil values if `clear` is true.
+pub fn attachment(
+    target: &TargetView,
+    clear: bool,
+) -> wgpu::RenderPassDepthStencilAttachmentDescriptor<&wgpu::TextureView> {
+    wgpu::RenderPassDepthStencilAttachmentDescriptor {
+        attachment: target.depth_stencil.view(),
+        depth_load_op: wgpu::LoadOp::Load,
+        depth_store_op: wgpu::StoreOp::Store,
+        clear_depth: 0.0,
+        stencil_load_op: if clear {
+            wgpu::LoadOp::Clear
+        } else {
+            wgpu::LoadOp::Load
+        },
+        stencil_store_op: wgpu::StoreOp::Store,
+        clear_stencil: 0,
+    }
+}
+
+fn face(
+    compare: wgpu::CompareFunction,
+    pass_op: wgpu::StencilOperation,
+) -> wgpu::StencilStateFaceDescriptor {
+    wgpu::StencilStateFaceDescriptor {
+        compare,
+        fail_op: wgpu::StencilOperation::Keep,
+        depth_fail_op: wgpu::StencilOperation::Keep,
+        pass_op,
+    }
+}
@@ -1,5 +1,8 @@
 use std::rc::Rc;
 
+use super::stencil;
+use super::texture;
+use super::types::Attachment;
 use super::{Gpu, TargetView};
 pub use wgpu::winit;
 
@@ -51,6 +54,22 @@ impl Surface {
         &self.target
     }
 
+    pub fn read_pixels(&self, gpu: &mut Gpu) -> image::DynamicImage {
+        let new_encoder = gpu.device.create_command_encoder(
+            &wgpu::CommandEncoderDescriptor { todo: 0 },
+        );
+
+        let encoder = std::mem::replace(&mut gpu.encoder, new_encoder);
+
+        texture::read_pixels(
+            &mut gpu.device,
+            encoder,
+            &self.buffer,
+            self.extent.width,
+            self.extent.height,
+        )
+    }
+
     pub fn resize(&mut self, gpu: &mut Gpu, size: winit::dpi::PhysicalSize) {
         let (swap_chain, extent, buffer, target) =
             new_swap_chain(&gpu.device, &self.surface, size);
@@ -134,7 +153,17 @@ fn new_swap_chain(
             | wgpu::TextureUsage::TRANSFER_SRC,
     });
 
-    let target = Rc::new(buffer.create_default_view());
+    let target = TargetView {
+        color: Rc::new(buffer.create_default_view()),
+        depth_stencil: Rc::new(Attachment::new(
+            device,
+            extent.width,
+            extent.height,
+            1,
+            stencil::FORMAT,
+        )),
+        multisample: None,
+    };
 
     (swap_chain, extent, buffer, target)
 }
@@ -1,14 +1,15 @@
 use std::fmt;
 use std::rc::Rc;
 
-use super::types::TargetView;
+use super::stencil;
+use super::types::{Attachment, Multisample, TargetView};
 use crate::graphics::gpu::quad::{self, Pipeline};
 use crate::graphics::Transformation;
 
 #[derive(Clone)]
 pub struct Texture {
     raw: Rc<wgpu::Texture>,
-    view: TargetView,
+    view: Rc<wgpu::TextureView>,
     binding: Rc<quad::TextureBinding>,
     width: u16,
     height: u16,
@@ -87,7 +88,50 @@ impl Texture {
         }
     }
 
-    pub(super) fn view(&self) -> &TargetView {
+    pub(super) fn update(
+        &self,
+        device: &mut wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        x: u16,
+        y: u16,
+        image: &image::RgbaImage,
+    ) {
+        let width = image.width();
+        let height = image.height();
+        let bgra = image::DynamicImage::ImageRgba8(image.clone())
+            .to_bgra()
+            .into_raw();
+
+        let temp_buf = device
+            .create_buffer_mapped(bgra.len(), wgpu::BufferUsage::TRANSFER_SRC)
+            .fill_from_slice(&bgra[..]);
+
+        encoder.copy_buffer_to_texture(
+            wgpu::BufferCopyView {
+                buffer: &temp_buf,
+                offset: 0,
+                row_pitch: 4 * width,
+                image_height: height,
+            },
+            wgpu::TextureCopyView {
+                texture: &self.raw,
+                array_layer: 0,
+                mip_level: 0,
+                origin: wgpu::Origin3d {
+                    x: f32::from(x),
+                    y: f32::from(y),
+                    z: 0.0,
+                },
+            },
+            wgpu::Extent3d {
+                width,
+                height,
+                depth: 1,
+            },
+        );
+    }
+
+    pub(super) fn view(&self) -> &Rc<wgpu::TextureView> {
         &self.view
     }
 
@@ -107,6 +151,7 @@ impl Texture {
 #[derive(Clone)]
 pub struct Drawable {
     texture: Texture,
+    target: TargetView,
 }
 
 impl Drawable {
@@ -115,6 +160,7 @@ impl Drawable {
         pipeline: &Pipeline,
         width: u16,
         height: u16,
+        samples: u16,
     ) -> Drawable {
         let (texture, view, binding) = create_texture_array(
             device,
@@ -136,7 +182,37 @@ impl Drawable {
             layers: 1,
         };
 
-        Drawable { texture }
+        let samples = u32::from(samples);
+
+        let multisample = if samples > 1 {
+            Some(Multisample {
+                attachment: Rc::new(Attachment::new(
+                    device,
+                    u32::from(width),
+                    u32::from(height),
+                    samples,
+                    wgpu::TextureFormat::Bgra8UnormSrgb,
+                )),
+                resolve: texture.clone(),
+                samples,
+            })
+        } else {
+            None
+        };
+
+        let target = TargetView {
+            color: texture.view().clone(),
+            depth_stencil: Rc::new(Attachment::new(
+                device,
+                u32::from(width),
+                u32::from(height),
+                samples,
+                stencil::FORMAT,
+            )),
+            multisample,
+        };
+
+        Drawable { texture, target }
     }
 
     pub fn texture(&self) -> &Texture {
@@ -144,79 +220,26 @@ impl Drawable {
     }
 
     pub fn target(&self) -> &TargetView {
-        self.texture().view()
+        &self.target
+    }
+
+    pub fn samples(&self) -> u16 {
+        self.target.samples() as u16
     }
 
     pub fn read_pixels(
         &self,
         device: &mut wgpu::Device,
-        mut encoder: wgpu::CommandEncoder,
+        encoder: wgpu::CommandEncoder,
     ) -> image::DynamicImage {
         let texture = self.texture();
 
-        let buffer_size = 4 * texture.width() as u64 * texture.height() as u64;
-
-        let buffer = device.create_buffer(&wgpu::BufferDescriptor {
-            size: buffer_size,
-            usage: wgpu::BufferUsage::TRANSFER_DST
-                | wgpu::BufferUsage::TRANSFER_SRC
-                | wgpu::BufferUsage::MAP_READ,
-        });
-
-        encoder.copy_texture_to_buffer(
-            wgpu::TextureCopyView {
-                texture: &texture.raw,
-                mip_level: 0,
-                array_layer: 0,
-                origin: wgpu::Origin3d {
-                    x: 0.0,
-                    y: 0.0,
-                    z: 0.0,
-                },
-            },
-            wgpu::BufferCopyView {
-                buffer: &buffer,
-                offset: 0,
-                row_pitch: 4 * texture.width() as u32,
-                image_height: texture.height() as u32,
-            },
-            wgpu::Extent3d {
-                width: texture.width() as u32,
-                height: texture.height() as u32,
-                depth: 1,
-            },
-        );
-
-        device.get_queue().submit(&[encoder.finish()]);
-
-        use std::cell::RefCell;
-
-        let pixels: Rc<RefCell<Option<Vec<u8>>>> = Rc::new(RefCell::new(None));
-        let write = pixels.clone();
-
-        buffer.map_read_async(0, buffer_size, move |result| {
-            match result {
-                Ok(mapping) => {
-                    *write.borrow_mut() = Some(mapping.data.to_vec());
-                }
-                Err(_) => {
-                    *write.borrow_mut() = Some(vec![]);
-                }
-            };
-        });
-
-        device.poll(true);
-
-        let data = pixels.borrow();
-        let bgra = data.clone().unwrap();
-
-        image::DynamicImage::ImageBgra8(
-            image::ImageBuffer::from_raw(
-                texture.width() as u32,
-                texture.height() as u32,
-                bgra,
-            )
-            .expect("Create BGRA8 image"),
+        read_pixels(
+            device,
+            encoder,
+            &texture.raw,
+            u32::from(texture.width()),
+            u32::from(texture.height()),
         )
     }
 
@@ -226,6 +249,75 @@ impl Drawable {
 }
 
 // Helpers
+pub(super) fn read_pixels(
+    device: &mut wgpu::Device,
+    mut encoder: wgpu::CommandEncoder,
+    texture: &wgpu::Texture,
+    width: u32,
+    height: u32,
+) -> image::DynamicImage {
+    let buffer_size = 4 * u64::from(width) * u64::from(height);
+
+    let buffer = device.create_buffer(&wgpu::BufferDescriptor {
+        size: buffer_size,
+        usage: wgpu::BufferUsage::TRANSFER_DST
+            | wgpu::BufferUsage::TRANSFER_SRC
+            | wgpu::BufferUsage::MAP_READ,
+    });
+
+    encoder.copy_texture_to_buffer(
+        wgpu::TextureCopyView {
+            texture,
+            mip_level: 0,
+            array_layer: 0,
+            origin: wgpu::Origin3d {
+                x: 0.0,
+                y: 0.0,
+                z: 0.0,
+            },
+        },
+        wgpu::BufferCopyView {
+            buffer: &buffer,
+            offset: 0,
+            row_pitch: 4 * width,
+            image_height: height,
+        },
+        wgpu::Extent3d {
+            width,
+            height,
+            depth: 1,
+        },
+    );
+
+    device.get_queue().submit(&[encoder.finish()]);
+
+    use std::cell::RefCell;
+
+    let pixels: Rc<RefCell<Option<Vec<u8>>>> = Rc::new(RefCell::new(None));
+    let write = pixels.clone();
+
+    buffer.map_read_async(0, buffer_size, move |result| {
+        match result {
+            Ok(mapping) => {
+                *write.borrow_mut() = Some(mapping.data.to_vec());
+            }
+            Err(_) => {
+                *write.borrow_mut() = Some(vec![]);
+            }
+        };
+    });
+
+    device.poll(true);
+
+    let data = pixels.borrow();
+    let bgra = data.clone().unwrap();
+
+    image::DynamicImage::ImageBgra8(
+        image::ImageBuffer::from_raw(width, height, bgra)
+            .expect("Create BGRA8 image"),
+    )
+}
+
 fn create_texture_array(
     device: &mut wgpu::Device,
     pipeline: &Pipeline,
@@ -1,9 +1,42 @@
 use std::mem;
+use std::rc::Rc;
 
-use crate::graphics::Transformation;
+use super::pipeline;
+use super::shader::Uniforms;
+use super::types::TargetView;
+use super::{blend, stencil};
+use crate::graphics::target::DrawState;
+use crate::graphics::{BlendMode, Rectangle, Transformation};
+
+/// Two triangles covering the whole target in normalized device coordinates.
+const FULLSCREEN_VERTICES: [Vertex; 4] = [
+    Vertex {
+        _position: [-1.0, -1.0],
+        _color: [1.0, 1.0, 1.0, 1.0],
+    },
+    Vertex {
+        _position: [1.0, -1.0],
+        _color: [1.0, 1.0, 1.0, 1.0],
+    },
+    Vertex {
+        _position: [1.0, 1.0],
+        _color: [1.0, 1.0, 1.0, 1.0],
+    },
+    Vertex {
+        _position: [-1.0, 1.0],
+        _color: [1.0, 1.0, 1.0, 1.0],
+    },
+];
+
+const FULLSCREEN_INDICES: [u32; 6] = [0, 1, 2, 0, 2, 3];
 
 pub struct Pipeline {
-    pipeline: wgpu::RenderPipeline,
+    pipelines: Pipelines,
+    layout: wgpu::PipelineLayout,
+    shader_layout: wgpu::PipelineLayout,
+    vertex_shader: wgpu::ShaderModule,
+    uniforms: wgpu::Buffer,
+    uniforms_bind_group: wgpu::BindGroup,
     transform: wgpu::Buffer,
     constants: wgpu::BindGroup,
     vertices: wgpu::Buffer,
@@ -50,65 +83,42 @@ impl Pipeline {
                 bind_group_layouts: &[&transform_layout],
             });
 
-        let vs_module = device
-            .create_shader_module(include_bytes!("shader/triangle.vert.spv"));
-        let fs_module = device
-            .create_shader_module(include_bytes!("shader/triangle.frag.spv"));
+        let uniforms_layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                bindings: &[wgpu::BindGroupLayoutBinding {
+                    binding: 0,
+                    visibility: wgpu::ShaderStage::FRAGMENT,
+                    ty: wgpu::BindingType::UniformBuffer,
+                }],
+            });
 
-        let pipeline =
-            device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
-                layout: &layout,
-                vertex_stage: wgpu::PipelineStageDescriptor {
-                    module: &vs_module,
-                    entry_point: "main",
-                },
-                fragment_stage: Some(wgpu::PipelineStageDescriptor {
-                    module: &fs_module,
-                    entry_point: "main",
-                }),
-                rasterization_state: wgpu::RasterizationStateDescriptor {
-                    front_face: wgpu::FrontFace::Ccw,
-                    cull_mode: wgpu::CullMode::None,
-                    depth_bias: 0,
-                    depth_bias_slope_scale: 0.0,
-                    depth_bias_clamp: 0.0,
-                },
-                primitive_topology: wgpu::PrimitiveTopology::TriangleList,
-                color_states: &[wgpu::ColorStateDescriptor {
-                    format: wgpu::TextureFormat::Bgra8UnormSrgb,
-                    color_blend: wgpu::BlendDescriptor {
-                        src_factor: wgpu::BlendFactor::SrcAlpha,
-                        dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
-                        operation: wgpu::BlendOperation::Add,
-                    },
-                    alpha_blend: wgpu::BlendDescriptor {
-                        src_factor: wgpu::BlendFactor::One,
-                        dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
-                        operation: wgpu::BlendOperation::Add,
+        let uniforms = device.create_buffer(&wgpu::BufferDescriptor {
+            size: mem::size_of::<Uniforms>() as u64,
+            usage: wgpu::BufferUsage::UNIFORM | wgpu::BufferUsage::TRANSFER_DST,
+        });
+
+        let uniforms_bind_group =
+            device.create_bind_group(&wgpu::BindGroupDescriptor {
+                layout: &uniforms_layout,
+                bindings: &[wgpu::Binding {
+                    binding: 0,
+                    resource: wgpu::BindingResource::Buffer {
+                        buffer: &uniforms,
+                        range: 0..mem::size_of::<Uniforms>() as u64,
                     },
-                    write_mask: wgpu::ColorWrite::ALL,
-                }],
-                depth_stencil_state: None,
-                index_format: wgpu::IndexFormat::Uint32,
-                vertex_buffers: &[wgpu::VertexBufferDescriptor {
-                    stride: mem::size_of::<Vertex>() as u64,
-                    step_mode: wgpu::InputStepMode::Vertex,
-                    attributes: &[
-                        wgpu::VertexAttributeDescriptor {
-                            shader_location: 0,
-                            format: wgpu::VertexFormat::Float2,
-                            offset: 0,
-                        },
-                        wgpu::VertexAttributeDescriptor {
-                            shader_location: 1,
-                            format: wgpu::VertexFormat::Float4,
-                            offset: 4 * 2,
-                        },
-                    ],
                 }],
-                sample_count: 1,
             });
 
+        let shader_layout =
+            device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+                bind_group_layouts: &[&transform_layout, &uniforms_layout],
+            });
+
+        let vs_module = device
+            .create_shader_module(include_bytes!("shader/triangle.vert.spv"));
+        let fs_module = device
+            .create_shader_module(include_bytes!("shader/triangle.frag.spv"));
+
         let vertices = device.create_buffer(&wgpu::BufferDescriptor {
             size: mem::size_of::<Vertex>() as u64
                 * Self::INITIAL_BUFFER_SIZE as u64,
@@ -122,7 +132,12 @@ impl Pipeline {
         });
 
         Pipeline {
-            pipeline,
+            pipelines: Pipelines::new(fs_module),
+            layout,
+            shader_layout,
+            vertex_shader: vs_module,
+            uniforms,
+            uniforms_bind_group,
             transform: transform_buffer,
             constants: constant_bind_group,
             vertices,
@@ -138,12 +153,140 @@ impl Pipeline {
         vertices: &[Vertex],
         indices: &[u32],
         transformation: &Transformation,
-        target: &wgpu::TextureView,
+        state: DrawState,
+        shader: Option<(&Pipelines, &Uniforms)>,
+        target: &TargetView,
     ) {
         if vertices.is_empty() || indices.is_empty() {
             return;
         }
 
+        let pipelines = match shader {
+            Some((pipelines, uniforms)) => {
+                let uniforms_buffer = device
+                    .create_buffer_mapped(
+                        uniforms.len(),
+                        wgpu::BufferUsage::TRANSFER_SRC,
+                    )
+                    .fill_from_slice(&uniforms[..]);
+
+                encoder.copy_buffer_to_buffer(
+                    &uniforms_buffer,
+                    0,
+                    &self.uniforms,
+                    0,
+                    mem::size_of::<Uniforms>() as u64,
+                );
+
+                pipelines
+            }
+            None => &self.pipelines,
+        };
+
+        let pipeline = self.render_pipeline(
+            device,
+            pipelines,
+            state.blend_mode,
+            stencil::Mode::for_mask(state.mask),
+            target.samples(),
+        );
+
+        self.upload(device, encoder, vertices, indices, transformation);
+
+        self.render(
+            encoder,
+            &pipeline,
+            shader.is_some(),
+            target,
+            state.clip,
+            u32::from(state.mask),
+            false,
+            indices.len() as u32,
+        );
+    }
+
+    /// Draws the given triangles on the stencil buffer, incrementing the
+    /// stencil value of the covered pixels that have the given `level`.
+    ///
+    /// Any stencil value greater than `level`, left by a previous mask of
+    /// the same level, is reset first.
+    pub fn draw_mask(
+        &mut self,
+        device: &mut wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        vertices: &[Vertex],
+        indices: &[u32],
+        transformation: &Transformation,
+        level: u8,
+        target: &TargetView,
+        clip: Option<Rectangle<u32>>,
+    ) {
+        if level > 0 {
+            let reset = self.render_pipeline(
+                device,
+                &self.pipelines,
+                BlendMode::Replace,
+                stencil::Mode::ResetMask,
+                target.samples(),
+            );
+
+            self.upload(
+                device,
+                encoder,
+                &FULLSCREEN_VERTICES,
+                &FULLSCREEN_INDICES,
+                &Transformation::identity(),
+            );
+
+            self.render(
+                encoder,
+                &reset,
+                false,
+                target,
+                None,
+                u32::from(level),
+                false,
+                FULLSCREEN_INDICES.len() as u32,
+            );
+        }
+
+        let write = self.render_pipeline(
+            device,
+            &self.pipelines,
+            BlendMode::Replace,
+            stencil::Mode::WriteMask,
+            target.samples(),
+        );
+
+        // An empty mask still needs to clear the stencil buffer
+        let count = if vertices.is_empty() || indices.is_empty() {
+            0
+        } else {
+            self.upload(device, encoder, vertices, indices, transformation);
+
+            indices.len() as u32
+        };
+
+        self.render(
+            encoder,
+            &write,
+            false,
+            target,
+            clip,
+            u32::from(level),
+            level == 0,
+            count,
+        );
+    }
+
+    fn upload(
+        &mut self,
+        device: &mut wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        vertices: &[Vertex],
+        indices: &[u32],
+        transformation: &Transformation,
+    ) {
         let matrix: [f32; 16] = transformation.clone().into();
 
         let transform_buffer = device
@@ -207,35 +350,156 @@ impl Pipeline {
             0,
             (mem::size_of::<u32>() * indices.len()) as u64,
         );
+    }
 
-        {
-            let mut render_pass =
-                encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
-                    color_attachments: &[
-                        wgpu::RenderPassColorAttachmentDescriptor {
-                            attachment: target,
-                            resolve_target: None,
-                            load_op: wgpu::LoadOp::Load,
-                            store_op: wgpu::StoreOp::Store,
-                            clear_color: wgpu::Color {
-                                r: 0.0,
-                                g: 0.0,
-                                b: 0.0,
-                                a: 0.0,
-                            },
+    fn render(
+        &self,
+        encoder: &mut wgpu::CommandEncoder,
+        pipeline: &wgpu::RenderPipeline,
+        has_uniforms: bool,
+        target: &TargetView,
+        clip: Option<Rectangle<u32>>,
+        stencil_reference: u32,
+        clear_stencil: bool,
+        count: u32,
+    ) {
+        let mut render_pass =
+            encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                color_attachments: &[
+                    wgpu::RenderPassColorAttachmentDescriptor {
+                        attachment: target.attachment(),
+                        resolve_target: None,
+                        load_op: wgpu::LoadOp::Load,
+                        store_op: wgpu::StoreOp::Store,
+                        clear_color: wgpu::Color {
+                            r: 0.0,
+                            g: 0.0,
+                            b: 0.0,
+                            a: 0.0,
                         },
-                    ],
-                    depth_stencil_attachment: None,
-                });
+                    },
+                ],
+                depth_stencil_attachment: Some(stencil::attachment(
+                    target,
+                    clear_stencil,
+                )),
+            });
+
+        render_pass.set_pipeline(pipeline);
+        render_pass.set_stencil_reference(stencil_reference);
+        render_pass.set_bind_group(0, &self.constants, &[]);
+
+        if let Some(clip) = clip {
+            render_pass.set_scissor_rect(
+                clip.x,
+                clip.y,
+                clip.width,
+                clip.height,
+            );
+        }
+
+        if has_uniforms {
+            render_pass.set_bind_group(1, &self.uniforms_bind_group, &[]);
+        }
 
-            render_pass.set_pipeline(&self.pipeline);
-            render_pass.set_bind_group(0, &self.constants, &[]);
-            render_pass.set_index_buffer(&self.indices, 0);
-            render_pass.set_vertex_buffers(&[(&self.vertices, 0)]);
+        render_pass.set_index_buffer(&self.indices, 0);
+        render_pass.set_vertex_buffers(&[(&self.vertices, 0)]);
 
-            render_pass.draw_indexed(0..indices.len() as u32, 0, 0..1);
+        if count > 0 {
+            render_pass.draw_indexed(0..count, 0, 0..1);
         }
     }
+
+    fn render_pipeline(
+        &self,
+        device: &mut wgpu::Device,
+        pipelines: &Pipelines,
+        blend_mode: BlendMode,
+        stencil_mode: stencil::Mode,
+        samples: u32,
+    ) -> Rc<wgpu::RenderPipeline> {
+        let layout = if pipelines.has_uniforms() {
+            &self.shader_layout
+        } else {
+            &self.layout
+        };
+
+        pipelines.get_or_create(
+            (blend_mode, stencil_mode, samples),
+            |fs_module| {
+                create_pipeline(
+                    device,
+                    layout,
+                    &self.vertex_shader,
+                    fs_module,
+                    blend_mode,
+                    stencil_mode,
+                    samples,
+                )
+            },
+        )
+    }
+}
+
+pub type Pipelines = pipeline::Cache;
+
+fn create_pipeline(
+    device: &mut wgpu::Device,
+    layout: &wgpu::PipelineLayout,
+    vs_module: &wgpu::ShaderModule,
+    fs_module: &wgpu::ShaderModule,
+    blend_mode: BlendMode,
+    stencil_mode: stencil::Mode,
+    samples: u32,
+) -> wgpu::RenderPipeline {
+    let color_state = if stencil_mode.writes_color() {
+        blend::color_state(blend_mode)
+    } else {
+        wgpu::ColorStateDescriptor {
+            write_mask: wgpu::ColorWrite::empty(),
+            ..blend::color_state(blend_mode)
+        }
+    };
+
+    device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+        layout,
+        vertex_stage: wgpu::PipelineStageDescriptor {
+            module: vs_module,
+            entry_point: "main",
+        },
+        fragment_stage: Some(wgpu::PipelineStageDescriptor {
+            module: fs_module,
+            entry_point: "main",
+        }),
+        rasterization_state: wgpu::RasterizationStateDescriptor {
+            front_face: wgpu::FrontFace::Ccw,
+            cull_mode: wgpu::CullMode::None,
+            depth_bias: 0,
+            depth_bias_slope_scale: 0.0,
+            depth_bias_clamp: 0.0,
+        },
+        primitive_topology: wgpu::PrimitiveTopology::TriangleList,
+        color_states: &[color_state],
+        depth_stencil_state: Some(stencil::state(stencil_mode)),
+        index_format: wgpu::IndexFormat::Uint32,
+        vertex_buffers: &[wgpu::VertexBufferDescriptor {
+            stride: mem::size_of::<Vertex>() as u64,
+            step_mode: wgpu::InputStepMode::Vertex,
+            attributes: &[
+                wgpu::VertexAttributeDescriptor {
+                    shader_location: 0,
+                    format: wgpu::VertexFormat::Float2,
+                    offset: 0,
+                },
+                wgpu::VertexAttributeDescriptor {
+                    shader_location: 1,
+                    format: wgpu::VertexFormat::Float4,
+                    offset: 4 * 2,
+                },
+            ],
+        }],
+        sample_count: samples,
+    })
 }
 
 #[derive(Debug, Clone, Copy)]
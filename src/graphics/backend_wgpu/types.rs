@@ -1,3 +1,80 @@
 use std::rc::Rc;
 
-pub type TargetView = Rc<wgpu::TextureView>;
+use super::texture::Texture;
+
+#[derive(Clone)]
+pub struct TargetView {
+    pub color: Rc<wgpu::TextureView>,
+    pub depth_stencil: Rc<Attachment>,
+    pub multisample: Option<Multisample>,
+}
+
+impl TargetView {
+    /// Returns the view the draw operations render to.
+    ///
+    /// It is a multisampled attachment if the target is multisampled.
+    /// Otherwise, it is the `color` view itself.
+    pub fn attachment(&self) -> &wgpu::TextureView {
+        match &self.multisample {
+            Some(multisample) => multisample.attachment.view(),
+            None => &self.color,
+        }
+    }
+
+    pub fn samples(&self) -> u32 {
+        self.multisample
+            .as_ref()
+            .map(|multisample| multisample.samples)
+            .unwrap_or(1)
+    }
+}
+
+/// The multisampled attachment of a target, which is resolved into its
+/// `color` view.
+#[derive(Clone)]
+pub struct Multisample {
+    pub attachment: Rc<Attachment>,
+    pub resolve: Texture,
+    pub samples: u32,
+}
+
+/// A texture only used as a render pass attachment.
+pub struct Attachment {
+    _texture: wgpu::Texture,
+    view: wgpu::TextureView,
+}
+
+impl Attachment {
+    pub fn new(
+        device: &wgpu::Device,
+        width: u32,
+        height: u32,
+        samples: u32,
+        format: wgpu::TextureFormat,
+    ) -> Attachment {
+        let texture = device.create_texture(&wgpu::TextureDescriptor {
+            size: wgpu::Extent3d {
+                width,
+                height,
+                depth: 1,
+            },
+            array_layer_count: 1,
+            mip_level_count: 1,
+            sample_count: samples,
+            dimension: wgpu::TextureDimension::D2,
+            format,
+            usage: wgpu::TextureUsage::OUTPUT_ATTACHMENT,
+        });
+
+        let view = texture.create_default_view();
+
+        Attachment {
+            _texture: texture,
+            view,
+        }
+    }
+
+    pub fn view(&self) -> &wgpu::TextureView {
+        &self.view
+    }
+}
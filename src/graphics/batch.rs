@@ -1,7 +1,7 @@
 use rayon::prelude::*;
 
 use crate::graphics::gpu;
-use crate::graphics::{Image, IntoQuad, Target};
+use crate::graphics::{BlendMode, Image, IntoQuad, Shader, Target};
 
 /// A collection of quads that will be drawn all at once using the same
 /// [`Image`].
@@ -12,6 +12,7 @@ pub struct Batch {
     instances: Vec<gpu::Quad>,
     x_unit: f32,
     y_unit: f32,
+    blend_mode: Option<BlendMode>,
 }
 
 impl Batch {
@@ -28,6 +29,7 @@ impl Batch {
             instances: Vec::new(),
             x_unit,
             y_unit,
+            blend_mode: None,
         }
     }
 
@@ -42,12 +44,36 @@ impl Batch {
         self.instances.push(instance);
     }
 
+    /// Sets the [`BlendMode`] used to draw the [`Batch`].
+    ///
+    /// By default, a [`Batch`] uses the [`BlendMode`] of the [`Target`] it is
+    /// drawn on.
+    ///
+    /// [`BlendMode`]: enum.BlendMode.html
+    /// [`Batch`]: struct.Batch.html
+    /// [`Target`]: struct.Target.html
+    pub fn set_blend_mode(&mut self, blend_mode: BlendMode) {
+        self.blend_mode = Some(blend_mode);
+    }
+
     /// Draws the [`Batch`] on the given [`Target`].
     ///
     /// [`Batch`]: struct.Batch.html
     /// [`Target`]: struct.Target.html
     pub fn draw(&self, target: &mut Target<'_>) {
-        target.draw_texture_quads(&self.image.texture, &self.instances[..]);
+        self.draw_quads(target, None);
+    }
+
+    /// Draws the [`Batch`] on the given [`Target`] using a custom [`Shader`].
+    ///
+    /// # Panics
+    /// It panics if the [`Shader`] was not created for quads.
+    ///
+    /// [`Batch`]: struct.Batch.html
+    /// [`Target`]: struct.Target.html
+    /// [`Shader`]: struct.Shader.html
+    pub fn draw_with_shader(&self, shader: &Shader, target: &mut Target<'_>) {
+        self.draw_quads(target, Some(shader));
     }
 
     /// Clears the [`Batch`] contents.
@@ -59,6 +85,18 @@ impl Batch {
     pub fn clear(&mut self) {
         self.instances.clear();
     }
+
+    fn draw_quads(&self, target: &mut Target<'_>, shader: Option<&Shader>) {
+        let texture = &self.image.texture;
+        let instances = &self.instances[..];
+
+        match self.blend_mode {
+            Some(blend_mode) => target
+                .blend(blend_mode)
+                .draw_texture_quads(texture, instances, shader),
+            None => target.draw_texture_quads(texture, instances, shader),
+        }
+    }
 }
 
 impl std::fmt::Debug for Batch {
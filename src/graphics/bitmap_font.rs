@@ -0,0 +1,377 @@
+mod descriptor;
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::graphics::{
+    Batch, Color, GlyphInfo, Gpu, HorizontalAlignment, Image, Point, Rectangle,
+    RichText, Sprite, Target, VerticalAlignment,
+};
+use crate::load::Task;
+use crate::Result;
+
+use descriptor::{Descriptor, Glyph};
+
+/// A font made of pre-rendered glyphs, packed in images.
+///
+/// A [`BitmapFont`] draws crisp text at any size that is a multiple of the
+/// size it was rendered at, which makes it a great fit for pixel-art games.
+/// It offers the same [`add`], [`measure`], and [`draw`] methods as a
+/// [`Font`].
+///
+/// It loads fonts in the [AngelCode BMFont] format, using either the text or
+/// the XML variant of the `.fnt` descriptor. Glyphs are drawn as quads using
+/// a [`Batch`] for every page image, and kerning pairs are applied.
+///
+/// A [`BitmapFont`] only has one typeface, so the [`FontStyle`] of a [`Span`]
+/// is ignored, and characters missing in the font are skipped. Glyphs are
+/// multiplied by the color of the text, so they should be white.
+///
+/// [`BitmapFont`]: struct.BitmapFont.html
+/// [`add`]: #method.add
+/// [`measure`]: #method.measure
+/// [`draw`]: #method.draw
+/// [`Font`]: struct.Font.html
+/// [AngelCode BMFont]: http://www.angelcode.com/products/bmfont/
+/// [`Batch`]: struct.Batch.html
+/// [`FontStyle`]: enum.FontStyle.html
+/// [`Span`]: struct.Span.html
+#[derive(Debug)]
+pub struct BitmapFont {
+    descriptor: Descriptor,
+    pages: Vec<Batch>,
+}
+
+impl BitmapFont {
+    /// Loads a [`BitmapFont`] from the `.fnt` descriptor at the given path.
+    ///
+    /// The page images are loaded relative to the directory of the
+    /// descriptor.
+    ///
+    /// [`BitmapFont`]: struct.BitmapFont.html
+    pub fn new<P: AsRef<Path>>(gpu: &mut Gpu, path: P) -> Result<BitmapFont> {
+        let path = path.as_ref();
+        let descriptor = Descriptor::parse(&fs::read_to_string(path)?)?;
+        let directory = path.parent().unwrap_or_else(|| Path::new(""));
+
+        let pages = descriptor
+            .pages
+            .iter()
+            .map(|page| Ok(Batch::new(Image::new(gpu, directory.join(page))?)))
+            .collect::<Result<_>>()?;
+
+        Ok(BitmapFont { descriptor, pages })
+    }
+
+    /// Creates a [`Task`] that loads a [`BitmapFont`] from the `.fnt`
+    /// descriptor at the given path.
+    ///
+    /// [`Task`]: ../load/struct.Task.html
+    /// [`BitmapFont`]: struct.BitmapFont.html
+    pub fn load<P: Into<PathBuf>>(path: P) -> Task<BitmapFont> {
+        let p = path.into();
+
+        Task::using_gpu(move |gpu| BitmapFont::new(gpu, &p))
+    }
+
+    /// Adds [`Text`] or [`RichText`] to this [`BitmapFont`].
+    ///
+    /// [`Text`]: struct.Text.html
+    /// [`RichText`]: struct.RichText.html
+    /// [`BitmapFont`]: struct.BitmapFont.html
+    pub fn add<'a, T: Into<RichText<'a>>>(&mut self, text: T) {
+        for (placed, pen, _) in self.arrange(&text.into()) {
+            let glyph = placed.glyph;
+
+            self.pages[glyph.page].add(Sprite {
+                source: glyph.source,
+                position: pen + glyph.offset * placed.scale,
+                scale: (placed.scale, placed.scale),
+                tint: placed.color,
+                ..Sprite::default()
+            });
+        }
+    }
+
+    /// Computes the layout bounds of the given [`Text`] or [`RichText`].
+    ///
+    /// [`Text`]: struct.Text.html
+    /// [`RichText`]: struct.RichText.html
+    pub fn measure<'a, T: Into<RichText<'a>>>(
+        &mut self,
+        text: T,
+    ) -> (f32, f32) {
+        let lines = self.lines(&text.into());
+
+        self.size(&lines)
+    }
+
+    /// Computes the positioned glyphs of the given [`Text`] or [`RichText`].
+    ///
+    /// Control characters, like line breaks, and characters missing in the
+    /// font do not have a glyph.
+    ///
+    /// [`Text`]: struct.Text.html
+    /// [`RichText`]: struct.RichText.html
+    pub fn layout<'a, T: Into<RichText<'a>>>(
+        &mut self,
+        text: T,
+    ) -> Vec<GlyphInfo> {
+        self.arrange(&text.into())
+            .into_iter()
+            .map(|(placed, pen, line)| GlyphInfo {
+                span: placed.span,
+                index: placed.index,
+                bounds: Rectangle {
+                    x: pen.x,
+                    y: pen.y,
+                    width: placed.glyph.advance * placed.scale,
+                    height: self.descriptor.line_height * placed.scale,
+                },
+                line,
+            })
+            .collect()
+    }
+
+    /// Finds the character of the given [`Text`] or [`RichText`] whose glyph
+    /// contains the given [`Point`], and returns its byte index.
+    ///
+    /// See [`GlyphInfo::index`] to learn how the byte index of a [`RichText`]
+    /// is computed.
+    ///
+    /// [`Text`]: struct.Text.html
+    /// [`RichText`]: struct.RichText.html
+    /// [`Point`]: type.Point.html
+    /// [`GlyphInfo::index`]: struct.GlyphInfo.html#structfield.index
+    pub fn hit_test<'a, T: Into<RichText<'a>>>(
+        &mut self,
+        text: T,
+        point: Point,
+    ) -> Option<usize> {
+        GlyphInfo::hit_test(&self.layout(text), point)
+    }
+
+    /// Draws and flushes all the text added to this [`BitmapFont`].
+    ///
+    /// [`BitmapFont`]: struct.BitmapFont.html
+    pub fn draw(&mut self, target: &mut Target<'_>) {
+        for page in &mut self.pages {
+            page.draw(target);
+            page.clear();
+        }
+    }
+
+    /// Positions the glyphs of the given [`RichText`], returning the top-left
+    /// corner of their cells and their line.
+    ///
+    /// [`RichText`]: struct.RichText.html
+    fn arrange(&self, text: &RichText<'_>) -> Vec<(Placed, Point, usize)> {
+        let lines = self.lines(text);
+        let (_, height) = self.size(&lines);
+
+        let mut y = match text.vertical_alignment {
+            VerticalAlignment::Top => text.position.y,
+            VerticalAlignment::Center => {
+                text.position.y + text.bounds.1 / 2.0 - height / 2.0
+            }
+            VerticalAlignment::Bottom => {
+                text.position.y + text.bounds.1 - height
+            }
+        };
+
+        let mut arranged = Vec::new();
+
+        for (i, line) in lines.into_iter().enumerate() {
+            let x = match text.horizontal_alignment {
+                HorizontalAlignment::Left => text.position.x,
+                HorizontalAlignment::Center => {
+                    text.position.x + text.bounds.0 / 2.0 - line.width() / 2.0
+                }
+                HorizontalAlignment::Right => {
+                    text.position.x + text.bounds.0 - line.width()
+                }
+            };
+
+            let base = y + line.base(&self.descriptor);
+            y += line.height(&self.descriptor);
+
+            arranged.extend(line.glyphs.into_iter().map(|placed| {
+                let pen = Point::new(
+                    x + placed.x,
+                    base - self.descriptor.base * placed.scale,
+                );
+
+                (placed, pen, i)
+            }));
+        }
+
+        arranged
+    }
+
+    /// Breaks the given [`RichText`] into lines, wrapping words that exceed
+    /// its width bounds.
+    ///
+    /// [`RichText`]: struct.RichText.html
+    fn lines(&self, text: &RichText<'_>) -> Vec<Line> {
+        let scale = text
+            .spans
+            .first()
+            .map_or(1.0, |span| span.size / self.descriptor.size);
+
+        let mut lines = vec![Line::new(scale)];
+        let mut previous = None;
+
+        let mut start = 0;
+
+        for (i, span) in text.spans.iter().enumerate() {
+            let scale = span.size / self.descriptor.size;
+
+            for (index, character) in span.content.char_indices() {
+                if character == '\n' {
+                    lines.push(Line::new(scale));
+                    previous = None;
+                    continue;
+                }
+
+                let glyph = match self.descriptor.glyphs.get(&character) {
+                    Some(glyph) => *glyph,
+                    None => continue,
+                };
+
+                let kerning = previous
+                    .and_then(|previous| {
+                        self.descriptor.kernings.get(&(previous, character))
+                    })
+                    .cloned()
+                    .unwrap_or(0.0);
+
+                let line = lines.last_mut().expect("Current line");
+                let x = line.pen + kerning * scale;
+
+                line.pen = x + glyph.advance * scale;
+                line.glyphs.push(Placed {
+                    span: i,
+                    index: start + index,
+                    character,
+                    glyph,
+                    x,
+                    scale,
+                    color: span.color,
+                });
+
+                previous = Some(character);
+
+                if line.width() > text.bounds.0 && !character.is_whitespace() {
+                    if let Some(next) = line.wrap(scale) {
+                        lines.push(next);
+                    }
+                }
+            }
+
+            start += span.content.len();
+        }
+
+        lines
+    }
+
+    fn size(&self, lines: &[Line]) -> (f32, f32) {
+        lines.iter().fold((0.0, 0.0), |(width, height), line| {
+            (
+                width.max(line.width()),
+                height + line.height(&self.descriptor),
+            )
+        })
+    }
+}
+
+/// A line of text, with its glyphs positioned relative to its start.
+#[derive(Debug)]
+struct Line {
+    glyphs: Vec<Placed>,
+    pen: f32,
+
+    /// The scale of the line when it has no glyphs.
+    scale: f32,
+}
+
+#[derive(Debug)]
+struct Placed {
+    span: usize,
+    index: usize,
+    character: char,
+    glyph: Glyph,
+    x: f32,
+    scale: f32,
+    color: Color,
+}
+
+impl Line {
+    fn new(scale: f32) -> Line {
+        Line {
+            glyphs: Vec::new(),
+            pen: 0.0,
+            scale,
+        }
+    }
+
+    /// The width of the line, ignoring trailing whitespace.
+    fn width(&self) -> f32 {
+        self.glyphs
+            .iter()
+            .rev()
+            .find(|placed| !placed.character.is_whitespace())
+            .map_or(0.0, |placed| {
+                placed.x + placed.glyph.advance * placed.scale
+            })
+    }
+
+    fn height(&self, descriptor: &Descriptor) -> f32 {
+        self.max_scale() * descriptor.line_height
+    }
+
+    fn base(&self, descriptor: &Descriptor) -> f32 {
+        self.max_scale() * descriptor.base
+    }
+
+    fn max_scale(&self) -> f32 {
+        if self.glyphs.is_empty() {
+            return self.scale;
+        }
+
+        self.glyphs
+            .iter()
+            .map(|placed| placed.scale)
+            .fold(0.0, f32::max)
+    }
+
+    /// Moves the last word of the line to a new line, if the line has more
+    /// than one word.
+    fn wrap(&mut self, scale: f32) -> Option<Line> {
+        let start = self
+            .glyphs
+            .iter()
+            .rposition(|placed| placed.character.is_whitespace())?
+            + 1;
+
+        if start == self.glyphs.len()
+            || self.glyphs[..start]
+                .iter()
+                .all(|placed| placed.character.is_whitespace())
+        {
+            return None;
+        }
+
+        let mut next = Line::new(scale);
+        let offset = self.glyphs[start].x;
+
+        next.glyphs = self.glyphs.split_off(start);
+        next.pen = self.pen - offset;
+        self.pen = offset;
+
+        for placed in &mut next.glyphs {
+            placed.x -= offset;
+        }
+
+        Some(next)
+    }
+}
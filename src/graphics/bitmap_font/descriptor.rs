@@ -0,0 +1,223 @@
+//! Parse AngelCode BMFont descriptors, in both text and XML formats.
+use std::collections::HashMap;
+
+use crate::graphics::{Rectangle, Vector};
+use crate::{Error, Result};
+
+/// The contents of a `.fnt` file.
+#[derive(Debug, Clone)]
+pub(super) struct Descriptor {
+    pub(super) size: f32,
+    pub(super) line_height: f32,
+    pub(super) base: f32,
+    pub(super) pages: Vec<String>,
+    pub(super) glyphs: HashMap<char, Glyph>,
+    pub(super) kernings: HashMap<(char, char), f32>,
+}
+
+/// A character of a bitmap font.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(super) struct Glyph {
+    /// The portion of the page that contains the glyph.
+    pub(super) source: Rectangle<u16>,
+
+    /// The offset of the glyph from the pen position.
+    pub(super) offset: Vector,
+
+    /// How much the pen moves after drawing the glyph.
+    pub(super) advance: f32,
+
+    /// The index of the page that contains the glyph.
+    pub(super) page: usize,
+}
+
+impl Descriptor {
+    pub(super) fn parse(source: &str) -> Result<Descriptor> {
+        let mut descriptor = Descriptor {
+            size: 0.0,
+            line_height: 0.0,
+            base: 0.0,
+            pages: Vec::new(),
+            glyphs: HashMap::new(),
+            kernings: HashMap::new(),
+        };
+
+        let mut pages = Vec::new();
+
+        for tag in tags(source) {
+            let (name, attributes) = parse_tag(tag);
+
+            match name {
+                "info" => {
+                    // A negative size means the size matches the height of
+                    // the characters, instead of the height of the cells
+                    descriptor.size = number(&attributes, "size")?.abs() as f32;
+                }
+                "common" => {
+                    descriptor.line_height =
+                        number(&attributes, "lineHeight")? as f32;
+                    descriptor.base = number(&attributes, "base")? as f32;
+                }
+                "page" => {
+                    let id = number(&attributes, "id")?;
+                    let file = attribute(&attributes, "file")?;
+
+                    pages.push((id, String::from(file)));
+                }
+                "char" => {
+                    let character = match character(&attributes, "id")? {
+                        Some(character) => character,
+                        None => continue,
+                    };
+
+                    let glyph = Glyph {
+                        source: Rectangle {
+                            x: number(&attributes, "x")? as u16,
+                            y: number(&attributes, "y")? as u16,
+                            width: number(&attributes, "width")? as u16,
+                            height: number(&attributes, "height")? as u16,
+                        },
+                        offset: Vector::new(
+                            number(&attributes, "xoffset")? as f32,
+                            number(&attributes, "yoffset")? as f32,
+                        ),
+                        advance: number(&attributes, "xadvance")? as f32,
+                        page: number(&attributes, "page").unwrap_or(0) as usize,
+                    };
+
+                    let _ = descriptor.glyphs.insert(character, glyph);
+                }
+                "kerning" => {
+                    let first = character(&attributes, "first")?;
+                    let second = character(&attributes, "second")?;
+                    let amount = number(&attributes, "amount")? as f32;
+
+                    if let (Some(first), Some(second)) = (first, second) {
+                        let _ =
+                            descriptor.kernings.insert((first, second), amount);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        pages.sort();
+        descriptor.pages = pages.into_iter().map(|(_, file)| file).collect();
+
+        if descriptor.size == 0.0 || descriptor.line_height == 0.0 {
+            return Err(invalid("missing info or common tags"));
+        }
+
+        if let Some(glyph) = descriptor
+            .glyphs
+            .values()
+            .find(|glyph| glyph.page >= descriptor.pages.len())
+        {
+            return Err(invalid(&format!("page {} not found", glyph.page)));
+        }
+
+        Ok(descriptor)
+    }
+}
+
+/// Splits the source of a descriptor into tags.
+///
+/// Every line of the text format is a tag. In the XML format, tags are
+/// delimited by angle brackets, and we skip declarations, comments, and
+/// closing tags.
+fn tags(source: &str) -> Vec<&str> {
+    if source.trim_start().starts_with('<') {
+        source
+            .split('<')
+            .filter_map(|tag| tag.split('>').next())
+            .filter(|tag| {
+                !(tag.starts_with('?')
+                    || tag.starts_with('!')
+                    || tag.starts_with('/'))
+            })
+            .map(|tag| tag.trim_end_matches('/'))
+            .collect()
+    } else {
+        source.lines().collect()
+    }
+}
+
+/// Parses a tag like `char id=65 x="12"` into its name and attributes.
+fn parse_tag(tag: &str) -> (&str, HashMap<&str, &str>) {
+    let tag = tag.trim();
+    let (name, mut rest) = match tag.find(char::is_whitespace) {
+        Some(i) => (&tag[..i], &tag[i..]),
+        None => (tag, ""),
+    };
+
+    let mut attributes = HashMap::new();
+
+    loop {
+        rest = rest.trim_start();
+
+        let equals = match rest.find('=') {
+            Some(equals) => equals,
+            None => break,
+        };
+
+        let key = rest[..equals].trim();
+        rest = &rest[equals + 1..];
+
+        let value = if rest.starts_with('"') {
+            let end = rest[1..].find('"').map_or(rest.len(), |end| end + 1);
+            let value = &rest[1..end];
+
+            rest = rest.get(end + 1..).unwrap_or("");
+            value
+        } else {
+            let end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+            let value = &rest[..end];
+
+            rest = &rest[end..];
+            value
+        };
+
+        let _ = attributes.insert(key, value);
+    }
+
+    (name, attributes)
+}
+
+fn attribute<'a>(
+    attributes: &HashMap<&str, &'a str>,
+    key: &str,
+) -> Result<&'a str> {
+    attributes
+        .get(key)
+        .cloned()
+        .ok_or_else(|| invalid(&format!("missing attribute \"{}\"", key)))
+}
+
+fn number(attributes: &HashMap<&str, &str>, key: &str) -> Result<i64> {
+    let value = attribute(attributes, key)?;
+
+    value
+        .parse()
+        .map_err(|_| invalid(&format!("attribute \"{}\" is not a number", key)))
+}
+
+/// Reads a character code, which is negative for the glyph used to draw
+/// invalid characters.
+fn character(
+    attributes: &HashMap<&str, &str>,
+    key: &str,
+) -> Result<Option<char>> {
+    let code = number(attributes, key)?;
+
+    if code < 0 {
+        return Ok(None);
+    }
+
+    std::char::from_u32(code as u32)
+        .map(Some)
+        .ok_or_else(|| invalid(&format!("invalid character code {}", code)))
+}
+
+fn invalid(reason: &str) -> Error {
+    Error::Font(format!("Invalid bitmap font descriptor, {}", reason))
+}
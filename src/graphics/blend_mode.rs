@@ -0,0 +1,57 @@
+/// A way to combine the colors of a draw operation with the colors of a
+/// [`Target`].
+///
+/// By default, draw operations use [`BlendMode::Alpha`]. You can change the
+/// blend mode of a [`Batch`] or a [`Mesh`], or of every draw operation on a
+/// [`Target`] using [`Target::blend`].
+///
+/// Text is always drawn using [`BlendMode::Alpha`].
+///
+/// [`Target`]: struct.Target.html
+/// [`BlendMode::Alpha`]: #variant.Alpha
+/// [`Batch`]: struct.Batch.html
+/// [`Mesh`]: struct.Mesh.html
+/// [`Target::blend`]: struct.Target.html#method.blend
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BlendMode {
+    /// Draws colors over the target based on their alpha channel.
+    Alpha,
+
+    /// Adds colors to the target, weighted by their alpha channel.
+    ///
+    /// Useful for light effects, like fire or glowing particles.
+    Additive,
+
+    /// Multiplies the colors of the target by the drawn colors, ignoring their
+    /// alpha channel.
+    ///
+    /// Useful to apply a lighting pass drawn in a [`Canvas`].
+    ///
+    /// [`Canvas`]: struct.Canvas.html
+    Multiply,
+
+    /// Like [`Alpha`], but for colors that are already multiplied by their
+    /// alpha channel.
+    ///
+    /// [`Alpha`]: #variant.Alpha
+    Premultiplied,
+
+    /// Replaces the colors of the target with the drawn colors.
+    Replace,
+}
+
+impl BlendMode {
+    pub(crate) const ALL: [BlendMode; 5] = [
+        BlendMode::Alpha,
+        BlendMode::Additive,
+        BlendMode::Multiply,
+        BlendMode::Premultiplied,
+        BlendMode::Replace,
+    ];
+}
+
+impl Default for BlendMode {
+    fn default() -> BlendMode {
+        BlendMode::Alpha
+    }
+}
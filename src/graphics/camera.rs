@@ -0,0 +1,155 @@
+use crate::graphics::{Point, Rectangle, Transformation, Vector};
+
+/// A 2D camera that looks at your game world.
+///
+/// A [`Camera`] maps world coordinates to screen coordinates. The world point
+/// at its `position` is shown at the center of its `viewport`, scaled by its
+/// `zoom` and rotated by its `rotation`.
+///
+/// Apply its [`transformation`] to a [`Target`] to draw in world coordinates:
+///
+/// ```
+/// # use coffee::graphics::{Camera, Frame, Point, Rectangle};
+/// #
+/// # fn draw(frame: &mut Frame<'_>) {
+/// let mut camera = Camera::new(Rectangle {
+///     x: 0.0,
+///     y: 0.0,
+///     width: frame.width(),
+///     height: frame.height(),
+/// });
+///
+/// camera.position = Point::new(1000.0, 500.0);
+/// camera.zoom = 2.0;
+///
+/// let mut target = frame.as_target();
+/// let mut world = target.transform(camera.transformation());
+///
+/// // Draw your world here...
+/// # }
+/// ```
+///
+/// The `viewport` only determines where the world is shown. A [`Camera`] does
+/// not prevent drawing outside of it.
+///
+/// [`Camera`]: struct.Camera.html
+/// [`transformation`]: #method.transformation
+/// [`Target`]: struct.Target.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Camera {
+    /// The world position shown at the center of the viewport.
+    pub position: Point,
+
+    /// The zoom level. A value greater than `1.0` zooms in.
+    pub zoom: f32,
+
+    /// The rotation of the camera, in radians.
+    pub rotation: f32,
+
+    /// The region of the screen where the world is shown, in pixels.
+    pub viewport: Rectangle<f32>,
+}
+
+impl Camera {
+    /// Creates a new [`Camera`] for the given viewport.
+    ///
+    /// The camera is initially centered on the viewport with no zoom nor
+    /// rotation. Therefore, world and screen coordinates match.
+    ///
+    /// [`Camera`]: struct.Camera.html
+    pub fn new(viewport: Rectangle<f32>) -> Camera {
+        Camera {
+            position: viewport.center(),
+            zoom: 1.0,
+            rotation: 0.0,
+            viewport,
+        }
+    }
+
+    /// Returns the [`Transformation`] that maps world coordinates to screen
+    /// coordinates.
+    ///
+    /// [`Transformation`]: struct.Transformation.html
+    pub fn transformation(&self) -> Transformation {
+        let center = self.viewport.center();
+
+        Transformation::translate(Vector::new(center.x, center.y))
+            * Transformation::scale(self.zoom)
+            * Transformation::rotate(-self.rotation)
+            * Transformation::translate(Vector::new(
+                -self.position.x,
+                -self.position.y,
+            ))
+    }
+
+    /// Converts a point in screen coordinates, like [`Mouse::cursor_position`],
+    /// to world coordinates.
+    ///
+    /// [`Mouse::cursor_position`]: ../input/struct.Mouse.html#method.cursor_position
+    pub fn screen_to_world(&self, point: Point) -> Point {
+        let center = self.viewport.center();
+        let (sin, cos) = self.rotation.sin_cos();
+
+        let x = (point.x - center.x) / self.zoom;
+        let y = (point.y - center.y) / self.zoom;
+
+        Point::new(
+            self.position.x + x * cos - y * sin,
+            self.position.y + x * sin + y * cos,
+        )
+    }
+
+    /// Converts a point in world coordinates to screen coordinates.
+    pub fn world_to_screen(&self, point: Point) -> Point {
+        let center = self.viewport.center();
+        let (sin, cos) = self.rotation.sin_cos();
+
+        let x = point.x - self.position.x;
+        let y = point.y - self.position.y;
+
+        Point::new(
+            center.x + (x * cos + y * sin) * self.zoom,
+            center.y + (y * cos - x * sin) * self.zoom,
+        )
+    }
+
+    /// Returns the smallest [`Rectangle`] in world coordinates that contains
+    /// everything shown in the viewport.
+    ///
+    /// You can use it to skip drawing anything outside of it.
+    ///
+    /// [`Rectangle`]: struct.Rectangle.html
+    pub fn visible_area(&self) -> Rectangle<f32> {
+        let viewport = self.viewport;
+
+        let corners = [
+            Point::new(viewport.x, viewport.y),
+            Point::new(viewport.x + viewport.width, viewport.y),
+            Point::new(viewport.x, viewport.y + viewport.height),
+            Point::new(
+                viewport.x + viewport.width,
+                viewport.y + viewport.height,
+            ),
+        ];
+
+        let first = self.screen_to_world(corners[0]);
+        let (mut min_x, mut min_y) = (first.x, first.y);
+        let (mut max_x, mut max_y) = (first.x, first.y);
+
+        for corner in &corners[1..] {
+            let point = self.screen_to_world(*corner);
+
+            min_x = min_x.min(point.x);
+            min_y = min_y.min(point.y);
+            max_x = max_x.max(point.x);
+            max_y = max_y.max(point.y);
+        }
+
+        Rectangle {
+            x: min_x,
+            y: min_y,
+            width: max_x - min_x,
+            height: max_y - min_y,
+        }
+    }
+}
@@ -1,13 +1,18 @@
 use crate::graphics::gpu::{self, texture, Gpu};
-use crate::graphics::{IntoQuad, Target};
+use crate::graphics::{IntoQuad, Shader, Target};
 use crate::load::Task;
-use crate::Result;
+use crate::{Error, Result};
 
 /// An off-screen rendering target.
 ///
 /// It can be used both as a [`Target`] and as a resource.
 ///
+/// A [`Canvas`] can be multisampled to smooth the edges of the meshes drawn
+/// on it. A multisampled [`Canvas`] is resolved automatically when drawn or
+/// read.
+///
 /// [`Target`]: struct.Target.html
+/// [`Canvas`]: struct.Canvas.html
 #[derive(Clone)]
 pub struct Canvas {
     drawable: texture::Drawable,
@@ -18,11 +23,48 @@ impl Canvas {
     ///
     /// [`Canvas`]: struct.Canvas.html
     pub fn new(gpu: &mut Gpu, width: u16, height: u16) -> Result<Canvas> {
+        Self::with_samples(gpu, width, height, 1)
+    }
+
+    /// Creates a new multisampled [`Canvas`] with the given size and number
+    /// of samples per pixel.
+    ///
+    /// The number of samples is rounded down to a power of two. Using `1`
+    /// sample is equivalent to calling [`new`].
+    ///
+    /// It returns [`Error::UnsupportedSamples`] if the [`Gpu`] does not
+    /// support that many samples.
+    ///
+    /// [`Canvas`]: struct.Canvas.html
+    /// [`new`]: #method.new
+    /// [`Error::UnsupportedSamples`]: ../enum.Error.html#variant.UnsupportedSamples
+    /// [`Gpu`]: struct.Gpu.html
+    pub fn with_samples(
+        gpu: &mut Gpu,
+        width: u16,
+        height: u16,
+        samples: u16,
+    ) -> Result<Canvas> {
+        let samples = 1 << (15 - samples.max(1).leading_zeros());
+        let supported = gpu.max_samples();
+
+        if samples > supported {
+            return Err(Error::UnsupportedSamples {
+                requested: samples,
+                supported,
+            });
+        }
+
         Ok(Canvas {
-            drawable: gpu.create_drawable_texture(width, height),
+            drawable: gpu.create_drawable_texture(width, height, samples),
         })
     }
 
+    #[cfg(feature = "software")]
+    pub(super) fn from_drawable(drawable: texture::Drawable) -> Canvas {
+        Canvas { drawable }
+    }
+
     /// Creates a [`Task`] that produces a new [`Canvas`] with the given size.
     ///
     /// [`Task`]: ../load/struct.Task.html
@@ -31,6 +73,21 @@ impl Canvas {
         Task::using_gpu(move |gpu| Canvas::new(gpu, width, height))
     }
 
+    /// Creates a [`Task`] that produces a new multisampled [`Canvas`] with
+    /// the given size and number of samples per pixel.
+    ///
+    /// [`Task`]: ../load/struct.Task.html
+    /// [`Canvas`]: struct.Canvas.html
+    pub fn load_with_samples(
+        width: u16,
+        height: u16,
+        samples: u16,
+    ) -> Task<Canvas> {
+        Task::using_gpu(move |gpu| {
+            Canvas::with_samples(gpu, width, height, samples)
+        })
+    }
+
     /// Returns the width of the [`Canvas`].
     ///
     /// [`Canvas`]: struct.Canvas.html
@@ -45,6 +102,13 @@ impl Canvas {
         self.drawable.texture().height()
     }
 
+    /// Returns the number of samples per pixel of the [`Canvas`].
+    ///
+    /// [`Canvas`]: struct.Canvas.html
+    pub fn samples(&self) -> u16 {
+        self.drawable.samples()
+    }
+
     /// Views the [`Canvas`] as a [`Target`].
     ///
     /// [`Canvas`]: struct.Canvas.html
@@ -66,13 +130,28 @@ impl Canvas {
     /// [`Canvas`]: struct.Canvas.html
     /// [`Target`]: struct.Target.html
     pub fn draw<Q: IntoQuad>(&self, quad: Q, target: &mut Target<'_>) {
-        target.draw_texture_quads(
-            &self.drawable.texture(),
-            &[gpu::Quad::from(quad.into_quad(
-                1.0 / self.width() as f32,
-                1.0 / self.height() as f32,
-            ))],
-        );
+        self.draw_quad(quad, target, None);
+    }
+
+    /// Renders the [`Canvas`] on the given [`Target`] using a custom
+    /// [`Shader`].
+    ///
+    /// This is useful to apply post-processing effects to a scene drawn on
+    /// the [`Canvas`].
+    ///
+    /// # Panics
+    /// It panics if the [`Shader`] was not created for quads.
+    ///
+    /// [`Canvas`]: struct.Canvas.html
+    /// [`Target`]: struct.Target.html
+    /// [`Shader`]: struct.Shader.html
+    pub fn draw_with_shader<Q: IntoQuad>(
+        &self,
+        quad: Q,
+        shader: &Shader,
+        target: &mut Target<'_>,
+    ) {
+        self.draw_quad(quad, target, Some(shader));
     }
 
     /// Reads the pixels of the [`Canvas`].
@@ -81,17 +160,36 @@ impl Canvas {
     ///
     /// [`Canvas`]: struct.Canvas.html
     pub fn read_pixels(&self, gpu: &mut Gpu) -> image::DynamicImage {
+        gpu.resolve_drawable(&self.drawable);
         gpu.read_drawable_texture_pixels(&self.drawable)
     }
+
+    fn draw_quad<Q: IntoQuad>(
+        &self,
+        quad: Q,
+        target: &mut Target<'_>,
+        shader: Option<&Shader>,
+    ) {
+        target.resolve(&self.drawable);
+        target.draw_texture_quads(
+            &self.drawable.texture(),
+            &[gpu::Quad::from(quad.into_quad(
+                1.0 / self.width() as f32,
+                1.0 / self.height() as f32,
+            ))],
+            shader,
+        );
+    }
 }
 
 impl std::fmt::Debug for Canvas {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "Canvas {{ width: {}, height: {} }}",
+            "Canvas {{ width: {}, height: {}, samples: {} }}",
             self.width(),
-            self.height()
+            self.height(),
+            self.samples()
         )
     }
 }
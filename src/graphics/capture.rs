@@ -0,0 +1,203 @@
+//! Capture the frames of your game as images.
+use std::fs::{self, File};
+use std::io::BufWriter;
+use std::path::PathBuf;
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+
+use gif::SetParameter;
+
+use crate::graphics::Frame;
+use crate::Result;
+
+/// The settings of a frame capture.
+///
+/// A capture is started and stopped by pressing the [`Game::CAPTURE_KEY`].
+/// Every capture is saved inside the [`directory`] with a name based on the
+/// time it was started.
+///
+/// [`Game::CAPTURE_KEY`]: ../../trait.Game.html#associatedconstant.CAPTURE_KEY
+/// [`directory`]: #structfield.directory
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Settings {
+    /// The format of the capture.
+    pub format: Format,
+
+    /// The directory where captures are saved.
+    ///
+    /// It is created if it does not exist.
+    pub directory: PathBuf,
+
+    /// The amount of frames between captured frames.
+    ///
+    /// For instance, `1` captures every frame and `3` captures one out of
+    /// three. Capturing a frame is slow, so capturing every frame may lower
+    /// the frame rate of your game considerably.
+    pub interval: u16,
+}
+
+impl Default for Settings {
+    fn default() -> Settings {
+        Settings {
+            format: Format::Png,
+            directory: PathBuf::from("captures"),
+            interval: 2,
+        }
+    }
+}
+
+/// The file format of a capture.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// A sequence of numbered PNG images, saved in their own directory.
+    Png,
+
+    /// An animated GIF.
+    ///
+    /// The delay of every frame matches the time that actually passed
+    /// between captures. Colors are quantized to a palette of 256 colors.
+    Gif,
+}
+
+/// A running capture.
+pub(crate) struct Recorder {
+    output: Output,
+    interval: u16,
+    frames: u32,
+    last_capture: Instant,
+}
+
+enum Output {
+    Png { directory: PathBuf, saved: u32 },
+    Gif { path: PathBuf, gif: Option<Gif> },
+}
+
+struct Gif {
+    encoder: gif::Encoder<BufWriter<File>>,
+    width: u16,
+    height: u16,
+    pending: Option<gif::Frame<'static>>,
+    delay: u16,
+}
+
+impl Recorder {
+    pub(crate) fn new(settings: Settings) -> Result<Recorder> {
+        let name = format!(
+            "capture-{}",
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|duration| duration.as_secs())
+                .unwrap_or(0)
+        );
+
+        fs::create_dir_all(&settings.directory)?;
+
+        let output = match settings.format {
+            Format::Png => {
+                let directory = settings.directory.join(name);
+                fs::create_dir(&directory)?;
+
+                Output::Png {
+                    directory,
+                    saved: 0,
+                }
+            }
+            Format::Gif => Output::Gif {
+                path: settings.directory.join(name + ".gif"),
+                gif: None,
+            },
+        };
+
+        Ok(Recorder {
+            output,
+            interval: settings.interval.max(1),
+            frames: 0,
+            last_capture: Instant::now(),
+        })
+    }
+
+    pub(crate) fn capture(&mut self, frame: &mut Frame<'_>) -> Result<()> {
+        let is_due = self.frames % u32::from(self.interval) == 0;
+        self.frames += 1;
+
+        if !is_due {
+            return Ok(());
+        }
+
+        let now = Instant::now();
+        let elapsed = now - self.last_capture;
+        self.last_capture = now;
+
+        let screenshot = frame.screenshot().to_rgba();
+
+        match &mut self.output {
+            Output::Png { directory, saved } => {
+                screenshot.save(directory.join(format!("{:06}.png", saved)))?;
+                *saved += 1;
+            }
+            Output::Gif { path, gif } => {
+                let width = screenshot.width() as u16;
+                let height = screenshot.height() as u16;
+
+                if gif.is_none() {
+                    let file = BufWriter::new(File::create(&path)?);
+                    let mut encoder =
+                        gif::Encoder::new(file, width, height, &[])?;
+                    encoder.set(gif::Repeat::Infinite)?;
+
+                    *gif = Some(Gif {
+                        encoder,
+                        width,
+                        height,
+                        pending: None,
+                        delay: 0,
+                    });
+                }
+
+                if let Some(gif) = gif {
+                    // GIF frames cannot be bigger than the first one, so we
+                    // skip frames after a resize
+                    if gif.width == width && gif.height == height {
+                        let mut pixels = screenshot.into_raw();
+
+                        gif.delay = (elapsed.as_millis() / 10) as u16;
+                        gif.write_pending()?;
+                        gif.pending = Some(gif::Frame::from_rgba(
+                            width,
+                            height,
+                            &mut pixels,
+                        ));
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn finish(&mut self) -> Result<()> {
+        if let Output::Gif { gif: Some(gif), .. } = &mut self.output {
+            gif.write_pending()?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Drop for Recorder {
+    fn drop(&mut self) {
+        let _ = self.finish();
+    }
+}
+
+impl Gif {
+    /// Writes the last captured frame, which is only known to be finished
+    /// once the next one is captured.
+    fn write_pending(&mut self) -> Result<()> {
+        if let Some(mut frame) = self.pending.take() {
+            frame.delay = self.delay;
+            self.encoder.write_frame(&frame)?;
+        }
+
+        Ok(())
+    }
+}
@@ -1,5 +1,7 @@
+use serde::{Deserialize, Serialize};
+
 /// An RGBA color in the sRGB color space.
-#[derive(PartialEq, Clone, Copy, Debug)]
+#[derive(PartialEq, Clone, Copy, Debug, Serialize, Deserialize)]
 pub struct Color {
     /// Red component.
     pub r: f32,
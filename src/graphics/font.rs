@@ -1,21 +1,62 @@
+mod system;
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
 use crate::graphics::gpu;
-use crate::graphics::{Gpu, Target, Text};
+use crate::graphics::{
+    Color, FontStyle, GlyphInfo, Gpu, HorizontalAlignment, Point, Rectangle,
+    RichText, Target, VerticalAlignment,
+};
 use crate::load::Task;
 use crate::Result;
 
 /// A collection of text with the same font.
+///
+/// A [`Font`] can also contain a typeface for every [`FontStyle`], which is
+/// used by the [`Span`]s of a [`RichText`] with that style, and a chain of
+/// fallback typefaces, which are used to draw the characters that the other
+/// typefaces lack.
+///
+/// [`Font`]: struct.Font.html
+/// [`FontStyle`]: enum.FontStyle.html
+/// [`Span`]: struct.Span.html
+/// [`RichText`]: struct.RichText.html
 #[allow(missing_debug_implementations)]
-pub struct Font(gpu::Font);
+pub struct Font {
+    raw: gpu::Font,
+    styles: HashMap<FontStyle, usize>,
+    fallbacks: Vec<usize>,
+}
 
 impl Font {
     pub(crate) const DEFAULT: &'static [u8] =
         include_bytes!("../../resources/font/Inconsolata-Regular.ttf");
 
+    /// Loads a [`Font`] from the given path.
+    ///
+    /// [`Font`]: struct.Font.html
+    pub fn new<P: AsRef<Path>>(gpu: &mut Gpu, path: P) -> Result<Font> {
+        Font::from_vec(gpu, fs::read(path)?)
+    }
+
+    /// Creates a [`Task`] that loads a [`Font`] from the given path.
+    ///
+    /// [`Task`]: ../load/struct.Task.html
+    /// [`Font`]: struct.Font.html
+    pub fn load<P: Into<PathBuf>>(path: P) -> Task<Font> {
+        let p = path.into();
+
+        Task::using_gpu(move |gpu| Font::new(gpu, &p))
+    }
+
     /// Loads a [`Font`] from raw data.
     ///
     /// [`Font`]: struct.Font.html
     pub fn from_bytes(gpu: &mut Gpu, bytes: &'static [u8]) -> Result<Font> {
-        Ok(Font(gpu.upload_font(bytes)))
+        Font::upload(gpu, Cow::Borrowed(bytes))
     }
 
     /// Creates a [`Task`] that loads a [`Font`] from raw data.
@@ -26,19 +67,174 @@ impl Font {
         Task::using_gpu(move |gpu| Font::from_bytes(gpu, bytes))
     }
 
-    /// Adds [`Text`] to this [`Font`].
+    /// Loads a [`Font`] from raw data owned at runtime.
+    ///
+    /// Use this when the data is not embedded in your binary; for instance,
+    /// when it is downloaded or read from an archive.
+    ///
+    /// [`Font`]: struct.Font.html
+    pub fn from_vec(gpu: &mut Gpu, bytes: Vec<u8>) -> Result<Font> {
+        Font::upload(gpu, Cow::Owned(bytes))
+    }
+
+    /// Loads a [`Font`] installed in the system, given a list of font
+    /// families in order of preference.
+    ///
+    /// The first family found is loaded. Families are matched against the
+    /// names of the TrueType and OpenType files in the usual font directories
+    /// of the operating system. Only the first font of a collection is used.
+    ///
+    /// [`Font`]: struct.Font.html
+    pub fn from_system(gpu: &mut Gpu, families: &[&str]) -> Result<Font> {
+        Font::from_vec(gpu, system::load(families)?)
+    }
+
+    /// Creates a [`Task`] that loads a [`Font`] installed in the system.
+    ///
+    /// See [`Font::from_system`] for more details.
+    ///
+    /// [`Task`]: ../load/struct.Task.html
+    /// [`Font`]: struct.Font.html
+    /// [`Font::from_system`]: #method.from_system
+    pub fn load_from_system(families: &[&str]) -> Task<Font> {
+        let families: Vec<String> =
+            families.iter().map(|family| family.to_string()).collect();
+
+        Task::using_gpu(move |gpu| {
+            let families: Vec<&str> =
+                families.iter().map(String::as_str).collect();
+
+            Font::from_system(gpu, &families)
+        })
+    }
+
+    /// Adds a typeface to this [`Font`] from raw data, which will be used to
+    /// draw the [`Span`]s with the given [`FontStyle`].
+    ///
+    /// Styles without a typeface are drawn with the regular one.
+    ///
+    /// [`Font`]: struct.Font.html
+    /// [`Span`]: struct.Span.html
+    /// [`FontStyle`]: enum.FontStyle.html
+    pub fn add_style<B: Into<Cow<'static, [u8]>>>(
+        &mut self,
+        style: FontStyle,
+        bytes: B,
+    ) -> Result<()> {
+        let typeface = self.raw.add_typeface(bytes.into())?;
+        let _ = self.styles.insert(style, typeface);
+
+        Ok(())
+    }
+
+    /// Adds a fallback typeface to this [`Font`] from raw data.
+    ///
+    /// Characters missing in the typeface of a [`Span`] are drawn with the
+    /// first fallback that contains them, in the order they were added. This
+    /// is useful to draw CJK text or symbols with a font that lacks them.
+    ///
+    /// [`Font`]: struct.Font.html
+    /// [`Span`]: struct.Span.html
+    pub fn add_fallback<B: Into<Cow<'static, [u8]>>>(
+        &mut self,
+        bytes: B,
+    ) -> Result<()> {
+        let typeface = self.raw.add_typeface(bytes.into())?;
+        self.fallbacks.push(typeface);
+
+        Ok(())
+    }
+
+    /// Adds a fallback typeface to this [`Font`] from a font installed in the
+    /// system, given a list of font families in order of preference.
+    ///
+    /// See [`Font::from_system`] and [`Font::add_fallback`] for more details.
+    ///
+    /// [`Font`]: struct.Font.html
+    /// [`Font::from_system`]: #method.from_system
+    /// [`Font::add_fallback`]: #method.add_fallback
+    pub fn add_system_fallback(&mut self, families: &[&str]) -> Result<()> {
+        self.add_fallback(system::load(families)?)
+    }
+
+    /// Adds [`Text`] or [`RichText`] to this [`Font`].
     ///
     /// [`Text`]: struct.Text.html
+    /// [`RichText`]: struct.RichText.html
     /// [`Font`]: struct.Font.html
-    pub fn add(&mut self, text: Text<'_>) {
-        self.0.add(text)
+    pub fn add<'a, T: Into<RichText<'a>>>(&mut self, text: T) {
+        let section = self.section(text.into());
+
+        self.raw.add(section)
     }
 
-    /// Computes the layout bounds of the given [`Text`].
+    /// Computes the layout bounds of the given [`Text`] or [`RichText`].
     ///
     /// [`Text`]: struct.Text.html
-    pub fn measure(&mut self, text: Text<'_>) -> (f32, f32) {
-        self.0.measure(text)
+    /// [`RichText`]: struct.RichText.html
+    pub fn measure<'a, T: Into<RichText<'a>>>(
+        &mut self,
+        text: T,
+    ) -> (f32, f32) {
+        let section = self.section(text.into());
+
+        self.raw.measure(section)
+    }
+
+    /// Computes the positioned glyphs of the given [`Text`] or [`RichText`].
+    ///
+    /// Control characters, like line breaks, do not have a glyph.
+    ///
+    /// [`Text`]: struct.Text.html
+    /// [`RichText`]: struct.RichText.html
+    pub fn layout<'a, T: Into<RichText<'a>>>(
+        &mut self,
+        text: T,
+    ) -> Vec<GlyphInfo> {
+        let text = text.into();
+        let characters = characters(&text);
+        let section = self.section(text);
+        let glyphs = self.raw.layout(section);
+
+        let mut line = 0;
+        let mut baseline = None;
+
+        characters
+            .into_iter()
+            .zip(glyphs)
+            .map(|((span, index), glyph)| {
+                let previous = baseline.replace(glyph.baseline);
+
+                if previous.map_or(false, |y| glyph.baseline > y) {
+                    line += 1;
+                }
+
+                GlyphInfo {
+                    span,
+                    index,
+                    bounds: glyph.bounds,
+                    line,
+                }
+            })
+            .collect()
+    }
+
+    /// Finds the character of the given [`Text`] or [`RichText`] whose glyph
+    /// contains the given [`Point`], and returns its byte index.
+    ///
+    /// See [`GlyphInfo::index`] to learn how the byte index of a [`RichText`]
+    /// is computed.
+    ///
+    /// [`Text`]: struct.Text.html
+    /// [`RichText`]: struct.RichText.html
+    /// [`Point`]: type.Point.html
+    /// [`GlyphInfo::index`]: struct.GlyphInfo.html#structfield.index
+    pub fn hit_test<'a, T: Into<RichText<'a>>>(
+        &mut self,
+        text: T,
+        point: Point,
+    ) -> Option<usize> {
+        GlyphInfo::hit_test(&self.layout(text), point)
     }
 
     /// Renders and flushes all the text added to this [`Font`].
@@ -46,6 +242,139 @@ impl Font {
     /// [`Font`]: struct.Font.html
     #[inline]
     pub fn draw(&mut self, target: &mut Target<'_>) {
-        target.draw_font(&mut self.0)
+        target.draw_font(&mut self.raw)
+    }
+
+    fn upload(gpu: &mut Gpu, bytes: Cow<'static, [u8]>) -> Result<Font> {
+        Ok(Font {
+            raw: gpu.upload_font(bytes)?,
+            styles: HashMap::new(),
+            fallbacks: Vec::new(),
+        })
+    }
+
+    /// Splits the spans of some [`RichText`] into runs of characters that
+    /// share the same typeface.
+    ///
+    /// [`RichText`]: struct.RichText.html
+    fn section<'a>(&self, text: RichText<'a>) -> Section<'a> {
+        let mut runs = Vec::with_capacity(text.spans.len());
+
+        for span in &text.spans {
+            let primary = self
+                .styles
+                .get(&span.style)
+                .or_else(|| self.styles.get(&FontStyle::Regular))
+                .cloned()
+                .unwrap_or(0);
+
+            let run = |content, typeface| Run {
+                content,
+                size: span.size,
+                color: span.color,
+                typeface,
+            };
+
+            if self.fallbacks.is_empty() {
+                runs.push(run(span.content, primary));
+                continue;
+            }
+
+            let mut start = 0;
+            let mut current = primary;
+
+            for (i, character) in span.content.char_indices() {
+                let typeface = if character.is_whitespace() {
+                    current
+                } else {
+                    std::iter::once(primary)
+                        .chain(self.fallbacks.iter().cloned())
+                        .find(|&typeface| {
+                            self.raw.has_glyph(typeface, character)
+                        })
+                        .unwrap_or(primary)
+                };
+
+                if typeface != current {
+                    if i > start {
+                        runs.push(run(&span.content[start..i], current));
+                    }
+
+                    start = i;
+                    current = typeface;
+                }
+            }
+
+            runs.push(run(&span.content[start..], current));
+        }
+
+        Section {
+            runs,
+            position: text.position,
+            bounds: text.bounds,
+            horizontal_alignment: text.horizontal_alignment,
+            vertical_alignment: text.vertical_alignment,
+        }
     }
 }
+
+/// Lists the span and byte index of the characters of some [`RichText`] that
+/// have a glyph.
+///
+/// The glyph brushes lay out a glyph for every character that is not a
+/// control character, in order.
+///
+/// [`RichText`]: struct.RichText.html
+pub(super) fn characters(text: &RichText<'_>) -> Vec<(usize, usize)> {
+    let mut characters = Vec::new();
+    let mut start = 0;
+
+    for (i, span) in text.spans.iter().enumerate() {
+        characters.extend(
+            span.content
+                .char_indices()
+                .filter(|(_, character)| !character.is_control())
+                .map(|(index, _)| (i, start + index)),
+        );
+
+        start += span.content.len();
+    }
+
+    characters
+}
+
+/// Some [`RichText`] with its typefaces resolved, as needed by the graphics
+/// backends.
+///
+/// [`RichText`]: struct.RichText.html
+#[derive(Debug, Clone, PartialEq)]
+pub(super) struct Section<'a> {
+    pub(super) runs: Vec<Run<'a>>,
+    pub(super) position: Point,
+    pub(super) bounds: (f32, f32),
+    pub(super) horizontal_alignment: HorizontalAlignment,
+    pub(super) vertical_alignment: VerticalAlignment,
+}
+
+/// A piece of a [`Section`] drawn with a single typeface.
+///
+/// [`Section`]: struct.Section.html
+#[derive(Debug, Clone, PartialEq)]
+pub(super) struct Run<'a> {
+    pub(super) content: &'a str,
+    pub(super) size: f32,
+    pub(super) color: Color,
+
+    /// The index of the typeface in the backend font, in the order they were
+    /// added. The regular typeface is always `0`.
+    pub(super) typeface: usize,
+}
+
+/// A glyph laid out by a graphics backend.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(super) struct Glyph {
+    pub(super) bounds: Rectangle<f32>,
+
+    /// The vertical position of the baseline of the line of the glyph.
+    pub(super) baseline: f32,
+}
@@ -0,0 +1,177 @@
+//! Look up fonts installed in the operating system.
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::{Error, Result};
+
+/// The extensions of the font files we can read.
+const EXTENSIONS: [&str; 3] = ["ttf", "otf", "ttc"];
+
+/// Reads the regular font file of the first font family found.
+pub(super) fn load(families: &[&str]) -> Result<Vec<u8>> {
+    let files = files();
+
+    let path = families
+        .iter()
+        .filter_map(|family| find(&files, family))
+        .next()
+        .ok_or_else(|| {
+            Error::Font(format!("No system font found for {:?}", families))
+        })?;
+
+    let mut bytes = fs::read(path)?;
+    first_face(&mut bytes);
+
+    Ok(bytes)
+}
+
+/// Finds the file that best matches a font family.
+///
+/// Font files are usually named after their family and style, like
+/// `DejaVuSans-Bold.ttf`. We ignore case, spaces, dashes, and underscores.
+/// Files of the exact family come first, so `DejaVuSans-Bold.ttf` is chosen
+/// over `DejaVuSansMono.ttf` for `DejaVu Sans`. Then, we prefer the regular
+/// style over the others.
+fn find<'a>(files: &'a [PathBuf], family: &str) -> Option<&'a PathBuf> {
+    let family = normalize(family);
+
+    if family.is_empty() {
+        return None;
+    }
+
+    files
+        .iter()
+        .filter_map(|path| {
+            let stem = path.file_stem()?.to_string_lossy();
+
+            // The style usually follows the last dash
+            let (name, style) = match stem.rfind('-') {
+                Some(index) => (&stem[..index], &stem[index + 1..]),
+                None => (&stem[..], ""),
+            };
+
+            let (is_exact, style) = if normalize(name) == family {
+                (true, normalize(style))
+            } else {
+                let name = normalize(&stem);
+
+                if !name.starts_with(&family) {
+                    return None;
+                }
+
+                (false, String::from(&name[family.len()..]))
+            };
+
+            let is_regular = style.is_empty() || style == "regular";
+
+            Some(((!is_exact, !is_regular), path))
+        })
+        .min()
+        .map(|(_, path)| path)
+}
+
+/// Keeps only the first font of a TrueType collection, if the given font data
+/// is one.
+///
+/// Collections simply list the offsets of their fonts in their header, so we
+/// change the amount of fonts to one.
+fn first_face(bytes: &mut [u8]) {
+    if bytes.len() >= 12 && bytes.starts_with(b"ttcf") {
+        bytes[8..12].copy_from_slice(&1u32.to_be_bytes());
+    }
+}
+
+fn normalize(name: &str) -> String {
+    name.chars()
+        .filter(|c| !c.is_whitespace() && *c != '-' && *c != '_')
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Lists the font files in the font directories of the system.
+fn files() -> Vec<PathBuf> {
+    let mut files = Vec::new();
+
+    for directory in directories() {
+        visit(&directory, 0, &mut files);
+    }
+
+    files.sort();
+    files
+}
+
+fn visit(directory: &Path, depth: usize, files: &mut Vec<PathBuf>) {
+    // Font directories are shallow, this avoids following symlink cycles
+    const MAX_DEPTH: usize = 5;
+
+    let entries = match fs::read_dir(directory) {
+        Ok(entries) => entries,
+        Err(_) => return,
+    };
+
+    for entry in entries.filter_map(|entry| entry.ok()) {
+        let path = entry.path();
+
+        if path.is_dir() {
+            if depth < MAX_DEPTH {
+                visit(&path, depth + 1, files);
+            }
+        } else if path.extension().map_or(false, |extension| {
+            let extension = extension.to_string_lossy();
+
+            EXTENSIONS
+                .iter()
+                .any(|candidate| extension.eq_ignore_ascii_case(candidate))
+        }) {
+            files.push(path);
+        }
+    }
+}
+
+fn directories() -> Vec<PathBuf> {
+    let home = env::var_os("HOME").map(PathBuf::from);
+
+    if cfg!(target_os = "windows") {
+        let mut directories = Vec::new();
+
+        if let Some(windows) = env::var_os("WINDIR") {
+            directories.push(PathBuf::from(windows).join("Fonts"));
+        }
+
+        if let Some(local) = env::var_os("LOCALAPPDATA") {
+            directories
+                .push(PathBuf::from(local).join("Microsoft/Windows/Fonts"));
+        }
+
+        directories
+    } else if cfg!(target_os = "macos") {
+        let mut directories = vec![
+            PathBuf::from("/System/Library/Fonts"),
+            PathBuf::from("/Library/Fonts"),
+        ];
+
+        if let Some(home) = home {
+            directories.push(home.join("Library/Fonts"));
+        }
+
+        directories
+    } else {
+        let mut directories = vec![
+            PathBuf::from("/usr/share/fonts"),
+            PathBuf::from("/usr/local/share/fonts"),
+        ];
+
+        if let Some(data) = env::var_os("XDG_DATA_HOME") {
+            directories.push(PathBuf::from(data).join("fonts"));
+        } else if let Some(home) = &home {
+            directories.push(home.join(".local/share/fonts"));
+        }
+
+        if let Some(home) = home {
+            directories.push(home.join(".fonts"));
+        }
+
+        directories
+    }
+}
@@ -59,6 +59,22 @@ impl Image {
         Ok(Image { texture })
     }
 
+    /// Replaces part of the [`Image`] with the given pixels, placing their
+    /// top-left corner at the given position.
+    ///
+    /// Every clone of the [`Image`] sees the change.
+    ///
+    /// [`Image`]: struct.Image.html
+    pub(super) fn update(
+        &self,
+        gpu: &mut Gpu,
+        x: u16,
+        y: u16,
+        pixels: &image::RgbaImage,
+    ) {
+        gpu.update_texture(&self.texture, x, y, pixels);
+    }
+
     /// Creates an [`Image`] representing a color palette.
     ///
     /// Each [`Color`] will be a pixel of the image, arranged horizontally.
@@ -98,8 +114,13 @@ impl Image {
 
     /// Draws the [`Image`] on the given [`Target`].
     ///
+    /// The [`Image`] is drawn using the [`BlendMode`] of the [`Target`]. You
+    /// can change it with [`Target::blend`].
+    ///
     /// [`Image`]: struct.Image.html
     /// [`Target`]: struct.Target.html
+    /// [`BlendMode`]: enum.BlendMode.html
+    /// [`Target::blend`]: struct.Target.html#method.blend
     #[inline]
     pub fn draw<Q: IntoQuad>(&self, quad: Q, target: &mut Target<'_>) {
         target.draw_texture_quads(
@@ -108,6 +129,7 @@ impl Image {
                 1.0 / self.width() as f32,
                 1.0 / self.height() as f32,
             ))],
+            None,
         );
     }
 }
@@ -1,4 +1,6 @@
-use crate::graphics::{gpu, Color, Rectangle, Shape, Target};
+use crate::graphics::{
+    gpu, BlendMode, Color, Rectangle, Shader, Shape, Target,
+};
 
 use lyon_tessellation as lyon;
 
@@ -19,6 +21,7 @@ use lyon_tessellation as lyon;
 pub struct Mesh {
     tolerance: f32,
     buffers: lyon::VertexBuffers<gpu::Vertex, u32>,
+    blend_mode: Option<BlendMode>,
 }
 
 impl Mesh {
@@ -29,6 +32,7 @@ impl Mesh {
         Mesh {
             tolerance: 0.1,
             buffers: lyon::VertexBuffers::new(),
+            blend_mode: None,
         }
     }
 
@@ -44,6 +48,7 @@ impl Mesh {
         Mesh {
             tolerance,
             buffers: lyon::VertexBuffers::new(),
+            blend_mode: None,
         }
     }
 
@@ -180,12 +185,52 @@ impl Mesh {
         }
     }
 
+    /// Sets the [`BlendMode`] used to draw the [`Mesh`].
+    ///
+    /// By default, a [`Mesh`] uses the [`BlendMode`] of the [`Target`] it is
+    /// drawn on.
+    ///
+    /// [`BlendMode`]: enum.BlendMode.html
+    /// [`Mesh`]: struct.Mesh.html
+    /// [`Target`]: struct.Target.html
+    pub fn set_blend_mode(&mut self, blend_mode: BlendMode) {
+        self.blend_mode = Some(blend_mode);
+    }
+
     /// Draws the [`Mesh`] on the given [`Target`].
     ///
     /// [`Mesh`]: struct.Mesh.html
     /// [`Target`]: struct.Target.html
     pub fn draw(&self, target: &mut Target<'_>) {
-        target.draw_triangles(&self.buffers.vertices, &self.buffers.indices);
+        self.draw_triangles(target, None);
+    }
+
+    /// Draws the [`Mesh`] on the given [`Target`] using a custom [`Shader`].
+    ///
+    /// # Panics
+    /// It panics if the [`Shader`] was not created for meshes.
+    ///
+    /// [`Mesh`]: struct.Mesh.html
+    /// [`Target`]: struct.Target.html
+    /// [`Shader`]: struct.Shader.html
+    pub fn draw_with_shader(&self, shader: &Shader, target: &mut Target<'_>) {
+        self.draw_triangles(target, Some(shader));
+    }
+
+    pub(super) fn buffers(&self) -> (&[gpu::Vertex], &[u32]) {
+        (&self.buffers.vertices, &self.buffers.indices)
+    }
+
+    fn draw_triangles(&self, target: &mut Target<'_>, shader: Option<&Shader>) {
+        let vertices = &self.buffers.vertices;
+        let indices = &self.buffers.indices;
+
+        match self.blend_mode {
+            Some(blend_mode) => target
+                .blend(blend_mode)
+                .draw_triangles(vertices, indices, shader),
+            None => target.draw_triangles(vertices, indices, shader),
+        }
     }
 
     fn fill_options(tolerance: f32) -> lyon::FillOptions {
@@ -1,3 +1,4 @@
+use crate::graphics::color::Color;
 use crate::graphics::point::Point;
 use crate::graphics::rectangle::Rectangle;
 
@@ -8,11 +9,67 @@ pub struct Quad {
     /// coordinates: [0.0, 1.0].
     pub source: Rectangle<f32>,
 
-    /// The position where the quad should be drawn.
+    /// The position where the origin of the quad should be drawn.
     pub position: Point,
 
     /// The size of the quad.
     pub size: (f32, f32),
+
+    /// The rotation of the quad around its origin, in radians.
+    pub rotation: f32,
+
+    /// The origin of the quad, relative to its size.
+    ///
+    /// `(0.0, 0.0)` is the top-left corner and `(0.5, 0.5)` is the center.
+    pub origin: Point,
+
+    /// Whether the quad should be flipped horizontally.
+    pub flip_x: bool,
+
+    /// Whether the quad should be flipped vertically.
+    pub flip_y: bool,
+
+    /// The color the resource is multiplied by. Use it to tint or fade the
+    /// quad.
+    pub tint: Color,
+}
+
+impl Quad {
+    /// Returns the `source` of the quad with its flips applied, as
+    /// `[x, y, width, height]`.
+    pub(crate) fn flipped_source(&self) -> [f32; 4] {
+        let Rectangle {
+            mut x,
+            mut y,
+            mut width,
+            mut height,
+        } = self.source;
+
+        if self.flip_x {
+            x += width;
+            width = -width;
+        }
+
+        if self.flip_y {
+            y += height;
+            height = -height;
+        }
+
+        [x, y, width, height]
+    }
+
+    /// Returns the position of the top-left corner of the quad, once rotated
+    /// around its origin.
+    pub(crate) fn top_left(&self) -> [f32; 2] {
+        let (sin, cos) = self.rotation.sin_cos();
+        let x = self.origin.x * self.size.0;
+        let y = self.origin.y * self.size.1;
+
+        [
+            self.position.x - (x * cos - y * sin),
+            self.position.y - (x * sin + y * cos),
+        ]
+    }
 }
 
 impl Default for Quad {
@@ -26,6 +83,11 @@ impl Default for Quad {
             },
             position: Point::new(0.0, 0.0),
             size: (1.0, 1.0),
+            rotation: 0.0,
+            origin: Point::new(0.0, 0.0),
+            flip_x: false,
+            flip_y: false,
+            tint: Color::WHITE,
         }
     }
 }
@@ -1,7 +1,9 @@
+use serde::{Deserialize, Serialize};
+
 use crate::graphics::Point;
 
 /// A generic rectangle.
-#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Serialize, Deserialize)]
 pub struct Rectangle<T> {
     /// X coordinate of the top-left corner.
     pub x: T,
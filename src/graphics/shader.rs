@@ -0,0 +1,170 @@
+use std::path::PathBuf;
+
+use crate::graphics::gpu;
+use crate::graphics::Gpu;
+use crate::load::Task;
+use crate::Result;
+
+/// A custom fragment shader.
+///
+/// A [`Shader`] replaces the fragment stage used to draw a [`Batch`], a
+/// [`Canvas`], or a [`Mesh`]. You can use it to implement effects like CRT
+/// filters, outlines, or palette swaps.
+///
+/// The source of a [`Shader`] depends on the graphics backend:
+///   * The `opengl` backend expects GLSL (`#version 150 core`).
+///   * The `vulkan`, `metal`, `dx11`, and `dx12` backends expect SPIR-V.
+///   * The `software` backend cannot run shaders. It ignores them and draws
+///     as usual.
+///
+/// # Quad shaders
+/// A [`Shader`] created with [`for_quads`] receives the same inputs as the
+/// default quad shader. In GLSL:
+///
+/// ```glsl
+/// #version 150 core
+///
+/// uniform sampler2DArray t_Texture;
+/// flat in uint v_Layer;
+/// in vec2 v_Uv;
+/// in vec4 v_Tint;
+///
+/// layout (std140) uniform Uniforms {
+///     vec4 u_Uniforms[16];
+/// };
+///
+/// out vec4 Target0;
+/// ```
+///
+/// In SPIR-V, `v_Uv`, `v_Layer`, and `v_Tint` use the locations `0`, `1`,
+/// and `2`. The sampler is bound at set `0`, binding `1`, the texture at set
+/// `1`, binding `0`, and the `Uniforms` block at set `2`, binding `0`.
+///
+/// # Mesh shaders
+/// A [`Shader`] created with [`for_meshes`] receives the color of the
+/// vertices. In GLSL:
+///
+/// ```glsl
+/// #version 150 core
+///
+/// in vec4 v_Color;
+///
+/// layout (std140) uniform Uniforms {
+///     vec4 u_Uniforms[16];
+/// };
+///
+/// out vec4 Target0;
+/// ```
+///
+/// In SPIR-V, `v_Color` uses the location `0` and the `Uniforms` block is
+/// bound at set `1`, binding `0`.
+///
+/// # Uniforms
+/// Every [`Shader`] has [`Shader::MAX_UNIFORMS`] uniforms of type `vec4`,
+/// initially zero. You can change them with [`set_uniform`].
+///
+/// [`Shader`]: struct.Shader.html
+/// [`Batch`]: struct.Batch.html
+/// [`Canvas`]: struct.Canvas.html
+/// [`Mesh`]: struct.Mesh.html
+/// [`for_quads`]: #method.for_quads
+/// [`for_meshes`]: #method.for_meshes
+/// [`Shader::MAX_UNIFORMS`]: #associatedconstant.MAX_UNIFORMS
+/// [`set_uniform`]: #method.set_uniform
+pub struct Shader {
+    raw: gpu::Shader,
+    kind: Kind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Kind {
+    Quads,
+    Meshes,
+}
+
+impl Shader {
+    /// The amount of uniforms of a [`Shader`].
+    ///
+    /// [`Shader`]: struct.Shader.html
+    pub const MAX_UNIFORMS: usize = 16;
+
+    /// Creates a [`Shader`] that draws quads from the given source.
+    ///
+    /// It can be used to draw a [`Batch`] or a [`Canvas`].
+    ///
+    /// [`Shader`]: struct.Shader.html
+    /// [`Batch`]: struct.Batch.html
+    /// [`Canvas`]: struct.Canvas.html
+    pub fn for_quads(gpu: &mut Gpu, source: &[u8]) -> Result<Shader> {
+        Shader::new(gpu, Kind::Quads, source)
+    }
+
+    /// Creates a [`Shader`] that draws meshes from the given source.
+    ///
+    /// It can be used to draw a [`Mesh`].
+    ///
+    /// [`Shader`]: struct.Shader.html
+    /// [`Mesh`]: struct.Mesh.html
+    pub fn for_meshes(gpu: &mut Gpu, source: &[u8]) -> Result<Shader> {
+        Shader::new(gpu, Kind::Meshes, source)
+    }
+
+    /// Creates a [`Task`] that loads a [`Shader`] that draws quads from the
+    /// given path.
+    ///
+    /// [`Task`]: ../load/struct.Task.html
+    /// [`Shader`]: struct.Shader.html
+    pub fn load_for_quads<P: Into<PathBuf>>(path: P) -> Task<Shader> {
+        Shader::load(Kind::Quads, path.into())
+    }
+
+    /// Creates a [`Task`] that loads a [`Shader`] that draws meshes from the
+    /// given path.
+    ///
+    /// [`Task`]: ../load/struct.Task.html
+    /// [`Shader`]: struct.Shader.html
+    pub fn load_for_meshes<P: Into<PathBuf>>(path: P) -> Task<Shader> {
+        Shader::load(Kind::Meshes, path.into())
+    }
+
+    /// Sets the uniform with the given index.
+    ///
+    /// The new value is used the next time the [`Shader`] draws.
+    ///
+    /// # Panics
+    /// It panics if the index is not less than [`Shader::MAX_UNIFORMS`].
+    ///
+    /// [`Shader`]: struct.Shader.html
+    /// [`Shader::MAX_UNIFORMS`]: #associatedconstant.MAX_UNIFORMS
+    pub fn set_uniform(&mut self, index: usize, value: [f32; 4]) {
+        assert!(index < Shader::MAX_UNIFORMS, "Invalid uniform: {}", index);
+
+        self.raw.set_uniform(index, value);
+    }
+
+    pub(super) fn raw(&self, kind: Kind) -> &gpu::Shader {
+        assert_eq!(self.kind, kind, "The shader cannot draw {:?}", kind);
+
+        &self.raw
+    }
+
+    fn new(gpu: &mut Gpu, kind: Kind, source: &[u8]) -> Result<Shader> {
+        let raw = gpu.create_shader(kind, source)?;
+
+        Ok(Shader { raw, kind })
+    }
+
+    fn load(kind: Kind, path: PathBuf) -> Task<Shader> {
+        Task::using_gpu(move |gpu| {
+            let source = std::fs::read(&path)?;
+
+            Shader::new(gpu, kind, &source)
+        })
+    }
+}
+
+impl std::fmt::Debug for Shader {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Shader {{ kind: {:?} }}", self.kind)
+    }
+}
@@ -1,4 +1,4 @@
-use crate::graphics::{IntoQuad, Point, Quad, Rectangle};
+use crate::graphics::{Color, IntoQuad, Point, Quad, Rectangle};
 
 /// A quad describing the portion of a resource in absolute coordinates.
 ///
@@ -13,11 +13,29 @@ pub struct Sprite {
     /// coordinates.
     pub source: Rectangle<u16>,
 
-    /// The position where the sprite should be drawn.
+    /// The position where the origin of the sprite should be drawn.
     pub position: Point,
 
     /// The scale to apply to the sprite.
     pub scale: (f32, f32),
+
+    /// The rotation of the sprite around its origin, in radians.
+    pub rotation: f32,
+
+    /// The origin of the sprite, relative to its size.
+    ///
+    /// `(0.0, 0.0)` is the top-left corner and `(0.5, 0.5)` is the center.
+    pub origin: Point,
+
+    /// Whether the sprite should be flipped horizontally.
+    pub flip_x: bool,
+
+    /// Whether the sprite should be flipped vertically.
+    pub flip_y: bool,
+
+    /// The color the sprite is multiplied by. Use it to tint or fade the
+    /// sprite.
+    pub tint: Color,
 }
 
 impl Default for Sprite {
@@ -32,6 +50,11 @@ impl Default for Sprite {
             },
             position: Point::new(0.0, 0.0),
             scale: (1.0, 1.0),
+            rotation: 0.0,
+            origin: Point::new(0.0, 0.0),
+            flip_x: false,
+            flip_y: false,
+            tint: Color::WHITE,
         }
     }
 }
@@ -50,6 +73,11 @@ impl IntoQuad for Sprite {
                 self.source.width as f32 * self.scale.0,
                 self.source.height as f32 * self.scale.1,
             ),
+            rotation: self.rotation,
+            origin: self.origin,
+            flip_x: self.flip_x,
+            flip_y: self.flip_y,
+            tint: self.tint,
         }
     }
 }
@@ -1,5 +1,10 @@
-use crate::graphics::gpu::{self, Font, Gpu, TargetView, Texture, Vertex};
-use crate::graphics::{Color, Transformation};
+use nalgebra::{Matrix3, Vector3};
+
+use crate::graphics::gpu::{
+    self, texture, Font, Gpu, TargetView, Texture, Vertex,
+};
+use crate::graphics::shader::{Kind, Shader};
+use crate::graphics::{BlendMode, Color, Mesh, Rectangle, Transformation};
 
 /// A rendering target.
 ///
@@ -16,7 +21,13 @@ use crate::graphics::{Color, Transformation};
 pub struct Target<'a> {
     gpu: &'a mut Gpu,
     view: TargetView,
+    width: f32,
+    height: f32,
+    projection: Transformation,
     transformation: Transformation,
+    blend_mode: BlendMode,
+    clip: Option<Rectangle<u32>>,
+    mask: u8,
 }
 
 impl<'a> Target<'a> {
@@ -26,10 +37,18 @@ impl<'a> Target<'a> {
         width: f32,
         height: f32,
     ) -> Target<'_> {
+        let projection = Transformation::orthographic(width, height);
+
         Target {
             gpu,
             view,
-            transformation: Transformation::orthographic(width, height),
+            width,
+            height,
+            projection,
+            transformation: projection,
+            blend_mode: BlendMode::Alpha,
+            clip: None,
+            mask: 0,
         }
     }
 
@@ -41,7 +60,8 @@ impl<'a> Target<'a> {
         transformation: Transformation,
     ) -> Target<'_> {
         let mut target = Self::new(gpu, view, width, height);
-        target.transformation = transformation * target.transformation;
+        target.projection = transformation * target.projection;
+        target.transformation = target.projection;
         target
     }
 
@@ -83,7 +103,166 @@ impl<'a> Target<'a> {
         Target {
             gpu: self.gpu,
             view: self.view.clone(),
+            width: self.width,
+            height: self.height,
+            projection: self.projection,
             transformation: self.transformation * transformation,
+            blend_mode: self.blend_mode,
+            clip: self.clip,
+            mask: self.mask,
+        }
+    }
+
+    /// Creates a new [`Target`] that draws using the given [`BlendMode`].
+    ///
+    /// Like [`transform`], the original [`Target`] is not affected. Use it to
+    /// choose the [`BlendMode`] of a single draw operation:
+    ///
+    /// ```
+    /// use coffee::graphics::{BlendMode, Frame, Image, Quad};
+    ///
+    /// fn draw_glow(glow: &Image, frame: &mut Frame) {
+    ///     let mut target = frame.as_target();
+    ///
+    ///     glow.draw(Quad::default(), &mut target.blend(BlendMode::Additive));
+    /// }
+    /// ```
+    ///
+    /// [`Target`]: struct.Target.html
+    /// [`BlendMode`]: enum.BlendMode.html
+    /// [`transform`]: #method.transform
+    pub fn blend(&mut self, blend_mode: BlendMode) -> Target<'_> {
+        Target {
+            gpu: self.gpu,
+            view: self.view.clone(),
+            width: self.width,
+            height: self.height,
+            projection: self.projection,
+            transformation: self.transformation,
+            blend_mode,
+            clip: self.clip,
+            mask: self.mask,
+        }
+    }
+
+    /// Creates a new [`Target`] that only draws inside the given [`Rectangle`].
+    ///
+    /// The [`Rectangle`] is given in pixels, with the origin at the top-left
+    /// corner of the [`Target`], and it is not affected by any
+    /// [`Transformation`]. If the current [`Target`] is already clipped, the
+    /// new [`Target`] only draws inside the intersection of both.
+    ///
+    /// Quads, meshes, and text are all clipped. Like [`transform`], the
+    /// original [`Target`] is not affected:
+    ///
+    /// ```
+    /// use coffee::graphics::{Frame, Image, Quad, Rectangle};
+    ///
+    /// fn draw_panel(background: &Image, frame: &mut Frame) {
+    ///     let mut target = frame.as_target();
+    ///
+    ///     let mut panel = target.clip(Rectangle {
+    ///         x: 10,
+    ///         y: 10,
+    ///         width: 200,
+    ///         height: 100,
+    ///     });
+    ///
+    ///     // Only the part inside the panel is drawn
+    ///     background.draw(Quad::default(), &mut panel);
+    /// }
+    /// ```
+    ///
+    /// [`Target`]: struct.Target.html
+    /// [`Rectangle`]: struct.Rectangle.html
+    /// [`Transformation`]: struct.Transformation.html
+    /// [`transform`]: #method.transform
+    pub fn clip(&mut self, rectangle: Rectangle<u32>) -> Target<'_> {
+        let clip = match self.clip {
+            Some(clip) => intersection(clip, rectangle),
+            None => rectangle,
+        };
+
+        Target {
+            gpu: self.gpu,
+            view: self.view.clone(),
+            width: self.width,
+            height: self.height,
+            projection: self.projection,
+            transformation: self.transformation,
+            blend_mode: self.blend_mode,
+            clip: Some(clip),
+            mask: self.mask,
+        }
+    }
+
+    /// Creates a new [`Target`] that only draws inside the given [`Mesh`].
+    ///
+    /// The [`Mesh`] is not drawn. Instead, it is used as a stencil: only the
+    /// pixels covered by its shapes can be drawn with the new [`Target`]. Its
+    /// colors are ignored. If the current [`Target`] is already masked, the
+    /// new [`Target`] only draws inside the intersection of both masks.
+    ///
+    /// Quads and meshes are masked, but text is not. Like [`transform`], the
+    /// original [`Target`] is not affected:
+    ///
+    /// ```
+    /// use coffee::graphics::{
+    ///     Color, Frame, Image, Mesh, Point, Quad, Shape,
+    /// };
+    ///
+    /// fn draw_light(scene: &Image, frame: &mut Frame) {
+    ///     let mut target = frame.as_target();
+    ///
+    ///     let mut cone = Mesh::new();
+    ///
+    ///     cone.fill(
+    ///         Shape::Polyline {
+    ///             points: vec![
+    ///                 Point::new(100.0, 100.0),
+    ///                 Point::new(300.0, 50.0),
+    ///                 Point::new(300.0, 150.0),
+    ///             ],
+    ///         },
+    ///         Color::WHITE,
+    ///     );
+    ///
+    ///     // Only the part of the scene inside the light cone is drawn
+    ///     scene.draw(Quad::default(), &mut target.mask(&cone));
+    /// }
+    /// ```
+    ///
+    /// # Panics
+    /// It panics if more than 255 masks are nested.
+    ///
+    /// [`Target`]: struct.Target.html
+    /// [`Mesh`]: struct.Mesh.html
+    /// [`transform`]: #method.transform
+    pub fn mask(&mut self, mesh: &Mesh) -> Target<'_> {
+        assert!(self.mask < std::u8::MAX, "Too many nested masks");
+
+        if !self.is_clipped_out() {
+            let (vertices, indices) = mesh.buffers();
+
+            self.gpu.draw_mask(
+                vertices,
+                indices,
+                &self.view,
+                &self.transformation,
+                self.state(),
+            );
+        }
+
+        Target {
+            gpu: self.gpu,
+            view: self.view.clone(),
+            width: self.width,
+            height: self.height,
+            projection: self.projection,
+            transformation: self.transformation,
+            blend_mode: self.blend_mode,
+            clip: self.clip,
+            mask: self.mask + 1,
         }
     }
 
@@ -99,12 +278,19 @@ impl<'a> Target<'a> {
         &mut self,
         vertices: &[Vertex],
         indices: &[u32],
+        shader: Option<&Shader>,
     ) {
+        if self.is_clipped_out() {
+            return;
+        }
+
         self.gpu.draw_triangles(
             vertices,
             indices,
             &self.view,
             &self.transformation,
+            self.state(),
+            shader.map(|shader| shader.raw(Kind::Meshes)),
         );
     }
 
@@ -112,22 +298,172 @@ impl<'a> Target<'a> {
         &mut self,
         texture: &Texture,
         instances: &[gpu::Quad],
+        shader: Option<&Shader>,
     ) {
+        if self.is_clipped_out() {
+            return;
+        }
+
         self.gpu.draw_texture_quads(
             texture,
             instances,
             &self.view,
             &self.transformation,
+            self.state(),
+            shader.map(|shader| shader.raw(Kind::Quads)),
         );
     }
 
+    pub(super) fn resolve(&mut self, drawable: &texture::Drawable) {
+        self.gpu.resolve_drawable(drawable);
+    }
+
     pub(in crate::graphics) fn draw_font(&mut self, font: &mut Font) {
-        self.gpu.draw_font(font, &self.view, self.transformation);
+        let clip = self.text_clip();
+
+        self.gpu
+            .draw_font(font, &self.view, self.transformation, clip);
+    }
+
+    fn state(&self) -> DrawState {
+        DrawState {
+            blend_mode: self.blend_mode,
+            clip: self.scissor(),
+            mask: self.mask,
+        }
+    }
+
+    fn is_clipped_out(&self) -> bool {
+        match self.clip {
+            Some(clip) => clip.width == 0 || clip.height == 0,
+            None => false,
+        }
+    }
+
+    /// Returns the clip rectangle in framebuffer pixels, with its origin at
+    /// the corner where the normalized device coordinates are `(-1, -1)`.
+    ///
+    /// Canvases flip their contents vertically on some backends, so this is
+    /// not always the same as the coordinate system of the `Target`.
+    fn scissor(&self) -> Option<Rectangle<u32>> {
+        let projection: Matrix3<f32> = self.projection.into();
+        let width = self.width;
+        let height = self.height;
+
+        self.clip.map(|clip| {
+            let bounds = bounding_box(&projection, clip, |[x, y]| {
+                [(x + 1.0) * 0.5 * width, (y + 1.0) * 0.5 * height]
+            });
+
+            // Some backends reject scissors extending past the target
+            let left = bounds.x.round().max(0.0).min(width);
+            let top = bounds.y.round().max(0.0).min(height);
+            let right = (bounds.x + bounds.width).round().max(left).min(width);
+            let bottom =
+                (bounds.y + bounds.height).round().max(top).min(height);
+
+            Rectangle {
+                x: left as u32,
+                y: top as u32,
+                width: (right - left) as u32,
+                height: (bottom - top) as u32,
+            }
+        })
+    }
+
+    /// Returns the clip rectangle in the coordinate system of the current
+    /// transformation, where text is laid out.
+    fn text_clip(&self) -> Option<Rectangle<f32>> {
+        let transformation: Matrix3<f32> = self.transformation.into();
+        let projection: Matrix3<f32> = self.projection.into();
+        let inverse = transformation.try_inverse()? * projection;
+
+        self.clip
+            .map(|clip| bounding_box(&inverse, clip, |point| point))
+    }
+}
+
+/// The state of a draw operation, as needed by the graphics backends.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(super) struct DrawState {
+    pub(super) blend_mode: BlendMode,
+
+    /// The clip rectangle in framebuffer pixels, as returned by
+    /// `Target::scissor`.
+    pub(super) clip: Option<Rectangle<u32>>,
+
+    /// The stencil value of the pixels that can be drawn, or `0` if the
+    /// target is not masked.
+    pub(super) mask: u8,
+}
+
+fn intersection(a: Rectangle<u32>, b: Rectangle<u32>) -> Rectangle<u32> {
+    let left = a.x.max(b.x);
+    let top = a.y.max(b.y);
+    let right =
+        a.x.saturating_add(a.width)
+            .min(b.x.saturating_add(b.width))
+            .max(left);
+    let bottom =
+        a.y.saturating_add(a.height)
+            .min(b.y.saturating_add(b.height))
+            .max(top);
+
+    Rectangle {
+        x: left,
+        y: top,
+        width: right - left,
+        height: bottom - top,
+    }
+}
+
+fn bounding_box<F>(
+    matrix: &Matrix3<f32>,
+    rectangle: Rectangle<u32>,
+    map: F,
+) -> Rectangle<f32>
+where
+    F: Fn([f32; 2]) -> [f32; 2],
+{
+    let left = rectangle.x as f32;
+    let top = rectangle.y as f32;
+    let right = left + rectangle.width as f32;
+    let bottom = top + rectangle.height as f32;
+
+    let corners = [[left, top], [right, top], [right, bottom], [left, bottom]]
+        .iter()
+        .map(|[x, y]| {
+            let point = matrix * Vector3::new(*x, *y, 1.0);
+
+            map([point.x, point.y])
+        })
+        .collect::<Vec<_>>();
+
+    let (mut min_x, mut min_y) = (corners[0][0], corners[0][1]);
+    let (mut max_x, mut max_y) = (min_x, min_y);
+
+    for corner in &corners[1..] {
+        min_x = min_x.min(corner[0]);
+        min_y = min_y.min(corner[1]);
+        max_x = max_x.max(corner[0]);
+        max_y = max_y.max(corner[1]);
+    }
+
+    Rectangle {
+        x: min_x,
+        y: min_y,
+        width: max_x - min_x,
+        height: max_y - min_y,
     }
 }
 
 impl<'a> std::fmt::Debug for Target<'a> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "Target {{ transformation: {:?} }}", self.transformation)
+        write!(
+            f,
+            "Target {{ transformation: {:?}, blend_mode: {:?}, clip: {:?}, \
+             mask: {} }}",
+            self.transformation, self.blend_mode, self.clip, self.mask
+        )
     }
 }
@@ -1,6 +1,6 @@
 use std::f32;
 
-use crate::graphics::{Color, Point};
+use crate::graphics::{Color, Point, Rectangle};
 
 /// A section of text.
 #[derive(Clone, PartialEq, Debug)]
@@ -42,6 +42,188 @@ impl Default for Text<'static> {
     }
 }
 
+/// A section of text made of [`Span`]s with different styles.
+///
+/// It can be added to a [`Font`] like a [`Text`]. Every [`Span`] chooses its
+/// own [`FontStyle`], size, and color, while wrapping and alignment apply to
+/// the whole section.
+///
+/// ```
+/// use coffee::graphics::{Color, FontStyle, Point, RichText, Span};
+///
+/// let dialogue = RichText {
+///     spans: vec![
+///         Span {
+///             content: "Bring me the ",
+///             color: Color::WHITE,
+///             ..Span::default()
+///         },
+///         Span {
+///             content: "golden key",
+///             color: Color::RED,
+///             style: FontStyle::Bold,
+///             ..Span::default()
+///         },
+///         Span {
+///             content: ", adventurer.",
+///             color: Color::WHITE,
+///             ..Span::default()
+///         },
+///     ],
+///     position: Point::new(20.0, 20.0),
+///     bounds: (400.0, 100.0),
+///     ..RichText::default()
+/// };
+/// ```
+///
+/// [`Span`]: struct.Span.html
+/// [`Font`]: struct.Font.html
+/// [`Text`]: struct.Text.html
+/// [`FontStyle`]: enum.FontStyle.html
+#[derive(Clone, PartialEq, Debug)]
+pub struct RichText<'a> {
+    /// Text spans, in order
+    pub spans: Vec<Span<'a>>,
+
+    /// Text position
+    pub position: Point,
+
+    /// Text bounds, in screen coordinates
+    pub bounds: (f32, f32),
+
+    /// Text horizontal alignment
+    pub horizontal_alignment: HorizontalAlignment,
+
+    /// Text vertical alignment
+    pub vertical_alignment: VerticalAlignment,
+}
+
+impl Default for RichText<'static> {
+    #[inline]
+    fn default() -> RichText<'static> {
+        RichText {
+            spans: Vec::new(),
+            position: Point::new(0.0, 0.0),
+            bounds: (f32::INFINITY, f32::INFINITY),
+            horizontal_alignment: HorizontalAlignment::Left,
+            vertical_alignment: VerticalAlignment::Top,
+        }
+    }
+}
+
+impl<'a> From<Text<'a>> for RichText<'a> {
+    fn from(text: Text<'a>) -> RichText<'a> {
+        RichText {
+            spans: vec![Span {
+                content: text.content,
+                size: text.size,
+                color: text.color,
+                style: FontStyle::Regular,
+            }],
+            position: text.position,
+            bounds: text.bounds,
+            horizontal_alignment: text.horizontal_alignment,
+            vertical_alignment: text.vertical_alignment,
+        }
+    }
+}
+
+/// A piece of [`RichText`] with its own style.
+///
+/// [`RichText`]: struct.RichText.html
+#[derive(Clone, PartialEq, Debug)]
+pub struct Span<'a> {
+    /// Span content
+    pub content: &'a str,
+
+    /// Span size
+    pub size: f32,
+
+    /// Span color
+    pub color: Color,
+
+    /// Span font style
+    pub style: FontStyle,
+}
+
+impl Default for Span<'static> {
+    #[inline]
+    fn default() -> Span<'static> {
+        Span {
+            content: "",
+            size: 16.0,
+            color: Color::BLACK,
+            style: FontStyle::Regular,
+        }
+    }
+}
+
+/// The style of a [`Span`].
+///
+/// A [`Font`] draws every style with its regular typeface, unless a typeface
+/// for the style has been added using [`Font::add_style`].
+///
+/// [`Span`]: struct.Span.html
+/// [`Font`]: struct.Font.html
+/// [`Font::add_style`]: struct.Font.html#method.add_style
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FontStyle {
+    /// Regular style
+    Regular,
+
+    /// Bold style
+    Bold,
+
+    /// Italic style
+    Italic,
+
+    /// Bold and italic style
+    BoldItalic,
+}
+
+/// A positioned glyph of some [`Text`] or [`RichText`].
+///
+/// You can obtain the glyphs of some text using [`Font::layout`].
+///
+/// [`Text`]: struct.Text.html
+/// [`RichText`]: struct.RichText.html
+/// [`Font::layout`]: struct.Font.html#method.layout
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GlyphInfo {
+    /// The index of the [`Span`] the glyph belongs to
+    ///
+    /// [`Span`]: struct.Span.html
+    pub span: usize,
+
+    /// The byte index of the character of the glyph, counting the content of
+    /// every previous [`Span`]
+    ///
+    /// [`Span`]: struct.Span.html
+    pub index: usize,
+
+    /// The bounds of the glyph, in screen coordinates
+    pub bounds: Rectangle<f32>,
+
+    /// The line of the glyph, starting at `0`
+    pub line: usize,
+}
+
+impl GlyphInfo {
+    /// Finds the glyph that contains the given [`Point`] and returns the byte
+    /// index of its character.
+    ///
+    /// [`Point`]: type.Point.html
+    pub(super) fn hit_test(
+        glyphs: &[GlyphInfo],
+        point: Point,
+    ) -> Option<usize> {
+        glyphs
+            .iter()
+            .find(|glyph| glyph.bounds.contains(point))
+            .map(|glyph| glyph.index)
+    }
+}
+
 /// The horizontal alignment of some resource.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum HorizontalAlignment {
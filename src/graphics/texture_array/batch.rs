@@ -1,5 +1,5 @@
 use super::{Index, TextureArray};
-use crate::graphics::{gpu, IntoQuad, Target};
+use crate::graphics::{gpu, IntoQuad, Shader, Target};
 
 /// A collection of quads that can be drawn with a [`TextureArray`] all at once.
 ///
@@ -50,6 +50,33 @@ impl Batch {
         target.draw_texture_quads(
             &self.texture_array.texture,
             &self.instances[..],
+            None,
         );
     }
+
+    /// Draws the [`Batch`] on the given [`Target`] using a custom [`Shader`].
+    ///
+    /// # Panics
+    /// It panics if the [`Shader`] was not created for quads.
+    ///
+    /// [`Batch`]: struct.Batch.html
+    /// [`Target`]: ../struct.Target.html
+    /// [`Shader`]: ../struct.Shader.html
+    pub fn draw_with_shader(&self, shader: &Shader, target: &mut Target<'_>) {
+        target.draw_texture_quads(
+            &self.texture_array.texture,
+            &self.instances[..],
+            Some(shader),
+        );
+    }
+
+    /// Clears the [`Batch`] contents.
+    ///
+    /// This is useful to avoid creating a new batch every frame and
+    /// reallocating the same memory.
+    ///
+    /// [`Batch`]: struct.Batch.html
+    pub fn clear(&mut self) {
+        self.instances.clear();
+    }
 }
@@ -42,10 +42,12 @@ impl Builder {
     ///
     /// As of now, the [`Builder`] uses a very naive placement algorithm. It
     /// simply places images in rows as they are added if there is any space left
-    /// in the current layer. If there is not, it creates a new layer.
+    /// in the current layer. If there is not, it creates a new layer. If you
+    /// need tighter packing, consider using an [`Atlas`] instead.
     ///
     /// [`TextureArray`]: struct.TextureArray.html
     /// [`Builder`]: struct.Builder.html
+    /// [`Atlas`]: ../atlas/struct.Atlas.html
     pub fn add<P: AsRef<Path>>(&mut self, path: P) -> Result<Index> {
         let img = {
             let mut buf = Vec::new();
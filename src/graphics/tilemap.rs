@@ -0,0 +1,344 @@
+//! Build, load, and draw tile-based maps.
+mod layer;
+mod property;
+mod tiled;
+mod tileset;
+
+pub use layer::{Layer, Tile};
+pub use property::{Properties, Property};
+pub use tileset::Tileset;
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use crate::graphics::{Color, Gpu, Point, Rectangle, Sprite, Target};
+use crate::load::Task;
+use crate::Result;
+use tileset::TileBatch;
+
+/// A map made of layers of tiles.
+///
+/// A [`Tilemap`] has a grid of a fixed size, where every cell is a tile of
+/// `tile_width` x `tile_height` pixels. Its [`Layer`] list is drawn in order,
+/// using the tiles of its [`Tileset`] list.
+///
+/// You can build a [`Tilemap`] yourself or load one created with the [Tiled]
+/// map editor. Both the JSON (`.json`) and the XML (`.tmx`) formats of Tiled
+/// are supported.
+///
+/// [`Tilemap`]: struct.Tilemap.html
+/// [`Layer`]: struct.Layer.html
+/// [`Tileset`]: struct.Tileset.html
+/// [Tiled]: https://www.mapeditor.org
+#[derive(Debug)]
+pub struct Tilemap {
+    width: u32,
+    height: u32,
+    tile_width: u16,
+    tile_height: u16,
+    tilesets: Vec<(u32, Tileset)>,
+    layers: Vec<Layer>,
+    batches: Vec<TileBatch>,
+
+    /// The properties of the map.
+    pub properties: Properties,
+}
+
+impl Tilemap {
+    /// Loads a [`Tilemap`] created with Tiled from the given path.
+    ///
+    /// The format is chosen using the extension of the file: `.json` or
+    /// `.tmx`. Tilesets stored in separate files and their images are loaded
+    /// relative to the map.
+    ///
+    /// Only finite, orthogonal maps are supported. Object, image, and group
+    /// layers are ignored.
+    ///
+    /// [`Tilemap`]: struct.Tilemap.html
+    pub fn new<P: AsRef<Path>>(gpu: &mut Gpu, path: P) -> Result<Tilemap> {
+        tiled::load(gpu, path.as_ref())
+    }
+
+    /// Creates a [`Task`] that loads a [`Tilemap`] created with Tiled from the
+    /// given path.
+    ///
+    /// [`Task`]: ../../load/struct.Task.html
+    /// [`Tilemap`]: struct.Tilemap.html
+    pub fn load<P: Into<PathBuf>>(path: P) -> Task<Tilemap> {
+        let path = path.into();
+
+        Task::using_gpu(move |gpu| Tilemap::new(gpu, &path))
+    }
+
+    /// Creates an empty [`Tilemap`] with the given size, in tiles, and tile
+    /// size, in pixels.
+    ///
+    /// [`Tilemap`]: struct.Tilemap.html
+    pub fn empty(
+        width: u32,
+        height: u32,
+        tile_width: u16,
+        tile_height: u16,
+    ) -> Tilemap {
+        Tilemap {
+            width,
+            height,
+            tile_width,
+            tile_height,
+            tilesets: Vec::new(),
+            layers: Vec::new(),
+            batches: Vec::new(),
+            properties: Properties::new(),
+        }
+    }
+
+    /// Returns the width of the [`Tilemap`], in tiles.
+    ///
+    /// [`Tilemap`]: struct.Tilemap.html
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Returns the height of the [`Tilemap`], in tiles.
+    ///
+    /// [`Tilemap`]: struct.Tilemap.html
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Returns the width of a tile of the [`Tilemap`], in pixels.
+    ///
+    /// [`Tilemap`]: struct.Tilemap.html
+    pub fn tile_width(&self) -> u16 {
+        self.tile_width
+    }
+
+    /// Returns the height of a tile of the [`Tilemap`], in pixels.
+    ///
+    /// [`Tilemap`]: struct.Tilemap.html
+    pub fn tile_height(&self) -> u16 {
+        self.tile_height
+    }
+
+    /// Adds a [`Tileset`] to the [`Tilemap`].
+    ///
+    /// It returns the id of the first tile of the [`Tileset`] in the
+    /// [`Tilemap`]. The tile with local id `n` has id `first_id + n`. The
+    /// first [`Tileset`] starts at `1`.
+    ///
+    /// [`Tileset`]: struct.Tileset.html
+    /// [`Tilemap`]: struct.Tilemap.html
+    pub fn add_tileset(&mut self, tileset: Tileset) -> u32 {
+        let first_id = match self.tilesets.last() {
+            Some((first_id, last)) => first_id + last.tile_count(),
+            None => 1,
+        };
+
+        self.insert_tileset(first_id, tileset);
+
+        first_id
+    }
+
+    /// Adds a [`Layer`] on top of the others.
+    ///
+    /// [`Layer`]: struct.Layer.html
+    pub fn add_layer(&mut self, layer: Layer) {
+        self.layers.push(layer);
+    }
+
+    /// Returns the layers of the [`Tilemap`], from bottom to top.
+    ///
+    /// [`Tilemap`]: struct.Tilemap.html
+    pub fn layers(&self) -> &[Layer] {
+        &self.layers
+    }
+
+    /// Returns the layers of the [`Tilemap`] mutably, from bottom to top.
+    ///
+    /// [`Tilemap`]: struct.Tilemap.html
+    pub fn layers_mut(&mut self) -> &mut [Layer] {
+        &mut self.layers
+    }
+
+    /// Returns the first [`Layer`] with the given name, if any.
+    ///
+    /// [`Layer`]: struct.Layer.html
+    pub fn layer(&self, name: &str) -> Option<&Layer> {
+        self.layers.iter().find(|layer| layer.name() == name)
+    }
+
+    /// Returns the [`Tile`] of a [`Layer`] at the given point, in pixels.
+    ///
+    /// [`Tile`]: struct.Tile.html
+    /// [`Layer`]: struct.Layer.html
+    pub fn tile_at(&self, layer: &Layer, point: Point) -> Option<Tile> {
+        if point.x < 0.0 || point.y < 0.0 {
+            return None;
+        }
+
+        let x = (point.x / f32::from(self.tile_width)) as u32;
+        let y = (point.y / f32::from(self.tile_height)) as u32;
+
+        layer.tile(x, y)
+    }
+
+    /// Returns the [`Properties`] of a [`Tile`], if it has any.
+    ///
+    /// Use it to query collisions or any other game logic of your tiles:
+    ///
+    /// ```
+    /// use coffee::graphics::tilemap::Tilemap;
+    /// use coffee::graphics::Point;
+    ///
+    /// fn is_solid(tilemap: &Tilemap, point: Point) -> bool {
+    ///     tilemap.layers().iter().any(|layer| {
+    ///         tilemap
+    ///             .tile_at(layer, point)
+    ///             .and_then(|tile| tilemap.tile_properties(tile))
+    ///             .and_then(|properties| properties.get("solid"))
+    ///             .and_then(|solid| solid.as_bool())
+    ///             .unwrap_or(false)
+    ///     })
+    /// }
+    /// ```
+    ///
+    /// [`Properties`]: type.Properties.html
+    /// [`Tile`]: struct.Tile.html
+    pub fn tile_properties(&self, tile: Tile) -> Option<&Properties> {
+        let (first_id, tileset) = self.tileset(tile.id)?;
+
+        tileset.tile_properties(tile.id - first_id)
+    }
+
+    /// Draws the [`Tilemap`] on the given [`Target`].
+    ///
+    /// Only the tiles that overlap the `visible` [`Rectangle`], in pixels, are
+    /// drawn. You can use [`Camera::visible_area`] to obtain it.
+    ///
+    /// [`Tilemap`]: struct.Tilemap.html
+    /// [`Target`]: ../struct.Target.html
+    /// [`Rectangle`]: ../struct.Rectangle.html
+    /// [`Camera::visible_area`]: ../struct.Camera.html#method.visible_area
+    pub fn draw(&mut self, visible: Rectangle<f32>, target: &mut Target<'_>) {
+        let tile_width = f32::from(self.tile_width);
+        let tile_height = f32::from(self.tile_height);
+
+        // Tiles taller than the grid grow upwards, so they can be visible
+        // from rows below the visible area
+        let overflow = self
+            .tilesets
+            .iter()
+            .map(|(_, tileset)| {
+                let extra = f32::from(tileset.max_tile_height()) - tile_height;
+
+                (extra.max(0.0) / tile_height).ceil() as u32
+            })
+            .max()
+            .unwrap_or(0);
+
+        let columns = range(visible.x, visible.width, tile_width, self.width);
+        let rows = range(visible.y, visible.height, tile_height, self.height);
+        let rows = rows.start..(rows.end + overflow).min(self.height);
+
+        for layer in self.layers.iter().filter(|layer| layer.is_visible) {
+            let tint = Color {
+                a: layer.opacity,
+                ..Color::WHITE
+            };
+
+            for y in rows.clone() {
+                for x in columns.clone() {
+                    let tile = match layer.tile(x, y) {
+                        Some(tile) => tile,
+                        None => continue,
+                    };
+
+                    let i = match self
+                        .tilesets
+                        .iter()
+                        .rposition(|(first_id, _)| *first_id <= tile.id)
+                    {
+                        Some(i) => i,
+                        None => continue,
+                    };
+
+                    let (first_id, tileset) = &self.tilesets[i];
+
+                    tileset.add(
+                        &mut self.batches[i],
+                        tile.id - first_id,
+                        Point::new(
+                            x as f32 * tile_width,
+                            (y + 1) as f32 * tile_height,
+                        ),
+                        Sprite {
+                            flip_x: tile.flip_x,
+                            flip_y: tile.flip_y,
+                            tint,
+                            ..Sprite::default()
+                        },
+                    );
+                }
+            }
+
+            for batch in self.batches.iter_mut() {
+                batch.draw(target);
+                batch.clear();
+            }
+        }
+    }
+
+    fn insert_tileset(&mut self, first_id: u32, tileset: Tileset) {
+        self.batches.push(tileset.batch());
+        self.tilesets.push((first_id, tileset));
+    }
+
+    fn tileset(&self, id: u32) -> Option<(u32, &Tileset)> {
+        self.tilesets
+            .iter()
+            .rev()
+            .find(|(first_id, _)| *first_id <= id)
+            .map(|(first_id, tileset)| (*first_id, tileset))
+    }
+}
+
+/// Returns the range of cells of a grid axis that overlap the given segment.
+fn range(
+    start: f32,
+    length: f32,
+    cell: f32,
+    cells: u32,
+) -> std::ops::Range<u32> {
+    let first = (start / cell).floor().max(0.0) as u32;
+    let last = ((start + length) / cell).ceil().max(0.0) as u32;
+
+    first.min(cells)..last.min(cells)
+}
+
+/// A tilemap loading error.
+#[derive(Debug, Clone)]
+pub enum Error {
+    /// The format of the file is not supported.
+    UnsupportedFormat(PathBuf),
+
+    /// The map uses a feature of Tiled that is not supported, like infinite
+    /// maps or compressed layers.
+    Unsupported(String),
+
+    /// The map is invalid.
+    Invalid(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::UnsupportedFormat(path) => {
+                write!(f, "Unsupported format: {}", path.display())
+            }
+            Error::Unsupported(feature) => {
+                write!(f, "Unsupported feature: {}", feature)
+            }
+            Error::Invalid(reason) => write!(f, "Invalid map: {}", reason),
+        }
+    }
+}
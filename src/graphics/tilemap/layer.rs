@@ -0,0 +1,184 @@
+use super::Properties;
+
+const FLIPPED_HORIZONTALLY: u32 = 0x8000_0000;
+const FLIPPED_VERTICALLY: u32 = 0x4000_0000;
+const FLIPPED_DIAGONALLY: u32 = 0x2000_0000;
+const FLAGS: u32 =
+    FLIPPED_HORIZONTALLY | FLIPPED_VERTICALLY | FLIPPED_DIAGONALLY;
+
+/// A grid of tiles in a [`Tilemap`].
+///
+/// [`Tilemap`]: struct.Tilemap.html
+#[derive(Debug, Clone, PartialEq)]
+pub struct Layer {
+    name: String,
+    width: u32,
+    height: u32,
+    tiles: Vec<u32>,
+
+    /// The properties of the layer.
+    pub properties: Properties,
+
+    /// Whether the layer is drawn.
+    pub is_visible: bool,
+
+    /// The opacity of the layer, from `0.0` to `1.0`.
+    pub opacity: f32,
+}
+
+/// A tile placed in a [`Layer`].
+///
+/// [`Layer`]: struct.Layer.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Tile {
+    /// The id of the tile in its [`Tilemap`].
+    ///
+    /// Every [`Tileset`] added to a [`Tilemap`] takes a range of ids,
+    /// starting at the id returned by [`Tilemap::add_tileset`].
+    ///
+    /// [`Tilemap`]: struct.Tilemap.html
+    /// [`Tileset`]: struct.Tileset.html
+    /// [`Tilemap::add_tileset`]: struct.Tilemap.html#method.add_tileset
+    pub id: u32,
+
+    /// Whether the tile is flipped horizontally.
+    pub flip_x: bool,
+
+    /// Whether the tile is flipped vertically.
+    pub flip_y: bool,
+}
+
+impl Tile {
+    /// Creates a new [`Tile`] with the given id.
+    ///
+    /// [`Tile`]: struct.Tile.html
+    pub fn new(id: u32) -> Tile {
+        Tile {
+            id,
+            flip_x: false,
+            flip_y: false,
+        }
+    }
+
+    /// Decodes a global tile id of Tiled, including its flip flags.
+    ///
+    /// Diagonal flips are not supported and they are ignored.
+    pub(super) fn from_gid(gid: u32) -> Option<Tile> {
+        let id = gid & !FLAGS;
+
+        if id == 0 {
+            None
+        } else {
+            Some(Tile {
+                id,
+                flip_x: gid & FLIPPED_HORIZONTALLY != 0,
+                flip_y: gid & FLIPPED_VERTICALLY != 0,
+            })
+        }
+    }
+
+    fn to_gid(self) -> u32 {
+        let mut gid = self.id & !FLAGS;
+
+        if self.flip_x {
+            gid |= FLIPPED_HORIZONTALLY;
+        }
+
+        if self.flip_y {
+            gid |= FLIPPED_VERTICALLY;
+        }
+
+        gid
+    }
+}
+
+impl Layer {
+    /// Creates a new empty [`Layer`] with the given name and size, in tiles.
+    ///
+    /// [`Layer`]: struct.Layer.html
+    pub fn new<S: Into<String>>(name: S, width: u32, height: u32) -> Layer {
+        Layer {
+            name: name.into(),
+            width,
+            height,
+            tiles: vec![0; width as usize * height as usize],
+            properties: Properties::new(),
+            is_visible: true,
+            opacity: 1.0,
+        }
+    }
+
+    /// Returns the name of the [`Layer`].
+    ///
+    /// [`Layer`]: struct.Layer.html
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the width of the [`Layer`], in tiles.
+    ///
+    /// [`Layer`]: struct.Layer.html
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Returns the height of the [`Layer`], in tiles.
+    ///
+    /// [`Layer`]: struct.Layer.html
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Returns the [`Tile`] at the given column and row, if any.
+    ///
+    /// [`Tile`]: struct.Tile.html
+    pub fn tile(&self, x: u32, y: u32) -> Option<Tile> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+
+        Tile::from_gid(self.tiles[self.index(x, y)])
+    }
+
+    /// Places a [`Tile`] at the given column and row, or removes it if
+    /// `None`.
+    ///
+    /// # Panics
+    /// It panics if the position is outside of the [`Layer`].
+    ///
+    /// [`Tile`]: struct.Tile.html
+    /// [`Layer`]: struct.Layer.html
+    pub fn set_tile(&mut self, x: u32, y: u32, tile: Option<Tile>) {
+        assert!(
+            x < self.width && y < self.height,
+            "Invalid tile position: ({}, {})",
+            x,
+            y
+        );
+
+        self.tiles[self.index(x, y)] = tile.map(Tile::to_gid).unwrap_or(0);
+    }
+
+    fn index(&self, x: u32, y: u32) -> usize {
+        y as usize * self.width as usize + x as usize
+    }
+
+    pub(super) fn from_gids(
+        name: String,
+        width: u32,
+        height: u32,
+        mut gids: Vec<u32>,
+    ) -> Layer {
+        gids.resize(width as usize * height as usize, 0);
+
+        Layer {
+            name,
+            width,
+            height,
+            tiles: gids,
+            properties: Properties::new(),
+            is_visible: true,
+            opacity: 1.0,
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use std::collections::HashMap;
+
+/// A set of named properties of a [`Tilemap`], a [`Layer`], or a tile.
+///
+/// You can use them to attach game logic to your maps, like marking which
+/// tiles are solid.
+///
+/// [`Tilemap`]: struct.Tilemap.html
+/// [`Layer`]: struct.Layer.html
+pub type Properties = HashMap<String, Property>;
+
+/// A custom property value.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Property {
+    /// A boolean value.
+    Bool(bool),
+
+    /// An integer value.
+    Int(i64),
+
+    /// A floating point value.
+    Float(f64),
+
+    /// A string value.
+    ///
+    /// Other kinds of Tiled properties, like colors and files, are also
+    /// loaded as strings.
+    String(String),
+}
+
+impl Property {
+    /// Returns the value of the [`Property`] if it is a boolean.
+    ///
+    /// [`Property`]: enum.Property.html
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            Property::Bool(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Returns the value of the [`Property`] if it is an integer.
+    ///
+    /// [`Property`]: enum.Property.html
+    pub fn as_int(&self) -> Option<i64> {
+        match self {
+            Property::Int(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Returns the value of the [`Property`] if it is a number.
+    ///
+    /// Integers are converted to floating point.
+    ///
+    /// [`Property`]: enum.Property.html
+    pub fn as_float(&self) -> Option<f64> {
+        match self {
+            Property::Float(value) => Some(*value),
+            Property::Int(value) => Some(*value as f64),
+            _ => None,
+        }
+    }
+
+    /// Returns the value of the [`Property`] if it is a string.
+    ///
+    /// [`Property`]: enum.Property.html
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Property::String(value) => Some(value),
+            _ => None,
+        }
+    }
+}
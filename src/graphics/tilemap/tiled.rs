@@ -0,0 +1,289 @@
+//! Load maps created with the Tiled map editor.
+mod json;
+mod tmx;
+
+use std::path::{Path, PathBuf};
+
+use super::tileset::ArrayTile;
+use super::{Error, Layer, Properties, Tilemap, Tileset};
+use crate::graphics::texture_array::Builder;
+use crate::graphics::{Gpu, Image};
+use crate::Result;
+
+/// The minimum layer size of the texture arrays built for image collections.
+const MIN_ARRAY_SIZE: u16 = 1024;
+
+/// The maximum amount of tiles of an image collection.
+///
+/// Tile ids are read from the map file, so they are bounded before
+/// allocating any tiles.
+const MAX_ARRAY_TILES: u32 = 1 << 16;
+
+pub fn load(gpu: &mut Gpu, path: &Path) -> Result<Tilemap> {
+    let map = match extension(path) {
+        Some("json") => json::map(path)?,
+        Some("tmx") => tmx::map(path)?,
+        _ => return Err(unsupported_format(path)),
+    };
+
+    map.build(gpu)
+}
+
+/// A map, before loading its images.
+struct Map {
+    width: u32,
+    height: u32,
+    tile_width: u16,
+    tile_height: u16,
+    properties: Properties,
+    tilesets: Vec<(u32, TilesetData)>,
+    layers: Vec<Layer>,
+}
+
+/// A tileset, before loading its images.
+///
+/// It either uses a single `image` or an image per tile.
+struct TilesetData {
+    tile_width: u16,
+    tile_height: u16,
+    margin: u16,
+    spacing: u16,
+    image: Option<PathBuf>,
+    tiles: Vec<TileData>,
+}
+
+struct TileData {
+    id: u32,
+    image: Option<TileImage>,
+    properties: Properties,
+}
+
+struct TileImage {
+    path: PathBuf,
+    width: u16,
+    height: u16,
+}
+
+impl Map {
+    fn build(self, gpu: &mut Gpu) -> Result<Tilemap> {
+        let mut tilemap = Tilemap::empty(
+            self.width,
+            self.height,
+            self.tile_width,
+            self.tile_height,
+        );
+
+        for (first_id, tileset) in self.tilesets {
+            tilemap.insert_tileset(first_id, tileset.build(gpu)?);
+        }
+
+        for layer in self.layers {
+            tilemap.add_layer(layer);
+        }
+
+        tilemap.properties = self.properties;
+
+        Ok(tilemap)
+    }
+}
+
+impl TilesetData {
+    fn build(self, gpu: &mut Gpu) -> Result<Tileset> {
+        let mut tileset = match &self.image {
+            Some(path) => {
+                let image = Image::new(gpu, path)?;
+
+                self.validate_spacing(&image)?;
+
+                Tileset::from_image_with_spacing(
+                    image,
+                    self.tile_width,
+                    self.tile_height,
+                    self.margin,
+                    self.spacing,
+                )
+            }
+            None => {
+                let size = self
+                    .tiles
+                    .iter()
+                    .filter_map(|tile| tile.image.as_ref())
+                    .map(|image| image.width.max(image.height))
+                    .max()
+                    .unwrap_or(0)
+                    .max(MIN_ARRAY_SIZE);
+
+                let tile_count = self
+                    .tiles
+                    .iter()
+                    .map(|tile| tile.id.saturating_add(1))
+                    .max()
+                    .unwrap_or(0);
+
+                if tile_count > MAX_ARRAY_TILES {
+                    return Err(invalid(format!(
+                        "tile id {} exceeds the maximum of {}",
+                        tile_count - 1,
+                        MAX_ARRAY_TILES - 1
+                    )));
+                }
+
+                let mut builder = Builder::new(size, size);
+                let mut tiles = vec![None; tile_count as usize];
+
+                for tile in &self.tiles {
+                    if let Some(image) = &tile.image {
+                        tiles[tile.id as usize] = Some(ArrayTile {
+                            index: builder.add(&image.path)?,
+                            width: image.width,
+                            height: image.height,
+                        });
+                    }
+                }
+
+                Tileset::from_array_tiles(builder.build(gpu), tiles)
+            }
+        };
+
+        for tile in self.tiles {
+            if !tile.properties.is_empty() {
+                tileset.set_tile_properties(tile.id, tile.properties);
+            }
+        }
+
+        Ok(tileset)
+    }
+
+    /// Checks that the tiles, margin, and spacing of an image tileset fit in
+    /// its image.
+    ///
+    /// They are read from the map file, so they may be anything.
+    fn validate_spacing(&self, image: &Image) -> Result<()> {
+        let fits = |size: u16, tile_size: u16| {
+            tile_size > 0
+                && self
+                    .margin
+                    .checked_mul(2)
+                    .and_then(|margins| margins.checked_add(tile_size))
+                    .map_or(false, |needed| needed <= size)
+                && tile_size.checked_add(self.spacing).is_some()
+        };
+
+        if fits(image.width(), self.tile_width)
+            && fits(image.height(), self.tile_height)
+        {
+            Ok(())
+        } else {
+            Err(invalid(format!(
+                "the tiles of {}x{} pixels, with a margin of {} and a \
+                 spacing of {}, do not fit in a tileset image of {}x{} pixels",
+                self.tile_width,
+                self.tile_height,
+                self.margin,
+                self.spacing,
+                image.width(),
+                image.height()
+            )))
+        }
+    }
+}
+
+/// Loads an external tileset.
+fn tileset(path: &Path) -> Result<TilesetData> {
+    match extension(path) {
+        Some("json") => json::tileset(path),
+        Some("tsx") => tmx::tileset(path),
+        _ => Err(unsupported_format(path)),
+    }
+}
+
+/// Decodes the tile data of a layer.
+fn decode(
+    data: &str,
+    encoding: Option<&str>,
+    compression: Option<&str>,
+) -> Result<Vec<u32>> {
+    if let Some(compression) = compression {
+        return Err(unsupported(format!("{} compression", compression)));
+    }
+
+    match encoding {
+        Some("csv") => data
+            .split(',')
+            .map(|gid| {
+                gid.trim()
+                    .parse()
+                    .map_err(|_| invalid(format!("invalid tile: {}", gid)))
+            })
+            .collect(),
+        Some("base64") => {
+            let bytes = decode_base64(data.trim())
+                .ok_or_else(|| invalid("invalid base64 data"))?;
+
+            Ok(bytes
+                .chunks_exact(4)
+                .map(|gid| {
+                    let gid = [gid[0], gid[1], gid[2], gid[3]];
+
+                    u32::from_le_bytes(gid)
+                })
+                .collect())
+        }
+        Some(encoding) => Err(unsupported(format!("{} encoding", encoding))),
+        None => Err(invalid("missing tile encoding")),
+    }
+}
+
+fn decode_base64(data: &str) -> Option<Vec<u8>> {
+    let mut bytes = Vec::with_capacity(data.len() * 3 / 4);
+    let mut buffer = 0u32;
+    let mut bits = 0;
+
+    for character in data.bytes() {
+        let value = match character {
+            b'A'..=b'Z' => character - b'A',
+            b'a'..=b'z' => character - b'a' + 26,
+            b'0'..=b'9' => character - b'0' + 52,
+            b'+' => 62,
+            b'/' => 63,
+            b'=' => break,
+            b' ' | b'\t' | b'\r' | b'\n' => continue,
+            _ => return None,
+        };
+
+        buffer = (buffer << 6) | u32::from(value);
+        bits += 6;
+
+        if bits >= 8 {
+            bits -= 8;
+            bytes.push((buffer >> bits) as u8);
+        }
+    }
+
+    Some(bytes)
+}
+
+fn extension(path: &Path) -> Option<&str> {
+    path.extension().and_then(|extension| extension.to_str())
+}
+
+fn check_orientation(orientation: Option<&str>) -> Result<()> {
+    match orientation {
+        None | Some("orthogonal") => Ok(()),
+        Some(orientation) => {
+            Err(unsupported(format!("{} orientation", orientation)))
+        }
+    }
+}
+
+fn unsupported_format(path: &Path) -> crate::Error {
+    crate::Error::Tilemap(Error::UnsupportedFormat(PathBuf::from(path)))
+}
+
+fn unsupported<S: Into<String>>(feature: S) -> crate::Error {
+    crate::Error::Tilemap(Error::Unsupported(feature.into()))
+}
+
+fn invalid<S: Into<String>>(reason: S) -> crate::Error {
+    crate::Error::Tilemap(Error::Invalid(reason.into()))
+}
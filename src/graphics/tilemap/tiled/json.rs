@@ -0,0 +1,215 @@
+use std::fs;
+use std::path::Path;
+
+use serde::Deserialize;
+
+use super::{Map, TileData, TileImage, TilesetData};
+use crate::graphics::tilemap::{Layer, Properties, Property};
+use crate::Result;
+
+#[derive(Deserialize)]
+struct RawMap {
+    width: u32,
+    height: u32,
+    tilewidth: u16,
+    tileheight: u16,
+    orientation: Option<String>,
+    #[serde(default)]
+    infinite: bool,
+    #[serde(default)]
+    layers: Vec<RawLayer>,
+    #[serde(default)]
+    tilesets: Vec<RawTileset>,
+    #[serde(default)]
+    properties: Vec<RawProperty>,
+}
+
+#[derive(Deserialize)]
+struct RawLayer {
+    #[serde(rename = "type")]
+    kind: String,
+    #[serde(default)]
+    name: String,
+    #[serde(default)]
+    width: u32,
+    #[serde(default)]
+    height: u32,
+    data: Option<RawData>,
+    encoding: Option<String>,
+    compression: Option<String>,
+    #[serde(default = "visible")]
+    visible: bool,
+    #[serde(default = "opacity")]
+    opacity: f32,
+    #[serde(default)]
+    properties: Vec<RawProperty>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawData {
+    Tiles(Vec<u32>),
+    Encoded(String),
+}
+
+#[derive(Deserialize)]
+struct RawTileset {
+    firstgid: Option<u32>,
+    source: Option<String>,
+    #[serde(default)]
+    tilewidth: u16,
+    #[serde(default)]
+    tileheight: u16,
+    #[serde(default)]
+    margin: u16,
+    #[serde(default)]
+    spacing: u16,
+    image: Option<String>,
+    #[serde(default)]
+    tiles: Vec<RawTile>,
+}
+
+#[derive(Deserialize)]
+struct RawTile {
+    id: u32,
+    image: Option<String>,
+    #[serde(default)]
+    imagewidth: u16,
+    #[serde(default)]
+    imageheight: u16,
+    #[serde(default)]
+    properties: Vec<RawProperty>,
+}
+
+#[derive(Deserialize)]
+struct RawProperty {
+    name: String,
+    #[serde(rename = "type", default)]
+    kind: String,
+    value: serde_json::Value,
+}
+
+fn visible() -> bool {
+    true
+}
+
+fn opacity() -> f32 {
+    1.0
+}
+
+pub fn map(path: &Path) -> Result<Map> {
+    let raw: RawMap = serde_json::from_slice(&fs::read(path)?)?;
+    let directory = path.parent().unwrap_or_else(|| Path::new(""));
+
+    super::check_orientation(raw.orientation.as_ref().map(String::as_str))?;
+
+    if raw.infinite {
+        return Err(super::unsupported("infinite maps"));
+    }
+
+    let mut tilesets = Vec::new();
+
+    for mut tileset in raw.tilesets {
+        let first_id = tileset
+            .firstgid
+            .ok_or_else(|| super::invalid("missing tileset firstgid"))?;
+
+        let tileset = match tileset.source.take() {
+            Some(source) => super::tileset(&directory.join(source))?,
+            None => tileset_data(tileset, directory),
+        };
+
+        tilesets.push((first_id, tileset));
+    }
+
+    let mut layers = Vec::new();
+
+    for layer in raw.layers {
+        if layer.kind != "tilelayer" {
+            continue;
+        }
+
+        let gids = match layer.data {
+            Some(RawData::Tiles(gids)) => gids,
+            Some(RawData::Encoded(data)) => super::decode(
+                &data,
+                layer.encoding.as_ref().map(String::as_str),
+                layer.compression.as_ref().map(String::as_str),
+            )?,
+            None => return Err(super::unsupported("infinite maps")),
+        };
+
+        let mut tiles =
+            Layer::from_gids(layer.name, layer.width, layer.height, gids);
+
+        tiles.is_visible = layer.visible;
+        tiles.opacity = layer.opacity;
+        tiles.properties = properties(layer.properties);
+
+        layers.push(tiles);
+    }
+
+    Ok(Map {
+        width: raw.width,
+        height: raw.height,
+        tile_width: raw.tilewidth,
+        tile_height: raw.tileheight,
+        properties: properties(raw.properties),
+        tilesets,
+        layers,
+    })
+}
+
+pub fn tileset(path: &Path) -> Result<TilesetData> {
+    let raw: RawTileset = serde_json::from_slice(&fs::read(path)?)?;
+    let directory = path.parent().unwrap_or_else(|| Path::new(""));
+
+    Ok(tileset_data(raw, directory))
+}
+
+fn tileset_data(raw: RawTileset, directory: &Path) -> TilesetData {
+    TilesetData {
+        tile_width: raw.tilewidth,
+        tile_height: raw.tileheight,
+        margin: raw.margin,
+        spacing: raw.spacing,
+        image: raw.image.map(|image| directory.join(image)),
+        tiles: raw
+            .tiles
+            .into_iter()
+            .map(|tile| TileData {
+                id: tile.id,
+                image: tile.image.map(|image| TileImage {
+                    path: directory.join(image),
+                    width: tile.imagewidth,
+                    height: tile.imageheight,
+                }),
+                properties: properties(tile.properties),
+            })
+            .collect(),
+    }
+}
+
+fn properties(raw: Vec<RawProperty>) -> Properties {
+    raw.into_iter()
+        .map(|property| {
+            let value = match (property.kind.as_str(), property.value) {
+                ("bool", serde_json::Value::Bool(value)) => {
+                    Property::Bool(value)
+                }
+                ("int", serde_json::Value::Number(value)) => {
+                    Property::Int(value.as_i64().unwrap_or(0))
+                }
+                ("float", serde_json::Value::Number(value)) => {
+                    Property::Float(value.as_f64().unwrap_or(0.0))
+                }
+                (_, serde_json::Value::String(value)) => {
+                    Property::String(value)
+                }
+                (_, value) => Property::String(value.to_string()),
+            };
+
+            (property.name, value)
+        })
+        .collect()
+}
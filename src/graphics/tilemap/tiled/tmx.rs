@@ -0,0 +1,223 @@
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+use roxmltree::{Document, Node};
+
+use super::{Map, TileData, TileImage, TilesetData};
+use crate::graphics::tilemap::{Layer, Properties, Property};
+use crate::Result;
+
+pub fn map(path: &Path) -> Result<Map> {
+    let source = fs::read_to_string(path)?;
+    let document = parse(&source)?;
+    let directory = path.parent().unwrap_or_else(|| Path::new(""));
+
+    let root = document.root_element();
+
+    if !root.has_tag_name("map") {
+        return Err(super::invalid("missing map element"));
+    }
+
+    super::check_orientation(root.attribute("orientation"))?;
+
+    if optional(root, "infinite")?.unwrap_or(0) != 0 {
+        return Err(super::unsupported("infinite maps"));
+    }
+
+    let mut tilesets = Vec::new();
+    let mut layers = Vec::new();
+
+    for node in root.children().filter(Node::is_element) {
+        match node.tag_name().name() {
+            "tileset" => {
+                let first_id = attribute(node, "firstgid")?;
+
+                let tileset = match node.attribute("source") {
+                    Some(source) => super::tileset(&directory.join(source))?,
+                    None => tileset_data(node, directory)?,
+                };
+
+                tilesets.push((first_id, tileset));
+            }
+            "layer" => layers.push(layer(node)?),
+            _ => {}
+        }
+    }
+
+    Ok(Map {
+        width: attribute(root, "width")?,
+        height: attribute(root, "height")?,
+        tile_width: attribute(root, "tilewidth")?,
+        tile_height: attribute(root, "tileheight")?,
+        properties: properties(root)?,
+        tilesets,
+        layers,
+    })
+}
+
+pub fn tileset(path: &Path) -> Result<TilesetData> {
+    let source = fs::read_to_string(path)?;
+    let document = parse(&source)?;
+    let directory = path.parent().unwrap_or_else(|| Path::new(""));
+
+    let root = document.root_element();
+
+    if !root.has_tag_name("tileset") {
+        return Err(super::invalid("missing tileset element"));
+    }
+
+    tileset_data(root, directory)
+}
+
+fn parse(source: &str) -> Result<Document<'_>> {
+    Document::parse(source).map_err(|error| super::invalid(error.to_string()))
+}
+
+fn tileset_data(node: Node<'_, '_>, directory: &Path) -> Result<TilesetData> {
+    let image = match child(node, "image") {
+        Some(image) => {
+            Some(directory.join(attribute::<String>(image, "source")?))
+        }
+        None => None,
+    };
+
+    let mut tiles = Vec::new();
+
+    for tile in children(node, "tile") {
+        let image = match child(tile, "image") {
+            Some(image) => Some(TileImage {
+                path: directory.join(attribute::<String>(image, "source")?),
+                width: attribute(image, "width")?,
+                height: attribute(image, "height")?,
+            }),
+            None => None,
+        };
+
+        tiles.push(TileData {
+            id: attribute(tile, "id")?,
+            image,
+            properties: properties(tile)?,
+        });
+    }
+
+    Ok(TilesetData {
+        tile_width: attribute(node, "tilewidth")?,
+        tile_height: attribute(node, "tileheight")?,
+        margin: optional(node, "margin")?.unwrap_or(0),
+        spacing: optional(node, "spacing")?.unwrap_or(0),
+        image,
+        tiles,
+    })
+}
+
+fn layer(node: Node<'_, '_>) -> Result<Layer> {
+    let data = child(node, "data")
+        .ok_or_else(|| super::invalid("missing layer data"))?;
+
+    let gids = match data.attribute("encoding") {
+        Some(encoding) => super::decode(
+            data.text().unwrap_or(""),
+            Some(encoding),
+            data.attribute("compression"),
+        )?,
+        None => {
+            let mut gids = Vec::new();
+
+            for tile in children(data, "tile") {
+                gids.push(optional(tile, "gid")?.unwrap_or(0));
+            }
+
+            if gids.is_empty() && child(data, "chunk").is_some() {
+                return Err(super::unsupported("infinite maps"));
+            }
+
+            gids
+        }
+    };
+
+    let mut layer = Layer::from_gids(
+        String::from(node.attribute("name").unwrap_or("")),
+        attribute(node, "width")?,
+        attribute(node, "height")?,
+        gids,
+    );
+
+    layer.is_visible = optional(node, "visible")?.unwrap_or(1) != 0;
+    layer.opacity = optional(node, "opacity")?.unwrap_or(1.0);
+    layer.properties = properties(node)?;
+
+    Ok(layer)
+}
+
+fn properties(node: Node<'_, '_>) -> Result<Properties> {
+    let mut properties = Properties::new();
+
+    let nodes = match child(node, "properties") {
+        Some(nodes) => nodes,
+        None => return Ok(properties),
+    };
+
+    for property in children(nodes, "property") {
+        let name = attribute(property, "name")?;
+
+        // Multiline strings are stored as text instead of an attribute
+        let value = property
+            .attribute("value")
+            .or_else(|| property.text())
+            .unwrap_or("");
+
+        let value = match property.attribute("type") {
+            Some("bool") => Property::Bool(value == "true"),
+            Some("int") => Property::Int(parse_value("property", value)?),
+            Some("float") => Property::Float(parse_value("property", value)?),
+            _ => Property::String(String::from(value)),
+        };
+
+        let _ = properties.insert(name, value);
+    }
+
+    Ok(properties)
+}
+
+fn child<'a, 'input>(
+    node: Node<'a, 'input>,
+    name: &str,
+) -> Option<Node<'a, 'input>> {
+    children(node, name).next()
+}
+
+fn children<'a, 'input: 'a, 'name>(
+    node: Node<'a, 'input>,
+    name: &'name str,
+) -> impl Iterator<Item = Node<'a, 'input>> + 'name
+where
+    'a: 'name,
+{
+    node.children()
+        .filter(move |child| child.has_tag_name(name))
+}
+
+fn attribute<T: FromStr>(node: Node<'_, '_>, name: &str) -> Result<T> {
+    optional(node, name)?.ok_or_else(|| {
+        super::invalid(format!(
+            "missing attribute `{}` in `{}`",
+            name,
+            node.tag_name().name()
+        ))
+    })
+}
+
+fn optional<T: FromStr>(node: Node<'_, '_>, name: &str) -> Result<Option<T>> {
+    match node.attribute(name) {
+        Some(value) => parse_value(name, value).map(Some),
+        None => Ok(None),
+    }
+}
+
+fn parse_value<T: FromStr>(name: &str, value: &str) -> Result<T> {
+    value
+        .trim()
+        .parse()
+        .map_err(|_| super::invalid(format!("invalid {}: {}", name, value)))
+}
@@ -0,0 +1,302 @@
+use std::collections::HashMap;
+
+use super::Properties;
+use crate::graphics::texture_array::{self, Index, TextureArray};
+use crate::graphics::{Batch, Image, Point, Rectangle, Sprite, Target};
+
+/// A collection of tiles that can be used by a [`Tilemap`].
+///
+/// The tiles of a [`Tileset`] are identified by their local id, starting at
+/// `0`. A [`Tileset`] can be backed by:
+///   * An [`Image`], where tiles are laid out in rows from left to right.
+///   * A [`TextureArray`], where every tile is a different [`Index`].
+///
+/// [`Tilemap`]: struct.Tilemap.html
+/// [`Tileset`]: struct.Tileset.html
+/// [`Image`]: ../struct.Image.html
+/// [`TextureArray`]: ../texture_array/struct.TextureArray.html
+/// [`Index`]: ../texture_array/struct.Index.html
+#[derive(Debug, Clone)]
+pub struct Tileset {
+    source: Source,
+    properties: HashMap<u32, Properties>,
+}
+
+#[derive(Debug, Clone)]
+enum Source {
+    Image {
+        image: Image,
+        tile_width: u16,
+        tile_height: u16,
+        columns: u16,
+        tile_count: u32,
+        margin: u16,
+        spacing: u16,
+    },
+    TextureArray {
+        texture_array: TextureArray,
+        tiles: Vec<Option<ArrayTile>>,
+    },
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(super) struct ArrayTile {
+    pub index: Index,
+    pub width: u16,
+    pub height: u16,
+}
+
+impl Tileset {
+    /// Creates a new [`Tileset`] from an [`Image`] split in tiles of the
+    /// given size.
+    ///
+    /// [`Tileset`]: struct.Tileset.html
+    /// [`Image`]: ../struct.Image.html
+    pub fn from_image(
+        image: Image,
+        tile_width: u16,
+        tile_height: u16,
+    ) -> Tileset {
+        Tileset::from_image_with_spacing(image, tile_width, tile_height, 0, 0)
+    }
+
+    /// Creates a new [`Tileset`] from an [`Image`] split in tiles of the
+    /// given size, with a `margin` around the [`Image`] and some `spacing`
+    /// between tiles, in pixels.
+    ///
+    /// [`Tileset`]: struct.Tileset.html
+    /// [`Image`]: ../struct.Image.html
+    pub fn from_image_with_spacing(
+        image: Image,
+        tile_width: u16,
+        tile_height: u16,
+        margin: u16,
+        spacing: u16,
+    ) -> Tileset {
+        let fit = |size: u16, tile_size: u16| {
+            let available = u32::from(size)
+                .saturating_sub(u32::from(margin) * 2)
+                + u32::from(spacing);
+
+            let tiles =
+                available / (u32::from(tile_size) + u32::from(spacing)).max(1);
+
+            tiles.min(u32::from(u16::max_value())) as u16
+        };
+
+        let columns = fit(image.width(), tile_width);
+        let rows = fit(image.height(), tile_height);
+
+        Tileset {
+            source: Source::Image {
+                image,
+                tile_width,
+                tile_height,
+                columns,
+                tile_count: u32::from(columns) * u32::from(rows),
+                margin,
+                spacing,
+            },
+            properties: HashMap::new(),
+        }
+    }
+
+    /// Creates a new [`Tileset`] from a [`TextureArray`].
+    ///
+    /// Every [`Index`] becomes a tile of the given size, in order.
+    ///
+    /// [`Tileset`]: struct.Tileset.html
+    /// [`TextureArray`]: ../texture_array/struct.TextureArray.html
+    /// [`Index`]: ../texture_array/struct.Index.html
+    pub fn from_texture_array(
+        texture_array: TextureArray,
+        indices: &[Index],
+        tile_width: u16,
+        tile_height: u16,
+    ) -> Tileset {
+        let tiles = indices
+            .iter()
+            .map(|index| {
+                Some(ArrayTile {
+                    index: *index,
+                    width: tile_width,
+                    height: tile_height,
+                })
+            })
+            .collect();
+
+        Tileset::from_array_tiles(texture_array, tiles)
+    }
+
+    pub(super) fn from_array_tiles(
+        texture_array: TextureArray,
+        tiles: Vec<Option<ArrayTile>>,
+    ) -> Tileset {
+        Tileset {
+            source: Source::TextureArray {
+                texture_array,
+                tiles,
+            },
+            properties: HashMap::new(),
+        }
+    }
+
+    /// Returns the amount of tiles in the [`Tileset`].
+    ///
+    /// [`Tileset`]: struct.Tileset.html
+    pub fn tile_count(&self) -> u32 {
+        match &self.source {
+            Source::Image { tile_count, .. } => *tile_count,
+            Source::TextureArray { tiles, .. } => tiles.len() as u32,
+        }
+    }
+
+    /// Returns the [`Properties`] of the tile with the given local id, if it
+    /// has any.
+    ///
+    /// [`Properties`]: type.Properties.html
+    pub fn tile_properties(&self, id: u32) -> Option<&Properties> {
+        self.properties.get(&id)
+    }
+
+    /// Sets the [`Properties`] of the tile with the given local id.
+    ///
+    /// [`Properties`]: type.Properties.html
+    pub fn set_tile_properties(&mut self, id: u32, properties: Properties) {
+        let _ = self.properties.insert(id, properties);
+    }
+
+    /// Returns the maximum height of the tiles in the [`Tileset`].
+    ///
+    /// [`Tileset`]: struct.Tileset.html
+    pub(super) fn max_tile_height(&self) -> u16 {
+        match &self.source {
+            Source::Image { tile_height, .. } => *tile_height,
+            Source::TextureArray { tiles, .. } => tiles
+                .iter()
+                .filter_map(|tile| tile.map(|tile| tile.height))
+                .max()
+                .unwrap_or(0),
+        }
+    }
+
+    pub(super) fn batch(&self) -> TileBatch {
+        match &self.source {
+            Source::Image { image, .. } => {
+                TileBatch::Image(Batch::new(image.clone()))
+            }
+            Source::TextureArray { texture_array, .. } => {
+                TileBatch::TextureArray(texture_array::Batch::new(
+                    texture_array.clone(),
+                ))
+            }
+        }
+    }
+
+    /// Adds the tile with the given local id to a [`TileBatch`] created by
+    /// this [`Tileset`].
+    ///
+    /// The bottom-left corner of the tile is placed at `bottom_left`.
+    pub(super) fn add(
+        &self,
+        batch: &mut TileBatch,
+        id: u32,
+        bottom_left: Point,
+        sprite: Sprite,
+    ) {
+        match (&self.source, batch) {
+            (
+                Source::Image {
+                    tile_width,
+                    tile_height,
+                    columns,
+                    tile_count,
+                    margin,
+                    spacing,
+                    ..
+                },
+                TileBatch::Image(batch),
+            ) => {
+                if id >= *tile_count {
+                    return;
+                }
+
+                let column = id % u32::from(*columns);
+                let row = id / u32::from(*columns);
+
+                // Tiles are inside the image, so their position fits
+                let offset = |index: u32, tile_size: u16| {
+                    (u32::from(*margin)
+                        + index * (u32::from(tile_size) + u32::from(*spacing)))
+                        as u16
+                };
+
+                batch.add(Sprite {
+                    source: Rectangle {
+                        x: offset(column, *tile_width),
+                        y: offset(row, *tile_height),
+                        width: *tile_width,
+                        height: *tile_height,
+                    },
+                    position: Point::new(
+                        bottom_left.x,
+                        bottom_left.y - f32::from(*tile_height),
+                    ),
+                    ..sprite
+                });
+            }
+            (
+                Source::TextureArray { tiles, .. },
+                TileBatch::TextureArray(batch),
+            ) => {
+                let tile = match tiles.get(id as usize) {
+                    Some(Some(tile)) => tile,
+                    _ => return,
+                };
+
+                batch.add(
+                    &tile.index,
+                    Sprite {
+                        source: Rectangle {
+                            x: 0,
+                            y: 0,
+                            width: tile.width,
+                            height: tile.height,
+                        },
+                        position: Point::new(
+                            bottom_left.x,
+                            bottom_left.y - f32::from(tile.height),
+                        ),
+                        ..sprite
+                    },
+                );
+            }
+            _ => {}
+        }
+    }
+}
+
+/// The batch used to draw the tiles of a [`Tileset`].
+///
+/// [`Tileset`]: struct.Tileset.html
+#[derive(Debug)]
+pub(super) enum TileBatch {
+    Image(Batch),
+    TextureArray(texture_array::Batch),
+}
+
+impl TileBatch {
+    pub fn draw(&self, target: &mut Target<'_>) {
+        match self {
+            TileBatch::Image(batch) => batch.draw(target),
+            TileBatch::TextureArray(batch) => batch.draw(target),
+        }
+    }
+
+    pub fn clear(&mut self) {
+        match self {
+            TileBatch::Image(batch) => batch.clear(),
+            TileBatch::TextureArray(batch) => batch.clear(),
+        }
+    }
+}
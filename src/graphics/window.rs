@@ -8,7 +8,11 @@ pub(crate) use event::{Event, EventLoop};
 pub use frame::Frame;
 pub use settings::Settings;
 
+#[cfg(feature = "audio")]
+use crate::audio::Mixer;
 use crate::graphics::gpu::{self, Gpu};
+#[cfg(feature = "software")]
+use crate::graphics::Canvas;
 use crate::Result;
 
 /// An open window.
@@ -19,6 +23,8 @@ use crate::Result;
 pub struct Window {
     gpu: Gpu,
     surface: gpu::Surface,
+    #[cfg(feature = "audio")]
+    mixer: Mixer,
     width: f32,
     height: f32,
     is_fullscreen: bool,
@@ -63,11 +69,36 @@ impl Window {
             is_fullscreen,
             gpu,
             surface,
+            #[cfg(feature = "audio")]
+            mixer: Mixer::new(),
             width,
             height,
         })
     }
 
+    #[cfg(feature = "software")]
+    pub(crate) fn headless(settings: Settings) -> Window {
+        let (width, height) = settings.size;
+
+        let (gpu, surface) =
+            Gpu::for_headless_window(width as u16, height as u16);
+
+        Window {
+            is_fullscreen: settings.fullscreen,
+            gpu,
+            surface,
+            #[cfg(feature = "audio")]
+            mixer: Mixer::null(),
+            width: width as f32,
+            height: height as f32,
+        }
+    }
+
+    #[cfg(feature = "software")]
+    pub(crate) fn canvas(&self) -> Canvas {
+        Canvas::from_drawable(self.surface.drawable().clone())
+    }
+
     /// Returns the [`Gpu`] linked to the [`Window`].
     ///
     /// [`Gpu`]: struct.Gpu.html
@@ -76,6 +107,15 @@ impl Window {
         &mut self.gpu
     }
 
+    /// Returns the audio [`Mixer`] linked to the [`Window`].
+    ///
+    /// [`Mixer`]: ../audio/struct.Mixer.html
+    /// [`Window`]: struct.Window.html
+    #[cfg(feature = "audio")]
+    pub fn mixer(&self) -> &Mixer {
+        &self.mixer
+    }
+
     pub(crate) fn frame(&mut self) -> Frame<'_> {
         Frame::new(self)
     }
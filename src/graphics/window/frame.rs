@@ -61,4 +61,17 @@ impl<'a> Frame<'a> {
     pub fn clear(&mut self, color: Color) {
         self.as_target().clear(color);
     }
+
+    /// Takes a screenshot of the frame.
+    ///
+    /// The screenshot contains everything that has been drawn on the frame so
+    /// far. Therefore, you will most likely want to call this at the end of
+    /// [`Game::draw`].
+    ///
+    /// _Note:_ This is a very slow operation.
+    ///
+    /// [`Game::draw`]: ../trait.Game.html#tymethod.draw
+    pub fn screenshot(&mut self) -> image::DynamicImage {
+        self.window.surface.read_pixels(&mut self.window.gpu)
+    }
 }
@@ -7,12 +7,16 @@ pub mod window;
 
 mod event;
 mod keyboard_and_mouse;
+mod recording;
 
 pub use crate::graphics::window::winit::ElementState as ButtonState;
 pub use event::Event;
 pub use keyboard::Keyboard;
 pub use keyboard_and_mouse::KeyboardAndMouse;
 pub use mouse::Mouse;
+pub use recording::Recording;
+
+pub(crate) use recording::Tape;
 
 /// The input of your [`Game`].
 ///
@@ -13,6 +13,10 @@ use std::time::SystemTime;
 /// [`Game::Input`]: ../trait.Game.html#associatedtype.Input
 /// [`Input`]: trait.Input.html
 #[derive(PartialEq, Clone, Copy, Debug)]
+#[cfg_attr(
+    feature = "recording",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub enum Event {
     /// A keyboard event
     Keyboard(keyboard::Event),
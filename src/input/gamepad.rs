@@ -13,7 +13,11 @@ use std::time::SystemTime;
 
 /// A gamepad identifier.
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
-pub struct Id(gilrs::GamepadId);
+#[cfg_attr(
+    feature = "recording",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct Id(usize);
 
 pub(crate) struct Tracker {
     context: Gilrs,
@@ -43,7 +47,7 @@ impl Tracker {
         {
             match event.try_into() {
                 Ok(gamepad_event) => {
-                    return Some((Id(id), gamepad_event, time));
+                    return Some((Id(id.into()), gamepad_event, time));
                 }
                 Err(_) => {}
             }
@@ -3,6 +3,10 @@ use crate::input::ButtonState;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 /// A keyboard event.
+#[cfg_attr(
+    feature = "recording",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub enum Event {
     /// A keyboard key was pressed or released.
     Input {
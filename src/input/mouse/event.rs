@@ -3,6 +3,10 @@ use crate::input::ButtonState;
 
 /// A mouse event.
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "recording",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub enum Event {
     /// The mouse cursor was moved
     CursorMoved {
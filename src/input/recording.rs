@@ -0,0 +1,183 @@
+use crate::input::{Event, Input};
+
+#[cfg(feature = "recording")]
+use crate::load::Task;
+#[cfg(feature = "recording")]
+use crate::Result;
+#[cfg(feature = "recording")]
+use std::path::Path;
+
+/// A log of input events, together with the tick they were processed on.
+///
+/// Coffee uses a fixed timestep. Therefore, feeding the same input events on
+/// the same ticks reproduces a play session exactly. You can obtain a
+/// [`Recording`] by running your game with [`Game::record`] and play it back
+/// with [`Game::replay`] or a [`Simulation`].
+///
+/// When the `recording` feature is enabled, a [`Recording`] can be saved to
+/// and loaded from a file. This way, a bug report can ship with a
+/// reproducible replay! This feature is only supported by the `opengl` and
+/// `software` backends.
+///
+/// [`Recording`]: struct.Recording.html
+/// [`Game::record`]: ../trait.Game.html#method.record
+/// [`Game::replay`]: ../trait.Game.html#method.replay
+/// [`Simulation`]: ../struct.Simulation.html
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(
+    feature = "recording",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct Recording {
+    events: Vec<(u64, Event)>,
+}
+
+impl Recording {
+    /// Creates a new empty [`Recording`].
+    ///
+    /// [`Recording`]: struct.Recording.html
+    pub fn new() -> Recording {
+        Recording { events: Vec::new() }
+    }
+
+    /// Logs an input event that was processed on the given tick.
+    ///
+    /// The events are kept ordered by tick. Events of the same tick keep the
+    /// order they were logged in.
+    pub fn push(&mut self, tick: u64, event: Event) {
+        let index = self
+            .events
+            .iter()
+            .rposition(|(logged, _)| *logged <= tick)
+            .map_or(0, |index| index + 1);
+
+        self.events.insert(index, (tick, event));
+    }
+
+    /// Returns the logged events, in order, together with their tick.
+    pub fn events(&self) -> &[(u64, Event)] {
+        &self.events
+    }
+
+    /// Returns the tick of the last logged event, if any.
+    pub fn last_tick(&self) -> Option<u64> {
+        self.events.last().map(|(tick, _)| *tick)
+    }
+
+    /// Saves the [`Recording`] to the given file.
+    ///
+    /// [`Recording`]: struct.Recording.html
+    #[cfg(feature = "recording")]
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let file = std::fs::File::create(path)?;
+
+        serde_json::to_writer(std::io::BufWriter::new(file), self)?;
+
+        Ok(())
+    }
+
+    /// Opens a [`Recording`] from the given file.
+    ///
+    /// [`Recording`]: struct.Recording.html
+    #[cfg(feature = "recording")]
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Recording> {
+        let file = std::fs::File::open(path)?;
+        let mut recording: Recording =
+            serde_json::from_reader(std::io::BufReader::new(file))?;
+
+        // The file may have been edited by hand
+        recording.events.sort_by_key(|(tick, _)| *tick);
+
+        Ok(recording)
+    }
+
+    /// Creates a [`Task`] that opens a [`Recording`] from the given file.
+    ///
+    /// [`Task`]: ../load/struct.Task.html
+    /// [`Recording`]: struct.Recording.html
+    #[cfg(feature = "recording")]
+    pub fn load<P: AsRef<Path>>(path: P) -> Task<Recording> {
+        let path = path.as_ref().to_path_buf();
+
+        Task::new(move || Recording::open(path))
+    }
+}
+
+/// The source of the input events of a game loop.
+///
+/// It counts the ticks of the loop and either lets live events through,
+/// optionally logging them, or replays a [`Recording`] instead.
+///
+/// [`Recording`]: struct.Recording.html
+pub(crate) struct Tape {
+    tick: u64,
+    mode: Mode,
+}
+
+enum Mode {
+    Live,
+    Record(Recording),
+    Replay { recording: Recording, next: usize },
+}
+
+impl Tape {
+    pub fn live() -> Tape {
+        Tape {
+            tick: 0,
+            mode: Mode::Live,
+        }
+    }
+
+    pub fn record() -> Tape {
+        Tape {
+            tick: 0,
+            mode: Mode::Record(Recording::new()),
+        }
+    }
+
+    pub fn replay(recording: Recording) -> Tape {
+        Tape {
+            tick: 0,
+            mode: Mode::Replay { recording, next: 0 },
+        }
+    }
+
+    /// Feeds a live event to the given input.
+    ///
+    /// Live events are ignored while replaying.
+    pub fn feed<I: Input>(&mut self, input: &mut I, event: Event) {
+        match &mut self.mode {
+            Mode::Live => input.update(event),
+            Mode::Record(recording) => {
+                recording.push(self.tick, event);
+                input.update(event);
+            }
+            Mode::Replay { .. } => {}
+        }
+    }
+
+    /// Feeds the recorded events of the current tick to the given input.
+    pub fn play<I: Input>(&mut self, input: &mut I) {
+        if let Mode::Replay { recording, next } = &mut self.mode {
+            while let Some((tick, event)) = recording.events.get(*next) {
+                if *tick > self.tick {
+                    break;
+                }
+
+                input.update(*event);
+                *next += 1;
+            }
+        }
+    }
+
+    pub fn tick(&mut self) {
+        self.tick += 1;
+    }
+
+    pub fn into_recording(self) -> Option<Recording> {
+        match self.mode {
+            Mode::Record(recording) => Some(recording),
+            _ => None,
+        }
+    }
+}
@@ -1,5 +1,9 @@
 /// A window event.
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "recording",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub enum Event {
     /// The game window gained focus.
     Focused,
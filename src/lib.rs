@@ -81,12 +81,20 @@ mod game;
 mod result;
 mod timer;
 
+#[cfg(feature = "software")]
+mod simulation;
+
+#[cfg(feature = "audio")]
+pub mod audio;
 pub mod graphics;
 pub mod input;
 pub mod load;
+pub mod testing;
 pub mod ui;
 
 pub use debug::Debug;
 pub use game::Game;
 pub use result::{Error, Result};
+#[cfg(feature = "software")]
+pub use simulation::Simulation;
 pub use timer::Timer;
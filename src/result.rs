@@ -2,7 +2,9 @@ use std::error;
 use std::fmt;
 use std::io;
 
-use crate::graphics::texture_array;
+#[cfg(feature = "audio")]
+use crate::audio;
+use crate::graphics::{atlas, texture_array, tilemap};
 
 /// A convenient result with a locked [`Error`] type.
 ///
@@ -21,11 +23,40 @@ pub enum Error {
     /// A texture array failed to load.
     TextureArray(texture_array::Error),
 
+    /// A tilemap failed to load.
+    Tilemap(tilemap::Error),
+
+    /// An atlas failed to pack an image.
+    Atlas(atlas::Error),
+
+    /// A shader failed to compile.
+    Shader(String),
+
+    /// A multisampled canvas needs more samples than the graphics processor
+    /// supports.
+    UnsupportedSamples {
+        /// The number of samples requested.
+        requested: u16,
+
+        /// The maximum number of samples supported.
+        supported: u16,
+    },
+
+    /// A font failed to load.
+    Font(String),
+
     /// A file failed to load.
     IO(io::Error),
 
     /// An image failed to load.
     Image(image::ImageError),
+
+    /// A sound or music track failed to load.
+    #[cfg(feature = "audio")]
+    Audio(audio::Error),
+
+    /// A file could not be serialized or deserialized.
+    Serialization(serde_json::Error),
 }
 
 impl fmt::Display for Error {
@@ -37,8 +68,25 @@ impl fmt::Display for Error {
             Error::TextureArray(error) => {
                 write!(f, "Texture array error: {}", error)
             }
+            Error::Tilemap(error) => write!(f, "Tilemap error: {}", error),
+            Error::Atlas(error) => write!(f, "Atlas error: {}", error),
+            Error::Shader(error) => write!(f, "Shader error: {}", error),
+            Error::UnsupportedSamples {
+                requested,
+                supported,
+            } => write!(
+                f,
+                "Unsupported number of samples: {} (maximum: {})",
+                requested, supported
+            ),
+            Error::Font(error) => write!(f, "Font error: {}", error),
             Error::IO(error) => write!(f, "IO error: {}", error),
             Error::Image(error) => write!(f, "Image error: {}", error),
+            #[cfg(feature = "audio")]
+            Error::Audio(error) => write!(f, "Audio error: {}", error),
+            Error::Serialization(error) => {
+                write!(f, "Serialization error: {}", error)
+            }
         }
     }
 }
@@ -48,6 +96,11 @@ impl error::Error for Error {
         match self {
             Error::IO(error) => Some(error),
             Error::Image(error) => Some(error),
+            #[cfg(feature = "audio")]
+            Error::Audio(audio::Error::Wav(error)) => Some(error),
+            #[cfg(feature = "audio")]
+            Error::Audio(audio::Error::Ogg(error)) => Some(error),
+            Error::Serialization(error) => Some(error),
             _ => None,
         }
     }
@@ -64,3 +117,16 @@ impl From<image::ImageError> for Error {
         Error::Image(error)
     }
 }
+
+#[cfg(feature = "audio")]
+impl From<audio::Error> for Error {
+    fn from(error: audio::Error) -> Error {
+        Error::Audio(error)
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(error: serde_json::Error) -> Error {
+        Error::Serialization(error)
+    }
+}
@@ -0,0 +1,235 @@
+use std::collections::BTreeMap;
+
+use crate::graphics::{Canvas, Gpu, Window, WindowSettings};
+use crate::input::{self, Input, Recording};
+use crate::{Game, Result, Timer};
+
+/// A deterministic, windowless runner for a [`Game`].
+///
+/// A [`Simulation`] loads a [`Game`] without opening a window and lets you
+/// advance it tick by tick, while feeding it a scripted sequence of input
+/// events.
+///
+/// Time is virtual: every tick advances the [`Timer`] exactly
+/// `1 / Game::TICKS_PER_SECOND` seconds, no matter how long it actually takes
+/// to run. Therefore, the same script always produces the same results, which
+/// makes a [`Simulation`] a great fit for gameplay regression tests.
+///
+/// A [`Simulation`] is only available when using the `software` graphics
+/// backend.
+///
+/// ```no_run
+/// # use coffee::graphics::{Frame, Window, WindowSettings};
+/// # use coffee::input::{keyboard, ButtonState, Event, Keyboard};
+/// # use coffee::load::Task;
+/// # use coffee::{Game, Result, Simulation, Timer};
+/// #
+/// # struct MyGame;
+/// #
+/// # impl Game for MyGame {
+/// #     type Input = Keyboard;
+/// #     type LoadingScreen = ();
+/// #
+/// #     fn load(_window: &Window) -> Task<MyGame> {
+/// #         Task::succeed(|| MyGame)
+/// #     }
+/// #
+/// #     fn draw(&mut self, _frame: &mut Frame, _timer: &Timer) {}
+/// # }
+/// #
+/// # fn main() -> Result<()> {
+/// let mut simulation = Simulation::<MyGame>::new(WindowSettings {
+///     title: String::from("Jump test"),
+///     size: (640, 480),
+///     resizable: false,
+///     fullscreen: false,
+///     maximized: false,
+/// })?;
+///
+/// simulation.schedule(
+///     10,
+///     Event::Keyboard(keyboard::Event::Input {
+///         state: ButtonState::Pressed,
+///         key_code: keyboard::KeyCode::Space,
+///     }),
+/// );
+///
+/// simulation.run(60);
+///
+/// // Check the state of the game here using `simulation.game()`
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`Game`]: trait.Game.html
+/// [`Simulation`]: struct.Simulation.html
+/// [`Timer`]: struct.Timer.html
+pub struct Simulation<G: Game> {
+    game: G,
+    input: G::Input,
+    window: Window,
+    timer: Timer,
+    tick: u64,
+    events: BTreeMap<u64, Vec<input::Event>>,
+}
+
+impl<G: Game> Simulation<G> {
+    /// Loads a [`Game`] and prepares a [`Simulation`] for it.
+    ///
+    /// The [`Game`] is loaded using [`Task::run`] on a headless [`Window`]
+    /// with the size of the given [`WindowSettings`]. No loading screen is
+    /// shown.
+    ///
+    /// [`Game`]: trait.Game.html
+    /// [`Simulation`]: struct.Simulation.html
+    /// [`Task::run`]: load/struct.Task.html#method.run
+    /// [`Window`]: graphics/struct.Window.html
+    /// [`WindowSettings`]: graphics/struct.WindowSettings.html
+    pub fn new(window_settings: WindowSettings) -> Result<Simulation<G>> {
+        let mut window = Window::headless(window_settings);
+        let game = G::load(&window).run(window.gpu())?;
+
+        Ok(Simulation {
+            game,
+            input: G::Input::new(),
+            window,
+            timer: Timer::new(G::TICKS_PER_SECOND),
+            tick: 0,
+            events: BTreeMap::new(),
+        })
+    }
+
+    /// Schedules an input event to be fed to the [`Game`] right before the
+    /// given tick.
+    ///
+    /// Events scheduled for a tick that has already been simulated are fed
+    /// before the next one. Events scheduled for the same tick are fed in
+    /// order.
+    ///
+    /// [`Game`]: trait.Game.html
+    pub fn schedule(&mut self, tick: u64, event: input::Event) {
+        self.events
+            .entry(tick.max(self.tick))
+            .or_insert_with(Vec::new)
+            .push(event);
+    }
+
+    /// Schedules all the events of a [`Recording`] on the ticks they were
+    /// recorded on.
+    ///
+    /// [`Recording`]: input/struct.Recording.html
+    pub fn replay(&mut self, recording: &Recording) {
+        for (tick, event) in recording.events() {
+            self.schedule(*tick, *event);
+        }
+    }
+
+    /// Advances the [`Simulation`] a single tick.
+    ///
+    /// The scheduled events for the current tick are fed to the [`Game`]
+    /// input, and then [`Game::interact`] and [`Game::update`] are called, in
+    /// this order.
+    ///
+    /// [`Simulation`]: struct.Simulation.html
+    /// [`Game`]: trait.Game.html
+    /// [`Game::interact`]: trait.Game.html#method.interact
+    /// [`Game::update`]: trait.Game.html#method.update
+    pub fn step(&mut self) {
+        let delta = self.timer.target_delta();
+        self.timer.advance(delta);
+
+        while self.timer.tick() {
+            if let Some(events) = self.events.remove(&self.tick) {
+                for event in events {
+                    self.input.update(event);
+                }
+            }
+
+            self.game.interact(&mut self.input, &mut self.window);
+            self.input.clear();
+
+            self.game.update(&self.window);
+            self.tick += 1;
+        }
+    }
+
+    /// Advances the [`Simulation`] the given amount of ticks.
+    ///
+    /// It stops early if the [`Game`] finishes.
+    ///
+    /// [`Simulation`]: struct.Simulation.html
+    /// [`Game`]: trait.Game.html
+    pub fn run(&mut self, ticks: u64) {
+        for _ in 0..ticks {
+            if self.game.is_finished() {
+                break;
+            }
+
+            self.step();
+        }
+    }
+
+    /// Draws the current state of the [`Game`] and returns the resulting
+    /// frame as a [`Canvas`].
+    ///
+    /// The returned [`Canvas`] shares its contents with the headless frame.
+    /// Draw it somewhere else or read its pixels before calling [`draw`]
+    /// again if you want to keep it.
+    ///
+    /// [`Game`]: trait.Game.html
+    /// [`Canvas`]: graphics/struct.Canvas.html
+    /// [`draw`]: #method.draw
+    pub fn draw(&mut self) -> Canvas {
+        self.game.draw(&mut self.window.frame(), &self.timer);
+        self.window.swap_buffers();
+
+        self.window.canvas()
+    }
+
+    /// Returns the amount of ticks simulated so far.
+    pub fn tick(&self) -> u64 {
+        self.tick
+    }
+
+    /// Returns a reference to the simulated [`Game`].
+    ///
+    /// [`Game`]: trait.Game.html
+    pub fn game(&self) -> &G {
+        &self.game
+    }
+
+    /// Returns a mutable reference to the simulated [`Game`].
+    ///
+    /// [`Game`]: trait.Game.html
+    pub fn game_mut(&mut self) -> &mut G {
+        &mut self.game
+    }
+
+    /// Returns the [`Gpu`] of the headless [`Window`].
+    ///
+    /// You will need it to read the pixels of a drawn frame.
+    ///
+    /// [`Gpu`]: graphics/struct.Gpu.html
+    /// [`Window`]: graphics/struct.Window.html
+    pub fn gpu(&mut self) -> &mut Gpu {
+        self.window.gpu()
+    }
+
+    /// Consumes the [`Simulation`] and returns the simulated [`Game`].
+    ///
+    /// [`Simulation`]: struct.Simulation.html
+    /// [`Game`]: trait.Game.html
+    pub fn into_game(self) -> G {
+        self.game
+    }
+}
+
+impl<G: Game> std::fmt::Debug for Simulation<G> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Simulation {{ tick: {}, window: {:?} }}",
+            self.tick, self.window
+        )
+    }
+}
@@ -0,0 +1,305 @@
+//! Test your drawings against model images.
+//!
+//! A [`Harness`] runs a drawing [`Task`] and compares the resulting [`Canvas`]
+//! with a model image stored as a PNG file. It does not need any user
+//! interaction, so it can be used in your `cargo test` suite:
+//!
+//! ```no_run
+//! use coffee::graphics::{Canvas, Color, Gpu};
+//! use coffee::load::Task;
+//! use coffee::testing::Harness;
+//!
+//! fn red_square() -> Task<Canvas> {
+//!     Task::using_gpu(|gpu| {
+//!         let mut canvas = Canvas::new(gpu, 100, 100)?;
+//!         canvas.as_target(gpu).clear(Color::RED);
+//!
+//!         Ok(canvas)
+//!     })
+//! }
+//!
+//! # fn test(gpu: &mut Gpu) {
+//! let harness = Harness::new("tests/models").tolerance(2);
+//!
+//! harness.assert(gpu, "red_square", red_square());
+//! # }
+//! ```
+//!
+//! Checks fail when a model image does not exist. Run your tests with the
+//! `COFFEE_UPDATE_MODELS` environment variable set to save your drawings as
+//! the new models, and commit them! Do the same when you change your drawings
+//! on purpose to overwrite the outdated models.
+//!
+//! [`Harness`]: struct.Harness.html
+//! [`Task`]: ../load/struct.Task.html
+//! [`Canvas`]: ../graphics/struct.Canvas.html
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::graphics::{Canvas, Gpu};
+use crate::load::Task;
+
+/// The environment variable that makes a [`Harness`] overwrite its model
+/// images.
+///
+/// [`Harness`]: struct.Harness.html
+pub const UPDATE_MODELS: &str = "COFFEE_UPDATE_MODELS";
+
+/// A golden-image test harness.
+///
+/// It compares drawings with model images, pixel by pixel. When a drawing does
+/// not match its model, the [`Harness`] writes the drawing and an image
+/// highlighting the different pixels in its [`failures`] directory.
+///
+/// [`Harness`]: struct.Harness.html
+/// [`failures`]: #method.failures
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Harness {
+    models: PathBuf,
+    failures: PathBuf,
+    tolerance: u8,
+    update: bool,
+}
+
+impl Harness {
+    /// Creates a new [`Harness`] that keeps model images in the given
+    /// directory.
+    ///
+    /// By default, failures are written in a `failures` directory inside of
+    /// it and pixels need to match exactly.
+    ///
+    /// [`Harness`]: struct.Harness.html
+    pub fn new<P: Into<PathBuf>>(models: P) -> Harness {
+        let models = models.into();
+
+        Harness {
+            failures: models.join("failures"),
+            models,
+            tolerance: 0,
+            update: std::env::var_os(UPDATE_MODELS).is_some(),
+        }
+    }
+
+    /// Sets the maximum difference allowed between the channels of two
+    /// pixels for them to be considered equal.
+    ///
+    /// A small tolerance helps to ignore slight rounding differences between
+    /// graphics backends.
+    pub fn tolerance(mut self, tolerance: u8) -> Harness {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Sets the directory where the drawings and their differences are
+    /// written when a check fails.
+    pub fn failures<P: Into<PathBuf>>(mut self, directory: P) -> Harness {
+        self.failures = directory.into();
+        self
+    }
+
+    /// Runs the given drawing [`Task`] and compares the produced [`Canvas`]
+    /// with the model image of the given name.
+    ///
+    /// If the `COFFEE_UPDATE_MODELS` environment variable is set, the drawing
+    /// is saved as the model image instead.
+    ///
+    /// [`Task`]: ../load/struct.Task.html
+    /// [`Canvas`]: ../graphics/struct.Canvas.html
+    pub fn check(
+        &self,
+        gpu: &mut Gpu,
+        name: &str,
+        drawing: Task<Canvas>,
+    ) -> Result<(), Error> {
+        let canvas = drawing.run(gpu).map_err(Error::Drawing)?;
+        let image = canvas.read_pixels(gpu).to_rgba();
+
+        let model_path = self.model_path(name);
+
+        if self.update {
+            return save(&image, &model_path);
+        }
+
+        if !model_path.exists() {
+            return Err(Error::ModelNotFound(model_path));
+        }
+
+        let model = image::open(&model_path)
+            .map_err(Error::ModelImageIsInvalid)?
+            .to_rgba();
+
+        if model.dimensions() != image.dimensions() {
+            return Err(Error::SizeMismatch {
+                expected: model.dimensions(),
+                actual: image.dimensions(),
+            });
+        }
+
+        let (differences, pixels) = compare(&model, &image, self.tolerance);
+
+        if pixels == 0 {
+            return Ok(());
+        }
+
+        let drawing = self.failures.join(format!("{}.png", name));
+        let diff = self.failures.join(format!("{}.diff.png", name));
+
+        save(&image, &drawing)?;
+        save(&differences, &diff)?;
+
+        Err(Error::Differences { pixels, diff })
+    }
+
+    /// Runs the given drawing [`Task`] and asserts that the produced
+    /// [`Canvas`] matches the model image of the given name.
+    ///
+    /// # Panics
+    /// It panics if the [`check`] fails.
+    ///
+    /// [`Task`]: ../load/struct.Task.html
+    /// [`Canvas`]: ../graphics/struct.Canvas.html
+    /// [`check`]: #method.check
+    pub fn assert(&self, gpu: &mut Gpu, name: &str, drawing: Task<Canvas>) {
+        if let Err(error) = self.check(gpu, name, drawing) {
+            panic!("Drawing \"{}\" does not match: {}", name, error);
+        }
+    }
+
+    fn model_path(&self, name: &str) -> PathBuf {
+        self.models.join(format!("{}.png", name))
+    }
+}
+
+/// Compares two images of the same size and returns an image highlighting
+/// their differences together with the amount of different pixels.
+///
+/// Different pixels are red, while equal ones are a faded version of the
+/// original.
+fn compare(
+    model: &image::RgbaImage,
+    image: &image::RgbaImage,
+    tolerance: u8,
+) -> (image::RgbaImage, usize) {
+    let mut differences = image::RgbaImage::new(model.width(), model.height());
+    let mut count = 0;
+
+    for (x, y, pixel) in differences.enumerate_pixels_mut() {
+        let expected = model.get_pixel(x, y).data;
+        let actual = image.get_pixel(x, y).data;
+
+        let is_equal = expected.iter().zip(actual.iter()).all(|(a, b)| {
+            (i16::from(*a) - i16::from(*b)).abs() <= i16::from(tolerance)
+        });
+
+        pixel.data = if is_equal {
+            let [r, g, b, _] = actual;
+            let faded = (u16::from(r) + u16::from(g) + u16::from(b)) / 12;
+
+            [faded as u8, faded as u8, faded as u8, 255]
+        } else {
+            count += 1;
+
+            [255, 0, 0, 255]
+        };
+    }
+
+    (differences, count)
+}
+
+fn save(image: &image::RgbaImage, path: &Path) -> Result<(), Error> {
+    if let Some(directory) = path.parent() {
+        fs::create_dir_all(directory)?;
+    }
+
+    image.save(path)?;
+
+    Ok(())
+}
+
+/// A failed [`Harness`] check.
+///
+/// [`Harness`]: struct.Harness.html
+#[derive(Debug)]
+pub enum Error {
+    /// The drawing [`Task`] failed.
+    ///
+    /// [`Task`]: ../load/struct.Task.html
+    Drawing(crate::Error),
+
+    /// The model image does not exist.
+    ///
+    /// Set the `COFFEE_UPDATE_MODELS` environment variable to create it.
+    ModelNotFound(PathBuf),
+
+    /// The model image could not be loaded.
+    ModelImageIsInvalid(image::ImageError),
+
+    /// The drawing and the model image have different sizes.
+    SizeMismatch {
+        /// The size of the model image.
+        expected: (u32, u32),
+
+        /// The size of the drawing.
+        actual: (u32, u32),
+    },
+
+    /// Some pixels of the drawing do not match the model image.
+    Differences {
+        /// The amount of different pixels.
+        pixels: usize,
+
+        /// The path of the image highlighting the differences.
+        diff: PathBuf,
+    },
+
+    /// An image could not be saved.
+    IO(io::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Drawing(error) => write!(f, "Drawing failed: {}", error),
+            Error::ModelNotFound(path) => write!(
+                f,
+                "Model image not found: {} (set {} to create it)",
+                path.display(),
+                UPDATE_MODELS
+            ),
+            Error::ModelImageIsInvalid(error) => {
+                write!(f, "Invalid model image: {}", error)
+            }
+            Error::SizeMismatch { expected, actual } => write!(
+                f,
+                "Expected a size of {}x{}, but found {}x{}",
+                expected.0, expected.1, actual.0, actual.1
+            ),
+            Error::Differences { pixels, diff } => write!(
+                f,
+                "{} pixels are different, see {}",
+                pixels,
+                diff.display()
+            ),
+            Error::IO(error) => write!(f, "IO error: {}", error),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Drawing(error) => Some(error),
+            Error::ModelImageIsInvalid(error) => Some(error),
+            Error::IO(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Error {
+        Error::IO(error)
+    }
+}
@@ -38,10 +38,18 @@ impl Timer {
         let diff = now - self.last_tick;
 
         self.last_tick = now;
-        self.accumulated_delta += diff;
+        self.advance(diff);
+    }
+
+    pub(crate) fn advance(&mut self, delta: time::Duration) {
+        self.accumulated_delta += delta;
         self.has_ticked = false;
     }
 
+    pub(crate) fn target_delta(&self) -> time::Duration {
+        self.target_delta
+    }
+
     pub(crate) fn tick(&mut self) -> bool {
         if self.accumulated_delta >= self.target_delta {
             self.accumulated_delta -= self.target_delta;
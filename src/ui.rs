@@ -141,14 +141,21 @@ pub mod core;
 mod renderer;
 pub mod widget;
 
+#[cfg(feature = "software")]
+mod simulation;
+
 #[doc(no_inline)]
 pub use self::core::{Align, Justify};
-pub use renderer::{Configuration, Renderer};
+pub use renderer::{theme, Configuration, Font, Renderer, Theme};
 pub use widget::{
-    button, image, progress_bar, slider, Button, Checkbox, Image, ProgressBar,
-    Radio, Slider, Text,
+    button, image, pick_list, progress_bar, scrollable, slider, text_input,
+    themed, tooltip, Button, Checkbox, Image, PickList, ProgressBar, Radio,
+    Slider, Text, TextInput, WithTooltip,
 };
 
+#[cfg(feature = "software")]
+pub use simulation::Simulation;
+
 /// A [`Column`] using the built-in [`Renderer`].
 ///
 /// [`Column`]: widget/struct.Column.html
@@ -167,6 +174,25 @@ pub type Row<'a, Message> = widget::Row<'a, Message, Renderer>;
 /// [`Renderer`]: struct.Renderer.html
 pub type Panel<'a, Message> = widget::Panel<'a, Message, Renderer>;
 
+/// A [`Scrollable`] using the built-in [`Renderer`].
+///
+/// [`Scrollable`]: widget/scrollable/struct.Scrollable.html
+/// [`Renderer`]: struct.Renderer.html
+pub type Scrollable<'a, Message> =
+    widget::Scrollable<'a, Message, Renderer>;
+
+/// A [`Themed`] wrapper using the built-in [`Renderer`].
+///
+/// [`Themed`]: widget/themed/struct.Themed.html
+/// [`Renderer`]: struct.Renderer.html
+pub type Themed<'a, Message> = widget::Themed<'a, Message, Renderer>;
+
+/// A [`Tooltip`] wrapper using the built-in [`Renderer`].
+///
+/// [`Tooltip`]: widget/tooltip/struct.Tooltip.html
+/// [`Renderer`]: struct.Renderer.html
+pub type Tooltip<'a, Message> = widget::Tooltip<'a, Message, Renderer>;
+
 /// An [`Element`] using the built-in [`Renderer`].
 ///
 /// [`Element`]: core/struct.Element.html
@@ -174,10 +200,10 @@ pub type Panel<'a, Message> = widget::Panel<'a, Message, Renderer>;
 pub type Element<'a, Message> = self::core::Element<'a, Message, Renderer>;
 
 use crate::game;
-use crate::graphics::{window, Point, Window, WindowSettings};
-use crate::input::{self, gamepad, mouse, Input as _};
+use crate::graphics::{window, Point, Rectangle, Window, WindowSettings};
+use crate::input::{self, gamepad, mouse, Input as _, Recording};
 use crate::load::{Join, LoadingScreen};
-use crate::ui::core::{Event, Interface, MouseCursor, Renderer as _};
+use crate::ui::core::{Cache, Event, Interface, MouseCursor, Renderer as _};
 use crate::{Debug, Game, Result, Timer};
 
 /// The user interface of your game.
@@ -270,123 +296,183 @@ pub trait UserInterface: Game {
     where
         Self: 'static + Sized,
     {
-        // Set up window
-        let event_loop = &mut window::EventLoop::new();
-        let window = &mut Window::new(window_settings, &event_loop)?;
-        let mut debug = Debug::new(window.gpu());
-
-        // Load game
-        debug.loading_started();
-        let mut loading_screen = Self::LoadingScreen::new(window.gpu())?;
-        let load = (
-            Self::load(window),
-            Self::Renderer::load(Self::configuration()),
-        )
-            .join();
-        let (game, renderer) = &mut loading_screen.run(load, window)?;
-        let input = &mut Input::new();
-        let mut gamepads = gamepad::Tracker::new();
-        debug.loading_finished();
-
-        // Game loop
-        let mut timer = Timer::new(Self::TICKS_PER_SECOND);
-        let mut alive = true;
-        let messages = &mut Vec::new();
-        let mut mouse_cursor = MouseCursor::OutOfBounds;
-        let mut ui_cache =
-            Interface::compute(game.layout(window), &renderer).cache();
-
-        while alive && !game.is_finished() {
-            debug.frame_started();
-            timer.update();
-
-            while timer.tick() {
-                interact(
-                    game,
-                    input,
-                    &mut debug,
-                    window,
-                    event_loop,
-                    gamepads.as_mut(),
-                    &mut alive,
-                );
+        run::<Self>(window_settings, &mut input::Tape::live())
+    }
 
-                debug.update_started();
-                game.update(window);
-                debug.update_finished();
-            }
+    /// Runs the [`Game`] with a user interface while recording its input.
+    ///
+    /// Call this method instead of [`Game::record`] once you have implemented
+    /// the [`UserInterface`].
+    ///
+    /// [`Game`]: ../trait.Game.html
+    /// [`UserInterface`]: trait.UserInterface.html
+    /// [`Game::record`]: ../trait.Game.html#method.record
+    fn record(window_settings: WindowSettings) -> Result<Recording>
+    where
+        Self: 'static + Sized,
+    {
+        let mut tape = input::Tape::record();
 
-            if !timer.has_ticked() {
-                interact(
-                    game,
-                    input,
-                    &mut debug,
-                    window,
-                    event_loop,
-                    gamepads.as_mut(),
-                    &mut alive,
-                );
-            }
+        run::<Self>(window_settings, &mut tape)?;
 
-            debug.draw_started();
-            game.draw(&mut window.frame(), &timer);
-            debug.draw_finished();
+        Ok(tape.into_recording().unwrap_or_default())
+    }
+
+    /// Runs the [`Game`] with a user interface, feeding it the input events of
+    /// a [`Recording`] instead of the live ones.
+    ///
+    /// Call this method instead of [`Game::replay`] once you have implemented
+    /// the [`UserInterface`].
+    ///
+    /// [`Game`]: ../trait.Game.html
+    /// [`Recording`]: ../input/struct.Recording.html
+    /// [`UserInterface`]: trait.UserInterface.html
+    /// [`Game::replay`]: ../trait.Game.html#method.replay
+    fn replay(
+        window_settings: WindowSettings,
+        recording: Recording,
+    ) -> Result<()>
+    where
+        Self: 'static + Sized,
+    {
+        run::<Self>(window_settings, &mut input::Tape::replay(recording))
+    }
+}
 
-            debug.ui_started();
-            let mut interface = Interface::compute_with_cache(
-                game.layout(window),
-                &renderer,
-                ui_cache,
+fn run<U: UserInterface + 'static>(
+    window_settings: WindowSettings,
+    tape: &mut input::Tape,
+) -> Result<()> {
+    // Set up window
+    let event_loop = &mut window::EventLoop::new();
+    let window = &mut Window::new(window_settings, &event_loop)?;
+    let mut debug = Debug::new(window.gpu());
+
+    // Load game
+    debug.loading_started();
+    let mut loading_screen = U::LoadingScreen::new(window.gpu())?;
+    let load = (
+        U::load(window),
+        U::Renderer::load(U::configuration()),
+    )
+        .join();
+    let (game, renderer) = &mut loading_screen.run(load, window)?;
+    let input = &mut Input::new();
+    let mut gamepads = gamepad::Tracker::new();
+    debug.loading_finished();
+
+    // Game loop
+    let mut timer = Timer::new(U::TICKS_PER_SECOND);
+    let mut alive = true;
+    let messages = &mut Vec::new();
+    let mut mouse_cursor = MouseCursor::OutOfBounds;
+    let mut ui_cache =
+        Interface::compute(game.layout(window), &renderer, viewport(window))
+            .cache();
+
+    while alive && !game.is_finished() {
+        debug.frame_started();
+        timer.update();
+
+        let mut ticks = 0;
+
+        while timer.tick() {
+            ticks += 1;
+
+            interact(
+                game,
+                input,
+                &mut debug,
+                window,
+                event_loop,
+                gamepads.as_mut(),
+                tape,
+                &mut alive,
             );
 
-            let cursor_position = input.cursor_position;
-            input.ui_events.drain(..).for_each(|event| {
-                interface.on_event(event, cursor_position, messages)
-            });
+            ui_cache = react(game, input, window, renderer, messages, ui_cache);
 
-            let new_cursor = interface.draw(
-                renderer,
-                &mut window.frame(),
-                input.cursor_position,
-            );
+            debug.update_started();
+            game.update(window);
+            debug.update_finished();
 
-            ui_cache = interface.cache();
+            tape.tick();
+        }
 
-            if new_cursor != mouse_cursor {
-                if new_cursor == MouseCursor::OutOfBounds {
-                    input.update(input::Event::Mouse(
-                        mouse::Event::CursorReturned,
-                    ));
-                } else if mouse_cursor == MouseCursor::OutOfBounds {
-                    input
-                        .update(input::Event::Mouse(mouse::Event::CursorTaken));
-                }
+        if !timer.has_ticked() {
+            interact(
+                game,
+                input,
+                &mut debug,
+                window,
+                event_loop,
+                gamepads.as_mut(),
+                tape,
+                &mut alive,
+            );
 
-                window.update_cursor(new_cursor.into());
-                mouse_cursor = new_cursor;
-            }
+            ui_cache = react(game, input, window, renderer, messages, ui_cache);
+        }
 
-            for message in messages.drain(..) {
-                game.react(message, window);
-            }
-            debug.ui_finished();
-
-            if debug.is_enabled() {
-                debug.debug_started();
-                game.debug(
-                    &mut input.game_input,
-                    &mut window.frame(),
-                    &mut debug,
+        debug.draw_started();
+        game.draw(&mut window.frame(), &timer);
+        debug.draw_finished();
+
+        debug.ui_started();
+        let mut interface = Interface::compute_with_cache(
+            game.layout(window),
+            &renderer,
+            viewport(window),
+            ui_cache,
+        );
+
+        interface.advance(timer.target_delta() * ticks);
+
+        let new_cursor = interface.draw(
+            renderer,
+            &mut window.frame(),
+            input.cursor_position,
+        );
+
+        ui_cache = interface.cache();
+
+        // The changes of the cursor depend on the frame rate, so they are fed
+        // through the tape to be replayed on the same tick
+        if new_cursor != mouse_cursor {
+            if new_cursor == MouseCursor::OutOfBounds {
+                tape.feed(
+                    input,
+                    input::Event::Mouse(mouse::Event::CursorReturned),
+                );
+            } else if mouse_cursor == MouseCursor::OutOfBounds {
+                tape.feed(
+                    input,
+                    input::Event::Mouse(mouse::Event::CursorTaken),
                 );
-                debug.debug_finished();
             }
 
-            window.swap_buffers();
-            debug.frame_finished();
+            window.update_cursor(new_cursor.into());
+            mouse_cursor = new_cursor;
         }
+        debug.ui_finished();
 
-        Ok(())
+        debug.capture(&mut window.frame());
+
+        if debug.is_enabled() {
+            debug.debug_started();
+            game.debug(
+                &mut input.game_input,
+                &mut window.frame(),
+                &mut debug,
+            );
+            debug.debug_finished();
+        }
+
+        window.swap_buffers();
+        debug.frame_finished();
     }
+
+    Ok(())
 }
 
 struct Input<I: input::Input> {
@@ -424,6 +510,45 @@ impl<I: input::Input> input::Input for Input<I> {
     }
 }
 
+/// Processes the pending events of the user interface and reacts to the
+/// produced messages.
+///
+/// It is called right after every interaction, so the messages are handled
+/// on the same tick as the input that produced them.
+fn react<U: UserInterface>(
+    game: &mut U,
+    input: &mut Input<U::Input>,
+    window: &mut Window,
+    renderer: &U::Renderer,
+    messages: &mut Vec<U::Message>,
+    cache: Cache,
+) -> Cache {
+    if input.ui_events.is_empty() {
+        return cache;
+    }
+
+    let mut interface = Interface::compute_with_cache(
+        game.layout(window),
+        renderer,
+        viewport(window),
+        cache,
+    );
+
+    let cursor_position = input.cursor_position;
+    input.ui_events.drain(..).for_each(|event| {
+        interface.on_event(event, cursor_position, messages)
+    });
+
+    let cache = interface.cache();
+
+    for message in messages.drain(..) {
+        game.react(message, window);
+    }
+
+    cache
+}
+
+#[allow(clippy::too_many_arguments)]
 fn interact<G: Game>(
     game: &mut G,
     input: &mut Input<G::Input>,
@@ -431,18 +556,34 @@ fn interact<G: Game>(
     window: &mut Window,
     event_loop: &mut window::EventLoop,
     gamepads: Option<&mut gamepad::Tracker>,
+    tape: &mut input::Tape,
     alive: &mut bool,
 ) {
     debug.interact_started();
 
     event_loop.poll(|event| {
-        game::process_window_event(game, input, debug, window, alive, event)
+        game::process_window_event(
+            game, input, debug, window, tape, alive, event,
+        )
     });
 
-    game::process_gamepad_events(gamepads, input);
+    game::process_gamepad_events(gamepads, input, tape);
+    tape.play(input);
 
     game.interact(&mut input.game_input, window);
     input.clear();
 
     debug.interact_finished();
 }
+
+/// Returns the visible area of the user interface in the given [`Window`].
+///
+/// [`Window`]: ../graphics/struct.Window.html
+fn viewport(window: &Window) -> Rectangle<f32> {
+    Rectangle {
+        x: 0.0,
+        y: 0.0,
+        width: window.width(),
+        height: window.height(),
+    }
+}
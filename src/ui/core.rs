@@ -7,7 +7,10 @@
 //! [`Renderer`]: trait.Renderer.html
 mod element;
 mod event;
+mod focus;
 mod hasher;
+mod hover;
+mod interaction;
 mod interface;
 mod layout;
 mod mouse_cursor;
@@ -21,8 +24,12 @@ pub use stretch::{geometry::Size, number::Number};
 
 pub use element::Element;
 pub use event::Event;
+pub(crate) use focus::Focus;
+pub use focus::Focusable;
 pub use hasher::Hasher;
-pub(crate) use interface::Interface;
+pub(crate) use hover::Hover;
+pub use interaction::Interaction;
+pub(crate) use interface::{Cache, Interface};
 pub use layout::Layout;
 pub use mouse_cursor::MouseCursor;
 pub use node::Node;
@@ -1,7 +1,9 @@
 use stretch::{geometry, result};
 
-use crate::graphics::{Color, Point};
-use crate::ui::core::{self, Event, Hasher, Layout, MouseCursor, Node, Widget};
+use crate::graphics::{Color, Point, Rectangle};
+use crate::ui::core::{
+    self, Event, Focusable, Hasher, Layout, MouseCursor, Node, Widget,
+};
 
 /// A generic [`Widget`].
 ///
@@ -240,6 +242,14 @@ where
     fn hash(&self, state: &mut Hasher) {
         self.widget.hash(state);
     }
+
+    fn focusable(&self, layout: Layout<'_>, focusables: &mut Vec<Focusable>) {
+        self.widget.focusable(layout, focusables)
+    }
+
+    fn overlay(&self, layout: Layout<'_>, overlays: &mut Vec<Rectangle<f32>>) {
+        self.widget.overlay(layout, overlays)
+    }
 }
 
 struct Explain<'a, Message, Renderer> {
@@ -296,4 +306,12 @@ where
     fn hash(&self, state: &mut Hasher) {
         self.element.widget.hash(state);
     }
+
+    fn focusable(&self, layout: Layout<'_>, focusables: &mut Vec<Focusable>) {
+        self.element.widget.focusable(layout, focusables)
+    }
+
+    fn overlay(&self, layout: Layout<'_>, overlays: &mut Vec<Rectangle<f32>>) {
+        self.element.widget.overlay(layout, overlays)
+    }
 }
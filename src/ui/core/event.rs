@@ -1,4 +1,4 @@
-use crate::input::{self, gamepad, keyboard, mouse};
+use crate::input::{self, gamepad, keyboard, mouse, ButtonState};
 
 /// A user interface event.
 ///
@@ -24,6 +24,35 @@ pub enum Event {
 }
 
 impl Event {
+    /// Returns whether the [`Event`] activates the focused widget.
+    ///
+    /// Pressing `Enter`, `Space`, or the `South` button of a gamepad activates
+    /// the focused widget. For instance, a focused [`Button`] is pressed.
+    ///
+    /// [`Event`]: enum.Event.html
+    /// [`Button`]: ../widget/button/struct.Button.html
+    pub fn is_activation(&self) -> bool {
+        match self {
+            Event::Keyboard(keyboard::Event::Input {
+                key_code: keyboard::KeyCode::Return,
+                state: ButtonState::Pressed,
+            })
+            | Event::Keyboard(keyboard::Event::Input {
+                key_code: keyboard::KeyCode::NumpadEnter,
+                state: ButtonState::Pressed,
+            })
+            | Event::Keyboard(keyboard::Event::Input {
+                key_code: keyboard::KeyCode::Space,
+                state: ButtonState::Pressed,
+            })
+            | Event::Gamepad {
+                event: gamepad::Event::ButtonPressed(gamepad::Button::South),
+                ..
+            } => true,
+            _ => false,
+        }
+    }
+
     pub(crate) fn from_input(event: input::Event) -> Option<Event> {
         match event {
             input::Event::Keyboard(keyboard_event) => {
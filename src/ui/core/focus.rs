@@ -0,0 +1,141 @@
+use crate::graphics::Rectangle;
+use crate::input::keyboard::{self, KeyCode};
+use crate::input::{gamepad, mouse, ButtonState};
+use crate::ui::core::{Event, Layout};
+
+/// The focusable widget that has the keyboard and gamepad focus.
+///
+/// Focusable widgets are ordered as they appear in the widget tree. `Tab`,
+/// `Down`, and the `DPadDown` button of a gamepad focus the next one, while
+/// `Shift + Tab`, `Up`, and `DPadUp` focus the previous one. Clicking with the
+/// mouse clears the focus.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub(crate) struct Focus {
+    index: Option<usize>,
+    is_shift_pressed: bool,
+}
+
+impl Focus {
+    /// Moves the focus if the given [`Event`] is a navigation event.
+    ///
+    /// [`Event`]: enum.Event.html
+    pub fn update(&mut self, event: Event, focusables: usize) {
+        match event {
+            Event::Keyboard(keyboard::Event::Input {
+                key_code: KeyCode::LShift,
+                state,
+            })
+            | Event::Keyboard(keyboard::Event::Input {
+                key_code: KeyCode::RShift,
+                state,
+            }) => {
+                self.is_shift_pressed = state == ButtonState::Pressed;
+            }
+            Event::Keyboard(keyboard::Event::Input {
+                key_code,
+                state: ButtonState::Pressed,
+            }) => match key_code {
+                KeyCode::Tab if self.is_shift_pressed => {
+                    self.previous(focusables)
+                }
+                KeyCode::Tab | KeyCode::Down => self.next(focusables),
+                KeyCode::Up => self.previous(focusables),
+                _ => {}
+            },
+            Event::Gamepad {
+                event: gamepad::Event::ButtonPressed(button),
+                ..
+            } => match button {
+                gamepad::Button::DPadDown => self.next(focusables),
+                gamepad::Button::DPadUp => self.previous(focusables),
+                _ => {}
+            },
+            Event::Mouse(mouse::Event::Input {
+                state: ButtonState::Pressed,
+                ..
+            }) => {
+                self.index = None;
+            }
+            _ => {}
+        }
+    }
+
+    /// Returns the focused widget, given all the focusable widgets.
+    pub fn focused(&self, focusables: &[Focusable]) -> Option<Focusable> {
+        self.index.and_then(|index| focusables.get(index).cloned())
+    }
+
+    fn next(&mut self, focusables: usize) {
+        self.index = match self.index {
+            _ if focusables == 0 => None,
+            Some(index) if index + 1 < focusables => Some(index + 1),
+            _ => Some(0),
+        };
+    }
+
+    fn previous(&mut self, focusables: usize) {
+        self.index = match self.index {
+            _ if focusables == 0 => None,
+            Some(index) if index > 0 && index < focusables => Some(index - 1),
+            _ => Some(focusables - 1),
+        };
+    }
+}
+
+/// A widget that can be focused using the keyboard or a gamepad.
+///
+/// It is collected by [`Widget::focusable`].
+///
+/// [`Widget::focusable`]: trait.Widget.html#method.focusable
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Focusable {
+    node: usize,
+    bounds: Rectangle<f32>,
+    clip: Rectangle<f32>,
+}
+
+impl Focusable {
+    /// Creates the [`Focusable`] of the widget with the given [`Layout`].
+    ///
+    /// [`Focusable`]: struct.Focusable.html
+    /// [`Layout`]: struct.Layout.html
+    pub fn new(layout: &Layout<'_>) -> Focusable {
+        Focusable {
+            node: layout.node(),
+            bounds: layout.bounds(),
+            clip: layout.clip_bounds(),
+        }
+    }
+
+    /// Returns the bounds of the [`Focusable`].
+    ///
+    /// [`Focusable`]: struct.Focusable.html
+    pub fn bounds(&self) -> Rectangle<f32> {
+        self.bounds
+    }
+
+    /// Returns the area where the [`Focusable`] may be visible, as its
+    /// containers may hide parts of it.
+    ///
+    /// [`Focusable`]: struct.Focusable.html
+    pub(crate) fn clip(&self) -> Rectangle<f32> {
+        self.clip
+    }
+
+    /// Returns whether any part of the [`Focusable`] is visible.
+    ///
+    /// [`Focusable`]: struct.Focusable.html
+    pub(crate) fn is_visible(&self) -> bool {
+        let bounds = self.bounds;
+        let clip = self.clip;
+
+        bounds.x < clip.x + clip.width
+            && clip.x < bounds.x + bounds.width
+            && bounds.y < clip.y + clip.height
+            && clip.y < bounds.y + bounds.height
+    }
+
+    pub(crate) fn node(&self) -> usize {
+        self.node
+    }
+}
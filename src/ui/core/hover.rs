@@ -0,0 +1,43 @@
+use std::time::Duration;
+
+use crate::input::mouse;
+use crate::ui::core::Event;
+
+/// The time the cursor has been resting in the same position.
+///
+/// It is measured in game time, so it advances with the ticks of the game.
+/// Moving the cursor, clicking, or scrolling with the mouse wheel restarts
+/// it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub(crate) struct Hover {
+    duration: Duration,
+}
+
+impl Hover {
+    /// Restarts the [`Hover`] if the given [`Event`] is a mouse interaction.
+    ///
+    /// [`Hover`]: struct.Hover.html
+    /// [`Event`]: enum.Event.html
+    pub fn update(&mut self, event: Event) {
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Mouse(mouse::Event::Input { .. })
+            | Event::Mouse(mouse::Event::WheelScrolled { .. }) => {
+                self.duration = Duration::default();
+            }
+            _ => {}
+        }
+    }
+
+    /// Adds the given amount of time to the [`Hover`].
+    ///
+    /// [`Hover`]: struct.Hover.html
+    pub fn advance(&mut self, delta: Duration) {
+        self.duration += delta;
+    }
+
+    /// Returns the time the cursor has been resting.
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+}
@@ -0,0 +1,74 @@
+use crate::graphics::{Point, Rectangle};
+use crate::ui::core::MouseCursor;
+
+/// The state of the interaction between the user and a widget.
+///
+/// It allows widgets to share the same model of interaction, so a renderer
+/// can style all of them consistently. For instance, a [`Button`] is drawn
+/// using the [`Interaction`] it produces.
+///
+/// [`Button`]: ../widget/button/struct.Button.html
+/// [`Interaction`]: enum.Interaction.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Interaction {
+    /// The widget is enabled, but the cursor is not over it.
+    Idle,
+
+    /// The cursor is over the widget.
+    Hovered,
+
+    /// The cursor is over the widget and it is being pressed.
+    Pressed,
+
+    /// The widget cannot be interacted with.
+    Disabled,
+}
+
+impl Interaction {
+    /// Computes the [`Interaction`] of a widget with the given bounds.
+    ///
+    /// It receives:
+    ///   * the bounds of the widget
+    ///   * the current cursor position
+    ///   * whether the widget is being pressed or not
+    ///   * whether the widget is enabled or not
+    ///
+    /// [`Interaction`]: enum.Interaction.html
+    pub fn new(
+        bounds: Rectangle<f32>,
+        cursor_position: Point,
+        is_pressed: bool,
+        is_enabled: bool,
+    ) -> Interaction {
+        if !is_enabled {
+            Interaction::Disabled
+        } else if !bounds.contains(cursor_position) {
+            Interaction::Idle
+        } else if is_pressed {
+            Interaction::Pressed
+        } else {
+            Interaction::Hovered
+        }
+    }
+
+    /// Returns whether the cursor is over the widget or not.
+    pub fn is_hovered(self) -> bool {
+        match self {
+            Interaction::Hovered | Interaction::Pressed => true,
+            Interaction::Idle | Interaction::Disabled => false,
+        }
+    }
+
+    /// Returns the [`MouseCursor`] of an interactive widget with this
+    /// [`Interaction`].
+    ///
+    /// [`MouseCursor`]: enum.MouseCursor.html
+    /// [`Interaction`]: enum.Interaction.html
+    pub fn mouse_cursor(self) -> MouseCursor {
+        if self.is_hovered() {
+            MouseCursor::Pointer
+        } else {
+            MouseCursor::OutOfBounds
+        }
+    }
+}
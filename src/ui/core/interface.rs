@@ -1,18 +1,27 @@
+use std::f32;
 use std::hash::Hasher;
+use std::time::Duration;
 use stretch::result;
 
-use crate::graphics::{Frame, Point};
-use crate::ui::core::{self, Element, Event, Layout, MouseCursor};
+use crate::graphics::{Frame, Point, Rectangle};
+use crate::ui::core::{
+    self, Element, Event, Focus, Focusable, Hover, Layout, MouseCursor,
+};
 
 pub struct Interface<'a, Message, Renderer> {
     hash: u64,
     root: Element<'a, Message, Renderer>,
     layout: result::Layout,
+    focus: Focus,
+    hover: Hover,
+    viewport: Rectangle<f32>,
 }
 
 pub struct Cache {
     hash: u64,
     layout: result::Layout,
+    focus: Focus,
+    hover: Hover,
 }
 
 impl<'a, Message, Renderer> Interface<'a, Message, Renderer>
@@ -22,6 +31,7 @@ where
     pub fn compute(
         root: Element<'a, Message, Renderer>,
         renderer: &Renderer,
+        viewport: Rectangle<f32>,
     ) -> Interface<'a, Message, Renderer> {
         let hasher = &mut twox_hash::XxHash::default();
         root.hash(hasher);
@@ -29,12 +39,20 @@ where
         let hash = hasher.finish();
         let layout = root.compute_layout(renderer);
 
-        Interface { hash, root, layout }
+        Interface {
+            hash,
+            root,
+            layout,
+            focus: Focus::default(),
+            hover: Hover::default(),
+            viewport,
+        }
     }
 
     pub fn compute_with_cache(
         root: Element<'a, Message, Renderer>,
         renderer: &Renderer,
+        viewport: Rectangle<f32>,
         cache: Cache,
     ) -> Interface<'a, Message, Renderer> {
         let hasher = &mut twox_hash::XxHash::default();
@@ -48,7 +66,14 @@ where
             root.compute_layout(renderer)
         };
 
-        Interface { hash, root, layout }
+        Interface {
+            hash,
+            root,
+            layout,
+            focus: cache.focus,
+            hover: cache.hover,
+            viewport,
+        }
     }
 
     pub fn on_event(
@@ -57,27 +82,51 @@ where
         cursor_position: Point,
         messages: &mut Vec<Message>,
     ) {
+        let focusables = self.focusables();
+        self.focus.update(event, focusables.len());
+        self.hover.update(event);
+
+        let focus = self.focus.focused(&focusables);
+        let (cursor_position, overlay_cursor) = self.cursor(cursor_position);
+        let hover = self.hover.duration();
+        let viewport = self.viewport;
         let Interface { root, layout, .. } = self;
 
         root.widget.on_event(
             event,
-            Self::layout(layout),
+            Self::layout(layout, focus, overlay_cursor, hover, viewport),
             cursor_position,
             messages,
         );
     }
 
+    /// Lets the given amount of game time pass, which makes the cursor rest
+    /// longer.
+    pub fn advance(&mut self, delta: Duration) {
+        self.hover.advance(delta);
+    }
+
     pub fn draw(
         &self,
         renderer: &mut Renderer,
         frame: &mut Frame<'_>,
         cursor_position: Point,
     ) -> MouseCursor {
+        let focus = self.focus.focused(&self.focusables());
+        let (cursor_position, overlay_cursor) = self.cursor(cursor_position);
+        let hover = self.hover.duration();
+        let viewport = self.viewport;
         let Interface { root, layout, .. } = self;
 
-        let cursor =
-            root.widget
-                .draw(renderer, Self::layout(layout), cursor_position);
+        let cursor = root.widget.draw(
+            renderer,
+            Self::layout(layout, focus, overlay_cursor, hover, viewport),
+            cursor_position,
+        );
+
+        if let Some(focus) = focus.filter(Focusable::is_visible) {
+            renderer.focus(focus.bounds(), focus.clip());
+        }
 
         renderer.flush(frame);
 
@@ -88,10 +137,64 @@ where
         Cache {
             hash: self.hash,
             layout: self.layout,
+            focus: self.focus,
+            hover: self.hover,
+        }
+    }
+
+    fn focusables(&self) -> Vec<Focusable> {
+        let mut focusables = Vec::new();
+
+        self.root.widget.focusable(
+            Self::layout(
+                &self.layout,
+                None,
+                None,
+                Duration::default(),
+                self.viewport,
+            ),
+            &mut focusables,
+        );
+
+        focusables
+    }
+
+    /// Hides the cursor from the user interface when it is over an overlay,
+    /// returning its actual position separately.
+    fn cursor(&self, cursor_position: Point) -> (Point, Option<Point>) {
+        let mut overlays = Vec::new();
+
+        self.root.widget.overlay(
+            Self::layout(
+                &self.layout,
+                None,
+                None,
+                Duration::default(),
+                self.viewport,
+            ),
+            &mut overlays,
+        );
+
+        if overlays
+            .iter()
+            .any(|overlay| overlay.contains(cursor_position))
+        {
+            (
+                Point::new(f32::INFINITY, f32::INFINITY),
+                Some(cursor_position),
+            )
+        } else {
+            (cursor_position, None)
         }
     }
 
-    fn layout(layout: &result::Layout) -> Layout<'_> {
-        Layout::new(layout, Point::new(0.0, 0.0))
+    fn layout(
+        layout: &result::Layout,
+        focus: Option<Focusable>,
+        overlay_cursor: Option<Point>,
+        hover: Duration,
+        viewport: Rectangle<f32>,
+    ) -> Layout<'_> {
+        Layout::new(layout, focus, overlay_cursor, hover, viewport)
     }
 }
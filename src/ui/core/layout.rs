@@ -1,6 +1,8 @@
+use std::time::Duration;
 use stretch::result;
 
 use crate::graphics::{Point, Rectangle, Vector};
+use crate::ui::core::Focusable;
 
 /// The computed bounds of a [`Node`] and its children.
 ///
@@ -16,17 +18,32 @@ use crate::graphics::{Point, Rectangle, Vector};
 pub struct Layout<'a> {
     layout: &'a result::Layout,
     position: Point,
+    node: usize,
+    clip: Rectangle<f32>,
+    focus: Option<Focusable>,
+    overlay_cursor: Option<Point>,
+    hover: Duration,
+    viewport: Rectangle<f32>,
 }
 
 impl<'a> Layout<'a> {
     pub(crate) fn new(
         layout: &'a result::Layout,
-        parent_position: Point,
+        focus: Option<Focusable>,
+        overlay_cursor: Option<Point>,
+        hover: Duration,
+        viewport: Rectangle<f32>,
     ) -> Self {
-        let position =
-            parent_position + Vector::new(layout.location.x, layout.location.y);
-
-        Layout { layout, position }
+        Layout {
+            layout,
+            position: Point::new(layout.location.x, layout.location.y),
+            node: 0,
+            clip: viewport,
+            focus,
+            overlay_cursor,
+            hover,
+            viewport,
+        }
     }
 
     /// Gets the bounds of the [`Layout`].
@@ -46,6 +63,94 @@ impl<'a> Layout<'a> {
         }
     }
 
+    /// Moves the [`Layout`], and all of its children, by the given
+    /// translation.
+    ///
+    /// [`Layout`]: struct.Layout.html
+    pub(crate) fn translate(self, translation: Vector) -> Layout<'a> {
+        Layout {
+            position: self.position + translation,
+            ..self
+        }
+    }
+
+    /// Hides the parts of the [`Layout`], and all of its children, outside of
+    /// the given bounds.
+    ///
+    /// [`Layout`]: struct.Layout.html
+    pub(crate) fn clip(self, bounds: Rectangle<f32>) -> Layout<'a> {
+        Layout {
+            clip: intersection(self.clip, bounds),
+            ..self
+        }
+    }
+
+    /// Returns the position of the [`Layout`] in its tree, in pre-order.
+    ///
+    /// [`Layout`]: struct.Layout.html
+    pub(crate) fn node(&self) -> usize {
+        self.node
+    }
+
+    /// Returns the area where the [`Layout`] may be visible.
+    ///
+    /// [`Layout`]: struct.Layout.html
+    pub(crate) fn clip_bounds(&self) -> Rectangle<f32> {
+        self.clip
+    }
+
+    /// Returns whether the [`Widget`] of the [`Layout`] has the keyboard and
+    /// gamepad focus.
+    ///
+    /// Only [focusable] widgets can be focused.
+    ///
+    /// [`Widget`]: trait.Widget.html
+    /// [`Layout`]: struct.Layout.html
+    /// [focusable]: trait.Widget.html#method.focusable
+    pub fn is_focused(&self) -> bool {
+        self.focus.map(|focus| focus.node()) == Some(self.node)
+    }
+
+    /// Returns the bounds of the focused [`Widget`], if any.
+    ///
+    /// [`Widget`]: trait.Widget.html
+    pub(crate) fn focus(&self) -> Option<Rectangle<f32>> {
+        self.focus.map(|focus| focus.bounds())
+    }
+
+    /// Returns the cursor position if it is over an overlay.
+    ///
+    /// The rest of the user interface receives a cursor position out of
+    /// bounds while the cursor is over an [overlay]. Widgets drawing an
+    /// overlay can use this position to handle events and draw it.
+    ///
+    /// [overlay]: trait.Widget.html#method.overlay
+    pub fn overlay_cursor(&self) -> Option<Point> {
+        self.overlay_cursor
+    }
+
+    /// Returns the time the cursor has been resting in its current position.
+    ///
+    /// It is measured in game time, which advances with every tick, so it
+    /// stays deterministic when replaying a recording. Moving the cursor,
+    /// clicking, or scrolling with the mouse wheel resets it. Widgets can use
+    /// it to react to the cursor after a delay, like a [`Tooltip`].
+    ///
+    /// [`Tooltip`]: ../widget/tooltip/struct.Tooltip.html
+    pub fn hover_duration(&self) -> Duration {
+        self.hover
+    }
+
+    /// Returns the visible area of the user interface, the bounds of the
+    /// window.
+    ///
+    /// Widgets drawing an [overlay] can use it to keep the overlay on screen.
+    ///
+    /// [overlay]: trait.Widget.html#method.overlay
+    pub fn viewport(&self) -> Rectangle<f32> {
+        self.viewport
+    }
+
     /// Returns an iterator over the [`Layout`] of the children of a [`Node`].
     ///
     /// [`Layout`]: struct.Layout.html
@@ -54,6 +159,35 @@ impl<'a> Layout<'a> {
         self.layout
             .children
             .iter()
-            .map(move |layout| Layout::new(layout, self.position))
+            .scan(self.node + 1, move |node, layout| {
+                let child = Layout {
+                    layout,
+                    position: self.position
+                        + Vector::new(layout.location.x, layout.location.y),
+                    node: *node,
+                    ..*self
+                };
+
+                *node += nodes(layout);
+
+                Some(child)
+            })
+    }
+}
+
+/// Counts the nodes of a layout tree.
+fn nodes(layout: &result::Layout) -> usize {
+    1 + layout.children.iter().map(nodes).sum::<usize>()
+}
+
+fn intersection(a: Rectangle<f32>, b: Rectangle<f32>) -> Rectangle<f32> {
+    let x = a.x.max(b.x);
+    let y = a.y.max(b.y);
+
+    Rectangle {
+        x,
+        y,
+        width: ((a.x + a.width).min(b.x + b.width) - x).max(0.0),
+        height: ((a.y + a.height).min(b.y + b.height) - y).max(0.0),
     }
 }
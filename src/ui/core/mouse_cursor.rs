@@ -20,6 +20,9 @@ pub enum MouseCursor {
 
     /// The cursor is grabbing a widget.
     Grabbing,
+
+    /// The cursor is over an editable text.
+    Text,
 }
 
 #[doc(hidden)]
@@ -32,6 +35,7 @@ impl From<MouseCursor> for winit::MouseCursor {
             MouseCursor::Working => winit::MouseCursor::Progress,
             MouseCursor::Grab => winit::MouseCursor::Grab,
             MouseCursor::Grabbing => winit::MouseCursor::Grabbing,
+            MouseCursor::Text => winit::MouseCursor::Text,
         }
     }
 }
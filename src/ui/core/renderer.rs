@@ -1,4 +1,4 @@
-use crate::graphics::{Color, Frame};
+use crate::graphics::{Color, Frame, Rectangle};
 use crate::load::Task;
 use crate::ui::core::Layout;
 
@@ -33,6 +33,19 @@ pub trait Renderer {
     /// [`Element::explain`]: struct.Element.html#method.explain
     fn explain(&mut self, layout: &Layout<'_>, color: Color);
 
+    /// Draws the focus indicator of the focused widget, given its bounds and
+    /// the area where it is visible.
+    ///
+    /// This will be called by the runtime after calling [`Widget::draw`] for
+    /// all the widgets of the user interface, when a visible widget has been
+    /// focused using the keyboard or a gamepad. The indicator must not be
+    /// drawn outside of the visible area, as parts of the widget may be
+    /// hidden, like in a [`Scrollable`].
+    ///
+    /// [`Widget::draw`]: trait.Widget.html#tymethod.draw
+    /// [`Scrollable`]: ../widget/scrollable/struct.Scrollable.html
+    fn focus(&mut self, bounds: Rectangle<f32>, clip: Rectangle<f32>);
+
     /// Flushes the renderer to draw on the given [`Frame`].
     ///
     /// This method will be called by the runtime after calling [`Widget::draw`]
@@ -1,5 +1,5 @@
-use crate::graphics::Point;
-use crate::ui::core::{Event, Hasher, Layout, MouseCursor, Node};
+use crate::graphics::{Point, Rectangle};
+use crate::ui::core::{Event, Focusable, Hasher, Layout, MouseCursor, Node};
 
 /// A component that displays information or allows interaction.
 ///
@@ -71,4 +71,43 @@ pub trait Widget<Message, Renderer>: std::fmt::Debug {
         _messages: &mut Vec<Message>,
     ) {
     }
+
+    /// Collects the focusable widgets of the [`Widget`], in order.
+    ///
+    /// Focusable widgets can be focused using the keyboard or a gamepad. A
+    /// focused [`Widget`] can check [`Layout::is_focused`] to react to
+    /// [activation] events.
+    ///
+    /// Interactive widgets should push a [`Focusable`] created with their own
+    /// [`Layout`], while containers must forward the call to their children.
+    /// By default, it does nothing.
+    ///
+    /// [`Widget`]: trait.Widget.html
+    /// [`Layout::is_focused`]: struct.Layout.html#method.is_focused
+    /// [activation]: enum.Event.html#method.is_activation
+    /// [`Focusable`]: struct.Focusable.html
+    /// [`Layout`]: struct.Layout.html
+    fn focusable(&self, _layout: Layout<'_>, _focusables: &mut Vec<Focusable>) {
+    }
+
+    /// Collects the bounds of the overlays of the [`Widget`].
+    ///
+    /// An overlay is drawn on top of all the other widgets, like the menu of
+    /// a [`PickList`]. While the cursor is over an overlay, the rest of the
+    /// user interface will receive a cursor position out of bounds, and the
+    /// [`Widget`] owning the overlay can obtain the actual position using
+    /// [`Layout::overlay_cursor`].
+    ///
+    /// Widgets with an open overlay should push its bounds, while containers
+    /// must forward the call to their children. By default, it does nothing.
+    ///
+    /// [`Widget`]: trait.Widget.html
+    /// [`PickList`]: ../widget/pick_list/struct.PickList.html
+    /// [`Layout::overlay_cursor`]: struct.Layout.html#method.overlay_cursor
+    fn overlay(
+        &self,
+        _layout: Layout<'_>,
+        _overlays: &mut Vec<Rectangle<f32>>,
+    ) {
+    }
 }
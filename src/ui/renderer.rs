@@ -2,18 +2,37 @@ mod button;
 mod checkbox;
 mod image;
 mod panel;
+mod pick_list;
 mod progress_bar;
 mod radio;
+mod scrollable;
 mod slider;
 mod text;
+mod text_input;
+mod themed;
+mod tooltip;
 
-use crate::graphics::{Batch, Color, Font, Frame, Image, Mesh, Shape};
+pub mod theme;
+
+pub use theme::Theme;
+
+use crate::graphics::{
+    self, Batch, BitmapFont, Color, Frame, GlyphInfo, HorizontalAlignment,
+    Image, Mesh, Point, Rectangle, RichText, Shape, Sprite, Target,
+    VerticalAlignment,
+};
 use crate::load::{Join, Task};
-use crate::ui::core;
+use crate::ui::core::{self, MouseCursor};
 
 use std::cell::RefCell;
+use std::mem;
 use std::rc::Rc;
 
+const FOCUS_MARGIN: f32 = 3.0;
+
+/// The amount of segments used to draw a rounded corner.
+const CORNER_SEGMENTS: usize = 8;
+
 /// A renderer capable of drawing all the [built-in widgets].
 ///
 /// It can be configured using [`Configuration`] and
@@ -25,7 +44,15 @@ use std::rc::Rc;
 pub struct Renderer {
     pub(crate) sprites: Batch,
     pub(crate) images: Vec<Batch>,
+    pub(crate) mesh: Mesh,
     pub(crate) font: Rc<RefCell<Font>>,
+    pub(crate) theme: Theme,
+    labels: Vec<Label>,
+    sprite_sheet: Image,
+    clip: Option<Rectangle<f32>>,
+    layers: Vec<Layer>,
+    overlays: Vec<Layer>,
+    tooltip: Option<tooltip::Tooltip>,
     explain_mesh: Mesh,
 }
 
@@ -34,22 +61,288 @@ impl std::fmt::Debug for Renderer {
         f.debug_struct("Renderer")
             .field("sprites", &self.sprites)
             .field("images", &self.images)
+            .field("theme", &self.theme)
+            .field("clip", &self.clip)
+            .field("layers", &self.layers)
+            .field("overlays", &self.overlays)
+            .field("tooltip", &self.tooltip)
             .finish()
     }
 }
 
+impl Renderer {
+    /// Queues some text to be drawn in the current layer.
+    pub(crate) fn add_text(&mut self, text: graphics::Text<'_>) {
+        self.labels.push(Label {
+            content: String::from(text.content),
+            position: text.position,
+            bounds: text.bounds,
+            size: text.size,
+            color: text.color,
+            horizontal_alignment: text.horizontal_alignment,
+            vertical_alignment: text.vertical_alignment,
+        });
+    }
+
+    /// Queues a sprite of the spritesheet to be drawn at the given position.
+    pub(crate) fn add_sprite(
+        &mut self,
+        source: Rectangle<u16>,
+        position: Point,
+    ) {
+        self.sprites.add(Sprite {
+            source,
+            position,
+            ..Sprite::default()
+        });
+    }
+
+    /// Queues a [`NineSlice`] of the spritesheet to be drawn stretched to the
+    /// given bounds.
+    ///
+    /// [`NineSlice`]: theme/struct.NineSlice.html
+    pub(crate) fn add_nine_slice(
+        &mut self,
+        slice: theme::NineSlice,
+        bounds: Rectangle<f32>,
+    ) {
+        let source = slice.source;
+
+        // Themes are loaded from files, so slices may be anything
+        let columns = [
+            (source.x, slice.left),
+            (
+                source.x.saturating_add(slice.left),
+                source
+                    .width
+                    .saturating_sub(slice.left.saturating_add(slice.right)),
+            ),
+            (
+                source
+                    .x
+                    .saturating_add(source.width)
+                    .saturating_sub(slice.right),
+                slice.right,
+            ),
+        ];
+
+        let rows = [
+            (source.y, slice.top),
+            (
+                source.y.saturating_add(slice.top),
+                source
+                    .height
+                    .saturating_sub(slice.top.saturating_add(slice.bottom)),
+            ),
+            (
+                source
+                    .y
+                    .saturating_add(source.height)
+                    .saturating_sub(slice.bottom),
+                slice.bottom,
+            ),
+        ];
+
+        // The borders keep their size, while the center takes the rest
+        let widths = [
+            f32::from(slice.left),
+            (bounds.width - f32::from(slice.left) - f32::from(slice.right))
+                .max(0.0),
+            f32::from(slice.right),
+        ];
+
+        let heights = [
+            f32::from(slice.top),
+            (bounds.height - f32::from(slice.top) - f32::from(slice.bottom))
+                .max(0.0),
+            f32::from(slice.bottom),
+        ];
+
+        let mut y = bounds.y;
+
+        for ((row, height), target_height) in rows.iter().zip(&heights) {
+            let mut x = bounds.x;
+
+            for ((column, width), target_width) in columns.iter().zip(&widths) {
+                if *width > 0 && *height > 0 {
+                    self.sprites.add(Sprite {
+                        source: Rectangle {
+                            x: *column,
+                            y: *row,
+                            width: *width,
+                            height: *height,
+                        },
+                        position: Point::new(x, y),
+                        scale: (
+                            target_width / f32::from(*width),
+                            target_height / f32::from(*height),
+                        ),
+                        ..Sprite::default()
+                    });
+                }
+
+                x += target_width;
+            }
+
+            y += target_height;
+        }
+    }
+
+    /// Returns the [`Shape`] of a rectangle with its corners rounded using
+    /// the border radius of the current [`Theme`].
+    ///
+    /// [`Shape`]: ../graphics/enum.Shape.html
+    /// [`Theme`]: theme/struct.Theme.html
+    pub(crate) fn rounded(&self, bounds: Rectangle<f32>) -> Shape {
+        let radius = self
+            .theme
+            .border_radius
+            .min(bounds.width / 2.0)
+            .min(bounds.height / 2.0);
+
+        if radius <= 0.0 {
+            return Shape::Rectangle(bounds);
+        }
+
+        let corners = [
+            (bounds.x + bounds.width - radius, bounds.y + radius),
+            (
+                bounds.x + bounds.width - radius,
+                bounds.y + bounds.height - radius,
+            ),
+            (bounds.x + radius, bounds.y + bounds.height - radius),
+            (bounds.x + radius, bounds.y + radius),
+        ];
+
+        let mut points =
+            Vec::with_capacity(corners.len() * (CORNER_SEGMENTS + 1) + 1);
+
+        for (i, (x, y)) in corners.iter().enumerate() {
+            let start = (i as f32 - 1.0) * std::f32::consts::FRAC_PI_2;
+
+            for segment in 0..=CORNER_SEGMENTS {
+                let angle = start
+                    + segment as f32 / CORNER_SEGMENTS as f32
+                        * std::f32::consts::FRAC_PI_2;
+
+                points.push(Point::new(
+                    x + radius * angle.cos(),
+                    y + radius * angle.sin(),
+                ));
+            }
+        }
+
+        // Polylines are open, so we close the shape explicitly
+        points.push(points[0]);
+
+        Shape::Polyline { points }
+    }
+
+    /// Draws everything produced by the given function using the given
+    /// [`Theme`].
+    ///
+    /// [`Theme`]: theme/struct.Theme.html
+    pub(crate) fn with_theme<F>(&mut self, theme: Theme, draw: F) -> MouseCursor
+    where
+        F: FnOnce(&mut Renderer) -> MouseCursor,
+    {
+        let previous = mem::replace(&mut self.theme, theme);
+        let cursor = draw(self);
+
+        self.theme = previous;
+
+        cursor
+    }
+
+    /// Draws everything produced by the given function inside the given
+    /// bounds only.
+    ///
+    /// Clips can be nested. Everything drawn before and after is kept in
+    /// separate layers, so the draw order is preserved.
+    pub(crate) fn clip<F>(
+        &mut self,
+        bounds: Rectangle<f32>,
+        draw: F,
+    ) -> MouseCursor
+    where
+        F: FnOnce(&mut Renderer) -> MouseCursor,
+    {
+        let previous = self.clip;
+
+        self.finish_layer();
+        self.clip = Some(match previous {
+            Some(clip) => intersection(clip, bounds),
+            None => bounds,
+        });
+
+        let cursor = draw(self);
+
+        self.finish_layer();
+        self.clip = previous;
+
+        cursor
+    }
+
+    /// Draws everything produced by the given function on top of all the
+    /// layers, ignoring the current clip.
+    ///
+    /// Overlays are drawn in the same order they are produced.
+    pub(crate) fn overlay<F>(&mut self, draw: F) -> MouseCursor
+    where
+        F: FnOnce(&mut Renderer) -> MouseCursor,
+    {
+        self.finish_layer();
+
+        let layers = mem::replace(&mut self.layers, Vec::new());
+        let clip = self.clip.take();
+
+        let cursor = draw(self);
+
+        self.finish_layer();
+        self.clip = clip;
+
+        let overlays = mem::replace(&mut self.layers, layers);
+        self.overlays.extend(overlays);
+
+        cursor
+    }
+
+    /// Moves everything drawn so far into a new [`Layer`].
+    ///
+    /// [`Layer`]: struct.Layer.html
+    fn finish_layer(&mut self) {
+        let sprites = Batch::new(self.sprite_sheet.clone());
+
+        self.layers.push(Layer {
+            clip: self.clip,
+            sprites: mem::replace(&mut self.sprites, sprites),
+            images: mem::replace(&mut self.images, Vec::new()),
+            mesh: mem::replace(&mut self.mesh, Mesh::new()),
+            labels: mem::replace(&mut self.labels, Vec::new()),
+        });
+    }
+}
+
 impl core::Renderer for Renderer {
     type Configuration = Configuration;
 
     fn load(config: Configuration) -> Task<Renderer> {
-        (config.sprites, config.font)
-            .join()
-            .map(|(sprites, font)| Renderer {
-                sprites: Batch::new(sprites),
+        (config.sprites, config.font, config.theme).join().map(
+            |(sprites, font, theme)| Renderer {
+                sprites: Batch::new(sprites.clone()),
                 images: Vec::new(),
+                mesh: Mesh::new(),
                 font: Rc::new(RefCell::new(font)),
+                theme,
+                labels: Vec::new(),
+                sprite_sheet: sprites,
+                clip: None,
+                layers: Vec::new(),
+                overlays: Vec::new(),
+                tooltip: None,
                 explain_mesh: Mesh::new(),
-            })
+            },
+        )
     }
 
     fn explain(&mut self, layout: &core::Layout<'_>, color: Color) {
@@ -61,24 +354,140 @@ impl core::Renderer for Renderer {
             .for_each(|layout| self.explain(&layout, color));
     }
 
+    fn focus(&mut self, bounds: Rectangle<f32>, clip: Rectangle<f32>) {
+        let indicator = self.rounded(Rectangle {
+            x: bounds.x - FOCUS_MARGIN,
+            y: bounds.y - FOCUS_MARGIN,
+            width: bounds.width + FOCUS_MARGIN * 2.0,
+            height: bounds.height + FOCUS_MARGIN * 2.0,
+        });
+
+        let _ = self.clip(clip, |renderer| {
+            renderer.mesh.stroke(
+                indicator,
+                renderer.theme.colors.focus,
+                renderer.theme.border_width,
+            );
+
+            MouseCursor::OutOfBounds
+        });
+    }
+
     fn flush(&mut self, frame: &mut Frame<'_>) {
+        self.flush_tooltip((frame.width(), frame.height()));
+        self.finish_layer();
+
         let target = &mut frame.as_target();
+        let mut font = self.font.borrow_mut();
+
+        let overlays = self.overlays.drain(..);
+
+        for layer in self.layers.drain(..).chain(overlays) {
+            match layer.clip.map(pixels) {
+                None => layer.draw(&mut font, target),
+                Some(Some(clip)) => {
+                    layer.draw(&mut font, &mut target.clip(clip));
+                }
+                // The layer is entirely clipped
+                Some(None) => {}
+            }
+        }
 
+        if !self.explain_mesh.is_empty() {
+            self.explain_mesh.draw(target);
+            self.explain_mesh = Mesh::new();
+        }
+    }
+}
+
+/// The primitives drawn by a [`Renderer`] with the same clip.
+///
+/// [`Renderer`]: struct.Renderer.html
+#[derive(Debug)]
+struct Layer {
+    clip: Option<Rectangle<f32>>,
+    sprites: Batch,
+    images: Vec<Batch>,
+    mesh: Mesh,
+    labels: Vec<Label>,
+}
+
+impl Layer {
+    fn draw(&self, font: &mut Font, target: &mut Target<'_>) {
         self.sprites.draw(target);
-        self.sprites.clear();
 
         for image in &self.images {
             image.draw(target);
         }
 
-        self.images.clear();
+        if !self.mesh.is_empty() {
+            self.mesh.draw(target);
+        }
 
-        self.font.borrow_mut().draw(target);
+        if self.labels.is_empty() {
+            return;
+        }
 
-        if !self.explain_mesh.is_empty() {
-            self.explain_mesh.draw(target);
-            self.explain_mesh = Mesh::new();
+        // Text is queued in the font until drawn, so we only add the text of
+        // a layer when drawing it, with its own clip
+        for label in &self.labels {
+            font.add(graphics::Text {
+                content: &label.content,
+                position: label.position,
+                bounds: label.bounds,
+                size: label.size,
+                color: label.color,
+                horizontal_alignment: label.horizontal_alignment,
+                vertical_alignment: label.vertical_alignment,
+            });
         }
+
+        font.draw(target);
+    }
+}
+
+/// Some [`Text`] that owns its content.
+///
+/// [`Text`]: ../graphics/struct.Text.html
+#[derive(Debug)]
+struct Label {
+    content: String,
+    position: Point,
+    bounds: (f32, f32),
+    size: f32,
+    color: Color,
+    horizontal_alignment: HorizontalAlignment,
+    vertical_alignment: VerticalAlignment,
+}
+
+fn intersection(a: Rectangle<f32>, b: Rectangle<f32>) -> Rectangle<f32> {
+    let x = a.x.max(b.x);
+    let y = a.y.max(b.y);
+
+    Rectangle {
+        x,
+        y,
+        width: ((a.x + a.width).min(b.x + b.width) - x).max(0.0),
+        height: ((a.y + a.height).min(b.y + b.height) - y).max(0.0),
+    }
+}
+
+/// Converts a clip rectangle to pixels, if it is not empty.
+fn pixels(clip: Rectangle<f32>) -> Option<Rectangle<u32>> {
+    let x = clip.x.max(0.0).round();
+    let y = clip.y.max(0.0).round();
+    let width = ((clip.x + clip.width).round() - x).max(0.0) as u32;
+    let height = ((clip.y + clip.height).round() - y).max(0.0) as u32;
+
+    if width == 0 || height == 0 {
+        None
+    } else {
+        Some(Rectangle {
+            x: x as u32,
+            y: y as u32,
+            width,
+            height,
+        })
     }
 }
 
@@ -94,10 +503,11 @@ impl core::Renderer for Renderer {
 /// # Example
 /// ```no_run
 /// use coffee::graphics::Image;
-/// use coffee::ui::Configuration;
+/// use coffee::ui::{Configuration, Theme};
 ///
 /// Configuration {
 ///     sprites: Image::load("resources/my_ui_sprites.png"),
+///     theme: Theme::load("resources/my_ui_theme.json"),
 ///     ..Configuration::default()
 /// };
 /// ```
@@ -105,9 +515,13 @@ impl core::Renderer for Renderer {
 pub struct Configuration {
     /// The spritesheet used to render the [different widgets] of the user interface.
     ///
-    /// The spritesheet needs to be structured like [the default spritesheet].
+    /// The regions of the spritesheet used by every widget are described by
+    /// the [`Sprites`] of the [`theme`]. By default, they describe
+    /// [the default spritesheet].
     ///
     /// [different widgets]: widget/index.html
+    /// [`Sprites`]: theme/struct.Sprites.html
+    /// [`theme`]: #structfield.theme
     /// [the default spritesheet]: https://raw.githubusercontent.com/hecrj/coffee/92aa6b64673116fdc49d8694a10ee5bf53afb1b5/resources/ui.png
     pub sprites: Task<Image>,
 
@@ -118,6 +532,89 @@ pub struct Configuration {
     /// [`Text`]: widget/text/struct.Text.html
     /// [Inconsolata Regular]: https://fonts.google.com/specimen/Inconsolata
     pub font: Task<Font>,
+
+    /// The [`Theme`] of the user interface.
+    ///
+    /// You can use [`Theme::load`] to load it from a file.
+    ///
+    /// [`Theme`]: theme/struct.Theme.html
+    /// [`Theme::load`]: theme/struct.Theme.html#method.load
+    pub theme: Task<Theme>,
+}
+
+/// The font used by the [`Renderer`] to draw text.
+///
+/// You can obtain one from a [`graphics::Font`] or a [`BitmapFont`] using
+/// `into`:
+///
+/// ```no_run
+/// use coffee::graphics::BitmapFont;
+/// use coffee::ui::Configuration;
+///
+/// Configuration {
+///     font: BitmapFont::load("resources/my_pixel_font.fnt").map(Into::into),
+///     ..Configuration::default()
+/// };
+/// ```
+///
+/// [`Renderer`]: struct.Renderer.html
+/// [`graphics::Font`]: ../graphics/struct.Font.html
+/// [`BitmapFont`]: ../graphics/struct.BitmapFont.html
+#[allow(missing_debug_implementations)]
+pub enum Font {
+    /// A font with outline typefaces, like TrueType fonts.
+    Outline(graphics::Font),
+
+    /// A font with pre-rendered glyphs.
+    Bitmap(BitmapFont),
+}
+
+impl Font {
+    pub(crate) fn add<'a, T: Into<RichText<'a>>>(&mut self, text: T) {
+        match self {
+            Font::Outline(font) => font.add(text),
+            Font::Bitmap(font) => font.add(text),
+        }
+    }
+
+    pub(crate) fn measure<'a, T: Into<RichText<'a>>>(
+        &mut self,
+        text: T,
+    ) -> (f32, f32) {
+        match self {
+            Font::Outline(font) => font.measure(text),
+            Font::Bitmap(font) => font.measure(text),
+        }
+    }
+
+    pub(crate) fn layout<'a, T: Into<RichText<'a>>>(
+        &mut self,
+        text: T,
+    ) -> Vec<GlyphInfo> {
+        match self {
+            Font::Outline(font) => font.layout(text),
+            Font::Bitmap(font) => font.layout(text),
+        }
+    }
+
+    pub(crate) fn draw(&mut self, target: &mut Target<'_>) {
+        match self {
+            Font::Outline(font) => font.draw(target),
+            Font::Bitmap(font) => font.draw(target),
+        }
+    }
+}
+
+impl From<graphics::Font> for Font {
+    fn from(font: graphics::Font) -> Font {
+        Font::Outline(font)
+    }
+}
+
+impl From<BitmapFont> for Font {
+    fn from(font: BitmapFont) -> Font {
+        Font::Bitmap(font)
+    }
 }
 
 impl Default for Configuration {
@@ -131,9 +628,11 @@ impl Default for Configuration {
                     ))?,
                 )
             }),
-            font: Font::load_from_bytes(include_bytes!(
+            font: graphics::Font::load_from_bytes(include_bytes!(
                 "../../resources/font/Inconsolata-Regular.ttf"
-            )),
+            ))
+            .map(Font::Outline),
+            theme: Task::succeed(Theme::default),
         }
     }
 }
@@ -1,116 +1,64 @@
 use crate::graphics::{
-    Color, HorizontalAlignment, Point, Rectangle, Sprite, Text,
-    VerticalAlignment,
+    HorizontalAlignment, Point, Rectangle, Text, VerticalAlignment,
 };
-use crate::ui::core::MouseCursor;
+use crate::ui::core::{Interaction, MouseCursor};
 use crate::ui::{button, Renderer};
 
-const LEFT: Rectangle<u16> = Rectangle {
-    x: 0,
-    y: 34,
-    width: 6,
-    height: 49,
-};
-
-const BACKGROUND: Rectangle<u16> = Rectangle {
-    x: LEFT.width,
-    y: LEFT.y,
-    width: 1,
-    height: LEFT.height,
-};
-
-const RIGHT: Rectangle<u16> = Rectangle {
-    x: LEFT.height - LEFT.width,
-    y: LEFT.y,
-    width: LEFT.width,
-    height: LEFT.height,
-};
-
 impl button::Renderer for Renderer {
     fn draw(
         &mut self,
-        cursor_position: Point,
         mut bounds: Rectangle<f32>,
-        state: &button::State,
+        interaction: Interaction,
         label: &str,
         class: button::Class,
     ) -> MouseCursor {
-        let mouse_over = bounds.contains(cursor_position);
+        let sprites = &self.theme.sprites;
+        let background = match class {
+            button::Class::Primary => sprites.primary_button,
+            button::Class::Secondary => sprites.secondary_button,
+            button::Class::Positive => sprites.positive_button,
+        };
 
-        let mut state_offset = 0;
+        let mut slice = background.idle;
 
-        if mouse_over {
-            if state.is_pressed() {
+        match interaction {
+            Interaction::Pressed => {
                 bounds.y += 4.0;
-                state_offset = RIGHT.x + RIGHT.width;
-            } else {
+                slice = background.pressed;
+            }
+            Interaction::Hovered => {
                 bounds.y -= 1.0;
             }
+            Interaction::Idle | Interaction::Disabled => {}
         }
 
-        let class_index = match class {
-            button::Class::Primary => 0,
-            button::Class::Secondary => 1,
-            button::Class::Positive => 2,
-        };
-
-        self.sprites.add(Sprite {
-            source: Rectangle {
-                x: LEFT.x + state_offset,
-                y: LEFT.y + class_index * LEFT.height,
-                ..LEFT
-            },
-            position: Point::new(bounds.x, bounds.y),
-            scale: (1.0, 1.0),
-        });
-
-        self.sprites.add(Sprite {
-            source: Rectangle {
-                x: BACKGROUND.x + state_offset,
-                y: BACKGROUND.y + class_index * BACKGROUND.height,
-                ..BACKGROUND
+        self.add_nine_slice(
+            slice,
+            Rectangle {
+                height: f32::from(slice.source.height),
+                ..bounds
             },
-            position: Point::new(bounds.x + LEFT.width as f32, bounds.y),
-            scale: (bounds.width - (LEFT.width + RIGHT.width) as f32, 1.0),
-        });
+        );
 
-        self.sprites.add(Sprite {
-            source: Rectangle {
-                x: RIGHT.x + state_offset,
-                y: RIGHT.y + class_index * RIGHT.height,
-                ..RIGHT
-            },
-            position: Point::new(
-                bounds.x + bounds.width - RIGHT.width as f32,
-                bounds.y,
-            ),
-            scale: (1.0, 1.0),
-        });
+        let colors = self.theme.colors;
 
-        self.font.borrow_mut().add(Text {
+        self.add_text(Text {
             content: label,
             position: Point::new(bounds.x, bounds.y - 4.0),
             bounds: (bounds.width, bounds.height),
-            color: if mouse_over {
-                Color::WHITE
-            } else {
-                Color {
-                    r: 0.9,
-                    g: 0.9,
-                    b: 0.9,
-                    a: 1.0,
+            color: match interaction {
+                Interaction::Idle => colors.label,
+                Interaction::Hovered | Interaction::Pressed => {
+                    colors.hovered_label
                 }
+                Interaction::Disabled => colors.disabled_label,
             },
-            size: 20.0,
+            size: self.theme.text_size,
             horizontal_alignment: HorizontalAlignment::Center,
             vertical_alignment: VerticalAlignment::Center,
             ..Text::default()
         });
 
-        if mouse_over {
-            MouseCursor::Pointer
-        } else {
-            MouseCursor::OutOfBounds
-        }
+        interaction.mouse_cursor()
     }
 }
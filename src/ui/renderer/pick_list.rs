@@ -0,0 +1,134 @@
+use crate::graphics::{
+    self, HorizontalAlignment, Point, Rectangle, Shape, VerticalAlignment,
+};
+use crate::ui::core::{Interaction, MouseCursor};
+use crate::ui::{pick_list, Renderer, Theme};
+
+use std::f32;
+
+const ARROW_SIZE: f32 = 8.0;
+
+impl pick_list::Renderer for Renderer {
+    fn draw(
+        &mut self,
+        bounds: Rectangle<f32>,
+        interaction: Interaction,
+        selected: Option<&str>,
+        menu: Option<pick_list::Menu<'_>>,
+    ) -> MouseCursor {
+        let theme = self.theme;
+        let field = self.rounded(bounds);
+
+        self.mesh.fill(field.clone(), theme.colors.background);
+        self.mesh.stroke(
+            field,
+            if interaction.is_hovered() || menu.is_some() {
+                theme.colors.accent
+            } else {
+                theme.colors.border
+            },
+            theme.border_width,
+        );
+
+        let arrow_x = bounds.x + bounds.width - theme.padding - ARROW_SIZE;
+        let arrow_y = bounds.y + (bounds.height - ARROW_SIZE / 2.0) / 2.0;
+
+        self.mesh.fill(
+            Shape::Polyline {
+                points: vec![
+                    Point::new(arrow_x, arrow_y),
+                    Point::new(arrow_x + ARROW_SIZE, arrow_y),
+                    Point::new(
+                        arrow_x + ARROW_SIZE / 2.0,
+                        arrow_y + ARROW_SIZE / 2.0,
+                    ),
+                ],
+            },
+            theme.colors.text,
+        );
+
+        if let Some(selected) = selected {
+            self.add_text(label(&theme, selected, bounds));
+        }
+
+        let cursor = match menu {
+            Some(menu) => self.overlay(|renderer| renderer.draw_menu(menu)),
+            None => MouseCursor::OutOfBounds,
+        };
+
+        if interaction.is_hovered() {
+            interaction.mouse_cursor()
+        } else {
+            cursor
+        }
+    }
+}
+
+impl Renderer {
+    fn draw_menu(&mut self, menu: pick_list::Menu<'_>) -> MouseCursor {
+        let theme = self.theme;
+        let background = self.rounded(menu.bounds);
+
+        self.mesh.fill(background.clone(), theme.colors.background);
+
+        let _ = self.clip(menu.bounds, |renderer| {
+            for (index, option) in menu.options.iter().enumerate() {
+                let bounds = menu.option_bounds(index);
+
+                if bounds.y + bounds.height < menu.bounds.y
+                    || bounds.y > menu.bounds.y + menu.bounds.height
+                {
+                    continue;
+                }
+
+                if menu.selected == Some(index) {
+                    renderer
+                        .mesh
+                        .fill(Shape::Rectangle(bounds), theme.colors.selection);
+                } else if menu.hovered == Some(index) {
+                    renderer
+                        .mesh
+                        .fill(Shape::Rectangle(bounds), theme.colors.hovered);
+                }
+
+                renderer.add_text(label(&theme, option, bounds));
+            }
+
+            MouseCursor::OutOfBounds
+        });
+
+        let scrollbar = menu.scroller.and_then(|scroller| {
+            self.draw_scrollbar(
+                menu.cursor_position,
+                menu.bounds,
+                scroller,
+                &menu.scroll,
+            )
+        });
+
+        self.mesh
+            .stroke(background, theme.colors.accent, theme.border_width);
+
+        match scrollbar {
+            Some(cursor) => cursor,
+            None if menu.hovered.is_some() => MouseCursor::Pointer,
+            None => MouseCursor::OutOfBounds,
+        }
+    }
+}
+
+fn label<'a>(
+    theme: &Theme,
+    content: &'a str,
+    bounds: Rectangle<f32>,
+) -> graphics::Text<'a> {
+    graphics::Text {
+        content,
+        position: Point::new(bounds.x + theme.padding, bounds.y),
+        bounds: (f32::INFINITY, bounds.height),
+        size: theme.text_size,
+        color: theme.colors.text,
+        horizontal_alignment: HorizontalAlignment::Left,
+        vertical_alignment: VerticalAlignment::Center,
+    }
+}
@@ -0,0 +1,81 @@
+use crate::graphics::{Point, Rectangle};
+use crate::ui::core::MouseCursor;
+use crate::ui::{scrollable, Renderer};
+
+impl scrollable::Renderer for Renderer {
+    fn draw<F>(
+        &mut self,
+        cursor_position: Point,
+        bounds: Rectangle<f32>,
+        scroller: Option<Rectangle<f32>>,
+        state: &scrollable::State,
+        draw_content: F,
+    ) -> MouseCursor
+    where
+        F: FnOnce(&mut Self) -> MouseCursor,
+    {
+        let cursor = self.clip(bounds, draw_content);
+
+        let scroller = match scroller {
+            Some(scroller) => scroller,
+            None => return cursor,
+        };
+
+        match self.draw_scrollbar(cursor_position, bounds, scroller, state) {
+            Some(cursor) => cursor,
+            None if cursor == MouseCursor::OutOfBounds
+                && bounds.contains(cursor_position) =>
+            {
+                MouseCursor::Idle
+            }
+            None => cursor,
+        }
+    }
+}
+
+impl Renderer {
+    /// Draws the scrollbar of some scrolled content with the given bounds.
+    ///
+    /// It returns the [`MouseCursor`] of the scrollbar, if the cursor is over
+    /// it or dragging it.
+    ///
+    /// [`MouseCursor`]: ../core/enum.MouseCursor.html
+    pub(super) fn draw_scrollbar(
+        &mut self,
+        cursor_position: Point,
+        bounds: Rectangle<f32>,
+        scroller: Rectangle<f32>,
+        state: &scrollable::State,
+    ) -> Option<MouseCursor> {
+        let rail = Rectangle {
+            y: bounds.y,
+            height: bounds.height,
+            ..scroller
+        };
+
+        let is_mouse_over_rail = rail.contains(cursor_position);
+        let is_active = state.is_scroller_grabbed() || is_mouse_over_rail;
+        let colors = self.theme.colors;
+
+        let rail = self.rounded(rail);
+        let scroller = self.rounded(scroller);
+
+        self.mesh.fill(rail, colors.rail);
+        self.mesh.fill(
+            scroller,
+            if is_active {
+                colors.active_scroller
+            } else {
+                colors.scroller
+            },
+        );
+
+        if state.is_scroller_grabbed() {
+            Some(MouseCursor::Grabbing)
+        } else if is_mouse_over_rail {
+            Some(MouseCursor::Grab)
+        } else {
+            None
+        }
+    }
+}
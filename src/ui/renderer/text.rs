@@ -69,7 +69,7 @@ impl text::Renderer for Renderer {
         horizontal_alignment: HorizontalAlignment,
         vertical_alignment: VerticalAlignment,
     ) {
-        self.font.borrow_mut().add(graphics::Text {
+        self.add_text(graphics::Text {
             content,
             position: Point::new(bounds.x, bounds.y),
             bounds: (bounds.width, bounds.height),
@@ -0,0 +1,101 @@
+use crate::graphics::{
+    self, HorizontalAlignment, Point, Rectangle, Shape, VerticalAlignment,
+};
+use crate::ui::core::{Interaction, MouseCursor};
+use crate::ui::{text_input, Renderer};
+
+use std::f32;
+
+const CURSOR_WIDTH: f32 = 2.0;
+
+impl text_input::Renderer for Renderer {
+    fn draw(
+        &mut self,
+        bounds: Rectangle<f32>,
+        interaction: Interaction,
+        state: &text_input::State,
+        value: &str,
+        placeholder: &str,
+    ) -> MouseCursor {
+        let theme = self.theme;
+        let colors = theme.colors;
+        let field = self.rounded(bounds);
+
+        self.mesh.fill(field.clone(), colors.background);
+        self.mesh.stroke(
+            field,
+            if state.is_focused() {
+                colors.accent
+            } else {
+                colors.border
+            },
+            theme.border_width,
+        );
+
+        let text = |content, color| graphics::Text {
+            content,
+            position: Point::new(bounds.x + theme.padding, bounds.y),
+            bounds: (f32::INFINITY, bounds.height),
+            size: theme.text_size,
+            color,
+            horizontal_alignment: HorizontalAlignment::Left,
+            vertical_alignment: VerticalAlignment::Center,
+        };
+
+        if value.is_empty() {
+            self.add_text(text(placeholder, colors.placeholder));
+        } else {
+            self.add_text(text(value, colors.text));
+        }
+
+        if state.is_focused() {
+            let glyphs =
+                self.font.borrow_mut().layout(text(value, colors.text));
+
+            // The glyph of a character may be missing in the font, so we
+            // look for the first glyph at or after its byte index
+            let offset = |position: usize| {
+                let index = value
+                    .char_indices()
+                    .nth(position)
+                    .map_or(value.len(), |(index, _)| index);
+
+                glyphs
+                    .iter()
+                    .find(|glyph| glyph.index >= index)
+                    .map(|glyph| glyph.bounds.x)
+                    .or_else(|| {
+                        glyphs
+                            .last()
+                            .map(|glyph| glyph.bounds.x + glyph.bounds.width)
+                    })
+                    .unwrap_or(bounds.x + theme.padding)
+            };
+
+            let line = |x: f32, width: f32| {
+                Shape::Rectangle(Rectangle {
+                    x,
+                    y: bounds.y + (bounds.height - theme.text_size) / 2.0,
+                    width,
+                    height: theme.text_size,
+                })
+            };
+
+            if let Some((start, end)) = state.selection() {
+                let start = offset(start);
+
+                self.mesh
+                    .fill(line(start, offset(end) - start), colors.selection);
+            }
+
+            self.mesh
+                .fill(line(offset(state.cursor()), CURSOR_WIDTH), colors.text);
+        }
+
+        if interaction.is_hovered() {
+            MouseCursor::Text
+        } else {
+            MouseCursor::OutOfBounds
+        }
+    }
+}
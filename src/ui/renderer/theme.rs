@@ -0,0 +1,430 @@
+//! Customize the look of the built-in [`Renderer`].
+//!
+//! A [`Theme`] can be created in Rust or loaded from a JSON file, letting
+//! artists restyle your user interface without touching any code. Any
+//! missing field in a file takes its default value:
+//!
+//! ```json
+//! {
+//!     "text_size": 24.0,
+//!     "border_radius": 4.0,
+//!     "colors": {
+//!         "accent": { "r": 0.9, "g": 0.5, "b": 0.2, "a": 1.0 }
+//!     },
+//!     "sprites": {
+//!         "panel": {
+//!             "source": { "x": 0, "y": 0, "width": 28, "height": 34 },
+//!             "left": 8,
+//!             "top": 8,
+//!             "right": 8,
+//!             "bottom": 8
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! [`Renderer`]: ../struct.Renderer.html
+//! [`Theme`]: struct.Theme.html
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::graphics::{Color, Rectangle};
+use crate::load::Task;
+use crate::Result;
+
+/// The look of the built-in [`Renderer`].
+///
+/// Colors and sizes are used by the widgets drawn with shapes, like a
+/// [`TextInput`], while [`Sprites`] describe the regions of the spritesheet
+/// used by the rest of widgets.
+///
+/// You can set the [`Theme`] of your user interface in [`Configuration`] and
+/// override it for part of it using a [`Themed`] widget.
+///
+/// [`Renderer`]: ../struct.Renderer.html
+/// [`TextInput`]: ../widget/text_input/struct.TextInput.html
+/// [`Sprites`]: struct.Sprites.html
+/// [`Theme`]: struct.Theme.html
+/// [`Configuration`]: ../struct.Configuration.html
+/// [`Themed`]: ../widget/themed/struct.Themed.html
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Theme {
+    /// The size of the text of interactive widgets, like the label of a
+    /// [`Button`].
+    ///
+    /// [`Button`]: ../widget/button/struct.Button.html
+    pub text_size: f32,
+
+    /// The space between the borders of a field and its text.
+    pub padding: f32,
+
+    /// The radius of the corners of fields, menus, and scrollbars.
+    pub border_radius: f32,
+
+    /// The width of the borders of fields, menus, and the focus indicator.
+    pub border_width: f32,
+
+    /// The colors of the widgets.
+    pub colors: Colors,
+
+    /// The regions of the spritesheet used by the widgets.
+    pub sprites: Sprites,
+}
+
+impl Theme {
+    /// Opens a [`Theme`] from the given JSON file.
+    ///
+    /// [`Theme`]: struct.Theme.html
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Theme> {
+        let file = std::fs::File::open(path)?;
+
+        Ok(serde_json::from_reader(std::io::BufReader::new(file))?)
+    }
+
+    /// Creates a [`Task`] that opens a [`Theme`] from the given JSON file.
+    ///
+    /// [`Task`]: ../../load/struct.Task.html
+    /// [`Theme`]: struct.Theme.html
+    pub fn load<P: AsRef<Path>>(path: P) -> Task<Theme> {
+        let path = path.as_ref().to_path_buf();
+
+        Task::new(move || Theme::open(path))
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Theme {
+        Theme {
+            text_size: 20.0,
+            padding: 10.0,
+            border_radius: 0.0,
+            border_width: 2.0,
+            colors: Colors::default(),
+            sprites: Sprites::default(),
+        }
+    }
+}
+
+/// The colors of a [`Theme`].
+///
+/// [`Theme`]: struct.Theme.html
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Colors {
+    /// The color of the text of fields.
+    pub text: Color,
+
+    /// The color of the placeholder of an empty field.
+    pub placeholder: Color,
+
+    /// The color of the label of a [`Button`].
+    ///
+    /// [`Button`]: ../widget/button/struct.Button.html
+    pub label: Color,
+
+    /// The color of the label of a hovered [`Button`].
+    ///
+    /// [`Button`]: ../widget/button/struct.Button.html
+    pub hovered_label: Color,
+
+    /// The color of the label of a disabled [`Button`].
+    ///
+    /// [`Button`]: ../widget/button/struct.Button.html
+    pub disabled_label: Color,
+
+    /// The background color of fields and menus.
+    pub background: Color,
+
+    /// The color of the borders of fields and menus.
+    pub border: Color,
+
+    /// The color of the borders of hovered or focused fields.
+    pub accent: Color,
+
+    /// The background color of selected text and options.
+    pub selection: Color,
+
+    /// The background color of hovered options.
+    pub hovered: Color,
+
+    /// The color of the focus indicator.
+    pub focus: Color,
+
+    /// The color of the rail of a scrollbar.
+    pub rail: Color,
+
+    /// The color of the scroller of a scrollbar.
+    pub scroller: Color,
+
+    /// The color of the scroller of a hovered or dragged scrollbar.
+    pub active_scroller: Color,
+}
+
+impl Default for Colors {
+    fn default() -> Colors {
+        Colors {
+            text: Color::BLACK,
+            placeholder: gray(0.6, 1.0),
+            label: gray(0.9, 1.0),
+            hovered_label: Color::WHITE,
+            disabled_label: gray(0.9, 0.5),
+            background: Color::WHITE,
+            border: gray(0.7, 1.0),
+            accent: Color {
+                r: 0.4,
+                g: 0.6,
+                b: 0.9,
+                a: 1.0,
+            },
+            selection: Color {
+                r: 0.75,
+                g: 0.85,
+                b: 1.0,
+                a: 1.0,
+            },
+            hovered: gray(0.9, 1.0),
+            focus: Color {
+                r: 0.9,
+                g: 0.8,
+                b: 0.3,
+                a: 1.0,
+            },
+            rail: gray(0.0, 0.3),
+            scroller: gray(0.7, 0.8),
+            active_scroller: gray(0.9, 0.9),
+        }
+    }
+}
+
+/// The regions of the spritesheet used by the widgets of a [`Theme`].
+///
+/// The default regions describe [the default spritesheet].
+///
+/// [`Theme`]: struct.Theme.html
+/// [the default spritesheet]: https://raw.githubusercontent.com/hecrj/coffee/92aa6b64673116fdc49d8694a10ee5bf53afb1b5/resources/ui.png
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Sprites {
+    /// The background of a [`Panel`].
+    ///
+    /// [`Panel`]: ../widget/panel/struct.Panel.html
+    pub panel: NineSlice,
+
+    /// The background of a [`Button`] of the `Primary` class.
+    ///
+    /// [`Button`]: ../widget/button/struct.Button.html
+    pub primary_button: Button,
+
+    /// The background of a [`Button`] of the `Secondary` class.
+    ///
+    /// [`Button`]: ../widget/button/struct.Button.html
+    pub secondary_button: Button,
+
+    /// The background of a [`Button`] of the `Positive` class.
+    ///
+    /// [`Button`]: ../widget/button/struct.Button.html
+    pub positive_button: Button,
+
+    /// The box of a [`Checkbox`].
+    ///
+    /// [`Checkbox`]: ../widget/checkbox/struct.Checkbox.html
+    pub checkbox: Toggle,
+
+    /// The circle of a [`Radio`] button.
+    ///
+    /// [`Radio`]: ../widget/radio/struct.Radio.html
+    pub radio: Toggle,
+
+    /// The rail of a [`Slider`], stretched horizontally.
+    ///
+    /// [`Slider`]: ../widget/slider/struct.Slider.html
+    pub slider_rail: Rectangle<u16>,
+
+    /// The marker of a [`Slider`].
+    ///
+    /// [`Slider`]: ../widget/slider/struct.Slider.html
+    pub slider_marker: Rectangle<u16>,
+
+    /// The marker of a hovered or dragged [`Slider`].
+    ///
+    /// [`Slider`]: ../widget/slider/struct.Slider.html
+    pub active_slider_marker: Rectangle<u16>,
+
+    /// The background of a [`ProgressBar`].
+    ///
+    /// [`ProgressBar`]: ../widget/progress_bar/struct.ProgressBar.html
+    pub progress_bar: NineSlice,
+
+    /// The bar of a [`ProgressBar`], which is cut to show the progress.
+    ///
+    /// [`ProgressBar`]: ../widget/progress_bar/struct.ProgressBar.html
+    pub progress_bar_fill: NineSlice,
+}
+
+impl Default for Sprites {
+    fn default() -> Sprites {
+        let button = |row: u16| Button {
+            idle: NineSlice::horizontal(
+                Rectangle {
+                    x: 0,
+                    y: 34 + row * 49,
+                    width: 49,
+                    height: 49,
+                },
+                6,
+            ),
+            pressed: NineSlice::horizontal(
+                Rectangle {
+                    x: 49,
+                    y: 34 + row * 49,
+                    width: 49,
+                    height: 49,
+                },
+                6,
+            ),
+        };
+
+        let toggle = |y: u16| Toggle {
+            idle: Rectangle {
+                x: 98,
+                y,
+                width: 28,
+                height: 28,
+            },
+            hovered: Rectangle {
+                x: 126,
+                y,
+                width: 28,
+                height: 28,
+            },
+            mark: Rectangle {
+                x: 154,
+                y,
+                width: 28,
+                height: 28,
+            },
+        };
+
+        Sprites {
+            panel: NineSlice {
+                source: Rectangle {
+                    x: 0,
+                    y: 0,
+                    width: 28,
+                    height: 34,
+                },
+                left: 8,
+                top: 8,
+                right: 8,
+                bottom: 8,
+            },
+            primary_button: button(0),
+            secondary_button: button(1),
+            positive_button: button(2),
+            checkbox: toggle(0),
+            radio: toggle(28),
+            slider_rail: Rectangle {
+                x: 98,
+                y: 56,
+                width: 1,
+                height: 4,
+            },
+            slider_marker: Rectangle {
+                x: 126,
+                y: 56,
+                width: 16,
+                height: 24,
+            },
+            active_slider_marker: Rectangle {
+                x: 142,
+                y: 56,
+                width: 16,
+                height: 24,
+            },
+            progress_bar: button(1).idle,
+            progress_bar_fill: button(0).idle,
+        }
+    }
+}
+
+/// A region of a spritesheet divided in nine parts, which can be stretched to
+/// any size while keeping its borders intact.
+///
+/// The corners are never stretched, the borders are stretched along their
+/// side, and the center is stretched in both directions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NineSlice {
+    /// The whole region in the spritesheet.
+    pub source: Rectangle<u16>,
+
+    /// The width of the left border.
+    pub left: u16,
+
+    /// The height of the top border.
+    pub top: u16,
+
+    /// The width of the right border.
+    pub right: u16,
+
+    /// The height of the bottom border.
+    pub bottom: u16,
+}
+
+impl NineSlice {
+    /// Creates a [`NineSlice`] with only left and right borders of the given
+    /// width.
+    ///
+    /// [`NineSlice`]: struct.NineSlice.html
+    pub fn horizontal(source: Rectangle<u16>, width: u16) -> NineSlice {
+        NineSlice {
+            source,
+            left: width,
+            top: 0,
+            right: width,
+            bottom: 0,
+        }
+    }
+}
+
+/// The backgrounds of a [`Button`].
+///
+/// [`Button`]: ../widget/button/struct.Button.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Button {
+    /// The background of an idle or hovered [`Button`].
+    ///
+    /// [`Button`]: ../widget/button/struct.Button.html
+    pub idle: NineSlice,
+
+    /// The background of a pressed [`Button`].
+    ///
+    /// [`Button`]: ../widget/button/struct.Button.html
+    pub pressed: NineSlice,
+}
+
+/// The sprites of a widget that can be checked or selected, like a
+/// [`Checkbox`].
+///
+/// [`Checkbox`]: ../widget/checkbox/struct.Checkbox.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Toggle {
+    /// The background of the idle widget.
+    pub idle: Rectangle<u16>,
+
+    /// The background of the hovered widget.
+    pub hovered: Rectangle<u16>,
+
+    /// The mark drawn on top of the background when checked or selected.
+    pub mark: Rectangle<u16>,
+}
+
+fn gray(value: f32, alpha: f32) -> Color {
+    Color {
+        r: value,
+        g: value,
+        b: value,
+        a: alpha,
+    }
+}
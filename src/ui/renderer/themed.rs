@@ -0,0 +1,13 @@
+use crate::ui::core::MouseCursor;
+use crate::ui::{themed, Renderer, Theme};
+
+impl themed::Renderer for Renderer {
+    type Theme = Theme;
+
+    fn draw<F>(&mut self, theme: &Theme, draw_content: F) -> MouseCursor
+    where
+        F: FnOnce(&mut Self) -> MouseCursor,
+    {
+        self.with_theme(*theme, draw_content)
+    }
+}
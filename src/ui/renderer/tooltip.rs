@@ -0,0 +1,99 @@
+use crate::graphics::{self, Point, Rectangle};
+use crate::ui::core::MouseCursor;
+use crate::ui::{tooltip, Renderer, Theme};
+
+use std::f32;
+
+/// The distance between the cursor and the text of a tooltip.
+const CURSOR_OFFSET: f32 = 16.0;
+
+impl tooltip::Renderer for Renderer {
+    fn draw(&mut self, cursor_position: Point, text: &str) {
+        // The innermost tooltip takes precedence
+        if self.tooltip.is_none() {
+            self.tooltip = Some(Tooltip {
+                text: String::from(text),
+                cursor_position,
+                theme: self.theme,
+            });
+        }
+    }
+}
+
+/// A tooltip waiting to be drawn, once the size of the window is known.
+#[derive(Debug)]
+pub(super) struct Tooltip {
+    text: String,
+    cursor_position: Point,
+    theme: Theme,
+}
+
+impl Renderer {
+    /// Draws the pending tooltip, if any, on top of everything else and
+    /// inside a viewport of the given size.
+    pub(super) fn flush_tooltip(&mut self, viewport: (f32, f32)) {
+        if let Some(tooltip) = self.tooltip.take() {
+            let _ = self.overlay(|renderer| {
+                renderer.with_theme(tooltip.theme, |renderer| {
+                    renderer.draw_tooltip(&tooltip, viewport)
+                })
+            });
+        }
+    }
+
+    fn draw_tooltip(
+        &mut self,
+        tooltip: &Tooltip,
+        (viewport_width, viewport_height): (f32, f32),
+    ) -> MouseCursor {
+        let theme = self.theme;
+
+        let text = graphics::Text {
+            content: &tooltip.text,
+            bounds: (
+                (viewport_width - theme.padding * 2.0).max(0.0),
+                f32::INFINITY,
+            ),
+            size: theme.text_size,
+            color: theme.colors.text,
+            ..graphics::Text::default()
+        };
+
+        let (text_width, text_height) =
+            self.font.borrow_mut().measure(text.clone());
+        let width = text_width + theme.padding * 2.0;
+        let height = text_height + theme.padding * 2.0;
+
+        // The tooltip is shown below the cursor, unless it does not fit
+        let cursor = tooltip.cursor_position;
+        let x = (cursor.x + CURSOR_OFFSET).min(viewport_width - width);
+        let y = if cursor.y + CURSOR_OFFSET + height <= viewport_height {
+            cursor.y + CURSOR_OFFSET
+        } else {
+            cursor.y - height
+        };
+
+        let bounds = Rectangle {
+            x: x.max(0.0),
+            y: y.max(0.0),
+            width,
+            height,
+        };
+
+        let background = self.rounded(bounds);
+
+        self.mesh.fill(background.clone(), theme.colors.background);
+        self.mesh
+            .stroke(background, theme.colors.border, theme.border_width);
+
+        self.add_text(graphics::Text {
+            position: Point::new(
+                bounds.x + theme.padding,
+                bounds.y + theme.padding,
+            ),
+            ..text
+        });
+
+        MouseCursor::OutOfBounds
+    }
+}
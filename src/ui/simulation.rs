@@ -0,0 +1,274 @@
+use std::collections::BTreeMap;
+
+use crate::graphics::{Canvas, Gpu, Window, WindowSettings};
+use crate::input::{self, mouse, Input as _, Recording};
+use crate::ui::core::{Cache, Interface, MouseCursor, Renderer as _};
+use crate::ui::{react, viewport, Input, UserInterface};
+use crate::{Result, Timer};
+
+/// A deterministic, windowless runner for a [`UserInterface`].
+///
+/// It works just like a [`Simulation`], but the scheduled events are also
+/// fed to the widgets of the [`UserInterface`], and the produced messages are
+/// handled by [`UserInterface::react`] on the same tick. Therefore, it can be
+/// used to test the interactions of your user interface.
+///
+/// A [`ui::Simulation`] is only available when using the `software` graphics
+/// backend.
+///
+/// [`UserInterface`]: trait.UserInterface.html
+/// [`UserInterface::react`]: trait.UserInterface.html#tymethod.react
+/// [`Simulation`]: ../struct.Simulation.html
+/// [`ui::Simulation`]: struct.Simulation.html
+pub struct Simulation<U: UserInterface> {
+    game: U,
+    renderer: U::Renderer,
+    input: Input<U::Input>,
+    messages: Vec<U::Message>,
+    cache: Option<Cache>,
+    window: Window,
+    timer: Timer,
+    tick: u64,
+    ticks_since_draw: u32,
+    mouse_cursor: MouseCursor,
+    events: BTreeMap<u64, Vec<input::Event>>,
+}
+
+impl<U: UserInterface> Simulation<U> {
+    /// Loads a [`UserInterface`] and its renderer and prepares a
+    /// [`Simulation`] for them.
+    ///
+    /// Both are loaded using [`Task::run`] on a headless [`Window`] with the
+    /// size of the given [`WindowSettings`]. No loading screen is shown.
+    ///
+    /// [`UserInterface`]: trait.UserInterface.html
+    /// [`Simulation`]: struct.Simulation.html
+    /// [`Task::run`]: ../load/struct.Task.html#method.run
+    /// [`Window`]: ../graphics/struct.Window.html
+    /// [`WindowSettings`]: ../graphics/struct.WindowSettings.html
+    pub fn new(window_settings: WindowSettings) -> Result<Simulation<U>> {
+        let mut window = Window::headless(window_settings);
+        let game = U::load(&window).run(window.gpu())?;
+        let renderer =
+            U::Renderer::load(U::configuration()).run(window.gpu())?;
+
+        Ok(Simulation {
+            game,
+            renderer,
+            input: Input::new(),
+            messages: Vec::new(),
+            cache: None,
+            window,
+            timer: Timer::new(U::TICKS_PER_SECOND),
+            tick: 0,
+            ticks_since_draw: 0,
+            mouse_cursor: MouseCursor::OutOfBounds,
+            events: BTreeMap::new(),
+        })
+    }
+
+    /// Schedules an input event to be fed to the [`UserInterface`] right
+    /// before the given tick.
+    ///
+    /// Events scheduled for a tick that has already been simulated are fed
+    /// before the next one. Events scheduled for the same tick are fed in
+    /// order.
+    ///
+    /// [`UserInterface`]: trait.UserInterface.html
+    pub fn schedule(&mut self, tick: u64, event: input::Event) {
+        self.events
+            .entry(tick.max(self.tick))
+            .or_insert_with(Vec::new)
+            .push(event);
+    }
+
+    /// Schedules all the events of a [`Recording`] on the ticks they were
+    /// recorded on.
+    ///
+    /// [`Recording`]: ../input/struct.Recording.html
+    pub fn replay(&mut self, recording: &Recording) {
+        for (tick, event) in recording.events() {
+            self.schedule(*tick, *event);
+        }
+    }
+
+    /// Advances the [`Simulation`] a single tick.
+    ///
+    /// The scheduled events for the current tick are fed to the game input
+    /// and the widgets, and then [`Game::interact`], [`UserInterface::react`]
+    /// and [`Game::update`] are called, in this order.
+    ///
+    /// [`Simulation`]: struct.Simulation.html
+    /// [`Game::interact`]: ../trait.Game.html#method.interact
+    /// [`UserInterface::react`]: trait.UserInterface.html#tymethod.react
+    /// [`Game::update`]: ../trait.Game.html#method.update
+    pub fn step(&mut self) {
+        let delta = self.timer.target_delta();
+        self.timer.advance(delta);
+
+        while self.timer.tick() {
+            if let Some(events) = self.events.remove(&self.tick) {
+                for event in events {
+                    self.input.update(event);
+                }
+            }
+
+            self.game
+                .interact(&mut self.input.game_input, &mut self.window);
+            self.input.clear();
+
+            let cache = self.cache();
+
+            self.cache = Some(react(
+                &mut self.game,
+                &mut self.input,
+                &mut self.window,
+                &self.renderer,
+                &mut self.messages,
+                cache,
+            ));
+
+            self.game.update(&self.window);
+            self.tick += 1;
+            self.ticks_since_draw += 1;
+        }
+    }
+
+    /// Advances the [`Simulation`] the given amount of ticks.
+    ///
+    /// It stops early if the [`UserInterface`] finishes.
+    ///
+    /// [`Simulation`]: struct.Simulation.html
+    /// [`UserInterface`]: trait.UserInterface.html
+    pub fn run(&mut self, ticks: u64) {
+        for _ in 0..ticks {
+            if self.game.is_finished() {
+                break;
+            }
+
+            self.step();
+        }
+    }
+
+    /// Draws the current state of the game and its user interface, and
+    /// returns the resulting frame as a [`Canvas`].
+    ///
+    /// The returned [`Canvas`] shares its contents with the headless frame.
+    /// Draw it somewhere else or read its pixels before calling [`draw`]
+    /// again if you want to keep it.
+    ///
+    /// [`Canvas`]: ../graphics/struct.Canvas.html
+    /// [`draw`]: #method.draw
+    pub fn draw(&mut self) -> Canvas {
+        self.game.draw(&mut self.window.frame(), &self.timer);
+
+        let cache = self.cache();
+
+        let mut interface = Interface::compute_with_cache(
+            self.game.layout(&self.window),
+            &self.renderer,
+            viewport(&self.window),
+            cache,
+        );
+
+        interface.advance(self.timer.target_delta() * self.ticks_since_draw);
+        self.ticks_since_draw = 0;
+
+        let new_cursor = interface.draw(
+            &mut self.renderer,
+            &mut self.window.frame(),
+            self.input.cursor_position,
+        );
+
+        self.cache = Some(interface.cache());
+
+        // Just like in a window, the changes of the cursor are fed on the
+        // next tick
+        if new_cursor != self.mouse_cursor {
+            if new_cursor == MouseCursor::OutOfBounds {
+                self.schedule(
+                    self.tick,
+                    input::Event::Mouse(mouse::Event::CursorReturned),
+                );
+            } else if self.mouse_cursor == MouseCursor::OutOfBounds {
+                self.schedule(
+                    self.tick,
+                    input::Event::Mouse(mouse::Event::CursorTaken),
+                );
+            }
+
+            self.mouse_cursor = new_cursor;
+        }
+
+        self.window.swap_buffers();
+        self.window.canvas()
+    }
+
+    /// Returns the [`MouseCursor`] requested by the user interface the last
+    /// time it was drawn.
+    ///
+    /// [`MouseCursor`]: core/enum.MouseCursor.html
+    pub fn mouse_cursor(&self) -> MouseCursor {
+        self.mouse_cursor
+    }
+
+    /// Returns the amount of ticks simulated so far.
+    pub fn tick(&self) -> u64 {
+        self.tick
+    }
+
+    /// Returns a reference to the simulated [`UserInterface`].
+    ///
+    /// [`UserInterface`]: trait.UserInterface.html
+    pub fn game(&self) -> &U {
+        &self.game
+    }
+
+    /// Returns a mutable reference to the simulated [`UserInterface`].
+    ///
+    /// [`UserInterface`]: trait.UserInterface.html
+    pub fn game_mut(&mut self) -> &mut U {
+        &mut self.game
+    }
+
+    /// Returns the [`Gpu`] of the headless [`Window`].
+    ///
+    /// You will need it to read the pixels of a drawn frame.
+    ///
+    /// [`Gpu`]: ../graphics/struct.Gpu.html
+    /// [`Window`]: ../graphics/struct.Window.html
+    pub fn gpu(&mut self) -> &mut Gpu {
+        self.window.gpu()
+    }
+
+    /// Consumes the [`Simulation`] and returns the simulated
+    /// [`UserInterface`].
+    ///
+    /// [`Simulation`]: struct.Simulation.html
+    /// [`UserInterface`]: trait.UserInterface.html
+    pub fn into_game(self) -> U {
+        self.game
+    }
+
+    fn cache(&mut self) -> Cache {
+        match self.cache.take() {
+            Some(cache) => cache,
+            None => Interface::compute(
+                self.game.layout(&self.window),
+                &self.renderer,
+                viewport(&self.window),
+            )
+            .cache(),
+        }
+    }
+}
+
+impl<U: UserInterface> std::fmt::Debug for Simulation<U> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Simulation {{ tick: {}, window: {:?} }}",
+            self.tick, self.window
+        )
+    }
+}
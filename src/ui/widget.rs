@@ -9,7 +9,8 @@
 //! ```
 //!
 //! However, if you want to use a custom renderer, you will need to work with
-//! the definitions of [`Row`], [`Column`], and [`Panel`] found in this module.
+//! the definitions of [`Row`], [`Column`], [`Panel`], [`Scrollable`],
+//! [`Themed`], and [`Tooltip`] found in this module.
 //!
 //! # Customization
 //! Every drawable widget has its own module with a `Renderer` trait that must
@@ -22,6 +23,9 @@
 //! [`Row`]: struct.Row.html
 //! [`Column`]: struct.Column.html
 //! [`Panel`]: struct.Panel.html
+//! [`Scrollable`]: struct.Scrollable.html
+//! [`Themed`]: struct.Themed.html
+//! [`Tooltip`]: struct.Tooltip.html
 //! [`Renderer`]: ../struct.Renderer.html
 mod column;
 mod row;
@@ -30,18 +34,28 @@ pub mod button;
 pub mod checkbox;
 pub mod image;
 pub mod panel;
+pub mod pick_list;
 pub mod progress_bar;
 pub mod radio;
+pub mod scrollable;
 pub mod slider;
 pub mod text;
+pub mod text_input;
+pub mod themed;
+pub mod tooltip;
 
 pub use self::image::Image;
 pub use button::Button;
 pub use checkbox::Checkbox;
 pub use column::Column;
 pub use panel::Panel;
+pub use pick_list::PickList;
 pub use progress_bar::ProgressBar;
 pub use radio::Radio;
 pub use row::Row;
+pub use scrollable::Scrollable;
 pub use slider::Slider;
 pub use text::Text;
+pub use text_input::TextInput;
+pub use themed::Themed;
+pub use tooltip::{Tooltip, WithTooltip};
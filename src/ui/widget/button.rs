@@ -9,16 +9,21 @@
 use crate::graphics::{Point, Rectangle};
 use crate::input::{mouse, ButtonState};
 use crate::ui::core::{
-    Align, Element, Event, Hasher, Layout, MouseCursor, Node, Style, Widget,
+    Align, Element, Event, Focusable, Hasher, Interaction, Layout, MouseCursor,
+    Node, Style, Widget,
 };
 
 use std::hash::Hash;
 
 /// A generic widget that produces a message when clicked.
 ///
+/// A [`Button`] is disabled until a message is set with [`on_press`].
+///
 /// It implements [`Widget`] when the associated [`core::Renderer`] implements
 /// the [`button::Renderer`] trait.
 ///
+/// [`Button`]: struct.Button.html
+/// [`on_press`]: struct.Button.html#method.on_press
 /// [`Widget`]: ../../core/trait.Widget.html
 /// [`core::Renderer`]: ../../core/trait.Renderer.html
 /// [`button::Renderer`]: trait.Renderer.html
@@ -169,6 +174,11 @@ where
                     }
                 }
             }
+            _ if event.is_activation() && layout.is_focused() => {
+                if let Some(on_press) = self.on_press {
+                    messages.push(on_press);
+                }
+            }
             _ => {}
         }
     }
@@ -179,18 +189,26 @@ where
         layout: Layout<'_>,
         cursor_position: Point,
     ) -> MouseCursor {
-        renderer.draw(
+        let bounds = layout.bounds();
+        let interaction = Interaction::new(
+            bounds,
             cursor_position,
-            layout.bounds(),
-            self.state,
-            &self.label,
-            self.class,
-        )
+            self.state.is_pressed,
+            self.on_press.is_some(),
+        );
+
+        renderer.draw(bounds, interaction, &self.label, self.class)
     }
 
     fn hash(&self, state: &mut Hasher) {
         self.style.hash(state);
     }
+
+    fn focusable(&self, layout: Layout<'_>, focusables: &mut Vec<Focusable>) {
+        if self.on_press.is_some() {
+            focusables.push(Focusable::new(&layout));
+        }
+    }
 }
 
 /// The local state of a [`Button`].
@@ -252,20 +270,18 @@ pub trait Renderer {
     /// Draws a [`Button`].
     ///
     /// It receives:
-    ///   * the current cursor position
     ///   * the bounds of the [`Button`]
-    ///   * the local state of the [`Button`]
+    ///   * the current [`Interaction`] with the [`Button`]
     ///   * the label of the [`Button`]
     ///   * the [`Class`] of the [`Button`]
     ///
     /// [`Button`]: struct.Button.html
-    /// [`State`]: struct.State.html
+    /// [`Interaction`]: ../../core/enum.Interaction.html
     /// [`Class`]: enum.Class.html
     fn draw(
         &mut self,
-        cursor_position: Point,
         bounds: Rectangle<f32>,
-        state: &State,
+        interaction: Interaction,
         label: &str,
         class: Class,
     ) -> MouseCursor;
@@ -6,7 +6,8 @@ use crate::graphics::{
 };
 use crate::input::{mouse, ButtonState};
 use crate::ui::core::{
-    Align, Element, Event, Hasher, Layout, MouseCursor, Node, Widget,
+    Align, Element, Event, Focusable, Hasher, Interaction, Layout, MouseCursor,
+    Node, Widget,
 };
 use crate::ui::widget::{text, Column, Row, Text};
 
@@ -119,6 +120,9 @@ where
                     messages.push((self.on_toggle)(!self.is_checked));
                 }
             }
+            _ if event.is_activation() && layout.is_focused() => {
+                messages.push((self.on_toggle)(!self.is_checked));
+            }
             _ => {}
         }
     }
@@ -143,11 +147,20 @@ where
             VerticalAlignment::Top,
         );
 
+        // The label can be clicked too
+        let interaction = if children
+            .iter()
+            .any(|child| child.bounds().contains(cursor_position))
+        {
+            Interaction::Hovered
+        } else {
+            Interaction::Idle
+        };
+
         self::Renderer::draw(
             renderer,
-            cursor_position,
             children[0].bounds(),
-            text_bounds,
+            interaction,
             self.is_checked,
         )
     }
@@ -155,6 +168,10 @@ where
     fn hash(&self, state: &mut Hasher) {
         self.label.hash(state);
     }
+
+    fn focusable(&self, layout: Layout<'_>, focusables: &mut Vec<Focusable>) {
+        focusables.push(Focusable::new(&layout));
+    }
 }
 
 /// The renderer of a [`Checkbox`].
@@ -168,17 +185,16 @@ pub trait Renderer {
     /// Draws a [`Checkbox`].
     ///
     /// It receives:
-    ///   * the current cursor position
-    ///   * the bounds of the [`Checkbox`]
-    ///   * the bounds of the label of the [`Checkbox`]
+    ///   * the bounds of the box of the [`Checkbox`]
+    ///   * the current [`Interaction`] with the [`Checkbox`]
     ///   * whether the [`Checkbox`] is checked or not
     ///
     /// [`Checkbox`]: struct.Checkbox.html
+    /// [`Interaction`]: ../../core/enum.Interaction.html
     fn draw(
         &mut self,
-        cursor_position: Point,
         bounds: Rectangle<f32>,
-        label_bounds: Rectangle<f32>,
+        interaction: Interaction,
         is_checked: bool,
     ) -> MouseCursor;
 }
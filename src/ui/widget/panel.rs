@@ -3,7 +3,7 @@ use std::hash::Hash;
 
 use crate::graphics::{Point, Rectangle};
 use crate::ui::core::{
-    Element, Event, Hasher, Layout, MouseCursor, Node, Style, Widget,
+    Element, Event, Focusable, Hasher, Layout, MouseCursor, Node, Style, Widget,
 };
 
 /// A box that can wrap a widget.
@@ -139,6 +139,19 @@ where
     fn hash(&self, state: &mut Hasher) {
         self.style.hash(state);
     }
+
+    fn focusable(&self, layout: Layout<'_>, focusables: &mut Vec<Focusable>) {
+        [&self.content].iter().zip(layout.children()).for_each(
+            |(child, layout)| child.widget.focusable(layout, focusables),
+        );
+    }
+
+    fn overlay(&self, layout: Layout<'_>, overlays: &mut Vec<Rectangle<f32>>) {
+        [&self.content]
+            .iter()
+            .zip(layout.children())
+            .for_each(|(child, layout)| child.widget.overlay(layout, overlays));
+    }
 }
 
 /// The renderer of a [`Panel`].
@@ -0,0 +1,582 @@
+//! Choose a single option from a list in a drop-down menu.
+//!
+//! A [`PickList`] has some local [`State`].
+//!
+//! [`PickList`]: struct.PickList.html
+//! [`State`]: struct.State.html
+use std::borrow::Cow;
+use std::hash::Hash;
+
+use crate::graphics::{Point, Rectangle};
+use crate::input::keyboard::{self, KeyCode};
+use crate::input::{gamepad, mouse, ButtonState};
+use crate::ui::core::{
+    Element, Event, Focusable, Hasher, Interaction, Layout, MouseCursor, Node,
+    Style, Widget,
+};
+use crate::ui::widget::scrollable;
+
+/// A field that opens a menu to choose one of many options when clicked.
+///
+/// A [`PickList`] will try to fill the horizontal space of its container.
+/// The options are shown in a menu below the field, which is drawn on top of
+/// the rest of the user interface. The menu is shown above the field instead
+/// when there is more room there, and it can be scrolled when its options do
+/// not fit in the window.
+///
+/// When focused using the keyboard or a gamepad, the selected option can
+/// also be changed with the horizontal arrows or the D-pad.
+///
+/// It implements [`Widget`] when the associated [`core::Renderer`] implements
+/// the [`pick_list::Renderer`] trait.
+///
+/// [`PickList`]: struct.PickList.html
+/// [`Widget`]: ../../core/trait.Widget.html
+/// [`core::Renderer`]: ../../core/trait.Renderer.html
+/// [`pick_list::Renderer`]: trait.Renderer.html
+///
+/// # Example
+/// ```
+/// use coffee::ui::{pick_list, PickList};
+///
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// pub enum Language {
+///     English,
+///     Spanish,
+///     Catalan,
+/// }
+///
+/// impl std::fmt::Display for Language {
+///     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+///         write!(f, "{:?}", self)
+///     }
+/// }
+///
+/// pub enum Message {
+///     LanguageSelected(Language),
+/// }
+///
+/// let state = &mut pick_list::State::new();
+/// let languages = [Language::English, Language::Spanish, Language::Catalan];
+///
+/// PickList::new(
+///     state,
+///     &languages[..],
+///     Some(Language::English),
+///     Message::LanguageSelected,
+/// );
+/// ```
+pub struct PickList<'a, T, Message>
+where
+    T: Clone,
+{
+    state: &'a mut State,
+    options: Cow<'a, [T]>,
+    selected: Option<T>,
+    on_selected: Box<dyn Fn(T) -> Message>,
+    style: Style,
+}
+
+impl<'a, T, Message> std::fmt::Debug for PickList<'a, T, Message>
+where
+    T: Clone + std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PickList")
+            .field("state", &self.state)
+            .field("options", &self.options)
+            .field("selected", &self.selected)
+            .field("style", &self.style)
+            .finish()
+    }
+}
+
+impl<'a, T, Message> PickList<'a, T, Message>
+where
+    T: Clone + PartialEq,
+{
+    /// Creates a new [`PickList`].
+    ///
+    /// It expects:
+    ///   * the local [`State`] of the [`PickList`]
+    ///   * the options of the [`PickList`]
+    ///   * the current selected option, if any
+    ///   * a function that will be called when an option is selected. It
+    ///   receives the selected option and must produce a `Message`.
+    ///
+    /// [`PickList`]: struct.PickList.html
+    /// [`State`]: struct.State.html
+    pub fn new<F>(
+        state: &'a mut State,
+        options: impl Into<Cow<'a, [T]>>,
+        selected: Option<T>,
+        on_selected: F,
+    ) -> Self
+    where
+        F: 'static + Fn(T) -> Message,
+    {
+        PickList {
+            state,
+            options: options.into(),
+            selected,
+            on_selected: Box::new(on_selected),
+            style: Style::default().min_width(100).fill_width(),
+        }
+    }
+
+    /// Sets the width of the [`PickList`] in pixels.
+    ///
+    /// [`PickList`]: struct.PickList.html
+    pub fn width(mut self, width: u32) -> Self {
+        self.style = self.style.width(width);
+        self
+    }
+
+    fn selected_index(&self) -> Option<usize> {
+        self.selected.as_ref().and_then(|selected| {
+            self.options.iter().position(|option| option == selected)
+        })
+    }
+
+    /// Selects the option at the given index, if it is not already selected.
+    fn select(&self, index: usize, messages: &mut Vec<Message>) {
+        if Some(index) != self.selected_index() {
+            messages.push((self.on_selected)(self.options[index].clone()));
+        }
+    }
+
+    /// Moves the selection by the given amount of options, returning the
+    /// index of the new selected option.
+    fn step(&self, steps: isize, messages: &mut Vec<Message>) -> Option<usize> {
+        if self.options.is_empty() {
+            return None;
+        }
+
+        let last = self.options.len() as isize - 1;
+        let index = match self.selected_index() {
+            Some(index) => (index as isize + steps).max(0).min(last),
+            None => 0,
+        };
+
+        self.select(index as usize, messages);
+
+        Some(index as usize)
+    }
+
+    /// Opens the menu of the [`PickList`], scrolled to show the selected
+    /// option at the top.
+    ///
+    /// [`PickList`]: struct.PickList.html
+    fn open(&mut self, bounds: Rectangle<f32>, viewport: Rectangle<f32>) {
+        let (menu, content) = self.menu_bounds(bounds, viewport);
+        let offset = self
+            .selected_index()
+            .map_or(0.0, |index| bounds.height * index as f32);
+
+        self.state.is_open = true;
+        self.state.menu.scroll_to(offset);
+        self.state.menu.clamp(menu, content);
+    }
+
+    /// Scrolls the open menu of the [`PickList`] to make the option at the
+    /// given index visible.
+    ///
+    /// [`PickList`]: struct.PickList.html
+    fn reveal(
+        &mut self,
+        index: usize,
+        bounds: Rectangle<f32>,
+        viewport: Rectangle<f32>,
+    ) {
+        let (menu, content) = self.menu_bounds(bounds, viewport);
+        let option = Rectangle {
+            y: content.y + bounds.height * index as f32
+                - self.state.menu.offset(),
+            height: bounds.height,
+            ..content
+        };
+
+        self.state.menu.reveal(option, menu, content);
+    }
+
+    /// Processes a keyboard or gamepad [`Event`] while the [`PickList`] is
+    /// focused.
+    ///
+    /// [`Event`]: ../../core/enum.Event.html
+    /// [`PickList`]: struct.PickList.html
+    fn on_focused_event(
+        &mut self,
+        event: Event,
+        bounds: Rectangle<f32>,
+        viewport: Rectangle<f32>,
+        messages: &mut Vec<Message>,
+    ) {
+        if event.is_activation() {
+            if self.state.is_open {
+                self.state.is_open = false;
+            } else {
+                self.open(bounds, viewport);
+            }
+        }
+
+        let steps = match event {
+            Event::Keyboard(keyboard::Event::Input {
+                key_code: KeyCode::Left,
+                state: ButtonState::Pressed,
+            })
+            | Event::Gamepad {
+                event: gamepad::Event::ButtonPressed(gamepad::Button::DPadLeft),
+                ..
+            } => -1,
+            Event::Keyboard(keyboard::Event::Input {
+                key_code: KeyCode::Right,
+                state: ButtonState::Pressed,
+            })
+            | Event::Gamepad {
+                event: gamepad::Event::ButtonPressed(gamepad::Button::DPadRight),
+                ..
+            } => 1,
+            Event::Keyboard(keyboard::Event::Input {
+                key_code: KeyCode::Escape,
+                state: ButtonState::Pressed,
+            }) => {
+                self.state.is_open = false;
+
+                return;
+            }
+            _ => return,
+        };
+
+        if let Some(index) = self.step(steps, messages) {
+            if self.state.is_open {
+                self.reveal(index, bounds, viewport);
+            }
+        }
+    }
+
+    /// Returns the visible bounds of the menu of the [`PickList`] and the
+    /// bounds of all of its options, given the bounds of the field and the
+    /// viewport.
+    ///
+    /// Every option takes the same height as the field. The menu is shown
+    /// below the field, unless it does not fit and there is more room above
+    /// it. Its height is limited to the available room, so the options can
+    /// be scrolled when they do not fit.
+    ///
+    /// [`PickList`]: struct.PickList.html
+    fn menu_bounds(
+        &self,
+        bounds: Rectangle<f32>,
+        viewport: Rectangle<f32>,
+    ) -> (Rectangle<f32>, Rectangle<f32>) {
+        let height = bounds.height * self.options.len() as f32;
+        let below = viewport.y + viewport.height - (bounds.y + bounds.height);
+        let above = bounds.y - viewport.y;
+
+        let menu = if height <= below || below >= above {
+            Rectangle {
+                x: bounds.x,
+                y: bounds.y + bounds.height,
+                width: bounds.width,
+                height: height.min(below.max(bounds.height)),
+            }
+        } else {
+            let visible_height = height.min(above.max(bounds.height));
+
+            Rectangle {
+                x: bounds.x,
+                y: bounds.y - visible_height,
+                width: bounds.width,
+                height: visible_height,
+            }
+        };
+
+        (menu, Rectangle { height, ..menu })
+    }
+
+    /// Returns the index of the option in the menu under the given point.
+    ///
+    /// The scrollbar of the menu does not belong to any option.
+    fn option_at(
+        &self,
+        bounds: Rectangle<f32>,
+        viewport: Rectangle<f32>,
+        point: Point,
+    ) -> Option<usize> {
+        let (menu, content) = self.menu_bounds(bounds, viewport);
+
+        if self.options.is_empty() || !menu.contains(point) {
+            return None;
+        }
+
+        let mut scroll = self.state.menu;
+        scroll.clamp(menu, content);
+
+        if let Some(scroller) = scroll.scroller(menu, content) {
+            if point.x >= scroller.x {
+                return None;
+            }
+        }
+
+        let index =
+            ((point.y - content.y + scroll.offset()) / bounds.height) as usize;
+
+        Some(index.min(self.options.len() - 1))
+    }
+}
+
+impl<'a, T, Message, Renderer> Widget<Message, Renderer>
+    for PickList<'a, T, Message>
+where
+    Renderer: self::Renderer,
+    T: Clone + PartialEq + ToString + std::fmt::Debug,
+{
+    fn node(&self, _renderer: &Renderer) -> Node {
+        Node::new(self.style.height(40))
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+    ) {
+        let bounds = layout.bounds();
+        let viewport = layout.viewport();
+
+        if self.state.is_open {
+            let (menu, content) = self.menu_bounds(bounds, viewport);
+
+            // The cursor is hidden from the widget while it is over the menu
+            let cursor = layout.overlay_cursor().unwrap_or(cursor_position);
+
+            self.state.menu.clamp(menu, content);
+
+            if self.state.menu.scroll(event, cursor, menu, content) {
+                return;
+            }
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::Input {
+                button: mouse::Button::Left,
+                state: ButtonState::Pressed,
+            }) => {
+                if self.state.is_open {
+                    let option = layout.overlay_cursor().and_then(|cursor| {
+                        self.option_at(bounds, viewport, cursor)
+                    });
+
+                    if let Some(index) = option {
+                        self.select(index, messages);
+                    }
+
+                    self.state.is_open = false;
+                } else if bounds.contains(cursor_position) {
+                    self.open(bounds, viewport);
+                }
+            }
+            Event::Keyboard(_) | Event::Gamepad { .. }
+                if layout.is_focused() =>
+            {
+                self.on_focused_event(event, bounds, viewport, messages);
+            }
+            Event::Keyboard(_) | Event::Gamepad { .. } => {
+                // The menu closes when the focus moves to another widget
+                if layout.focus().is_some() {
+                    self.state.is_open = false;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> MouseCursor {
+        let bounds = layout.bounds();
+        let options: Vec<String> =
+            self.options.iter().map(ToString::to_string).collect();
+        let selected = self.selected_index();
+
+        let menu = if self.state.is_open {
+            let viewport = layout.viewport();
+            let (menu, content) = self.menu_bounds(bounds, viewport);
+
+            let mut scroll = self.state.menu;
+            scroll.clamp(menu, content);
+
+            let hovered = layout
+                .overlay_cursor()
+                .filter(|_| !scroll.is_scroller_grabbed())
+                .and_then(|cursor| self.option_at(bounds, viewport, cursor));
+
+            Some(Menu {
+                bounds: menu,
+                option_height: bounds.height,
+                options: &options,
+                selected,
+                hovered,
+                scroller: scroll.scroller(menu, content),
+                scroll,
+                cursor_position: layout
+                    .overlay_cursor()
+                    .unwrap_or(cursor_position),
+            })
+        } else {
+            None
+        };
+
+        let interaction =
+            Interaction::new(bounds, cursor_position, false, true);
+
+        renderer.draw(
+            bounds,
+            interaction,
+            selected.map(|index| options[index].as_str()),
+            menu,
+        )
+    }
+
+    fn hash(&self, state: &mut Hasher) {
+        self.style.hash(state);
+    }
+
+    fn focusable(&self, layout: Layout<'_>, focusables: &mut Vec<Focusable>) {
+        focusables.push(Focusable::new(&layout));
+    }
+
+    fn overlay(&self, layout: Layout<'_>, overlays: &mut Vec<Rectangle<f32>>) {
+        if self.state.is_open {
+            let (menu, _) =
+                self.menu_bounds(layout.bounds(), layout.viewport());
+
+            overlays.push(menu);
+        }
+    }
+}
+
+/// The local state of a [`PickList`].
+///
+/// [`PickList`]: struct.PickList.html
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct State {
+    is_open: bool,
+    menu: scrollable::State,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> State {
+        State::default()
+    }
+
+    /// Returns whether the menu of the associated [`PickList`] is open or
+    /// not.
+    ///
+    /// [`PickList`]: struct.PickList.html
+    pub fn is_open(&self) -> bool {
+        self.is_open
+    }
+}
+
+/// The open menu of a [`PickList`].
+///
+/// It must be drawn on top of the rest of the user interface.
+///
+/// [`PickList`]: struct.PickList.html
+#[derive(Debug, Clone, Copy)]
+pub struct Menu<'a> {
+    /// The visible bounds of the menu.
+    ///
+    /// The options outside of these bounds must be hidden.
+    pub bounds: Rectangle<f32>,
+
+    /// The height of every option.
+    pub option_height: f32,
+
+    /// The labels of the options, in order.
+    pub options: &'a [String],
+
+    /// The index of the selected option, if any.
+    pub selected: Option<usize>,
+
+    /// The index of the option under the cursor, if any.
+    pub hovered: Option<usize>,
+
+    /// The bounds of the scroller of the menu, if the options overflow.
+    pub scroller: Option<Rectangle<f32>>,
+
+    /// The scrolling state of the options.
+    pub scroll: scrollable::State,
+
+    /// The current cursor position.
+    ///
+    /// Unlike the cursor position received by the rest of the user
+    /// interface, it is not hidden while the cursor is over the menu.
+    pub cursor_position: Point,
+}
+
+impl<'a> Menu<'a> {
+    /// Returns the bounds of the option at the given index in the [`Menu`],
+    /// taking the scrolling into account.
+    ///
+    /// [`Menu`]: struct.Menu.html
+    pub fn option_bounds(&self, index: usize) -> Rectangle<f32> {
+        Rectangle {
+            x: self.bounds.x,
+            y: self.bounds.y + self.option_height * index as f32
+                - self.scroll.offset(),
+            width: self.bounds.width,
+            height: self.option_height,
+        }
+    }
+}
+
+/// The renderer of a [`PickList`].
+///
+/// Your [`core::Renderer`] will need to implement this trait before being
+/// able to use a [`PickList`] in your user interface.
+///
+/// [`PickList`]: struct.PickList.html
+/// [`core::Renderer`]: ../../core/trait.Renderer.html
+pub trait Renderer {
+    /// Draws a [`PickList`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`PickList`]
+    ///   * the current [`Interaction`] with the field of the [`PickList`]
+    ///   * the label of the selected option, if any
+    ///   * the open [`Menu`] of the [`PickList`], if any
+    ///
+    /// [`PickList`]: struct.PickList.html
+    /// [`Interaction`]: ../../core/enum.Interaction.html
+    /// [`Menu`]: struct.Menu.html
+    fn draw(
+        &mut self,
+        bounds: Rectangle<f32>,
+        interaction: Interaction,
+        selected: Option<&str>,
+        menu: Option<Menu<'_>>,
+    ) -> MouseCursor;
+}
+
+impl<'a, T, Message, Renderer> From<PickList<'a, T, Message>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+    T: 'a + Clone + PartialEq + ToString + std::fmt::Debug,
+    Message: 'static,
+{
+    fn from(
+        pick_list: PickList<'a, T, Message>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(pick_list)
+    }
+}
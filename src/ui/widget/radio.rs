@@ -4,7 +4,8 @@ use crate::graphics::{
 };
 use crate::input::{mouse, ButtonState};
 use crate::ui::core::{
-    Align, Element, Event, Hasher, Layout, MouseCursor, Node, Widget,
+    Align, Element, Event, Focusable, Hasher, Interaction, Layout, MouseCursor,
+    Node, Widget,
 };
 use crate::ui::widget::{text, Column, Row, Text};
 
@@ -135,6 +136,9 @@ where
                     messages.push(self.on_click);
                 }
             }
+            _ if event.is_activation() && layout.is_focused() => {
+                messages.push(self.on_click);
+            }
             _ => {}
         }
     }
@@ -160,11 +164,13 @@ where
             VerticalAlignment::Top,
         );
 
+        let interaction =
+            Interaction::new(layout.bounds(), cursor_position, false, true);
+
         self::Renderer::draw(
             renderer,
-            cursor_position,
             children[0].bounds(),
-            layout.bounds(),
+            interaction,
             self.is_selected,
         )
     }
@@ -172,6 +178,10 @@ where
     fn hash(&self, state: &mut Hasher) {
         self.label.hash(state);
     }
+
+    fn focusable(&self, layout: Layout<'_>, focusables: &mut Vec<Focusable>) {
+        focusables.push(Focusable::new(&layout));
+    }
 }
 
 /// The renderer of a [`Radio`] button.
@@ -185,17 +195,17 @@ pub trait Renderer {
     /// Draws a [`Radio`] button.
     ///
     /// It receives:
-    ///   * the current cursor position
-    ///   * the bounds of the [`Radio`]
-    ///   * the bounds of the label of the [`Radio`]
+    ///   * the bounds of the circle of the [`Radio`]
+    ///   * the current [`Interaction`] with the [`Radio`], including its
+    ///   label
     ///   * whether the [`Radio`] is selected or not
     ///
     /// [`Radio`]: struct.Radio.html
+    /// [`Interaction`]: ../../core/enum.Interaction.html
     fn draw(
         &mut self,
-        cursor_position: Point,
         bounds: Rectangle<f32>,
-        label_bounds: Rectangle<f32>,
+        interaction: Interaction,
         is_selected: bool,
     ) -> MouseCursor;
 }
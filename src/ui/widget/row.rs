@@ -1,9 +1,9 @@
 use std::hash::Hash;
 
-use crate::graphics::Point;
+use crate::graphics::{Point, Rectangle};
 use crate::ui::core::{
-    Align, Element, Event, Hasher, Justify, Layout, MouseCursor, Node, Style,
-    Widget,
+    Align, Element, Event, Focusable, Hasher, Justify, Layout, MouseCursor,
+    Node, Style, Widget,
 };
 
 /// A container that places its contents horizontally.
@@ -205,6 +205,19 @@ impl<'a, Message, Renderer> Widget<Message, Renderer>
             child.widget.hash(state);
         }
     }
+
+    fn focusable(&self, layout: Layout<'_>, focusables: &mut Vec<Focusable>) {
+        self.children.iter().zip(layout.children()).for_each(
+            |(child, layout)| child.widget.focusable(layout, focusables),
+        );
+    }
+
+    fn overlay(&self, layout: Layout<'_>, overlays: &mut Vec<Rectangle<f32>>) {
+        self.children
+            .iter()
+            .zip(layout.children())
+            .for_each(|(child, layout)| child.widget.overlay(layout, overlays));
+    }
 }
 
 impl<'a, Message, Renderer> From<Row<'a, Message, Renderer>>
@@ -0,0 +1,518 @@
+//! Scroll content that does not fit in its container.
+//!
+//! A [`Scrollable`] has some local [`State`].
+//!
+//! [`Scrollable`]: struct.Scrollable.html
+//! [`State`]: struct.State.html
+use std::f32;
+use std::hash::Hash;
+
+use crate::graphics::{Point, Rectangle, Vector};
+use crate::input::{mouse, ButtonState};
+use crate::ui::core::{
+    Element, Event, Focusable, Hasher, Layout, MouseCursor, Node, Style, Widget,
+};
+
+/// The amount of pixels scrolled by every line of a mouse wheel movement.
+const WHEEL_STEP: f32 = 60.0;
+
+/// The width of the scrollbar in pixels.
+const SCROLLBAR_WIDTH: f32 = 10.0;
+
+/// The minimum height of the scroller in pixels.
+const MIN_SCROLLER_HEIGHT: f32 = 20.0;
+
+/// A container that clips its content and lets users scroll it vertically.
+///
+/// A [`Scrollable`] will try to fill the horizontal space of its container.
+/// Its content is laid out with all the vertical space it needs, so you
+/// should limit the height of the [`Scrollable`] itself using [`height`] or
+/// [`max_height`].
+///
+/// The content can be scrolled using the mouse wheel or by dragging the
+/// scrollbar, which is shown when the content overflows.
+///
+/// It implements [`Widget`] when the associated [`core::Renderer`] implements
+/// the [`scrollable::Renderer`] trait.
+///
+/// [`Scrollable`]: struct.Scrollable.html
+/// [`height`]: #method.height
+/// [`max_height`]: #method.max_height
+/// [`Widget`]: ../../core/trait.Widget.html
+/// [`core::Renderer`]: ../../core/trait.Renderer.html
+/// [`scrollable::Renderer`]: trait.Renderer.html
+///
+/// # Example
+/// ```
+/// use coffee::ui::{scrollable, Column, Scrollable, Text};
+///
+/// pub enum Message {}
+///
+/// let state = &mut scrollable::State::new();
+/// let items = (0..100).fold(Column::new(), |column, i| {
+///     column.push(Text::new(&format!("Item {}", i)))
+/// });
+///
+/// let inventory: Scrollable<Message> =
+///     Scrollable::new(state, items).height(300);
+/// ```
+pub struct Scrollable<'a, Message, Renderer> {
+    state: &'a mut State,
+    style: Style,
+    content: Element<'a, Message, Renderer>,
+}
+
+impl<'a, Message, Renderer> std::fmt::Debug
+    for Scrollable<'a, Message, Renderer>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Scrollable")
+            .field("state", &self.state)
+            .field("style", &self.style)
+            .field("content", &self.content)
+            .finish()
+    }
+}
+
+impl<'a, Message, Renderer> Scrollable<'a, Message, Renderer> {
+    /// Creates a new [`Scrollable`] with the given [`State`] and content.
+    ///
+    /// [`Scrollable`]: struct.Scrollable.html
+    /// [`State`]: struct.State.html
+    pub fn new<E>(state: &'a mut State, content: E) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        let mut style = Style::default().fill_width();
+        style.0.flex_direction = stretch::style::FlexDirection::Column;
+        style.0.overflow = stretch::style::Overflow::Scroll;
+
+        Scrollable {
+            state,
+            style,
+            content: content.into(),
+        }
+    }
+
+    /// Sets the width of the [`Scrollable`] in pixels.
+    ///
+    /// [`Scrollable`]: struct.Scrollable.html
+    pub fn width(mut self, width: u32) -> Self {
+        self.style = self.style.width(width);
+        self
+    }
+
+    /// Sets the height of the [`Scrollable`] in pixels.
+    ///
+    /// [`Scrollable`]: struct.Scrollable.html
+    pub fn height(mut self, height: u32) -> Self {
+        self.style = self.style.height(height);
+        self
+    }
+
+    /// Sets the maximum height of the [`Scrollable`] in pixels.
+    ///
+    /// [`Scrollable`]: struct.Scrollable.html
+    pub fn max_height(mut self, max_height: u32) -> Self {
+        self.style = self.style.max_height(max_height);
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Scrollable<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn node(&self, renderer: &Renderer) -> Node {
+        let mut content = self.content.widget.node(renderer);
+
+        // The content must keep its height, no matter how small the
+        // scrollable is
+        let mut style = content.0.style();
+        style.flex_shrink = 0.0;
+        content.0.set_style(style);
+
+        Node::with_children(self.style, vec![content])
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+    ) {
+        let bounds = layout.bounds();
+        let content = layout.children().next().expect("Scrollable content");
+        let content_bounds = content.bounds();
+
+        self.state.clamp(bounds, content_bounds);
+
+        let is_mouse_over = bounds.contains(cursor_position);
+
+        if self
+            .state
+            .scroll(event, cursor_position, bounds, content_bounds)
+        {
+            return;
+        }
+
+        let offset = self.state.offset;
+
+        self.content.widget.on_event(
+            event,
+            content.translate(Vector::new(0.0, -offset)),
+            content_cursor(is_mouse_over, cursor_position),
+            messages,
+        );
+
+        match event {
+            Event::Keyboard(_) | Event::Gamepad { .. } => {
+                if let Some(focus) = layout.focus() {
+                    self.state.reveal(focus, bounds, content_bounds);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> MouseCursor {
+        let bounds = layout.bounds();
+        let content = layout.children().next().expect("Scrollable content");
+        let content_bounds = content.bounds();
+
+        let mut state = *self.state;
+        state.clamp(bounds, content_bounds);
+
+        let is_mouse_over =
+            bounds.contains(cursor_position) && !state.is_scroller_grabbed();
+
+        renderer.draw(
+            cursor_position,
+            bounds,
+            state.scroller(bounds, content_bounds),
+            &state,
+            |renderer| {
+                self.content.widget.draw(
+                    renderer,
+                    content.translate(Vector::new(0.0, -state.offset)),
+                    content_cursor(is_mouse_over, cursor_position),
+                )
+            },
+        )
+    }
+
+    fn hash(&self, state: &mut Hasher) {
+        self.style.hash(state);
+        self.content.widget.hash(state);
+    }
+
+    fn focusable(&self, layout: Layout<'_>, focusables: &mut Vec<Focusable>) {
+        let bounds = layout.bounds();
+        let content = layout.children().next().expect("Scrollable content");
+
+        let mut state = *self.state;
+        state.clamp(bounds, content.bounds());
+
+        // The focus ring of partially hidden content must be clipped
+        self.content.widget.focusable(
+            content
+                .translate(Vector::new(0.0, -state.offset))
+                .clip(bounds),
+            focusables,
+        );
+    }
+
+    fn overlay(&self, layout: Layout<'_>, overlays: &mut Vec<Rectangle<f32>>) {
+        let bounds = layout.bounds();
+        let content = layout.children().next().expect("Scrollable content");
+
+        let mut state = *self.state;
+        state.clamp(bounds, content.bounds());
+
+        self.content.widget.overlay(
+            content.translate(Vector::new(0.0, -state.offset)),
+            overlays,
+        );
+    }
+}
+
+/// Returns the cursor position that the content of a [`Scrollable`] should
+/// see.
+///
+/// The parts of the content outside of the [`Scrollable`] are hidden, so they
+/// must not be hovered or clicked.
+///
+/// [`Scrollable`]: struct.Scrollable.html
+fn content_cursor(is_mouse_over: bool, cursor_position: Point) -> Point {
+    if is_mouse_over {
+        cursor_position
+    } else {
+        Point::new(f32::INFINITY, f32::INFINITY)
+    }
+}
+
+/// The local state of a [`Scrollable`].
+///
+/// [`Scrollable`]: struct.Scrollable.html
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct State {
+    offset: f32,
+    scroller_grabbed_at: Option<f32>,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> State {
+        State::default()
+    }
+
+    /// Returns the amount of pixels the content of the associated
+    /// [`Scrollable`] is scrolled down.
+    ///
+    /// [`Scrollable`]: struct.Scrollable.html
+    pub fn offset(&self) -> f32 {
+        self.offset
+    }
+
+    /// Scrolls the content of the associated [`Scrollable`] down to the given
+    /// amount of pixels.
+    ///
+    /// The offset is limited to the height of the content the next time the
+    /// [`Scrollable`] is laid out. Therefore, you can use `f32::INFINITY` to
+    /// scroll to the bottom.
+    ///
+    /// [`Scrollable`]: struct.Scrollable.html
+    pub fn scroll_to(&mut self, offset: f32) {
+        self.offset = offset;
+    }
+
+    /// Returns whether the scroller of the associated [`Scrollable`] is
+    /// currently being dragged or not.
+    ///
+    /// [`Scrollable`]: struct.Scrollable.html
+    pub fn is_scroller_grabbed(&self) -> bool {
+        self.scroller_grabbed_at.is_some()
+    }
+
+    /// Scrolls the content using the given mouse [`Event`], if it is a
+    /// wheel movement over the bounds or an interaction with the scrollbar.
+    ///
+    /// It returns whether the [`Event`] was captured by the scrollbar or
+    /// not.
+    ///
+    /// [`Event`]: ../../core/enum.Event.html
+    pub(crate) fn scroll(
+        &mut self,
+        event: Event,
+        cursor_position: Point,
+        bounds: Rectangle<f32>,
+        content_bounds: Rectangle<f32>,
+    ) -> bool {
+        let scroller = self.scroller(bounds, content_bounds);
+
+        match event {
+            Event::Mouse(mouse::Event::WheelScrolled { delta_y, .. }) => {
+                if bounds.contains(cursor_position) {
+                    self.offset -= delta_y * WHEEL_STEP;
+                    self.clamp(bounds, content_bounds);
+                }
+            }
+            Event::Mouse(mouse::Event::Input {
+                button: mouse::Button::Left,
+                state,
+            }) => match state {
+                ButtonState::Pressed => {
+                    if let Some(scroller) = scroller {
+                        let rail = Rectangle {
+                            y: bounds.y,
+                            height: bounds.height,
+                            ..scroller
+                        };
+
+                        if rail.contains(cursor_position) {
+                            // Clicking the rail centers the scroller on the
+                            // cursor
+                            let grabbed_at =
+                                if scroller.contains(cursor_position) {
+                                    cursor_position.y - scroller.y
+                                } else {
+                                    scroller.height / 2.0
+                                };
+
+                            self.scroller_grabbed_at = Some(grabbed_at);
+                            self.drag(cursor_position, bounds, content_bounds);
+
+                            return true;
+                        }
+                    }
+                }
+                ButtonState::Released => {
+                    if self.scroller_grabbed_at.take().is_some() {
+                        return true;
+                    }
+                }
+            },
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                if self.is_scroller_grabbed() {
+                    self.drag(cursor_position, bounds, content_bounds);
+
+                    return true;
+                }
+            }
+            _ => {}
+        }
+
+        false
+    }
+
+    /// Returns the bounds of the scroller, the handle of the scrollbar, if
+    /// the content overflows.
+    pub(crate) fn scroller(
+        &self,
+        bounds: Rectangle<f32>,
+        content_bounds: Rectangle<f32>,
+    ) -> Option<Rectangle<f32>> {
+        let max_offset = max_offset(bounds, content_bounds);
+
+        if max_offset <= 0.0 {
+            return None;
+        }
+
+        let height = (bounds.height * bounds.height / content_bounds.height)
+            .max(MIN_SCROLLER_HEIGHT)
+            .min(bounds.height);
+
+        Some(Rectangle {
+            x: bounds.x + bounds.width - SCROLLBAR_WIDTH,
+            y: bounds.y + self.offset / max_offset * (bounds.height - height),
+            width: SCROLLBAR_WIDTH,
+            height,
+        })
+    }
+
+    /// Scrolls to keep the grabbed point of the scroller under the cursor.
+    fn drag(
+        &mut self,
+        cursor_position: Point,
+        bounds: Rectangle<f32>,
+        content_bounds: Rectangle<f32>,
+    ) {
+        let (grabbed_at, scroller) = match (
+            self.scroller_grabbed_at,
+            self.scroller(bounds, content_bounds),
+        ) {
+            (Some(grabbed_at), Some(scroller)) => (grabbed_at, scroller),
+            _ => return,
+        };
+
+        let track = bounds.height - scroller.height;
+
+        if track > 0.0 {
+            let y = cursor_position.y - grabbed_at - bounds.y;
+
+            self.offset = y / track * max_offset(bounds, content_bounds);
+            self.clamp(bounds, content_bounds);
+        }
+    }
+
+    /// Scrolls to make the given focused bounds visible, if they are part of
+    /// the content.
+    ///
+    /// The bounds are expected to be translated by the current offset.
+    pub(crate) fn reveal(
+        &mut self,
+        focus: Rectangle<f32>,
+        bounds: Rectangle<f32>,
+        content_bounds: Rectangle<f32>,
+    ) {
+        let visible_content = Rectangle {
+            y: content_bounds.y - self.offset,
+            ..content_bounds
+        };
+
+        let is_in_content = visible_content
+            .contains(Point::new(focus.x, focus.y))
+            && visible_content.contains(Point::new(
+                focus.x + focus.width,
+                focus.y + focus.height,
+            ));
+
+        if !is_in_content {
+            return;
+        }
+
+        if focus.y < bounds.y {
+            self.offset -= bounds.y - focus.y;
+        } else if focus.y + focus.height > bounds.y + bounds.height {
+            self.offset += focus.y + focus.height - (bounds.y + bounds.height);
+        }
+
+        self.clamp(bounds, content_bounds);
+    }
+
+    pub(crate) fn clamp(
+        &mut self,
+        bounds: Rectangle<f32>,
+        content_bounds: Rectangle<f32>,
+    ) {
+        self.offset =
+            self.offset.min(max_offset(bounds, content_bounds)).max(0.0);
+    }
+}
+
+fn max_offset(bounds: Rectangle<f32>, content_bounds: Rectangle<f32>) -> f32 {
+    (content_bounds.height - bounds.height).max(0.0)
+}
+
+/// The renderer of a [`Scrollable`].
+///
+/// Your [`core::Renderer`] will need to implement this trait before being
+/// able to use a [`Scrollable`] in your user interface.
+///
+/// [`Scrollable`]: struct.Scrollable.html
+/// [`core::Renderer`]: ../../core/trait.Renderer.html
+pub trait Renderer {
+    /// Draws a [`Scrollable`].
+    ///
+    /// It receives:
+    ///   * the current cursor position
+    ///   * the bounds of the [`Scrollable`]
+    ///   * the bounds of the scroller, if the content overflows
+    ///   * the local state of the [`Scrollable`]
+    ///   * a function that draws the content of the [`Scrollable`], which
+    ///   must only be visible inside its bounds
+    ///
+    /// [`Scrollable`]: struct.Scrollable.html
+    fn draw<F>(
+        &mut self,
+        cursor_position: Point,
+        bounds: Rectangle<f32>,
+        scroller: Option<Rectangle<f32>>,
+        state: &State,
+        draw_content: F,
+    ) -> MouseCursor
+    where
+        F: FnOnce(&mut Self) -> MouseCursor;
+}
+
+impl<'a, Message, Renderer> From<Scrollable<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'static,
+{
+    fn from(
+        scrollable: Scrollable<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(scrollable)
+    }
+}
@@ -8,11 +8,19 @@ use std::hash::Hash;
 use std::ops::RangeInclusive;
 
 use crate::graphics::{Point, Rectangle};
-use crate::input::{mouse, ButtonState};
+use crate::input::keyboard::{self, KeyCode};
+use crate::input::{gamepad, mouse, ButtonState};
 use crate::ui::core::{
-    Element, Event, Hasher, Layout, MouseCursor, Node, Style, Widget,
+    Element, Event, Focusable, Hasher, Interaction, Layout, MouseCursor, Node,
+    Style, Widget,
 };
 
+/// The amount of steps that a focused [`Slider`] takes to go through its
+/// range when using the keyboard or a gamepad.
+///
+/// [`Slider`]: struct.Slider.html
+const FOCUS_STEPS: f32 = 20.0;
+
 /// An horizontal bar and a handle that selects a single value from a range of
 /// values.
 ///
@@ -98,6 +106,16 @@ impl<'a, Message> Slider<'a, Message> {
         self.style = self.style.width(width);
         self
     }
+
+    /// Moves the value of the [`Slider`] by the given amount of steps.
+    ///
+    /// [`Slider`]: struct.Slider.html
+    fn step(&self, steps: f32, messages: &mut Vec<Message>) {
+        let (start, end) = (*self.range.start(), *self.range.end());
+        let value = self.value + steps * (end - start) / FOCUS_STEPS;
+
+        messages.push((self.on_change)(value.max(start).min(end)));
+    }
 }
 
 impl<'a, Message, Renderer> Widget<Message, Renderer> for Slider<'a, Message>
@@ -151,6 +169,30 @@ where
                     change();
                 }
             }
+            Event::Keyboard(keyboard::Event::Input {
+                key_code: KeyCode::Left,
+                state: ButtonState::Pressed,
+            })
+            | Event::Gamepad {
+                event: gamepad::Event::ButtonPressed(gamepad::Button::DPadLeft),
+                ..
+            } => {
+                if layout.is_focused() {
+                    self.step(-1.0, messages);
+                }
+            }
+            Event::Keyboard(keyboard::Event::Input {
+                key_code: KeyCode::Right,
+                state: ButtonState::Pressed,
+            })
+            | Event::Gamepad {
+                event: gamepad::Event::ButtonPressed(gamepad::Button::DPadRight),
+                ..
+            } => {
+                if layout.is_focused() {
+                    self.step(1.0, messages);
+                }
+            }
             _ => {}
         }
     }
@@ -161,18 +203,25 @@ where
         layout: Layout<'_>,
         cursor_position: Point,
     ) -> MouseCursor {
-        renderer.draw(
-            cursor_position,
-            layout.bounds(),
-            self.state,
-            self.range.clone(),
-            self.value,
-        )
+        let bounds = layout.bounds();
+
+        // The marker stays grabbed when the cursor leaves the slider
+        let interaction = if self.state.is_dragging() {
+            Interaction::Pressed
+        } else {
+            Interaction::new(bounds, cursor_position, false, true)
+        };
+
+        renderer.draw(bounds, interaction, self.range.clone(), self.value)
     }
 
     fn hash(&self, state: &mut Hasher) {
         self.style.hash(state);
     }
+
+    fn focusable(&self, layout: Layout<'_>, focusables: &mut Vec<Focusable>) {
+        focusables.push(Focusable::new(&layout));
+    }
 }
 
 /// The local state of a [`Slider`].
@@ -211,20 +260,18 @@ pub trait Renderer {
     /// Draws a [`Slider`].
     ///
     /// It receives:
-    ///   * the current cursor position
     ///   * the bounds of the [`Slider`]
-    ///   * the local state of the [`Slider`]
+    ///   * the current [`Interaction`] with the [`Slider`], which is
+    ///   `Pressed` while its marker is being dragged
     ///   * the range of values of the [`Slider`]
     ///   * the current value of the [`Slider`]
     ///
     /// [`Slider`]: struct.Slider.html
-    /// [`State`]: struct.State.html
-    /// [`Class`]: enum.Class.html
+    /// [`Interaction`]: ../../core/enum.Interaction.html
     fn draw(
         &mut self,
-        cursor_position: Point,
         bounds: Rectangle<f32>,
-        state: &State,
+        interaction: Interaction,
         range: RangeInclusive<f32>,
         value: f32,
     ) -> MouseCursor;
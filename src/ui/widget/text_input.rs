@@ -0,0 +1,433 @@
+//! Let users type and edit a single line of text.
+//!
+//! A [`TextInput`] has some local [`State`].
+//!
+//! [`TextInput`]: struct.TextInput.html
+//! [`State`]: struct.State.html
+use std::hash::Hash;
+
+use crate::graphics::{Point, Rectangle};
+use crate::input::keyboard::{self, KeyCode};
+use crate::input::{mouse, ButtonState};
+use crate::ui::core::{
+    Element, Event, Focusable, Hasher, Interaction, Layout, MouseCursor, Node,
+    Style, Widget,
+};
+
+/// A field that can be focused to type a single line of text.
+///
+/// A [`TextInput`] will try to fill the horizontal space of its container.
+///
+/// The value of a [`TextInput`] is owned by your application. When the user
+/// edits it, the [`TextInput`] produces a message with the new value, which
+/// you should store and provide again in your next [`UserInterface::layout`].
+///
+/// It implements [`Widget`] when the associated [`core::Renderer`] implements
+/// the [`text_input::Renderer`] trait.
+///
+/// [`TextInput`]: struct.TextInput.html
+/// [`UserInterface::layout`]: ../../trait.UserInterface.html#tymethod.layout
+/// [`Widget`]: ../../core/trait.Widget.html
+/// [`core::Renderer`]: ../../core/trait.Renderer.html
+/// [`text_input::Renderer`]: trait.Renderer.html
+///
+/// # Example
+/// ```
+/// use coffee::ui::{text_input, TextInput};
+///
+/// #[derive(Clone)]
+/// pub enum Message {
+///     NameChanged(String),
+///     NameSubmitted,
+/// }
+///
+/// let state = &mut text_input::State::new();
+/// let name = "Ferris";
+///
+/// TextInput::new(state, "Type your name...", name, Message::NameChanged)
+///     .max_length(16)
+///     .on_submit(Message::NameSubmitted);
+/// ```
+pub struct TextInput<'a, Message> {
+    state: &'a mut State,
+    placeholder: String,
+    value: String,
+    max_length: Option<usize>,
+    on_change: Box<dyn Fn(String) -> Message>,
+    on_submit: Option<Message>,
+    style: Style,
+}
+
+impl<'a, Message> std::fmt::Debug for TextInput<'a, Message> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TextInput")
+            .field("state", &self.state)
+            .field("placeholder", &self.placeholder)
+            .field("value", &self.value)
+            .field("max_length", &self.max_length)
+            .field("style", &self.style)
+            .finish()
+    }
+}
+
+impl<'a, Message> TextInput<'a, Message> {
+    /// Creates a new [`TextInput`].
+    ///
+    /// It expects:
+    ///   * the local [`State`] of the [`TextInput`]
+    ///   * a placeholder, shown when the [`TextInput`] is empty
+    ///   * the current value of the [`TextInput`]
+    ///   * a function that will be called when the value is edited. It
+    ///   receives the new value of the [`TextInput`] and must produce a
+    ///   `Message`.
+    ///
+    /// [`TextInput`]: struct.TextInput.html
+    /// [`State`]: struct.State.html
+    pub fn new<F>(
+        state: &'a mut State,
+        placeholder: &str,
+        value: &str,
+        on_change: F,
+    ) -> Self
+    where
+        F: 'static + Fn(String) -> Message,
+    {
+        TextInput {
+            state,
+            placeholder: String::from(placeholder),
+            value: String::from(value),
+            max_length: None,
+            on_change: Box::new(on_change),
+            on_submit: None,
+            style: Style::default().min_width(100).fill_width(),
+        }
+    }
+
+    /// Sets the maximum amount of characters of the [`TextInput`].
+    ///
+    /// Characters typed beyond the limit are ignored.
+    ///
+    /// [`TextInput`]: struct.TextInput.html
+    pub fn max_length(mut self, max_length: usize) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
+
+    /// Sets the message that will be produced when the user presses `Enter`
+    /// while the [`TextInput`] is focused.
+    ///
+    /// [`TextInput`]: struct.TextInput.html
+    pub fn on_submit(mut self, message: Message) -> Self {
+        self.on_submit = Some(message);
+        self
+    }
+
+    /// Sets the width of the [`TextInput`] in pixels.
+    ///
+    /// [`TextInput`]: struct.TextInput.html
+    pub fn width(mut self, width: u32) -> Self {
+        self.style = self.style.width(width);
+        self
+    }
+
+    /// Inserts a character at the cursor, replacing the current selection.
+    fn insert(&mut self, character: char, messages: &mut Vec<Message>) {
+        let mut value = self.value.clone();
+        let cursor = self.remove_selection(&mut value);
+
+        if self
+            .max_length
+            .map_or(false, |max_length| value.chars().count() >= max_length)
+        {
+            return;
+        }
+
+        value.insert(byte_index(&value, cursor), character);
+
+        self.state.cursor = cursor + 1;
+        self.state.selection = None;
+        self.change(value, messages);
+    }
+
+    /// Removes the current selection or, if there is none, the character at
+    /// the given offset from the cursor.
+    fn remove(&mut self, offset: isize, messages: &mut Vec<Message>) {
+        let mut value = self.value.clone();
+
+        if self.state.selection().is_some() {
+            self.state.cursor = self.remove_selection(&mut value);
+        } else {
+            let index = self.state.cursor as isize + offset;
+
+            if index < 0 || index as usize >= value.chars().count() {
+                return;
+            }
+
+            let _ = value.remove(byte_index(&value, index as usize));
+            self.state.cursor = index as usize;
+        }
+
+        self.state.selection = None;
+        self.change(value, messages);
+    }
+
+    /// Removes the current selection from the given value and returns the
+    /// resulting position of the cursor.
+    fn remove_selection(&self, value: &mut String) -> usize {
+        match self.state.selection() {
+            Some((start, end)) => {
+                let range = byte_index(value, start)..byte_index(value, end);
+                value.replace_range(range, "");
+
+                start
+            }
+            None => self.state.cursor,
+        }
+    }
+
+    fn change(&mut self, value: String, messages: &mut Vec<Message>) {
+        if value != self.value {
+            self.value = value.clone();
+            messages.push((self.on_change)(value));
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for TextInput<'a, Message>
+where
+    Renderer: self::Renderer,
+    Message: Clone,
+{
+    fn node(&self, _renderer: &Renderer) -> Node {
+        Node::new(self.style.height(40))
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+    ) {
+        let length = self.value.chars().count();
+        self.state.clamp(length);
+
+        if layout.focus().is_some() {
+            self.state.is_focused = layout.is_focused();
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::Input {
+                button: mouse::Button::Left,
+                state: ButtonState::Pressed,
+            }) => {
+                self.state.is_focused =
+                    layout.bounds().contains(cursor_position);
+                self.state.cursor = length;
+                self.state.selection = None;
+            }
+            Event::Keyboard(keyboard::Event::Input {
+                key_code: KeyCode::LShift,
+                state,
+            })
+            | Event::Keyboard(keyboard::Event::Input {
+                key_code: KeyCode::RShift,
+                state,
+            }) => {
+                self.state.is_shift_pressed = state == ButtonState::Pressed;
+            }
+            Event::Keyboard(keyboard::Event::TextEntered { character })
+                if self.state.is_focused && !character.is_control() =>
+            {
+                self.insert(character, messages);
+            }
+            Event::Keyboard(keyboard::Event::Input {
+                key_code,
+                state: ButtonState::Pressed,
+            }) if self.state.is_focused => match key_code {
+                KeyCode::Back => self.remove(-1, messages),
+                KeyCode::Delete => self.remove(0, messages),
+                KeyCode::Left => {
+                    let cursor = self.state.cursor;
+                    self.state.move_cursor(cursor.saturating_sub(1));
+                }
+                KeyCode::Right => {
+                    let cursor = self.state.cursor;
+                    self.state.move_cursor((cursor + 1).min(length));
+                }
+                KeyCode::Home => self.state.move_cursor(0),
+                KeyCode::End => self.state.move_cursor(length),
+                KeyCode::Return | KeyCode::NumpadEnter => {
+                    if let Some(on_submit) = self.on_submit.clone() {
+                        messages.push(on_submit);
+                    }
+                }
+                KeyCode::Escape => {
+                    self.state.is_focused = false;
+                    self.state.selection = None;
+                }
+                _ => {}
+            },
+            _ => {}
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> MouseCursor {
+        let mut state = *self.state;
+        state.clamp(self.value.chars().count());
+
+        let bounds = layout.bounds();
+        let interaction =
+            Interaction::new(bounds, cursor_position, false, true);
+
+        renderer.draw(
+            bounds,
+            interaction,
+            &state,
+            &self.value,
+            &self.placeholder,
+        )
+    }
+
+    fn hash(&self, state: &mut Hasher) {
+        self.style.hash(state);
+    }
+
+    fn focusable(&self, layout: Layout<'_>, focusables: &mut Vec<Focusable>) {
+        focusables.push(Focusable::new(&layout));
+    }
+}
+
+/// The local state of a [`TextInput`].
+///
+/// Positions are measured in characters, not in bytes.
+///
+/// [`TextInput`]: struct.TextInput.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct State {
+    is_focused: bool,
+    cursor: usize,
+    selection: Option<usize>,
+    is_shift_pressed: bool,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> State {
+        State::default()
+    }
+
+    /// Creates a new [`State`] for a [`TextInput`] that is already focused.
+    ///
+    /// [`State`]: struct.State.html
+    /// [`TextInput`]: struct.TextInput.html
+    pub fn focused() -> State {
+        State {
+            is_focused: true,
+            ..State::default()
+        }
+    }
+
+    /// Returns whether the associated [`TextInput`] is currently focused or
+    /// not.
+    ///
+    /// [`TextInput`]: struct.TextInput.html
+    pub fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    /// Returns the position of the cursor.
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    /// Returns the start and end positions of the selected text, if any.
+    pub fn selection(&self) -> Option<(usize, usize)> {
+        self.selection
+            .map(|anchor| (anchor.min(self.cursor), anchor.max(self.cursor)))
+    }
+
+    /// Moves the cursor, extending the selection if `Shift` is pressed.
+    fn move_cursor(&mut self, position: usize) {
+        if self.is_shift_pressed {
+            let anchor = self.selection.unwrap_or(self.cursor);
+
+            self.selection = if anchor == position {
+                None
+            } else {
+                Some(anchor)
+            };
+        } else {
+            self.selection = None;
+        }
+
+        self.cursor = position;
+    }
+
+    /// Keeps the positions inside a value of the given length, which may have
+    /// been changed by the application.
+    fn clamp(&mut self, length: usize) {
+        self.cursor = self.cursor.min(length);
+        self.selection = self
+            .selection
+            .map(|anchor| anchor.min(length))
+            .filter(|&anchor| anchor != self.cursor);
+    }
+}
+
+/// Returns the byte index of the character at the given position.
+fn byte_index(value: &str, position: usize) -> usize {
+    value
+        .char_indices()
+        .nth(position)
+        .map_or(value.len(), |(index, _)| index)
+}
+
+/// The renderer of a [`TextInput`].
+///
+/// Your [`core::Renderer`] will need to implement this trait before being
+/// able to use a [`TextInput`] in your user interface.
+///
+/// [`TextInput`]: struct.TextInput.html
+/// [`core::Renderer`]: ../../core/trait.Renderer.html
+pub trait Renderer {
+    /// Draws a [`TextInput`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`TextInput`]
+    ///   * the current [`Interaction`] with the [`TextInput`]
+    ///   * the local state of the [`TextInput`]
+    ///   * the current value of the [`TextInput`]
+    ///   * the placeholder of the [`TextInput`]
+    ///
+    /// [`TextInput`]: struct.TextInput.html
+    /// [`Interaction`]: ../../core/enum.Interaction.html
+    fn draw(
+        &mut self,
+        bounds: Rectangle<f32>,
+        interaction: Interaction,
+        state: &State,
+        value: &str,
+        placeholder: &str,
+    ) -> MouseCursor;
+}
+
+impl<'a, Message, Renderer> From<TextInput<'a, Message>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+    Message: 'static + Clone,
+{
+    fn from(
+        text_input: TextInput<'a, Message>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(text_input)
+    }
+}
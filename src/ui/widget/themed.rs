@@ -0,0 +1,157 @@
+//! Change the look of part of your user interface.
+use crate::graphics::{Point, Rectangle};
+use crate::ui::core::{
+    Element, Event, Focusable, Hasher, Layout, MouseCursor, Node, Widget,
+};
+
+/// A wrapper that draws its content using a different theme.
+///
+/// It does not change the layout of its content in any way.
+///
+/// It implements [`Widget`] when the associated [`core::Renderer`] implements
+/// the [`themed::Renderer`] trait. For the built-in [`Renderer`], the theme
+/// is a [`Theme`].
+///
+/// [`Widget`]: ../../core/trait.Widget.html
+/// [`core::Renderer`]: ../../core/trait.Renderer.html
+/// [`themed::Renderer`]: trait.Renderer.html
+/// [`Renderer`]: ../../struct.Renderer.html
+/// [`Theme`]: ../../theme/struct.Theme.html
+///
+/// # Example
+/// ```
+/// use coffee::graphics::Color;
+/// use coffee::ui::{button, Button, Theme, Themed};
+///
+/// #[derive(Clone, Copy)]
+/// pub enum Message {
+///     Quit,
+/// }
+///
+/// let mut theme = Theme::default();
+/// theme.colors.label = Color::RED;
+///
+/// let quit = &mut button::State::new();
+///
+/// Themed::new(theme, Button::new(quit, "Quit").on_press(Message::Quit));
+/// ```
+pub struct Themed<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    theme: Renderer::Theme,
+    content: Element<'a, Message, Renderer>,
+}
+
+impl<'a, Message, Renderer> std::fmt::Debug for Themed<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Themed")
+            .field("theme", &self.theme)
+            .field("content", &self.content)
+            .finish()
+    }
+}
+
+impl<'a, Message, Renderer> Themed<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    /// Creates a new [`Themed`] wrapper that draws the given content with
+    /// the given theme.
+    ///
+    /// [`Themed`]: struct.Themed.html
+    pub fn new<E>(theme: Renderer::Theme, content: E) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        Themed {
+            theme,
+            content: content.into(),
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Themed<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn node(&self, renderer: &Renderer) -> Node {
+        self.content.widget.node(renderer)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+    ) {
+        self.content
+            .widget
+            .on_event(event, layout, cursor_position, messages)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> MouseCursor {
+        renderer.draw(&self.theme, |renderer| {
+            self.content.widget.draw(renderer, layout, cursor_position)
+        })
+    }
+
+    fn hash(&self, state: &mut Hasher) {
+        self.content.widget.hash(state);
+    }
+
+    fn focusable(&self, layout: Layout<'_>, focusables: &mut Vec<Focusable>) {
+        self.content.widget.focusable(layout, focusables)
+    }
+
+    fn overlay(&self, layout: Layout<'_>, overlays: &mut Vec<Rectangle<f32>>) {
+        self.content.widget.overlay(layout, overlays)
+    }
+}
+
+/// The renderer of a [`Themed`] wrapper.
+///
+/// Your [`core::Renderer`] will need to implement this trait before being
+/// able to use a [`Themed`] wrapper in your user interface.
+///
+/// [`Themed`]: struct.Themed.html
+/// [`core::Renderer`]: ../../core/trait.Renderer.html
+pub trait Renderer {
+    /// The theme that can be applied to part of the user interface.
+    type Theme: std::fmt::Debug;
+
+    /// Draws the content of a [`Themed`] wrapper.
+    ///
+    /// It receives:
+    ///   * the theme of the [`Themed`] wrapper
+    ///   * a function that draws the content of the [`Themed`] wrapper,
+    ///   which must use the given theme
+    ///
+    /// [`Themed`]: struct.Themed.html
+    fn draw<F>(&mut self, theme: &Self::Theme, draw_content: F) -> MouseCursor
+    where
+        F: FnOnce(&mut Self) -> MouseCursor;
+}
+
+impl<'a, Message, Renderer> From<Themed<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'static,
+{
+    fn from(
+        themed: Themed<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(themed)
+    }
+}
@@ -0,0 +1,218 @@
+//! Show some help text when hovering part of your user interface.
+use std::time::Duration;
+
+use crate::graphics::{Point, Rectangle};
+use crate::ui::core::{
+    Element, Event, Focusable, Hasher, Layout, MouseCursor, Node, Widget,
+};
+
+/// A wrapper that shows some text when the cursor rests over its content.
+///
+/// The text is shown after a delay, near the cursor, and on top of the rest
+/// of the user interface. It does not change the layout of its content in
+/// any way.
+///
+/// You can also use [`WithTooltip::tooltip`] to wrap any widget.
+///
+/// It implements [`Widget`] when the associated [`core::Renderer`] implements
+/// the [`tooltip::Renderer`] trait.
+///
+/// [`WithTooltip::tooltip`]: trait.WithTooltip.html#tymethod.tooltip
+/// [`Widget`]: ../../core/trait.Widget.html
+/// [`core::Renderer`]: ../../core/trait.Renderer.html
+/// [`tooltip::Renderer`]: trait.Renderer.html
+///
+/// # Example
+/// ```
+/// use coffee::ui::{button, Button, Tooltip};
+/// use std::time::Duration;
+///
+/// #[derive(Clone, Copy)]
+/// pub enum Message {
+///     Save,
+/// }
+///
+/// let save = &mut button::State::new();
+///
+/// Tooltip::new(
+///     Button::new(save, "Save").on_press(Message::Save),
+///     "Saves your progress",
+/// )
+/// .delay(Duration::from_millis(200));
+/// ```
+pub struct Tooltip<'a, Message, Renderer> {
+    content: Element<'a, Message, Renderer>,
+    text: String,
+    delay: Duration,
+}
+
+impl<'a, Message, Renderer> std::fmt::Debug for Tooltip<'a, Message, Renderer> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Tooltip")
+            .field("content", &self.content)
+            .field("text", &self.text)
+            .field("delay", &self.delay)
+            .finish()
+    }
+}
+
+impl<'a, Message, Renderer> Tooltip<'a, Message, Renderer> {
+    /// Creates a new [`Tooltip`] that shows the given text when hovering the
+    /// given content.
+    ///
+    /// By default, the text is shown after the cursor rests for half a
+    /// second.
+    ///
+    /// [`Tooltip`]: struct.Tooltip.html
+    pub fn new<E>(content: E, text: &str) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        Tooltip {
+            content: content.into(),
+            text: String::from(text),
+            delay: Duration::from_millis(500),
+        }
+    }
+
+    /// Sets the time the cursor needs to rest over the content of the
+    /// [`Tooltip`] before showing its text.
+    ///
+    /// [`Tooltip`]: struct.Tooltip.html
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Tooltip<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn node(&self, renderer: &Renderer) -> Node {
+        self.content.widget.node(renderer)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+    ) {
+        self.content
+            .widget
+            .on_event(event, layout, cursor_position, messages)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> MouseCursor {
+        let is_visible = layout.bounds().contains(cursor_position)
+            && layout.hover_duration() >= self.delay;
+
+        let cursor =
+            self.content.widget.draw(renderer, layout, cursor_position);
+
+        if is_visible {
+            renderer.draw(cursor_position, &self.text);
+        }
+
+        cursor
+    }
+
+    fn hash(&self, state: &mut Hasher) {
+        self.content.widget.hash(state);
+    }
+
+    fn focusable(&self, layout: Layout<'_>, focusables: &mut Vec<Focusable>) {
+        self.content.widget.focusable(layout, focusables)
+    }
+
+    fn overlay(&self, layout: Layout<'_>, overlays: &mut Vec<Rectangle<f32>>) {
+        self.content.widget.overlay(layout, overlays)
+    }
+}
+
+/// A widget that can be wrapped in a [`Tooltip`].
+///
+/// It is implemented for anything that can be turned into an [`Element`].
+///
+/// [`Tooltip`]: struct.Tooltip.html
+/// [`Element`]: ../../core/struct.Element.html
+///
+/// # Example
+/// ```
+/// use coffee::ui::{button, Button, Element, WithTooltip};
+///
+/// #[derive(Clone, Copy)]
+/// pub enum Message {
+///     Save,
+/// }
+///
+/// let save = &mut button::State::new();
+///
+/// let element: Element<Message> = Button::new(save, "Save")
+///     .on_press(Message::Save)
+///     .tooltip("Saves your progress");
+/// ```
+pub trait WithTooltip<'a, Message, Renderer> {
+    /// Wraps the widget in a [`Tooltip`] showing the given text.
+    ///
+    /// The text is shown when the cursor rests over the widget. Use
+    /// [`Tooltip::new`] directly to customize its delay.
+    ///
+    /// [`Tooltip`]: struct.Tooltip.html
+    /// [`Tooltip::new`]: struct.Tooltip.html#method.new
+    fn tooltip(self, text: &str) -> Element<'a, Message, Renderer>;
+}
+
+impl<'a, Message, Renderer, E> WithTooltip<'a, Message, Renderer> for E
+where
+    E: Into<Element<'a, Message, Renderer>>,
+    Renderer: 'a + self::Renderer,
+    Message: 'static,
+{
+    fn tooltip(self, text: &str) -> Element<'a, Message, Renderer> {
+        Tooltip::new(self, text).into()
+    }
+}
+
+/// The renderer of a [`Tooltip`].
+///
+/// Your [`core::Renderer`] will need to implement this trait before being
+/// able to use a [`Tooltip`] in your user interface.
+///
+/// [`Tooltip`]: struct.Tooltip.html
+/// [`core::Renderer`]: ../../core/trait.Renderer.html
+pub trait Renderer {
+    /// Draws the text of a hovered [`Tooltip`].
+    ///
+    /// The text should be drawn on top of the rest of the user interface,
+    /// near the cursor, and inside the window. When nested tooltips are
+    /// hovered, this is called for the innermost one first.
+    ///
+    /// It receives:
+    ///   * the current cursor position
+    ///   * the text of the [`Tooltip`]
+    ///
+    /// [`Tooltip`]: struct.Tooltip.html
+    fn draw(&mut self, cursor_position: Point, text: &str);
+}
+
+impl<'a, Message, Renderer> From<Tooltip<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'static,
+{
+    fn from(
+        tooltip: Tooltip<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(tooltip)
+    }
+}
@@ -0,0 +1,45 @@
+use coffee::graphics::{self, Canvas, Color, Image, Point, Rectangle};
+use coffee::load::Task;
+
+pub struct Quad {}
+
+impl Quad {
+    pub fn draw() -> Task<Canvas> {
+        Task::using_gpu(|gpu| {
+            let mut canvas =
+                Canvas::new(gpu, 300, 300).expect("Canvas creation");
+
+            let image = Image::from_colors(
+                gpu,
+                &[Color::RED, Color::GREEN, Color::BLUE, Color::WHITE],
+            )
+            .expect("Image creation");
+
+            {
+                let mut target = canvas.as_target(gpu);
+                target.clear(Color::BLACK);
+
+                for (i, x) in [0.0, 0.25, 0.5, 0.75].iter().enumerate() {
+                    let offset = 20.0 + i as f32 * 70.0;
+
+                    image.draw(
+                        graphics::Quad {
+                            source: Rectangle {
+                                x: *x,
+                                y: 0.0,
+                                width: 0.25,
+                                height: 1.0,
+                            },
+                            position: Point::new(offset, offset),
+                            size: (120.0, 50.0),
+                            ..graphics::Quad::default()
+                        },
+                        &mut target,
+                    );
+                }
+            }
+
+            Ok(canvas)
+        })
+    }
+}
@@ -0,0 +1,33 @@
+use coffee::graphics::{self, Canvas, Color, Font, Point};
+use coffee::load::Task;
+
+pub struct Text {}
+
+impl Text {
+    pub fn draw() -> Task<Canvas> {
+        Task::using_gpu(|gpu| {
+            let mut canvas =
+                Canvas::new(gpu, 300, 300).expect("Canvas creation");
+
+            let mut font =
+                Font::from_bytes(gpu, Font::DEFAULT).expect("Font creation");
+
+            font.add(graphics::Text {
+                content: "A caffeinated game",
+                position: Point::new(20.0, 20.0),
+                bounds: (260.0, 260.0),
+                size: 30.0,
+                color: Color::WHITE,
+                ..graphics::Text::default()
+            });
+
+            {
+                let mut target = canvas.as_target(gpu);
+                target.clear(Color::BLACK);
+                font.draw(&mut target);
+            }
+
+            Ok(canvas)
+        })
+    }
+}
@@ -0,0 +1,175 @@
+use coffee::graphics::animation::{Animation, Frame, Mode};
+use std::time::Duration;
+
+fn frames(count: u16) -> Vec<Frame<u16>> {
+    (0..count)
+        .map(|source| Frame {
+            source,
+            duration: Duration::from_millis(100),
+        })
+        .collect()
+}
+
+fn play(animation: &mut Animation<u16>, steps: usize) -> Vec<u16> {
+    (0..steps)
+        .map(|_| {
+            animation.advance(Duration::from_millis(100));
+            *animation.current()
+        })
+        .collect()
+}
+
+#[test]
+fn plays_frames_according_to_mode() {
+    let mut once = Animation::new(frames(3), Mode::Once);
+    let mut looping = Animation::new(frames(3), Mode::Loop);
+    let mut ping_pong = Animation::new(frames(3), Mode::PingPong);
+
+    assert_eq!(play(&mut once, 4), [1, 2, 2, 2]);
+    assert!(once.is_finished());
+
+    assert_eq!(play(&mut looping, 4), [1, 2, 0, 1]);
+    assert_eq!(play(&mut ping_pong, 6), [1, 2, 1, 0, 1, 2]);
+}
+
+#[test]
+fn advances_with_ticks() {
+    let mut animation = Animation::new(frames(2), Mode::Loop);
+
+    for _ in 0..4 {
+        animation.tick(50);
+    }
+
+    assert_eq!(*animation.current(), 0);
+
+    animation.tick(50);
+
+    assert_eq!(*animation.current(), 1);
+}
+
+#[cfg(feature = "software")]
+mod sprite_sheet {
+    use coffee::graphics::animation::{Animation, Frame, Mode, SpriteSheet};
+    use coffee::graphics::{Gpu, Rectangle};
+    use coffee::Error;
+    use std::time::Duration;
+
+    const FIXTURES: &str = "tests/_animation";
+
+    fn frame(x: u16, milliseconds: u64) -> Frame<Rectangle<u16>> {
+        Frame {
+            source: Rectangle {
+                x,
+                y: 0,
+                width: 16,
+                height: 16,
+            },
+            duration: Duration::from_millis(milliseconds),
+        }
+    }
+
+    fn aseprite(fixture: &str) -> Result<SpriteSheet, Error> {
+        let mut gpu = Gpu::headless();
+
+        SpriteSheet::from_aseprite(
+            &mut gpu,
+            format!("{}/{}", FIXTURES, fixture),
+        )
+    }
+
+    fn texture_packer(fixture: &str) -> Result<SpriteSheet, Error> {
+        let mut gpu = Gpu::headless();
+
+        SpriteSheet::from_texture_packer(
+            &mut gpu,
+            format!("{}/{}", FIXTURES, fixture),
+        )
+    }
+
+    fn assert_aseprite_sheet(sheet: &SpriteSheet) {
+        let first = frame(0, 50);
+        let second = frame(16, 100);
+        let third = frame(32, 150);
+
+        assert_eq!(sheet.frames(), &[first, second, third][..]);
+        assert_eq!(sheet.frame("walk 1.png"), Some(&second));
+
+        assert_eq!(
+            sheet.animation("forward"),
+            Some(Animation::new(vec![first, second, third], Mode::Loop))
+        );
+        assert_eq!(
+            sheet.animation("reverse"),
+            Some(Animation::new(vec![third, second, first], Mode::Loop))
+        );
+        assert_eq!(
+            sheet.animation("pingpong"),
+            Some(Animation::new(vec![second, third], Mode::PingPong))
+        );
+        assert_eq!(
+            sheet.animation("pingpong_reverse"),
+            Some(Animation::new(vec![second, first], Mode::PingPong))
+        );
+        assert_eq!(sheet.animation("missing"), None);
+    }
+
+    fn assert_texture_packer_sheet(sheet: &SpriteSheet) {
+        let red = frame(0, 100);
+        let green = frame(16, 100);
+        let blue = frame(32, 100);
+
+        assert_eq!(sheet.frames(), &[red, green, blue][..]);
+        assert_eq!(sheet.frame("blue"), Some(&blue));
+        assert_eq!(
+            sheet.animation("traffic"),
+            Some(Animation::new(vec![green, blue, red], Mode::Loop))
+        );
+        assert_eq!(sheet.animation_names().collect::<Vec<_>>(), ["traffic"]);
+    }
+
+    #[test]
+    fn loads_aseprite_hash_frames() {
+        let sheet = aseprite("aseprite_hash.json").expect("Load sheet");
+
+        assert_aseprite_sheet(&sheet);
+    }
+
+    #[test]
+    fn loads_aseprite_array_frames() {
+        let sheet = aseprite("aseprite_array.json").expect("Load sheet");
+
+        assert_aseprite_sheet(&sheet);
+    }
+
+    #[test]
+    fn rejects_out_of_range_aseprite_tags() {
+        match aseprite("aseprite_out_of_range.json") {
+            Err(Error::Serialization(_)) => {}
+            result => panic!("Expected an invalid tag, got: {:?}", result),
+        }
+    }
+
+    #[test]
+    fn loads_texture_packer_hash_frames() {
+        let sheet =
+            texture_packer("texture_packer_hash.json").expect("Load sheet");
+
+        assert_texture_packer_sheet(&sheet);
+    }
+
+    #[test]
+    fn loads_texture_packer_array_frames() {
+        let sheet =
+            texture_packer("texture_packer_array.json").expect("Load sheet");
+
+        assert_texture_packer_sheet(&sheet);
+    }
+
+    #[test]
+    fn rejects_unknown_texture_packer_frames() {
+        match texture_packer("texture_packer_unknown_frame.json") {
+            Err(Error::Serialization(_)) => {}
+            result => panic!("Expected an unknown frame, got: {:?}", result),
+        }
+    }
+}
@@ -0,0 +1,138 @@
+#![cfg(feature = "software")]
+use coffee::graphics::atlas::{Atlas, Builder, Settings};
+use coffee::graphics::{Canvas, Color, Gpu, Quad, Rectangle, Vector};
+
+fn square(size: u32, color: [u8; 4]) -> image::DynamicImage {
+    image::DynamicImage::ImageRgba8(image::RgbaImage::from_pixel(
+        size,
+        size,
+        image::Rgba(color),
+    ))
+}
+
+fn overlaps(a: &Rectangle<u16>, b: &Rectangle<u16>) -> bool {
+    a.x < b.x + b.width
+        && b.x < a.x + a.width
+        && a.y < b.y + b.height
+        && b.y < a.y + a.height
+}
+
+#[test]
+fn packs_trimmed_images_without_overlapping() {
+    let mut gpu = Gpu::headless();
+    let mut builder = Builder::new(Settings::default());
+
+    let mut framed = image::RgbaImage::new(8, 8);
+
+    for x in 2..5 {
+        for y in 3..7 {
+            framed.put_pixel(x, y, image::Rgba([255, 0, 0, 255]));
+        }
+    }
+
+    builder.add_image("framed", &image::DynamicImage::ImageRgba8(framed));
+
+    for i in 0..10 {
+        builder.add_image(&format!("square {}", i), &square(20, [0; 4]));
+        builder.add_image(&format!("big {}", i), &square(40, [255; 4]));
+    }
+
+    let atlas = builder.build(&mut gpu).expect("Build atlas");
+    let framed = atlas.region("framed").expect("Framed region");
+
+    assert_eq!(framed.source.width, 3);
+    assert_eq!(framed.source.height, 4);
+    assert_eq!(framed.offset, Vector::new(2.0, 3.0));
+
+    let regions: Vec<_> = atlas
+        .regions()
+        .map(|(_, region)| region.source)
+        .filter(|source| source.width > 0)
+        .collect();
+
+    assert_eq!(regions.len(), 11);
+
+    for (i, a) in regions.iter().enumerate() {
+        for b in &regions[i + 1..] {
+            assert!(!overlaps(a, b), "{:?} overlaps {:?}", a, b);
+        }
+    }
+}
+
+#[test]
+fn grows_when_inserting_images() {
+    let mut gpu = Gpu::headless();
+    let mut atlas = Atlas::new(
+        &mut gpu,
+        Settings {
+            max_width: 1024,
+            max_height: 1024,
+            ..Settings::default()
+        },
+    )
+    .expect("Create atlas");
+
+    let initial_width = atlas.image().width();
+
+    let first = atlas
+        .insert(&mut gpu, "first", &square(200, [255; 4]))
+        .expect("Insert first image");
+
+    let second = atlas
+        .insert(&mut gpu, "second", &square(200, [255; 4]))
+        .expect("Insert second image");
+
+    assert!(atlas.image().width() > initial_width);
+    assert!(!overlaps(&first.source, &second.source));
+    assert!(atlas
+        .insert(&mut gpu, "huge", &square(2000, [255; 4]))
+        .is_err());
+}
+
+#[test]
+fn updates_the_image_in_place_when_inserting() {
+    let mut gpu = Gpu::headless();
+    let mut atlas =
+        Atlas::new(&mut gpu, Settings::default()).expect("Create atlas");
+
+    let red = atlas
+        .insert(&mut gpu, "red", &square(4, [255, 0, 0, 255]))
+        .expect("Insert red image");
+
+    let image = atlas.image().clone();
+
+    let green = atlas
+        .insert(&mut gpu, "green", &square(4, [0, 255, 0, 255]))
+        .expect("Insert green image");
+
+    let width = image.width();
+    let height = image.height();
+
+    assert_eq!(atlas.image().width(), width);
+    assert_eq!(atlas.image().height(), height);
+
+    let mut canvas = Canvas::new(&mut gpu, width, height).expect("Canvas");
+
+    {
+        let mut target = canvas.as_target(&mut gpu);
+
+        target.clear(Color::BLACK);
+        image.draw(
+            Quad {
+                size: (f32::from(width), f32::from(height)),
+                ..Quad::default()
+            },
+            &mut target,
+        );
+    }
+
+    let pixels = canvas.read_pixels(&mut gpu).to_rgba();
+    let pixel = |region: &Rectangle<u16>| {
+        pixels
+            .get_pixel(u32::from(region.x), u32::from(region.y))
+            .data
+    };
+
+    assert_eq!(pixel(&red.source), [255, 0, 0, 255]);
+    assert_eq!(pixel(&green.source), [0, 255, 0, 255]);
+}
@@ -0,0 +1,194 @@
+#![cfg(feature = "audio")]
+use std::io::Cursor;
+use std::time::Duration;
+
+use coffee::audio::{self, Music, Sound};
+use coffee::Error;
+
+fn wav(channels: u16, sample_rate: u32, frames: usize) -> Vec<u8> {
+    let mut bytes = Cursor::new(Vec::new());
+
+    {
+        let mut writer = hound::WavWriter::new(
+            &mut bytes,
+            hound::WavSpec {
+                channels,
+                sample_rate,
+                bits_per_sample: 16,
+                sample_format: hound::SampleFormat::Int,
+            },
+        )
+        .expect("Create WAV writer");
+
+        for i in 0..frames * channels as usize {
+            writer.write_sample((i % 100) as i16).expect("Write sample");
+        }
+
+        writer.finalize().expect("Finalize WAV");
+    }
+
+    bytes.into_inner()
+}
+
+#[cfg(feature = "software")]
+fn stereo_wav(sample_rate: u32, frames: &[[f32; 2]]) -> Vec<u8> {
+    let mut bytes = Cursor::new(Vec::new());
+
+    {
+        let mut writer = hound::WavWriter::new(
+            &mut bytes,
+            hound::WavSpec {
+                channels: 2,
+                sample_rate,
+                bits_per_sample: 32,
+                sample_format: hound::SampleFormat::Float,
+            },
+        )
+        .expect("Create WAV writer");
+
+        for sample in frames.iter().flat_map(|frame| frame.iter()) {
+            writer.write_sample(*sample).expect("Write sample");
+        }
+
+        writer.finalize().expect("Finalize WAV");
+    }
+
+    bytes.into_inner()
+}
+
+#[cfg(feature = "software")]
+fn sound(sample_rate: u32, frames: &[[f32; 2]]) -> Sound {
+    Sound::from_bytes(&stereo_wav(sample_rate, frames)).expect("Decode")
+}
+
+#[cfg(feature = "software")]
+fn read(mixer: &audio::Mixer, frames: usize, channels: usize) -> Vec<f32> {
+    let mut buffer = vec![0.0; frames * channels];
+    mixer.read(&mut buffer, channels);
+
+    buffer
+}
+
+#[cfg(feature = "software")]
+fn assert_samples(actual: &[f32], expected: &[f32]) {
+    assert_eq!(actual.len(), expected.len());
+
+    for (sample, expected_sample) in actual.iter().zip(expected) {
+        assert!(
+            (sample - expected_sample).abs() < 1e-6,
+            "Expected {:?}, found {:?}",
+            expected,
+            actual
+        );
+    }
+}
+
+#[test]
+fn decodes_wav_sounds() {
+    let mono = Sound::from_bytes(&wav(1, 22_050, 11_025)).expect("Decode");
+    let stereo = Sound::from_bytes(&wav(2, 44_100, 44_100)).expect("Decode");
+
+    assert_eq!(mono.duration(), Duration::from_millis(500));
+    assert_eq!(stereo.duration(), Duration::from_secs(1));
+}
+
+#[test]
+fn rejects_unsupported_formats() {
+    let result = Sound::from_bytes(b"definitely not audio");
+
+    assert!(match result {
+        Err(Error::Audio(audio::Error::UnsupportedFormat)) => true,
+        _ => false,
+    });
+
+    assert!(Music::from_bytes(b"RIFF but not really").is_err());
+}
+
+#[cfg(feature = "software")]
+#[test]
+fn applies_volume_and_panning() {
+    let mixer = audio::Mixer::null();
+    mixer.set_volume(0, 0.5);
+    mixer.set_panning(0, 1.0);
+
+    mixer.play(0, &sound(44_100, &[[0.5, 0.5], [0.5, 0.5]]));
+
+    assert_samples(&read(&mixer, 3, 2), &[0.0, 0.25, 0.0, 0.25, 0.0, 0.0]);
+    assert!(!mixer.is_playing(0));
+}
+
+#[cfg(feature = "software")]
+#[test]
+fn mixes_channels_with_master_volume() {
+    let mixer = audio::Mixer::null();
+    mixer.set_master_volume(0.5);
+
+    let sound = sound(44_100, &[[0.8, 0.2]]);
+    mixer.play(0, &sound);
+    mixer.play(1, &sound);
+
+    assert_samples(&read(&mixer, 1, 2), &[0.8, 0.2]);
+}
+
+#[cfg(feature = "software")]
+#[test]
+fn clamps_samples() {
+    let mixer = audio::Mixer::null();
+
+    let sound = sound(44_100, &[[0.8, -0.8]]);
+    mixer.play(0, &sound);
+    mixer.play(1, &sound);
+
+    assert_samples(&read(&mixer, 1, 2), &[1.0, -1.0]);
+}
+
+#[cfg(feature = "software")]
+#[test]
+fn downmixes_to_mono() {
+    let mixer = audio::Mixer::null();
+
+    mixer.play(0, &sound(44_100, &[[0.2, 0.6]]));
+
+    assert_samples(&read(&mixer, 1, 1), &[0.4]);
+}
+
+#[cfg(feature = "software")]
+#[test]
+fn loops_sounds() {
+    let mixer = audio::Mixer::null();
+    mixer.set_looping(0, true);
+
+    mixer.play(0, &sound(44_100, &[[0.2, 0.2], [0.4, 0.4]]));
+
+    assert_samples(
+        &read(&mixer, 4, 2),
+        &[0.2, 0.2, 0.4, 0.4, 0.2, 0.2, 0.4, 0.4],
+    );
+    assert!(mixer.is_playing(0));
+}
+
+#[cfg(feature = "software")]
+#[test]
+fn loops_music() {
+    let mixer = audio::Mixer::null();
+    mixer.set_looping(0, true);
+
+    let music =
+        Music::from_bytes(&stereo_wav(44_100, &[[0.2, 0.2], [0.4, 0.4]]))
+            .expect("Load music");
+
+    mixer.play_music(0, &music);
+
+    assert_samples(&read(&mixer, 6, 1), &[0.2, 0.4, 0.2, 0.4, 0.2, 0.4]);
+    assert!(mixer.is_playing(0));
+}
+
+#[cfg(feature = "software")]
+#[test]
+fn resamples_with_linear_interpolation() {
+    let mixer = audio::Mixer::null();
+
+    mixer.play(0, &sound(22_050, &[[0.0, 0.0], [0.8, 0.8]]));
+
+    assert_samples(&read(&mixer, 5, 1), &[0.0, 0.4, 0.8, 0.8, 0.0]);
+}
@@ -0,0 +1,125 @@
+#![cfg(feature = "software")]
+use std::fs;
+use std::path::PathBuf;
+
+use coffee::graphics::{BitmapFont, Canvas, Color, Gpu, Point, Text};
+
+const TEXT: &str = "\
+info face=\"Test\" size=8
+common lineHeight=10 base=8 scaleW=32 scaleH=8 pages=1
+page id=0 file=\"page.png\"
+chars count=3
+char id=32 x=0 y=0 width=0 height=0 xoffset=0 yoffset=0 xadvance=4 page=0
+char id=65 x=0 y=0 width=6 height=8 xoffset=0 yoffset=0 xadvance=7 page=0
+char id=66 x=8 y=0 width=6 height=8 xoffset=0 yoffset=0 xadvance=7 page=0
+kernings count=1
+kerning first=65 second=66 amount=-2
+";
+
+const XML: &str = r#"<?xml version="1.0"?>
+<font>
+  <info face="Test" size="8" />
+  <common lineHeight="10" base="8" scaleW="32" scaleH="8" pages="1" />
+  <pages>
+    <page id="0" file="page.png" />
+  </pages>
+  <chars count="3">
+    <char id="32" x="0" y="0" width="0" height="0" xoffset="0" yoffset="0" xadvance="4" page="0" />
+    <char id="65" x="0" y="0" width="6" height="8" xoffset="0" yoffset="0" xadvance="7" page="0" />
+    <char id="66" x="8" y="0" width="6" height="8" xoffset="0" yoffset="0" xadvance="7" page="0" />
+  </chars>
+  <kernings count="1">
+    <kerning first="65" second="66" amount="-2" />
+  </kernings>
+</font>
+"#;
+
+/// Writes a font in a directory of its own for the given test, as tests run
+/// in parallel.
+fn write_font(test: &str, name: &str, descriptor: &str) -> PathBuf {
+    let directory = std::env::temp_dir().join(format!(
+        "coffee-bitmap-font-{}-{}",
+        std::process::id(),
+        test
+    ));
+
+    fs::create_dir_all(&directory).expect("Create directory");
+
+    image::RgbaImage::from_pixel(32, 8, image::Rgba([255, 255, 255, 255]))
+        .save(directory.join("page.png"))
+        .expect("Save page");
+
+    let path = directory.join(name);
+    fs::write(&path, descriptor).expect("Write descriptor");
+
+    path
+}
+
+fn text(content: &str, size: f32) -> Text<'_> {
+    Text {
+        content,
+        size,
+        ..Text::default()
+    }
+}
+
+#[test]
+fn lays_out_text_with_kerning_and_wrapping() {
+    let mut gpu = Gpu::headless();
+
+    for (name, descriptor) in &[("text.fnt", TEXT), ("xml.fnt", XML)] {
+        let path = write_font("layout", name, descriptor);
+        let mut font = BitmapFont::new(&mut gpu, &path).expect("Load font");
+
+        assert_eq!(font.measure(text("AB", 8.0)), (12.0, 10.0));
+        assert_eq!(font.measure(text("AB", 16.0)), (24.0, 20.0));
+        assert_eq!(font.measure(text("A\nB", 8.0)), (7.0, 20.0));
+
+        assert_eq!(
+            font.measure(Text {
+                bounds: (20.0, 100.0),
+                ..text("AB AB", 8.0)
+            }),
+            (12.0, 20.0)
+        );
+
+        let glyphs = font.layout(text("AB AB", 8.0));
+        let indices: Vec<_> = glyphs.iter().map(|glyph| glyph.index).collect();
+
+        assert_eq!(indices, vec![0, 1, 2, 3, 4]);
+        assert_eq!(glyphs[1].bounds.x, 5.0);
+        assert_eq!(
+            font.hit_test(text("AB", 8.0), Point::new(9.0, 5.0)),
+            Some(1)
+        );
+    }
+}
+
+#[test]
+fn draws_glyphs_with_the_color_of_the_text() {
+    let mut gpu = Gpu::headless();
+    let path = write_font("draw", "draw.fnt", TEXT);
+    let mut font = BitmapFont::new(&mut gpu, &path).expect("Load font");
+
+    let mut canvas = Canvas::new(&mut gpu, 16, 16).expect("Create canvas");
+
+    {
+        let mut target = canvas.as_target(&mut gpu);
+        target.clear(Color::BLACK);
+
+        font.add(Text {
+            content: "A",
+            position: Point::new(0.0, 0.0),
+            size: 8.0,
+            color: Color::RED,
+            ..Text::default()
+        });
+
+        font.draw(&mut target);
+    }
+
+    let pixels = canvas.read_pixels(&mut gpu).to_rgba();
+
+    assert_eq!(pixels.get_pixel(2, 2).data, [255, 0, 0, 255]);
+    assert_eq!(pixels.get_pixel(10, 2).data, [0, 0, 0, 255]);
+}
@@ -0,0 +1,51 @@
+#![cfg(feature = "software")]
+use coffee::graphics::{BlendMode, Canvas, Color, Gpu, Image, Quad};
+
+fn render(background: Color, color: Color, blend_mode: BlendMode) -> [u8; 4] {
+    let mut gpu = Gpu::headless();
+    let image = Image::from_colors(&mut gpu, &[color]).expect("Create image");
+    let mut canvas = Canvas::new(&mut gpu, 1, 1).expect("Create canvas");
+
+    {
+        let mut target = canvas.as_target(&mut gpu);
+
+        target.clear(background);
+        image.draw(
+            Quad {
+                size: (1.0, 1.0),
+                ..Quad::default()
+            },
+            &mut target.blend(blend_mode),
+        );
+    }
+
+    canvas.read_pixels(&mut gpu).to_rgba().get_pixel(0, 0).data
+}
+
+#[test]
+fn additive_adds_colors() {
+    let pixel = render(Color::RED, Color::GREEN, BlendMode::Additive);
+
+    assert_eq!(pixel, [255, 255, 0, 255]);
+}
+
+#[test]
+fn multiply_multiplies_colors() {
+    let pixel = render(Color::WHITE, Color::BLUE, BlendMode::Multiply);
+
+    assert_eq!(pixel, [0, 0, 255, 255]);
+}
+
+#[test]
+fn replace_overwrites_colors() {
+    let transparent = Color {
+        r: 0.0,
+        g: 0.0,
+        b: 0.0,
+        a: 0.0,
+    };
+
+    let pixel = render(Color::RED, transparent, BlendMode::Replace);
+
+    assert_eq!(pixel, [0, 0, 0, 0]);
+}
@@ -0,0 +1,61 @@
+use coffee::graphics::{Camera, Point, Rectangle};
+
+fn viewport() -> Rectangle<f32> {
+    Rectangle {
+        x: 0.0,
+        y: 0.0,
+        width: 800.0,
+        height: 600.0,
+    }
+}
+
+fn assert_close(a: Point, b: Point) {
+    assert!((a - b).norm() < 1e-3, "{} != {}", a, b);
+}
+
+#[test]
+fn matches_screen_coordinates_by_default() {
+    let camera = Camera::new(viewport());
+    let point = Point::new(123.0, 456.0);
+
+    assert_close(camera.screen_to_world(point), point);
+    assert_close(camera.world_to_screen(point), point);
+    assert_eq!(camera.visible_area(), viewport());
+}
+
+#[test]
+fn converts_between_screen_and_world_coordinates() {
+    let mut camera = Camera::new(viewport());
+    camera.position = Point::new(1000.0, -200.0);
+    camera.zoom = 2.0;
+    camera.rotation = std::f32::consts::FRAC_PI_2;
+
+    assert_close(
+        camera.screen_to_world(Point::new(400.0, 300.0)),
+        camera.position,
+    );
+
+    assert_close(
+        camera.world_to_screen(Point::new(1010.0, -200.0)),
+        Point::new(400.0, 280.0),
+    );
+
+    let point = Point::new(37.0, 512.0);
+
+    assert_close(camera.world_to_screen(camera.screen_to_world(point)), point);
+}
+
+#[test]
+fn computes_the_visible_area() {
+    let mut camera = Camera::new(viewport());
+    camera.position = Point::new(0.0, 0.0);
+    camera.zoom = 2.0;
+    camera.rotation = std::f32::consts::FRAC_PI_2;
+
+    let area = camera.visible_area();
+
+    assert!((area.x + 150.0).abs() < 1e-3);
+    assert!((area.y + 200.0).abs() < 1e-3);
+    assert!((area.width - 300.0).abs() < 1e-3);
+    assert!((area.height - 400.0).abs() < 1e-3);
+}
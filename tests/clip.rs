@@ -0,0 +1,128 @@
+#![cfg(feature = "software")]
+use coffee::graphics::{
+    Canvas, Color, Gpu, Image, Mesh, Quad, Rectangle, Shape, Transformation,
+};
+
+#[test]
+fn clip_skips_pixels_outside_of_rectangle() {
+    let mut gpu = Gpu::headless();
+    let image = Image::from_colors(&mut gpu, &[Color::RED]).expect("Image");
+    let mut canvas = Canvas::new(&mut gpu, 4, 4).expect("Create canvas");
+
+    {
+        let mut target = canvas.as_target(&mut gpu);
+
+        target.clear(Color::BLACK);
+        image.draw(
+            Quad {
+                size: (4.0, 4.0),
+                ..Quad::default()
+            },
+            &mut target.clip(Rectangle {
+                x: 1,
+                y: 2,
+                width: 2,
+                height: 1,
+            }),
+        );
+    }
+
+    let pixels = canvas.read_pixels(&mut gpu).to_rgba();
+
+    for (x, y, pixel) in pixels.enumerate_pixels() {
+        let expected = if (x == 1 || x == 2) && y == 2 {
+            [255, 0, 0, 255]
+        } else {
+            [0, 0, 0, 255]
+        };
+
+        assert_eq!(pixel.data, expected, "pixel ({}, {})", x, y);
+    }
+}
+
+#[test]
+fn clip_ignores_transformations_and_intersects() {
+    let mut gpu = Gpu::headless();
+    let mut canvas = Canvas::new(&mut gpu, 4, 4).expect("Create canvas");
+
+    let mut mesh = Mesh::new();
+    mesh.fill(
+        Shape::Rectangle(Rectangle {
+            x: 0.0,
+            y: 0.0,
+            width: 2.0,
+            height: 2.0,
+        }),
+        Color::GREEN,
+    );
+
+    {
+        let mut target = canvas.as_target(&mut gpu);
+        target.clear(Color::BLACK);
+
+        let mut clipped = target.clip(Rectangle {
+            x: 0,
+            y: 0,
+            width: 3,
+            height: 4,
+        });
+
+        let mut clipped = clipped.clip(Rectangle {
+            x: 2,
+            y: 0,
+            width: 2,
+            height: 4,
+        });
+
+        mesh.draw(&mut clipped.transform(Transformation::scale(2.0)));
+    }
+
+    let pixels = canvas.read_pixels(&mut gpu).to_rgba();
+
+    for (x, _, pixel) in pixels.enumerate_pixels() {
+        let expected = if x == 2 {
+            [0, 255, 0, 255]
+        } else {
+            [0, 0, 0, 255]
+        };
+
+        assert_eq!(pixel.data, expected);
+    }
+}
+
+#[test]
+fn clip_extending_past_the_target_is_limited_to_it() {
+    let mut gpu = Gpu::headless();
+    let image = Image::from_colors(&mut gpu, &[Color::RED]).expect("Image");
+    let mut canvas = Canvas::new(&mut gpu, 4, 4).expect("Create canvas");
+
+    {
+        let mut target = canvas.as_target(&mut gpu);
+
+        target.clear(Color::BLACK);
+        image.draw(
+            Quad {
+                size: (4.0, 4.0),
+                ..Quad::default()
+            },
+            &mut target.clip(Rectangle {
+                x: 2,
+                y: 3,
+                width: 100,
+                height: 100,
+            }),
+        );
+    }
+
+    let pixels = canvas.read_pixels(&mut gpu).to_rgba();
+
+    for (x, y, pixel) in pixels.enumerate_pixels() {
+        let expected = if x >= 2 && y == 3 {
+            [255, 0, 0, 255]
+        } else {
+            [0, 0, 0, 255]
+        };
+
+        assert_eq!(pixel.data, expected, "pixel ({}, {})", x, y);
+    }
+}
@@ -0,0 +1,189 @@
+#![cfg(feature = "software")]
+use coffee::graphics::{Color, Frame, Window, WindowSettings};
+use coffee::input::keyboard::{self, KeyCode};
+use coffee::input::{mouse, ButtonState, Event};
+use coffee::load::Task;
+use coffee::ui::{
+    self, button, Button, Column, Element, Renderer, UserInterface,
+};
+use coffee::{Game, Timer};
+
+struct Menu {
+    buttons: Vec<button::State>,
+    visible: usize,
+    pressed: Vec<usize>,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Message {
+    Pressed(usize),
+}
+
+impl Game for Menu {
+    type Input = ();
+    type LoadingScreen = ();
+
+    fn load(_window: &Window) -> Task<Menu> {
+        Task::succeed(|| Menu {
+            buttons: vec![button::State::new(); 5],
+            visible: 3,
+            pressed: Vec::new(),
+        })
+    }
+
+    fn draw(&mut self, frame: &mut Frame<'_>, _timer: &Timer) {
+        frame.clear(Color::BLACK);
+    }
+}
+
+impl UserInterface for Menu {
+    type Message = Message;
+    type Renderer = Renderer;
+
+    fn react(&mut self, message: Message, _window: &mut Window) {
+        match message {
+            Message::Pressed(i) => self.pressed.push(i),
+        }
+    }
+
+    fn layout(&mut self, _window: &Window) -> Element<'_, Message> {
+        self.buttons
+            .iter_mut()
+            .take(self.visible)
+            .enumerate()
+            .fold(Column::new().width(100), |column, (i, state)| {
+                column.push(
+                    Button::new(state, "Option").on_press(Message::Pressed(i)),
+                )
+            })
+            .into()
+    }
+}
+
+fn menu() -> ui::Simulation<Menu> {
+    ui::Simulation::<Menu>::new(WindowSettings {
+        title: String::from("Focus test - Coffee"),
+        size: (100, 250),
+        resizable: false,
+        fullscreen: false,
+        maximized: false,
+    })
+    .expect("Load simulation")
+}
+
+/// Feeds the given events to the simulation, one per tick.
+fn feed(simulation: &mut ui::Simulation<Menu>, events: &[Event]) {
+    for event in events {
+        let tick = simulation.tick();
+
+        simulation.schedule(tick, *event);
+        simulation.step();
+    }
+}
+
+/// Activates the focused button and returns its index, if any.
+fn focused(simulation: &mut ui::Simulation<Menu>) -> Option<usize> {
+    simulation.game_mut().pressed.clear();
+    feed(simulation, &[press(KeyCode::Return)]);
+
+    simulation.game().pressed.last().cloned()
+}
+
+fn key(key_code: KeyCode, state: ButtonState) -> Event {
+    Event::Keyboard(keyboard::Event::Input { key_code, state })
+}
+
+fn press(key_code: KeyCode) -> Event {
+    key(key_code, ButtonState::Pressed)
+}
+
+#[test]
+fn next_wraps_around() {
+    let mut simulation = menu();
+    assert_eq!(focused(&mut simulation), None);
+
+    feed(&mut simulation, &[press(KeyCode::Tab)]);
+    assert_eq!(focused(&mut simulation), Some(0));
+
+    feed(
+        &mut simulation,
+        &[press(KeyCode::Tab), press(KeyCode::Down)],
+    );
+    assert_eq!(focused(&mut simulation), Some(2));
+
+    feed(&mut simulation, &[press(KeyCode::Tab)]);
+    assert_eq!(focused(&mut simulation), Some(0));
+}
+
+#[test]
+fn previous_wraps_around() {
+    let mut simulation = menu();
+
+    feed(&mut simulation, &[press(KeyCode::Up)]);
+    assert_eq!(focused(&mut simulation), Some(2));
+
+    feed(&mut simulation, &[press(KeyCode::Up), press(KeyCode::Up)]);
+    assert_eq!(focused(&mut simulation), Some(0));
+
+    feed(&mut simulation, &[press(KeyCode::Up)]);
+    assert_eq!(focused(&mut simulation), Some(2));
+}
+
+#[test]
+fn stays_inside_shrinking_focusables() {
+    let focus_last = |simulation: &mut ui::Simulation<Menu>| {
+        simulation.game_mut().visible = 5;
+        feed(simulation, &[press(KeyCode::Up)]);
+
+        simulation.game_mut().visible = 3;
+    };
+
+    let mut simulation = menu();
+    focus_last(&mut simulation);
+    assert_eq!(focused(&mut simulation), None);
+
+    feed(&mut simulation, &[press(KeyCode::Down)]);
+    assert_eq!(focused(&mut simulation), Some(0));
+
+    focus_last(&mut simulation);
+    feed(&mut simulation, &[press(KeyCode::Up)]);
+    assert_eq!(focused(&mut simulation), Some(2));
+
+    simulation.game_mut().visible = 0;
+    feed(&mut simulation, &[press(KeyCode::Down), press(KeyCode::Up)]);
+
+    simulation.game_mut().visible = 3;
+    assert_eq!(focused(&mut simulation), None);
+}
+
+#[test]
+fn navigates_with_tab_and_shift() {
+    let mut simulation = menu();
+
+    feed(&mut simulation, &[press(KeyCode::Tab), press(KeyCode::Tab)]);
+    assert_eq!(focused(&mut simulation), Some(1));
+
+    feed(
+        &mut simulation,
+        &[press(KeyCode::LShift), press(KeyCode::Tab)],
+    );
+    assert_eq!(focused(&mut simulation), Some(0));
+
+    feed(
+        &mut simulation,
+        &[
+            key(KeyCode::LShift, ButtonState::Released),
+            press(KeyCode::Tab),
+        ],
+    );
+    assert_eq!(focused(&mut simulation), Some(1));
+
+    feed(
+        &mut simulation,
+        &[Event::Mouse(mouse::Event::Input {
+            button: mouse::Button::Left,
+            state: ButtonState::Pressed,
+        })],
+    );
+    assert_eq!(focused(&mut simulation), None);
+}
@@ -0,0 +1,115 @@
+#![cfg(feature = "software")]
+use coffee::graphics::{Font, FontStyle, Gpu, Point, RichText, Span, Text};
+
+const INCONSOLATA: &[u8] =
+    include_bytes!("../resources/font/Inconsolata-Regular.ttf");
+
+#[test]
+fn measures_rich_text() {
+    let mut gpu = Gpu::headless();
+    let mut font = Font::from_bytes(&mut gpu, INCONSOLATA).expect("Load font");
+
+    let text = font.measure(Text {
+        content: "Hello, world!",
+        size: 20.0,
+        ..Text::default()
+    });
+
+    // Styles without a typeface fall back to the regular one
+    let rich_text = font.measure(RichText {
+        spans: vec![
+            Span {
+                content: "Hello, ",
+                size: 20.0,
+                ..Span::default()
+            },
+            Span {
+                content: "world!",
+                size: 20.0,
+                style: FontStyle::Bold,
+                ..Span::default()
+            },
+        ],
+        ..RichText::default()
+    });
+
+    assert_eq!(text, rich_text);
+
+    let (width, height) = font.measure(RichText {
+        spans: vec![
+            Span {
+                content: "Hello, ",
+                size: 20.0,
+                ..Span::default()
+            },
+            Span {
+                content: "world!",
+                size: 40.0,
+                ..Span::default()
+            },
+        ],
+        ..RichText::default()
+    });
+
+    assert!(width > text.0);
+    assert!(height > text.1);
+}
+
+#[test]
+fn loads_fonts_at_runtime() {
+    let mut gpu = Gpu::headless();
+    let text = Text {
+        content: "Hello, world!",
+        size: 20.0,
+        ..Text::default()
+    };
+
+    let mut embedded =
+        Font::from_bytes(&mut gpu, INCONSOLATA).expect("Load embedded font");
+
+    let mut file =
+        Font::new(&mut gpu, "resources/font/Inconsolata-Regular.ttf")
+            .expect("Load font file");
+
+    let mut owned =
+        Font::from_vec(&mut gpu, INCONSOLATA.to_vec()).expect("Load font");
+
+    owned
+        .add_fallback(INCONSOLATA.to_vec())
+        .expect("Add fallback");
+
+    let size = embedded.measure(text.clone());
+
+    assert_eq!(file.measure(text.clone()), size);
+    assert_eq!(owned.measure(text), size);
+
+    assert!(Font::from_vec(&mut gpu, vec![0; 64]).is_err());
+    assert!(Font::from_system(&mut gpu, &["Not A Real Font Family"]).is_err());
+}
+
+#[test]
+fn lays_out_glyphs() {
+    let mut gpu = Gpu::headless();
+    let mut font = Font::from_bytes(&mut gpu, INCONSOLATA).expect("Load font");
+
+    let text = Text {
+        content: "ab\ncd",
+        size: 20.0,
+        ..Text::default()
+    };
+
+    let glyphs = font.layout(text.clone());
+
+    let indices: Vec<_> = glyphs.iter().map(|glyph| glyph.index).collect();
+    let lines: Vec<_> = glyphs.iter().map(|glyph| glyph.line).collect();
+
+    assert_eq!(indices, vec![0, 1, 3, 4]);
+    assert_eq!(lines, vec![0, 0, 1, 1]);
+    assert!(glyphs[0].bounds.x < glyphs[1].bounds.x);
+    assert!(glyphs[0].bounds.y < glyphs[2].bounds.y);
+
+    let d = glyphs[3].bounds.center();
+
+    assert_eq!(font.hit_test(text.clone(), d), Some(4));
+    assert_eq!(font.hit_test(text, Point::new(500.0, 500.0)), None);
+}
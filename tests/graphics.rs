@@ -1,394 +1,126 @@
-#![cfg(not(target_os = "windows"))]
-use coffee::graphics::{
-    Color, Frame, Gpu, Point, Quad, Window, WindowSettings,
-};
+use coffee::graphics::{Canvas, Color, Gpu};
 use coffee::load::Task;
-use coffee::ui::{
-    button, Button, Checkbox, Column, Element, Justify, Panel, Renderer, Row,
-    Text, UserInterface,
-};
-use coffee::{Game, Result, Timer};
+use coffee::testing::{self, Harness};
 
 mod _graphics;
-use _graphics::{test, Test};
+use _graphics::Test;
 
-#[test]
-#[ignore]
-fn graphics() -> Result<()> {
-    env_logger::init();
-
-    <Runner as UserInterface>::run(WindowSettings {
-        title: String::from("Graphics integration tests - Coffee"),
-        size: (1280, 1024),
-        resizable: false,
-        fullscreen: false,
-        maximized: false,
-    })
-}
-
-struct Runner {
-    state: State,
-}
-
-pub enum State {
-    Pending {
-        tests: Vec<Test>,
-    },
-    Running {
-        remaining: Vec<Test>,
-        current: test::Drawing,
-    },
-    AskingToSaveModelImage {
-        remaining: Vec<Test>,
-        current: test::Drawing,
-        save_button: button::State,
-        fail_button: button::State,
-        saved: bool,
-    },
-    ReportingDifferences {
-        remaining: Vec<Test>,
-        current: test::Differences,
-        show: bool,
-        quit_button: button::State,
-    },
-    Finished,
-}
+fn assert_drawings(gpu: &mut Gpu) {
+    let harness = Harness::new("tests/_graphics/models").tolerance(2);
 
-struct Progress {
-    remaining: usize,
-    current: Option<Test>,
-}
-
-impl State {
-    fn progress(&self) -> Option<Progress> {
-        match self {
-            State::Pending { tests } => Some(Progress {
-                remaining: tests.len(),
-                current: None,
-            }),
-            State::Running { current, remaining } => Some(Progress {
-                remaining: remaining.len(),
-                current: Some(current.test()),
-            }),
-            State::ReportingDifferences {
-                current, remaining, ..
-            } => Some(Progress {
-                remaining: remaining.len(),
-                current: Some(current.test()),
-            }),
-            State::AskingToSaveModelImage {
-                current, remaining, ..
-            } => Some(Progress {
-                remaining: remaining.len(),
-                current: Some(current.test()),
-            }),
-            State::Finished { .. } => None,
-        }
+    for test in Test::all() {
+        harness.assert(gpu, &test.to_string(), test.draw());
     }
 }
 
-impl Runner {
-    fn run_next(&mut self, gpu: &mut Gpu) {
-        // We need to own the current state to avoid awkward copies.
-        // TODO: Not sure if there is a better way to do this.
-        // Something like `replace` but taking a closure would be nice.
-        let state = std::mem::replace(&mut self.state, State::Finished);
-
-        let next = |mut remaining: Vec<Test>, gpu: &mut Gpu| {
-            if let Some(next) = remaining.pop() {
-                State::Running {
-                    remaining,
-                    current: next.run(gpu),
-                }
-            } else {
-                State::Finished
-            }
-        };
-
-        self.state = match state {
-            State::Pending { mut tests } => {
-                if let Some(first) = tests.pop() {
-                    State::Running {
-                        remaining: tests,
-                        current: first.run(gpu),
-                    }
-                } else {
-                    State::Finished
-                }
-            }
-            State::Running { remaining, current } => {
-                let differences = current.differences(gpu);
-
-                match differences {
-                    Ok(None) => next(remaining, gpu),
-                    Ok(Some(differences)) => State::ReportingDifferences {
-                        remaining,
-                        current: differences,
-                        show: false,
-                        quit_button: button::State::new(),
-                    },
-                    Err(test::Error::ModelImageNotFound(_)) => {
-                        State::AskingToSaveModelImage {
-                            remaining,
-                            current,
-                            save_button: button::State::new(),
-                            fail_button: button::State::new(),
-                            saved: false,
-                        }
-                    }
-                    Err(error) => panic!("Something went wrong: {:?}", error),
-                }
-            }
-            State::ReportingDifferences { remaining, .. } => {
-                next(remaining, gpu)
-            }
-            State::AskingToSaveModelImage { remaining, .. } => {
-                next(remaining, gpu)
-            }
-            State::Finished { .. } => state,
-        }
-    }
+#[cfg(feature = "software")]
+#[test]
+fn drawings_match_their_models() {
+    assert_drawings(&mut Gpu::headless());
 }
 
-impl Game for Runner {
-    type LoadingScreen = ();
-    type Input = ();
+/// Runs the same comparisons using a window and the hardware backend of the
+/// enabled graphics feature.
+#[cfg(not(feature = "software"))]
+#[test]
+#[ignore]
+fn drawings_match_their_models() -> coffee::Result<()> {
+    use coffee::graphics::{Frame, Window, WindowSettings};
+    use coffee::{Game, Timer};
 
-    fn load(_window: &Window) -> Task<Runner> {
-        Task::succeed(|| Runner {
-            state: State::Pending { tests: Test::all() },
-        })
-    }
+    env_logger::init();
 
-    fn interact(&mut self, _input: &mut (), window: &mut Window) {
-        match self.state {
-            State::Pending { .. } => self.run_next(window.gpu()),
-            State::Running { .. } => self.run_next(window.gpu()),
-            State::AskingToSaveModelImage { saved, .. } if saved => {
-                self.run_next(window.gpu())
-            }
-            _ => {}
-        }
-    }
+    struct Runner;
 
-    fn draw(&mut self, frame: &mut Frame, _timer: &Timer) {
-        frame.clear(Color {
-            r: 0.3,
-            g: 0.3,
-            b: 0.6,
-            a: 1.0,
-        });
+    impl Game for Runner {
+        type Input = ();
+        type LoadingScreen = ();
 
-        let canvas = match &self.state {
-            State::Running { current, .. } => Some(current.canvas()),
-            State::ReportingDifferences { current, .. } => {
-                Some(current.canvas())
-            }
-            State::AskingToSaveModelImage { current, .. } => {
-                Some(current.canvas())
-            }
-            _ => None,
-        };
+        fn load(_window: &Window) -> Task<Runner> {
+            Task::using_gpu(|gpu| {
+                assert_drawings(gpu);
 
-        if let Some(canvas) = canvas {
-            canvas.draw(
-                Quad {
-                    position: Point::new(
-                        frame.width() * 0.5 - canvas.width() as f32 * 0.5,
-                        frame.height() * 0.5 - canvas.height() as f32 * 0.5,
-                    ),
-                    size: (canvas.width() as f32, canvas.height() as f32),
-                    ..Quad::default()
-                },
-                &mut frame.as_target(),
-            );
+                Ok(Runner)
+            })
         }
 
-        match &self.state {
-            State::ReportingDifferences { current, show, .. } if *show => {
-                let image = current.image();
-
-                image.draw(
-                    Quad {
-                        position: Point::new(
-                            frame.width() * 0.5 - image.width() as f32 * 0.5,
-                            frame.height() * 0.5 - image.height() as f32 * 0.5,
-                        ),
-                        size: (image.width() as f32, image.height() as f32),
-                        ..Quad::default()
-                    },
-                    &mut frame.as_target(),
-                );
-            }
-            _ => {}
+        fn draw(&mut self, frame: &mut Frame<'_>, _timer: &Timer) {
+            frame.clear(Color::BLACK);
         }
-    }
 
-    fn is_finished(&self) -> bool {
-        match self.state {
-            State::Finished => true,
-            _ => false,
+        fn is_finished(&self) -> bool {
+            true
         }
     }
 
-    fn on_close_request(&mut self) -> bool {
-        panic!("Exited before completion")
-    }
-}
-
-#[derive(Debug, Clone, Copy)]
-pub enum Message {
-    CreateModelImage,
-    FailTest,
-    ToggleDifferences(bool),
+    Runner::run(WindowSettings {
+        title: String::from("Graphics integration tests - Coffee"),
+        size: (1280, 1024),
+        resizable: false,
+        fullscreen: false,
+        maximized: false,
+    })
 }
 
-impl UserInterface for Runner {
-    type Message = Message;
-    type Renderer = Renderer;
+#[cfg(feature = "software")]
+fn fill(color: Color) -> Task<Canvas> {
+    Task::using_gpu(move |gpu| {
+        let mut canvas = Canvas::new(gpu, 8, 8)?;
+        canvas.as_target(gpu).clear(color);
 
-    fn react(&mut self, msg: Message, window: &mut Window) {
-        match msg {
-            Message::CreateModelImage => match &mut self.state {
-                State::AskingToSaveModelImage { saved, current, .. }
-                    if !*saved =>
-                {
-                    current.save_as_model(window.gpu());
+        Ok(canvas)
+    })
+}
 
-                    *saved = true;
-                }
-                _ => {}
-            },
-            Message::FailTest => {
-                if let Some(progress) = self.state.progress() {
-                    match progress.current {
-                        Some(test) => panic!("\"{:?}\" test failed", test),
-                        None => {}
-                    }
-                }
-            }
-            Message::ToggleDifferences(value) => {
-                if let State::ReportingDifferences { show, .. } =
-                    &mut self.state
-                {
-                    *show = value;
-                }
-            }
-        }
+#[cfg(feature = "software")]
+#[test]
+fn harness_reports_differences() {
+    // Updating models skips every comparison
+    if std::env::var_os(testing::UPDATE_MODELS).is_some() {
+        return;
     }
 
-    fn layout(&mut self, window: &Window) -> Element<Message> {
-        let progress: Element<_> = match self.state.progress() {
-            Some(progress) => status_line(progress),
-            None => Column::new().into(),
-        };
+    let mut gpu = Gpu::headless();
+    let directory = std::env::temp_dir()
+        .join(format!("coffee-harness-{}", std::process::id()));
 
-        let dialog: Element<Message> = match &mut self.state {
-            State::AskingToSaveModelImage {
-                current,
-                save_button,
-                fail_button,
-                ..
-            } => Row::new()
-                .justify_content(Justify::Center)
-                .push(save_model_image_dialog(
-                    current.test(),
-                    save_button,
-                    fail_button,
-                ))
-                .into(),
-            State::ReportingDifferences {
-                current,
-                show,
-                quit_button,
-                ..
-            } => Row::new()
-                .justify_content(Justify::Center)
-                .push(differences_dialog(current.test(), *show, quit_button))
-                .into(),
-            _ => Column::new().into(),
-        };
+    let harness = Harness::new(&directory).tolerance(10);
+    let model = directory.join("fill.png");
 
-        Column::new()
-            .width(window.width() as u32)
-            .height(window.height() as u32)
-            .padding(20)
-            .spacing(20)
-            .justify_content(Justify::SpaceBetween)
-            .push(progress)
-            .push(dialog)
-            .into()
+    match harness.check(&mut gpu, "fill", fill(Color::RED)) {
+        Err(testing::Error::ModelNotFound(path)) => assert_eq!(path, model),
+        result => panic!("Unexpected result: {:?}", result),
     }
-}
-
-// UI elements
-fn status_line<'a>(progress: Progress) -> Element<'a, Message> {
-    Row::new()
-        .justify_content(Justify::SpaceBetween)
-        .push(Text::new(&match progress.current {
-            Some(test) => format!("Testing {:?}...", test),
-            None => String::from("Pending..."),
-        }))
-        .push(Text::new(&format!(
-            "{} tests remaining",
-            progress.remaining
-        )))
-        .into()
-}
-
-fn save_model_image_dialog<'a>(
-    test: Test,
-    save_button: &'a mut button::State,
-    fail_button: &'a mut button::State,
-) -> Element<'a, Message> {
-    let message = Text::new(&format!(
-        "No model image exists for the \"{:?}\" test. \
-         Create one from the current drawing?",
-        test
-    ));
 
-    let options = Row::new()
-        .spacing(10)
-        .push(
-            Button::new(fail_button, "No, fail the test.")
-                .class(button::Class::Secondary)
-                .fill_width()
-                .on_press(Message::FailTest),
+    std::fs::create_dir_all(&directory).expect("Create models directory");
+
+    fill(Color::RED)
+        .run(&mut gpu)
+        .expect("Draw model")
+        .read_pixels(&mut gpu)
+        .save(&model)
+        .expect("Save model");
+
+    harness
+        .check(
+            &mut gpu,
+            "fill",
+            fill(Color {
+                r: 0.99,
+                g: 0.0,
+                b: 0.0,
+                a: 1.0,
+            }),
         )
-        .push(
-            Button::new(save_button, "Yes, create it.")
-                .class(button::Class::Positive)
-                .fill_width()
-                .on_press(Message::CreateModelImage),
-        );
-
-    Panel::new(Column::new().spacing(20).push(message).push(options)).into()
-}
-
-fn differences_dialog<'a>(
-    test: Test,
-    show: bool,
-    quit_button: &'a mut button::State,
-) -> Element<'a, Message> {
-    let message =
-        Text::new(&format!("Differences found for the \"{:?}\" test.", test));
+        .expect("Differences within tolerance");
 
-    let show_checkbox =
-        Checkbox::new(show, "Overlay differences", Message::ToggleDifferences);
-
-    let options = Row::new().spacing(10).push(
-        Button::new(quit_button, "Quit")
-            .class(button::Class::Secondary)
-            .fill_width()
-            .on_press(Message::FailTest),
-    );
+    match harness.check(&mut gpu, "fill", fill(Color::BLUE)) {
+        Err(testing::Error::Differences { pixels, diff }) => {
+            assert_eq!(pixels, 64);
+            assert!(diff.exists());
+        }
+        result => panic!("Unexpected result: {:?}", result),
+    }
 
-    Panel::new(
-        Column::new()
-            .spacing(20)
-            .push(message)
-            .push(show_checkbox)
-            .push(options),
-    )
-    .into()
+    let _ = std::fs::remove_dir_all(&directory);
 }
@@ -0,0 +1,120 @@
+#![cfg(feature = "software")]
+use coffee::graphics::{
+    Canvas, Color, Gpu, Image, Mesh, Point, Quad, Rectangle, Shape, Target,
+};
+use coffee::Error;
+
+fn rectangle(x: f32, y: f32, width: f32, height: f32) -> Mesh {
+    let mut mesh = Mesh::new();
+
+    mesh.fill(
+        Shape::Rectangle(Rectangle {
+            x,
+            y,
+            width,
+            height,
+        }),
+        Color::WHITE,
+    );
+
+    mesh
+}
+
+fn fill(image: &Image, target: &mut Target<'_>) {
+    image.draw(
+        Quad {
+            size: (4.0, 4.0),
+            ..Quad::default()
+        },
+        target,
+    );
+}
+
+#[test]
+fn mask_draws_only_inside_nested_meshes() {
+    let mut gpu = Gpu::headless();
+    let red = Image::from_colors(&mut gpu, &[Color::RED]).expect("Image");
+    let blue = Image::from_colors(&mut gpu, &[Color::BLUE]).expect("Image");
+    let mut canvas = Canvas::new(&mut gpu, 4, 4).expect("Create canvas");
+
+    {
+        let mut target = canvas.as_target(&mut gpu);
+        target.clear(Color::BLACK);
+
+        let mut left = target.mask(&rectangle(0.0, 0.0, 2.0, 4.0));
+
+        // Sibling masks of the same level do not affect each other
+        fill(&red, &mut left.mask(&rectangle(0.0, 0.0, 4.0, 1.0)));
+        fill(&blue, &mut left.mask(&rectangle(0.0, 3.0, 4.0, 1.0)));
+    }
+
+    let pixels = canvas.read_pixels(&mut gpu).to_rgba();
+
+    for (x, y, pixel) in pixels.enumerate_pixels() {
+        let expected = match (x, y) {
+            (0..=1, 0) => [255, 0, 0, 255],
+            (0..=1, 3) => [0, 0, 255, 255],
+            _ => [0, 0, 0, 255],
+        };
+
+        assert_eq!(pixel.data, expected, "pixel ({}, {})", x, y);
+    }
+}
+
+#[test]
+fn multisampled_canvas_smooths_edges() {
+    let mut gpu = Gpu::headless();
+
+    let mut triangle = Mesh::new();
+    triangle.fill(
+        Shape::Polyline {
+            points: vec![
+                Point::new(0.0, 0.0),
+                Point::new(4.0, 0.0),
+                Point::new(0.0, 2.0),
+            ],
+        },
+        Color::RED,
+    );
+
+    let mut draw = |samples| {
+        let mut canvas = Canvas::with_samples(&mut gpu, 4, 4, samples)
+            .expect("Create canvas");
+
+        {
+            let mut target = canvas.as_target(&mut gpu);
+            target.clear(Color::BLACK);
+            triangle.draw(&mut target);
+        }
+
+        (canvas.samples(), canvas.read_pixels(&mut gpu).to_rgba())
+    };
+
+    let (samples, aliased) = draw(1);
+    assert_eq!(samples, 1);
+    assert_eq!(aliased.get_pixel(1, 1).data[0], 0);
+
+    // The number of samples is rounded down to a power of two
+    let (samples, smooth) = draw(5);
+    assert_eq!(samples, 4);
+
+    let edge = smooth.get_pixel(1, 1).data[0];
+    assert!(edge > 0 && edge < 255, "edge is {}", edge);
+    assert_eq!(smooth.get_pixel(0, 0).data, [255, 0, 0, 255]);
+}
+
+#[test]
+fn rejects_unsupported_sample_counts() {
+    let mut gpu = Gpu::headless();
+
+    match Canvas::with_samples(&mut gpu, 4, 4, 40) {
+        Err(Error::UnsupportedSamples {
+            requested,
+            supported,
+        }) => {
+            assert_eq!(requested, 32);
+            assert_eq!(supported, 16);
+        }
+        result => panic!("Expected an error, got: {:?}", result.err()),
+    }
+}
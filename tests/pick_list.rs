@@ -0,0 +1,222 @@
+#![cfg(feature = "software")]
+use coffee::graphics::{Color, Frame, Window, WindowSettings};
+use coffee::input::{mouse, ButtonState, Event};
+use coffee::load::Task;
+use coffee::ui::{
+    self, pick_list, Column, Element, PickList, Renderer, UserInterface,
+};
+use coffee::{Game, Timer};
+
+// Every option takes the height of the field, which is 40 pixels high
+struct Form {
+    state: pick_list::State,
+    field_y: u32,
+    options: Vec<usize>,
+    selected: Option<usize>,
+    selections: Vec<usize>,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Message {
+    Selected(usize),
+}
+
+impl Game for Form {
+    type Input = ();
+    type LoadingScreen = ();
+
+    fn load(_window: &Window) -> Task<Form> {
+        Task::succeed(|| Form {
+            state: pick_list::State::new(),
+            field_y: 0,
+            options: Vec::new(),
+            selected: None,
+            selections: Vec::new(),
+        })
+    }
+
+    fn draw(&mut self, frame: &mut Frame<'_>, _timer: &Timer) {
+        frame.clear(Color::BLACK);
+    }
+}
+
+impl UserInterface for Form {
+    type Message = Message;
+    type Renderer = Renderer;
+
+    fn react(&mut self, message: Message, _window: &mut Window) {
+        match message {
+            Message::Selected(option) => {
+                self.selected = Some(option);
+                self.selections.push(option);
+            }
+        }
+    }
+
+    fn layout(&mut self, _window: &Window) -> Element<'_, Message> {
+        Column::new()
+            .width(800)
+            .push(Column::new().height(self.field_y))
+            .push(
+                PickList::new(
+                    &mut self.state,
+                    &self.options[..],
+                    self.selected,
+                    Message::Selected,
+                )
+                .width(200),
+            )
+            .into()
+    }
+}
+
+/// Creates a simulation with a field at the given height and the given
+/// amount of options, in a window of 800x600.
+fn form(field_y: u32, options: usize) -> ui::Simulation<Form> {
+    let mut simulation = ui::Simulation::<Form>::new(WindowSettings {
+        title: String::from("PickList test - Coffee"),
+        size: (800, 600),
+        resizable: false,
+        fullscreen: false,
+        maximized: false,
+    })
+    .expect("Load simulation");
+
+    let game = simulation.game_mut();
+    game.field_y = field_y;
+    game.options = (0..options).collect();
+
+    simulation
+}
+
+/// Feeds the given events to the simulation, one per tick.
+fn feed(simulation: &mut ui::Simulation<Form>, events: &[Event]) {
+    for event in events {
+        let tick = simulation.tick();
+
+        simulation.schedule(tick, *event);
+        simulation.step();
+    }
+}
+
+/// Moves the cursor to the given position and clicks.
+fn click(simulation: &mut ui::Simulation<Form>, x: f32, y: f32) {
+    feed(
+        simulation,
+        &[
+            Event::Mouse(mouse::Event::CursorMoved { x, y }),
+            Event::Mouse(mouse::Event::Input {
+                button: mouse::Button::Left,
+                state: ButtonState::Pressed,
+            }),
+            Event::Mouse(mouse::Event::Input {
+                button: mouse::Button::Left,
+                state: ButtonState::Released,
+            }),
+        ],
+    );
+}
+
+/// Scrolls the menu to the bottom using the mouse wheel over the given
+/// position.
+fn scroll_to_bottom(simulation: &mut ui::Simulation<Form>, x: f32, y: f32) {
+    feed(
+        simulation,
+        &[
+            Event::Mouse(mouse::Event::CursorMoved { x, y }),
+            Event::Mouse(mouse::Event::WheelScrolled {
+                delta_x: 0.0,
+                delta_y: -100.0,
+            }),
+        ],
+    );
+}
+
+fn is_open(simulation: &ui::Simulation<Form>) -> bool {
+    simulation.game().state.is_open()
+}
+
+#[test]
+fn menu_is_shown_below_the_field_when_it_fits() {
+    let mut simulation = form(100, 3);
+
+    click(&mut simulation, 50.0, 120.0);
+    assert!(is_open(&simulation));
+
+    // The options do not overflow, so there is no scrollbar on the right
+    click(&mut simulation, 195.0, 200.0);
+    assert!(!is_open(&simulation));
+    assert_eq!(simulation.game().selections, [1]);
+}
+
+#[test]
+fn menu_is_limited_to_the_viewport() {
+    let mut simulation = form(0, 20);
+
+    click(&mut simulation, 50.0, 20.0);
+    scroll_to_bottom(&mut simulation, 50.0, 300.0);
+
+    // The menu is 560 pixels high, so the last option is at the bottom
+    click(&mut simulation, 50.0, 580.0);
+    assert_eq!(simulation.game().selections, [19]);
+}
+
+#[test]
+fn menu_flips_above_the_field_when_there_is_more_room() {
+    let mut simulation = form(500, 5);
+
+    click(&mut simulation, 50.0, 520.0);
+    click(&mut simulation, 50.0, 320.0);
+
+    click(&mut simulation, 50.0, 520.0);
+    click(&mut simulation, 50.0, 490.0);
+
+    assert_eq!(simulation.game().selections, [0, 4]);
+
+    // The menu is limited to the room above the field
+    let mut simulation = form(300, 20);
+
+    click(&mut simulation, 50.0, 320.0);
+    click(&mut simulation, 50.0, 10.0);
+
+    click(&mut simulation, 50.0, 320.0);
+    scroll_to_bottom(&mut simulation, 50.0, 150.0);
+    click(&mut simulation, 50.0, 290.0);
+
+    assert_eq!(simulation.game().selections, [0, 19]);
+}
+
+#[test]
+fn options_are_found_taking_the_scroll_into_account() {
+    let mut simulation = form(0, 20);
+    simulation.game_mut().selected = Some(3);
+
+    // The menu is opened with the selected option at the top
+    click(&mut simulation, 50.0, 20.0);
+    click(&mut simulation, 50.0, 90.0);
+    assert_eq!(simulation.game().selections, [4]);
+
+    // Clicking the field closes the menu without selecting anything
+    click(&mut simulation, 50.0, 20.0);
+    click(&mut simulation, 50.0, 30.0);
+    assert!(!is_open(&simulation));
+
+    // The scrollbar is on the right side of the menu
+    click(&mut simulation, 50.0, 20.0);
+    click(&mut simulation, 195.0, 90.0);
+    assert!(is_open(&simulation));
+    assert_eq!(simulation.game().selections, [4]);
+}
+
+#[test]
+fn opening_the_menu_scrolls_to_the_selected_option() {
+    let mut simulation = form(0, 20);
+    simulation.game_mut().selected = Some(18);
+
+    click(&mut simulation, 50.0, 20.0);
+    assert!(is_open(&simulation));
+
+    // The offset is clamped to show the last options at the bottom
+    click(&mut simulation, 50.0, 60.0);
+    assert_eq!(simulation.game().selections, [6]);
+}
@@ -0,0 +1,70 @@
+#![cfg(feature = "software")]
+use coffee::graphics::{Canvas, Color, Gpu, Image, Point, Quad};
+
+fn render(colors: &[Color], quad: Quad) -> Vec<[u8; 4]> {
+    let mut gpu = Gpu::headless();
+    let image = Image::from_colors(&mut gpu, colors).expect("Create image");
+    let mut canvas = Canvas::new(&mut gpu, 4, 2).expect("Create canvas");
+
+    {
+        let mut target = canvas.as_target(&mut gpu);
+
+        target.clear(Color::BLACK);
+        image.draw(quad, &mut target);
+    }
+
+    canvas
+        .read_pixels(&mut gpu)
+        .to_rgba()
+        .pixels()
+        .map(|pixel| pixel.data)
+        .collect()
+}
+
+const RED: [u8; 4] = [255, 0, 0, 255];
+const BLUE: [u8; 4] = [0, 0, 255, 255];
+const GREEN: [u8; 4] = [0, 255, 0, 255];
+
+#[test]
+fn flips_quads() {
+    let pixels = render(
+        &[Color::RED, Color::BLUE],
+        Quad {
+            size: (4.0, 2.0),
+            flip_x: true,
+            ..Quad::default()
+        },
+    );
+
+    assert_eq!(pixels, vec![BLUE, BLUE, RED, RED, BLUE, BLUE, RED, RED]);
+}
+
+#[test]
+fn rotates_quads_around_their_origin() {
+    let pixels = render(
+        &[Color::RED, Color::BLUE],
+        Quad {
+            position: Point::new(2.0, 1.0),
+            size: (4.0, 2.0),
+            origin: Point::new(0.5, 0.5),
+            rotation: std::f32::consts::PI,
+            ..Quad::default()
+        },
+    );
+
+    assert_eq!(pixels, vec![BLUE, BLUE, RED, RED, BLUE, BLUE, RED, RED]);
+}
+
+#[test]
+fn tints_quads() {
+    let pixels = render(
+        &[Color::WHITE],
+        Quad {
+            size: (4.0, 2.0),
+            tint: Color::GREEN,
+            ..Quad::default()
+        },
+    );
+
+    assert!(pixels.iter().all(|pixel| *pixel == GREEN));
+}
@@ -0,0 +1,219 @@
+#![cfg(feature = "software")]
+use coffee::graphics::{Color, Frame, Window, WindowSettings};
+use coffee::input::keyboard::{self, KeyCode};
+use coffee::input::{mouse, ButtonState, Event};
+use coffee::load::Task;
+use coffee::ui::{
+    self, button, scrollable, Button, Column, Element, Renderer, Row,
+    Scrollable, UserInterface,
+};
+use coffee::{Game, Timer};
+
+// The scrollable is 100 pixels high and its content is 400 pixels high, so
+// the maximum offset is 300 and the scroller is 25 pixels high
+const ITEMS: usize = 8;
+
+struct List {
+    scrollable: scrollable::State,
+    items: Vec<button::State>,
+    outside: button::State,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Message {
+    Pressed,
+}
+
+impl Game for List {
+    type Input = ();
+    type LoadingScreen = ();
+
+    fn load(_window: &Window) -> Task<List> {
+        Task::succeed(|| List {
+            scrollable: scrollable::State::new(),
+            items: vec![button::State::new(); ITEMS],
+            outside: button::State::new(),
+        })
+    }
+
+    fn draw(&mut self, frame: &mut Frame<'_>, _timer: &Timer) {
+        frame.clear(Color::BLACK);
+    }
+}
+
+impl UserInterface for List {
+    type Message = Message;
+    type Renderer = Renderer;
+
+    fn react(&mut self, _message: Message, _window: &mut Window) {}
+
+    fn layout(&mut self, _window: &Window) -> Element<'_, Message> {
+        let items =
+            self.items.iter_mut().fold(Column::new(), |column, state| {
+                column
+                    .push(Button::new(state, "Item").on_press(Message::Pressed))
+            });
+
+        Row::new()
+            .width(200)
+            .push(
+                Scrollable::new(&mut self.scrollable, items)
+                    .width(100)
+                    .height(100),
+            )
+            .push(
+                Button::new(&mut self.outside, "Outside")
+                    .on_press(Message::Pressed),
+            )
+            .into()
+    }
+}
+
+fn list() -> ui::Simulation<List> {
+    ui::Simulation::<List>::new(WindowSettings {
+        title: String::from("Scrollable test - Coffee"),
+        size: (200, 100),
+        resizable: false,
+        fullscreen: false,
+        maximized: false,
+    })
+    .expect("Load simulation")
+}
+
+/// Feeds the given events to the simulation, one per tick.
+fn feed(simulation: &mut ui::Simulation<List>, events: &[Event]) {
+    for event in events {
+        let tick = simulation.tick();
+
+        simulation.schedule(tick, *event);
+        simulation.step();
+    }
+}
+
+fn offset(simulation: &ui::Simulation<List>) -> f32 {
+    simulation.game().scrollable.offset()
+}
+
+fn move_cursor(x: f32, y: f32) -> Event {
+    Event::Mouse(mouse::Event::CursorMoved { x, y })
+}
+
+fn click(state: ButtonState) -> Event {
+    Event::Mouse(mouse::Event::Input {
+        button: mouse::Button::Left,
+        state,
+    })
+}
+
+fn key(key_code: KeyCode, state: ButtonState) -> Event {
+    Event::Keyboard(keyboard::Event::Input { key_code, state })
+}
+
+fn tab() -> Event {
+    key(KeyCode::Tab, ButtonState::Pressed)
+}
+
+#[test]
+fn clamps_the_offset_to_the_content() {
+    let mut simulation = list();
+
+    simulation.game_mut().scrollable.scroll_to(500.0);
+    feed(&mut simulation, &[move_cursor(50.0, 50.0)]);
+    assert_eq!(offset(&simulation), 300.0);
+
+    simulation.game_mut().scrollable.scroll_to(-10.0);
+    feed(&mut simulation, &[move_cursor(50.0, 60.0)]);
+    assert_eq!(offset(&simulation), 0.0);
+
+    // Content that fits cannot be scrolled
+    simulation.game_mut().items.truncate(1);
+    simulation.game_mut().scrollable.scroll_to(50.0);
+    feed(&mut simulation, &[move_cursor(50.0, 50.0)]);
+    assert_eq!(offset(&simulation), 0.0);
+}
+
+#[test]
+fn scrolls_with_the_mouse_wheel() {
+    let mut simulation = list();
+    let wheel = |delta_y| {
+        Event::Mouse(mouse::Event::WheelScrolled {
+            delta_x: 0.0,
+            delta_y,
+        })
+    };
+
+    feed(&mut simulation, &[move_cursor(50.0, 50.0), wheel(-1.0)]);
+    assert_eq!(offset(&simulation), 60.0);
+
+    feed(&mut simulation, &[wheel(-10.0)]);
+    assert_eq!(offset(&simulation), 300.0);
+
+    // The wheel only scrolls when the cursor is over the scrollable
+    feed(&mut simulation, &[move_cursor(150.0, 50.0), wheel(1.0)]);
+    assert_eq!(offset(&simulation), 300.0);
+}
+
+#[test]
+fn drags_the_grabbed_scroller() {
+    let mut simulation = list();
+
+    feed(&mut simulation, &[move_cursor(95.0, 37.5)]);
+    assert_eq!(offset(&simulation), 0.0);
+
+    feed(
+        &mut simulation,
+        &[
+            move_cursor(95.0, 0.0),
+            click(ButtonState::Pressed),
+            move_cursor(95.0, 37.5),
+        ],
+    );
+    assert!(simulation.game().scrollable.is_scroller_grabbed());
+    assert_eq!(offset(&simulation), 150.0);
+
+    feed(&mut simulation, &[move_cursor(95.0, 200.0)]);
+    assert_eq!(offset(&simulation), 300.0);
+
+    feed(&mut simulation, &[move_cursor(95.0, -50.0)]);
+    assert_eq!(offset(&simulation), 0.0);
+
+    feed(
+        &mut simulation,
+        &[click(ButtonState::Released), move_cursor(95.0, 37.5)],
+    );
+    assert!(!simulation.game().scrollable.is_scroller_grabbed());
+    assert_eq!(offset(&simulation), 0.0);
+}
+
+#[test]
+fn reveals_focused_content() {
+    let mut simulation = list();
+
+    // Already visible
+    feed(&mut simulation, &[tab(), tab()]);
+    assert_eq!(offset(&simulation), 0.0);
+
+    // Below the visible area
+    feed(&mut simulation, &[tab(), tab()]);
+    assert_eq!(offset(&simulation), 100.0);
+
+    // Above the visible area
+    feed(
+        &mut simulation,
+        &[key(KeyCode::LShift, ButtonState::Pressed), tab(), tab()],
+    );
+    assert_eq!(offset(&simulation), 50.0);
+}
+
+#[test]
+fn ignores_focus_outside_of_the_content() {
+    let mut simulation = list();
+
+    // Focusing backwards wraps around to the button next to the scrollable
+    feed(
+        &mut simulation,
+        &[key(KeyCode::LShift, ButtonState::Pressed), tab()],
+    );
+
+    assert_eq!(offset(&simulation), 0.0);
+}
@@ -0,0 +1,43 @@
+#![cfg(feature = "software")]
+use coffee::graphics::{Batch, Canvas, Color, Gpu, Image, Quad, Shader};
+
+#[test]
+fn software_ignores_shaders() {
+    let mut gpu = Gpu::headless();
+    let image = Image::from_colors(&mut gpu, &[Color::RED]).expect("Image");
+    let mut canvas = Canvas::new(&mut gpu, 1, 1).expect("Create canvas");
+    let mut shader = Shader::for_quads(&mut gpu, b"").expect("Create shader");
+
+    shader.set_uniform(0, [1.0, 0.0, 0.0, 1.0]);
+
+    {
+        let mut batch = Batch::new(image);
+        batch.add(Quad {
+            size: (1.0, 1.0),
+            ..Quad::default()
+        });
+
+        let mut target = canvas.as_target(&mut gpu);
+
+        target.clear(Color::BLACK);
+        batch.draw_with_shader(&shader, &mut target);
+    }
+
+    let pixels = canvas.read_pixels(&mut gpu).to_rgba();
+
+    assert_eq!(pixels.get_pixel(0, 0).data, [255, 0, 0, 255]);
+}
+
+#[test]
+#[should_panic]
+fn mesh_shaders_cannot_draw_quads() {
+    let mut gpu = Gpu::headless();
+    let image = Image::from_colors(&mut gpu, &[Color::RED]).expect("Image");
+    let mut canvas = Canvas::new(&mut gpu, 1, 1).expect("Create canvas");
+    let shader = Shader::for_meshes(&mut gpu, b"").expect("Create shader");
+
+    let batch = Batch::new(image);
+    let mut target = canvas.as_target(&mut gpu);
+
+    batch.draw_with_shader(&shader, &mut target);
+}
@@ -0,0 +1,143 @@
+#![cfg(feature = "software")]
+use coffee::graphics::{Color, Frame, Window, WindowSettings};
+use coffee::input::{keyboard, ButtonState, Event, Keyboard, Recording};
+use coffee::load::Task;
+use coffee::{Game, Simulation, Timer};
+
+struct Counter {
+    presses: u32,
+    updates: u32,
+}
+
+impl Game for Counter {
+    type Input = Keyboard;
+    type LoadingScreen = ();
+
+    fn load(_window: &Window) -> Task<Counter> {
+        Task::succeed(|| Counter {
+            presses: 0,
+            updates: 0,
+        })
+    }
+
+    fn interact(&mut self, keyboard: &mut Keyboard, _window: &mut Window) {
+        if keyboard.was_key_released(keyboard::KeyCode::Space) {
+            self.presses += 1;
+        }
+    }
+
+    fn update(&mut self, _window: &Window) {
+        self.updates += 1;
+    }
+
+    fn draw(&mut self, frame: &mut Frame<'_>, _timer: &Timer) {
+        frame.clear(Color::RED);
+    }
+}
+
+fn settings() -> WindowSettings {
+    WindowSettings {
+        title: String::from("Simulation test - Coffee"),
+        size: (64, 48),
+        resizable: false,
+        fullscreen: false,
+        maximized: false,
+    }
+}
+
+fn space(state: ButtonState) -> Event {
+    Event::Keyboard(keyboard::Event::Input {
+        state,
+        key_code: keyboard::KeyCode::Space,
+    })
+}
+
+#[test]
+fn feeds_scheduled_events_on_their_tick() {
+    let mut simulation =
+        Simulation::<Counter>::new(settings()).expect("Load simulation");
+
+    simulation.schedule(5, space(ButtonState::Pressed));
+    simulation.schedule(6, space(ButtonState::Released));
+
+    simulation.run(6);
+    assert_eq!(simulation.game().presses, 0);
+
+    simulation.run(1);
+    assert_eq!(simulation.game().presses, 1);
+    assert_eq!(simulation.game().updates, 7);
+    assert_eq!(simulation.tick(), 7);
+}
+
+#[test]
+fn recordings_keep_events_ordered_by_tick() {
+    let mut recording = Recording::new();
+
+    recording.push(3, space(ButtonState::Pressed));
+    recording.push(1, space(ButtonState::Released));
+    recording.push(3, space(ButtonState::Released));
+    recording.push(0, space(ButtonState::Pressed));
+
+    assert_eq!(
+        recording.events(),
+        &[
+            (0, space(ButtonState::Pressed)),
+            (1, space(ButtonState::Released)),
+            (3, space(ButtonState::Pressed)),
+            (3, space(ButtonState::Released)),
+        ][..]
+    );
+}
+
+#[test]
+fn draws_frames_into_a_canvas() {
+    let mut simulation =
+        Simulation::<Counter>::new(settings()).expect("Load simulation");
+
+    simulation.run(1);
+
+    let canvas = simulation.draw();
+    let image = canvas.read_pixels(simulation.gpu()).to_rgba();
+
+    assert_eq!(image.dimensions(), (64, 48));
+    assert!(image.pixels().all(|pixel| pixel.data == [255, 0, 0, 255]));
+}
+
+struct Photographer {
+    screenshot: Option<image::DynamicImage>,
+}
+
+impl Game for Photographer {
+    type Input = ();
+    type LoadingScreen = ();
+
+    fn load(_window: &Window) -> Task<Photographer> {
+        Task::succeed(|| Photographer { screenshot: None })
+    }
+
+    fn draw(&mut self, frame: &mut Frame<'_>, _timer: &Timer) {
+        frame.clear(Color::BLUE);
+
+        self.screenshot = Some(frame.screenshot());
+    }
+}
+
+#[test]
+fn takes_screenshots_of_frames() {
+    let mut simulation =
+        Simulation::<Photographer>::new(settings()).expect("Load simulation");
+
+    let _ = simulation.draw();
+
+    let screenshot = simulation
+        .game()
+        .screenshot
+        .as_ref()
+        .expect("Screenshot")
+        .to_rgba();
+
+    assert_eq!(screenshot.dimensions(), (64, 48));
+    assert!(screenshot
+        .pixels()
+        .all(|pixel| pixel.data == [0, 0, 255, 255]));
+}
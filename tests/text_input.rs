@@ -0,0 +1,284 @@
+#![cfg(feature = "software")]
+use coffee::graphics::{Color, Frame, Window, WindowSettings};
+use coffee::input::keyboard::{self, KeyCode};
+use coffee::input::{ButtonState, Event};
+use coffee::load::Task;
+use coffee::ui::{
+    self, text_input, Element, Renderer, TextInput, UserInterface,
+};
+use coffee::{Game, Timer};
+
+struct Form {
+    state: text_input::State,
+    value: String,
+    max_length: Option<usize>,
+    changes: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    Changed(String),
+}
+
+impl Game for Form {
+    type Input = ();
+    type LoadingScreen = ();
+
+    fn load(_window: &Window) -> Task<Form> {
+        Task::succeed(|| Form {
+            state: text_input::State::focused(),
+            value: String::new(),
+            max_length: None,
+            changes: Vec::new(),
+        })
+    }
+
+    fn draw(&mut self, frame: &mut Frame<'_>, _timer: &Timer) {
+        frame.clear(Color::BLACK);
+    }
+}
+
+impl UserInterface for Form {
+    type Message = Message;
+    type Renderer = Renderer;
+
+    fn react(&mut self, message: Message, _window: &mut Window) {
+        match message {
+            Message::Changed(value) => {
+                self.value = value.clone();
+                self.changes.push(value);
+            }
+        }
+    }
+
+    fn layout(&mut self, _window: &Window) -> Element<'_, Message> {
+        let input =
+            TextInput::new(&mut self.state, "", &self.value, Message::Changed);
+
+        match self.max_length {
+            Some(max_length) => input.max_length(max_length).into(),
+            None => input.into(),
+        }
+    }
+}
+
+fn form(value: &str) -> ui::Simulation<Form> {
+    let mut simulation = ui::Simulation::<Form>::new(WindowSettings {
+        title: String::from("TextInput test - Coffee"),
+        size: (200, 100),
+        resizable: false,
+        fullscreen: false,
+        maximized: false,
+    })
+    .expect("Load simulation");
+
+    simulation.game_mut().value = String::from(value);
+    simulation
+}
+
+/// Feeds the given events to the simulation, one per tick.
+fn feed(simulation: &mut ui::Simulation<Form>, events: &[Event]) {
+    for event in events {
+        let tick = simulation.tick();
+
+        simulation.schedule(tick, *event);
+        simulation.step();
+    }
+}
+
+fn key(key_code: KeyCode, state: ButtonState) -> Event {
+    Event::Keyboard(keyboard::Event::Input { key_code, state })
+}
+
+fn press(key_code: KeyCode) -> Event {
+    key(key_code, ButtonState::Pressed)
+}
+
+fn text(character: char) -> Event {
+    Event::Keyboard(keyboard::Event::TextEntered { character })
+}
+
+#[test]
+fn inserts_at_the_cursor() {
+    let mut simulation = form("ác");
+
+    feed(&mut simulation, &[press(KeyCode::Right), text('b')]);
+
+    let game = simulation.game();
+    assert_eq!(game.changes, ["ábc"]);
+    assert_eq!(game.state.cursor(), 2);
+}
+
+#[test]
+fn inserts_replacing_the_selection() {
+    let mut simulation = form("añoé");
+
+    feed(
+        &mut simulation,
+        &[
+            press(KeyCode::Right),
+            press(KeyCode::LShift),
+            press(KeyCode::Right),
+            press(KeyCode::Right),
+            key(KeyCode::LShift, ButtonState::Released),
+            text('x'),
+        ],
+    );
+
+    let game = simulation.game();
+    assert_eq!(game.changes, ["axé"]);
+    assert_eq!(game.state.cursor(), 2);
+    assert_eq!(game.state.selection(), None);
+}
+
+#[test]
+fn ignores_characters_beyond_max_length() {
+    let mut simulation = form("ñé");
+    simulation.game_mut().max_length = Some(2);
+
+    feed(&mut simulation, &[press(KeyCode::End), text('x')]);
+
+    assert!(simulation.game().changes.is_empty());
+    assert_eq!(simulation.game().state.cursor(), 2);
+
+    // Replacing a selection frees space for the new character
+    feed(
+        &mut simulation,
+        &[
+            press(KeyCode::LShift),
+            press(KeyCode::Left),
+            key(KeyCode::LShift, ButtonState::Released),
+            text('x'),
+        ],
+    );
+
+    assert_eq!(simulation.game().changes, ["ñx"]);
+}
+
+#[test]
+fn removes_characters_around_the_cursor() {
+    let mut backspace = form("añb");
+    feed(
+        &mut backspace,
+        &[
+            press(KeyCode::Right),
+            press(KeyCode::Right),
+            press(KeyCode::Back),
+        ],
+    );
+
+    let mut delete = form("añb");
+    feed(
+        &mut delete,
+        &[press(KeyCode::Right), press(KeyCode::Delete)],
+    );
+
+    assert_eq!(backspace.game().changes, ["ab"]);
+    assert_eq!(delete.game().changes, ["ab"]);
+    assert_eq!(backspace.game().state.cursor(), 1);
+    assert_eq!(delete.game().state.cursor(), 1);
+}
+
+#[test]
+fn removes_nothing_at_the_edges() {
+    let mut simulation = form("ñ");
+
+    feed(
+        &mut simulation,
+        &[
+            press(KeyCode::Back),
+            press(KeyCode::End),
+            press(KeyCode::Delete),
+        ],
+    );
+
+    assert!(simulation.game().changes.is_empty());
+}
+
+#[test]
+fn removes_the_selection() {
+    let mut simulation = form("éñb");
+
+    feed(
+        &mut simulation,
+        &[
+            press(KeyCode::LShift),
+            press(KeyCode::Right),
+            press(KeyCode::Right),
+            key(KeyCode::LShift, ButtonState::Released),
+            press(KeyCode::Back),
+        ],
+    );
+
+    let game = simulation.game();
+    assert_eq!(game.changes, ["b"]);
+    assert_eq!(game.state.cursor(), 0);
+    assert_eq!(game.state.selection(), None);
+}
+
+#[test]
+fn moves_the_cursor_extending_the_selection_with_shift() {
+    let mut simulation = form("abcd");
+    let selection =
+        |simulation: &ui::Simulation<Form>| simulation.game().state.selection();
+
+    feed(
+        &mut simulation,
+        &[press(KeyCode::Right), press(KeyCode::Right)],
+    );
+    assert_eq!(selection(&simulation), None);
+
+    feed(
+        &mut simulation,
+        &[press(KeyCode::LShift), press(KeyCode::Right)],
+    );
+    assert_eq!(selection(&simulation), Some((2, 3)));
+
+    feed(&mut simulation, &[press(KeyCode::Home)]);
+    assert_eq!(selection(&simulation), Some((0, 2)));
+
+    // Returning to the anchor clears the selection
+    feed(
+        &mut simulation,
+        &[press(KeyCode::Right), press(KeyCode::Right)],
+    );
+    assert_eq!(selection(&simulation), None);
+
+    feed(
+        &mut simulation,
+        &[
+            press(KeyCode::Left),
+            key(KeyCode::LShift, ButtonState::Released),
+            press(KeyCode::End),
+        ],
+    );
+    assert_eq!(selection(&simulation), None);
+    assert_eq!(simulation.game().state.cursor(), 4);
+}
+
+#[test]
+fn clamps_positions_to_the_value() {
+    let mut simulation = form("abcde");
+
+    feed(
+        &mut simulation,
+        &[
+            press(KeyCode::Right),
+            press(KeyCode::LShift),
+            press(KeyCode::End),
+        ],
+    );
+
+    // The application changes the value on its own
+    simulation.game_mut().value = String::from("abc");
+    feed(&mut simulation, &[press(KeyCode::LShift)]);
+
+    assert_eq!(simulation.game().state.cursor(), 3);
+    assert_eq!(simulation.game().state.selection(), Some((1, 3)));
+
+    simulation.game_mut().value = String::from("a");
+    feed(&mut simulation, &[press(KeyCode::LShift)]);
+
+    assert_eq!(simulation.game().state.cursor(), 1);
+    assert_eq!(simulation.game().state.selection(), None);
+}
@@ -0,0 +1,63 @@
+use coffee::graphics::{Color, Rectangle};
+use coffee::ui::theme::{Colors, Sprites};
+use coffee::ui::Theme;
+
+#[test]
+fn empty_theme_files_are_the_default_theme() {
+    let theme: Theme = serde_json::from_str("{}").unwrap();
+
+    assert_eq!(theme, Theme::default());
+}
+
+#[test]
+fn missing_fields_take_their_default_value() {
+    let theme: Theme = serde_json::from_str(r#"{ "padding": 4.0 }"#).unwrap();
+
+    assert_eq!(
+        theme,
+        Theme {
+            padding: 4.0,
+            ..Theme::default()
+        }
+    );
+}
+
+#[test]
+fn missing_colors_and_sprites_take_their_default_value() {
+    let theme: Theme = serde_json::from_str(
+        r#"{
+            "colors": {
+                "accent": { "r": 0.9, "g": 0.5, "b": 0.2, "a": 1.0 }
+            },
+            "sprites": {
+                "slider_rail": { "x": 1, "y": 2, "width": 3, "height": 4 }
+            }
+        }"#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        theme,
+        Theme {
+            colors: Colors {
+                accent: Color {
+                    r: 0.9,
+                    g: 0.5,
+                    b: 0.2,
+                    a: 1.0,
+                },
+                ..Colors::default()
+            },
+            sprites: Sprites {
+                slider_rail: Rectangle {
+                    x: 1,
+                    y: 2,
+                    width: 3,
+                    height: 4,
+                },
+                ..Sprites::default()
+            },
+            ..Theme::default()
+        }
+    );
+}
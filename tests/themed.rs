@@ -0,0 +1,177 @@
+#![cfg(feature = "software")]
+use coffee::graphics::{
+    Color, Frame, Image, Rectangle, Window, WindowSettings,
+};
+use coffee::load::Task;
+use coffee::ui::theme::NineSlice;
+use coffee::ui::{
+    self, text_input, Column, Configuration, Element, Panel, Renderer, Row,
+    TextInput, Theme, Themed, UserInterface,
+};
+use coffee::{Game, Timer};
+
+const RED: [u8; 4] = [255, 0, 0, 255];
+const GREEN: [u8; 4] = [0, 255, 0, 255];
+const BLUE: [u8; 4] = [0, 0, 255, 255];
+const BLACK: [u8; 4] = [0, 0, 0, 255];
+
+// The spritesheet is a green square of 3x3 pixels followed by a blue column
+fn spritesheet() -> image::DynamicImage {
+    image::DynamicImage::ImageRgba8(image::RgbaImage::from_fn(4, 3, |x, _| {
+        image::Rgba(if x < 3 { GREEN } else { BLUE })
+    }))
+}
+
+fn panel(source: Rectangle<u16>, border: u16) -> NineSlice {
+    NineSlice {
+        source,
+        left: border,
+        top: border,
+        right: border,
+        bottom: border,
+    }
+}
+
+/// Draws some text inputs in the first row and some panels in the second
+/// one. The middle and last widgets are wrapped in a [`Themed`] widget.
+struct Gallery {
+    fields: [text_input::State; 3],
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    Changed(String),
+}
+
+impl Game for Gallery {
+    type Input = ();
+    type LoadingScreen = ();
+
+    fn load(_window: &Window) -> Task<Gallery> {
+        Task::succeed(|| Gallery {
+            fields: [text_input::State::new(); 3],
+        })
+    }
+
+    fn draw(&mut self, frame: &mut Frame<'_>, _timer: &Timer) {
+        frame.clear(Color::BLACK);
+    }
+}
+
+impl UserInterface for Gallery {
+    type Message = Message;
+    type Renderer = Renderer;
+
+    fn react(&mut self, _message: Message, _window: &mut Window) {}
+
+    fn layout(&mut self, _window: &Window) -> Element<'_, Message> {
+        let mut blue = Theme::default();
+        blue.colors.background = Color::BLUE;
+        blue.sprites.panel = panel(
+            Rectangle {
+                x: 3,
+                y: 0,
+                width: 1,
+                height: 3,
+            },
+            0,
+        );
+
+        // The borders of the panel do not fit in its source
+        let mut oversized = Theme::default();
+        oversized.sprites.panel = panel(
+            Rectangle {
+                x: 0,
+                y: 0,
+                width: 3,
+                height: 3,
+            },
+            2,
+        );
+
+        let [first, second, third] = &mut self.fields;
+
+        let fields = Row::new()
+            .push(field(first))
+            .push(Themed::new(blue, field(second)))
+            .push(field(third));
+
+        let empty_panel = || Panel::new(Column::new()).width(100);
+
+        let panels = Row::new()
+            .push(empty_panel())
+            .push(Themed::new(blue, empty_panel()))
+            .push(Themed::new(oversized, empty_panel()));
+
+        Column::new().width(300).push(fields).push(panels).into()
+    }
+
+    fn configuration() -> Configuration {
+        let mut theme = Theme::default();
+        theme.colors.background = Color::RED;
+        theme.sprites.panel = panel(
+            Rectangle {
+                x: 0,
+                y: 0,
+                width: 3,
+                height: 3,
+            },
+            1,
+        );
+
+        Configuration {
+            sprites: Task::using_gpu(|gpu| {
+                Image::from_image(gpu, &spritesheet())
+            }),
+            theme: Task::succeed(move || theme),
+            ..Configuration::default()
+        }
+    }
+}
+
+fn field(state: &mut text_input::State) -> TextInput<'_, Message> {
+    TextInput::new(state, "", "", Message::Changed).width(100)
+}
+
+fn draw() -> image::RgbaImage {
+    let mut simulation = ui::Simulation::<Gallery>::new(WindowSettings {
+        title: String::from("Themed test - Coffee"),
+        size: (300, 80),
+        resizable: false,
+        fullscreen: false,
+        maximized: false,
+    })
+    .expect("Load simulation");
+
+    let canvas = simulation.draw();
+
+    canvas.read_pixels(simulation.gpu()).to_rgba()
+}
+
+#[test]
+fn draws_shapes_with_the_colors_of_the_theme() {
+    let pixels = draw();
+
+    assert_eq!(pixels.get_pixel(50, 20).data, RED);
+    assert_eq!(pixels.get_pixel(150, 20).data, BLUE);
+
+    // The theme is restored after the themed widget
+    assert_eq!(pixels.get_pixel(250, 20).data, RED);
+}
+
+#[test]
+fn draws_the_sprites_of_the_theme() {
+    let pixels = draw();
+
+    assert_eq!(pixels.get_pixel(50, 60).data, GREEN);
+    assert_eq!(pixels.get_pixel(150, 60).data, BLUE);
+}
+
+#[test]
+fn draws_only_the_corners_of_oversized_slices() {
+    let pixels = draw();
+
+    assert_eq!(pixels.get_pixel(200, 40).data, GREEN);
+    assert_eq!(pixels.get_pixel(299, 79).data, GREEN);
+    assert_eq!(pixels.get_pixel(250, 60).data, BLACK);
+}
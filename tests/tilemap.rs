@@ -0,0 +1,185 @@
+#![cfg(feature = "software")]
+use coffee::graphics::tilemap::{
+    self, Layer, Property, Tile, Tilemap, Tileset,
+};
+use coffee::graphics::{Canvas, Color, Gpu, Image, Rectangle};
+
+#[test]
+fn draws_only_visible_tiles() {
+    let mut gpu = Gpu::headless();
+    let image = Image::from_colors(&mut gpu, &[Color::RED, Color::GREEN])
+        .expect("Image");
+    let mut canvas = Canvas::new(&mut gpu, 2, 2).expect("Create canvas");
+
+    let mut tilemap = Tilemap::empty(2, 2, 1, 1);
+    let first_id = tilemap.add_tileset(Tileset::from_image(image, 1, 1));
+
+    let mut layer = Layer::new("ground", 2, 2);
+
+    for x in 0..2 {
+        for y in 0..2 {
+            layer.set_tile(x, y, Some(Tile::new(first_id + y)));
+        }
+    }
+
+    tilemap.add_layer(layer);
+
+    {
+        let mut target = canvas.as_target(&mut gpu);
+
+        target.clear(Color::BLACK);
+        tilemap.draw(
+            Rectangle {
+                x: 0.0,
+                y: 0.0,
+                width: 1.0,
+                height: 2.0,
+            },
+            &mut target,
+        );
+    }
+
+    let pixels = canvas.read_pixels(&mut gpu).to_rgba();
+
+    assert_eq!(pixels.get_pixel(0, 0).data, [255, 0, 0, 255]);
+    assert_eq!(pixels.get_pixel(0, 1).data, [0, 255, 0, 255]);
+    assert_eq!(pixels.get_pixel(1, 0).data, [0, 0, 0, 255]);
+    assert_eq!(pixels.get_pixel(1, 1).data, [0, 0, 0, 255]);
+}
+
+#[test]
+fn loads_tiled_json_maps() {
+    let directory = std::env::temp_dir().join("coffee_tilemap_json");
+    std::fs::create_dir_all(&directory).expect("Create directory");
+
+    image::RgbaImage::from_pixel(2, 1, image::Rgba([255, 255, 255, 255]))
+        .save(directory.join("tiles.png"))
+        .expect("Save tileset image");
+
+    std::fs::write(
+        directory.join("map.json"),
+        r#"{
+            "width": 2,
+            "height": 1,
+            "tilewidth": 1,
+            "tileheight": 1,
+            "orientation": "orthogonal",
+            "infinite": false,
+            "properties": [
+                { "name": "title", "type": "string", "value": "Test" }
+            ],
+            "tilesets": [{
+                "firstgid": 1,
+                "tilewidth": 1,
+                "tileheight": 1,
+                "image": "tiles.png",
+                "tiles": [{
+                    "id": 1,
+                    "properties": [
+                        { "name": "solid", "type": "bool", "value": true }
+                    ]
+                }]
+            }],
+            "layers": [{
+                "type": "tilelayer",
+                "name": "ground",
+                "width": 2,
+                "height": 1,
+                "data": [1, 2147483650]
+            }]
+        }"#,
+    )
+    .expect("Save map");
+
+    let mut gpu = Gpu::headless();
+    let tilemap =
+        Tilemap::new(&mut gpu, directory.join("map.json")).expect("Load map");
+
+    let layer = tilemap.layer("ground").expect("Ground layer");
+    let solid = layer.tile(1, 0).expect("Solid tile");
+
+    assert_eq!(layer.tile(0, 0), Some(Tile::new(1)));
+    assert_eq!(solid.id, 2);
+    assert!(solid.flip_x);
+    assert_eq!(
+        tilemap
+            .tile_properties(solid)
+            .and_then(|properties| properties.get("solid")),
+        Some(&Property::Bool(true))
+    );
+    assert_eq!(
+        tilemap.properties.get("title"),
+        Some(&Property::String(String::from("Test")))
+    );
+}
+
+#[test]
+fn rejects_huge_tile_ids() {
+    let directory = std::env::temp_dir().join("coffee_tilemap_huge_ids");
+    std::fs::create_dir_all(&directory).expect("Create directory");
+
+    std::fs::write(
+        directory.join("map.json"),
+        r#"{
+            "width": 1,
+            "height": 1,
+            "tilewidth": 1,
+            "tileheight": 1,
+            "tilesets": [{
+                "firstgid": 1,
+                "tiles": [{
+                    "id": 4294967295,
+                    "image": "tile.png",
+                    "imagewidth": 1,
+                    "imageheight": 1
+                }]
+            }],
+            "layers": []
+        }"#,
+    )
+    .expect("Save map");
+
+    let mut gpu = Gpu::headless();
+
+    match Tilemap::new(&mut gpu, directory.join("map.json")) {
+        Err(coffee::Error::Tilemap(tilemap::Error::Invalid(_))) => {}
+        result => panic!("Expected an invalid map, got: {:?}", result.err()),
+    }
+}
+
+#[test]
+fn rejects_tilesets_not_fitting_their_image() {
+    let directory = std::env::temp_dir().join("coffee_tilemap_huge_margin");
+    std::fs::create_dir_all(&directory).expect("Create directory");
+
+    image::RgbaImage::from_pixel(2, 1, image::Rgba([255, 255, 255, 255]))
+        .save(directory.join("tiles.png"))
+        .expect("Save tileset image");
+
+    std::fs::write(
+        directory.join("map.json"),
+        r#"{
+            "width": 1,
+            "height": 1,
+            "tilewidth": 1,
+            "tileheight": 1,
+            "tilesets": [{
+                "firstgid": 1,
+                "tilewidth": 1,
+                "tileheight": 1,
+                "margin": 40000,
+                "spacing": 65535,
+                "image": "tiles.png"
+            }],
+            "layers": []
+        }"#,
+    )
+    .expect("Save map");
+
+    let mut gpu = Gpu::headless();
+
+    match Tilemap::new(&mut gpu, directory.join("map.json")) {
+        Err(coffee::Error::Tilemap(tilemap::Error::Invalid(_))) => {}
+        result => panic!("Expected an invalid map, got: {:?}", result.err()),
+    }
+}